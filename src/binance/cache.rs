@@ -0,0 +1,290 @@
+//! Response caches for account endpoints and exchange metadata
+//!
+//! Reading the balances resource and then calling `get_account` a moment later
+//! would otherwise cost two signed requests and their request weight. Responses
+//! are cached per endpoint and credentials for a short TTL and dropped as soon
+//! as an order is placed or canceled so reads never lag behind the caller's
+//! own writes.
+//!
+//! `exchangeInfo` (weight 20) changes only when pairs are listed or halted, so
+//! it is cached for minutes rather than seconds and refetched once it expires.
+//!
+//! Public ticker/price responses can additionally be kept as last-known-good
+//! values and served, marked stale, while Binance returns 5xx or times out.
+//! Account and order data never falls back this way.
+//!
+//! ## Configuration
+//!
+//! - `ACCOUNT_CACHE_TTL_MS`: Cache window in milliseconds (default: 2000, `0` disables)
+//! - `EXCHANGE_INFO_CACHE_TTL_SECS`: exchangeInfo cache window in seconds (default: 600, `0` disables)
+//! - `ALLOW_STALE_ON_OUTAGE`: Serve last-known-good ticker/price data during outages (default: false)
+
+use crate::binance::types::{AccountInfo, Order};
+use crate::error::McpError;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default account cache TTL
+pub const DEFAULT_ACCOUNT_CACHE_TTL: Duration = Duration::from_millis(2000);
+
+/// Default exchangeInfo cache TTL
+pub const DEFAULT_EXCHANGE_INFO_TTL: Duration = Duration::from_secs(600);
+
+/// Parses `EXCHANGE_INFO_CACHE_TTL_SECS`, falling back to the default on missing or invalid input
+pub fn parse_exchange_info_ttl(value: Option<&str>) -> Duration {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_EXCHANGE_INFO_TTL)
+}
+
+/// Parses `ACCOUNT_CACHE_TTL_MS`, falling back to the default on missing or invalid input
+pub fn parse_cache_ttl(value: Option<&str>) -> Duration {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_ACCOUNT_CACHE_TTL)
+}
+
+/// Builds a cache key from the endpoint and a hash of the API key
+///
+/// The API key itself is never stored; only its hash is part of the key.
+pub fn cache_key(endpoint: &str, base_url: &str, api_key: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    api_key.hash(&mut hasher);
+    format!("{}|{}|{:016x}", endpoint, base_url, hasher.finish())
+}
+
+/// Thread-safe map of values that expire after a fixed TTL
+#[derive(Debug, Clone)]
+pub struct TtlCache<V> {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, (Instant, V)>>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    /// Creates a cache; a zero `ttl` disables caching
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the cached value if it is younger than the TTL
+    pub fn get(&self, key: &str) -> Option<V> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        let entries = self.entries.lock().expect("Cache lock poisoned");
+        entries
+            .get(key)
+            .filter(|(stored_at, _)| stored_at.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Stores a value (no-op when caching is disabled)
+    pub fn insert(&self, key: String, value: V) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("Cache lock poisoned");
+        entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    /// Drops every cached value
+    pub fn clear(&self) {
+        self.entries.lock().expect("Cache lock poisoned").clear();
+    }
+}
+
+/// Cached account info and open orders, shared across client clones
+#[derive(Debug, Clone)]
+pub struct AccountCache {
+    /// `GET /api/v3/account` responses
+    pub account: TtlCache<AccountInfo>,
+    /// `GET /api/v3/openOrders` responses (key includes the symbol filter)
+    pub open_orders: TtlCache<Vec<Order>>,
+}
+
+impl AccountCache {
+    /// Creates an account cache with the given TTL (zero disables caching)
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            account: TtlCache::new(ttl),
+            open_orders: TtlCache::new(ttl),
+        }
+    }
+
+    /// Drops all cached account state (called after order placement/cancellation)
+    pub fn invalidate(&self) {
+        self.account.clear();
+        self.open_orders.clear();
+    }
+}
+
+/// Last successful market-data responses, served during Binance outages
+///
+/// When disabled, [`LastKnownGood::resolve`] passes results through untouched
+/// and stores nothing.
+#[derive(Debug, Clone, Default)]
+pub struct LastKnownGood {
+    enabled: bool,
+    /// Key -> (stored at, ms since Unix epoch; response body)
+    entries: Arc<Mutex<HashMap<String, (i64, serde_json::Value)>>>,
+}
+
+impl LastKnownGood {
+    /// Creates the fallback cache (`ALLOW_STALE_ON_OUTAGE`)
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Whether stale fallback is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Remembers a fresh response, or replaces an outage error with the last one
+    ///
+    /// On an outage ([`McpError::is_outage`]) with a cached object body, returns
+    /// that body with `stale: true` and `as_of` (ms since Unix epoch when it was
+    /// fetched) added. Fresh bodies are returned unchanged, and every other
+    /// error is returned as is.
+    pub fn resolve(
+        &self,
+        key: &str,
+        fetched: Result<serde_json::Value, McpError>,
+    ) -> Result<serde_json::Value, McpError> {
+        if !self.enabled {
+            return fetched;
+        }
+
+        let mut entries = self.entries.lock().expect("Cache lock poisoned");
+        match fetched {
+            Ok(body) => {
+                entries.insert(
+                    key.to_string(),
+                    (chrono::Utc::now().timestamp_millis(), body.clone()),
+                );
+                Ok(body)
+            }
+            Err(err) if err.is_outage() => match entries.get(key) {
+                Some((as_of, serde_json::Value::Object(body))) => {
+                    tracing::warn!(key, as_of, error = %err, "Serving stale data during Binance outage");
+                    let mut body = body.clone();
+                    body.insert("stale".to_string(), serde_json::Value::Bool(true));
+                    body.insert("as_of".to_string(), serde_json::Value::from(*as_of));
+                    Ok(serde_json::Value::Object(body))
+                }
+                _ => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_hit_and_miss() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        assert_eq!(cache.get("a"), None);
+
+        cache.insert("a".to_string(), 1);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("b"), None);
+    }
+
+    #[test]
+    fn test_cache_expiry() {
+        let cache = TtlCache::new(Duration::from_millis(20));
+        cache.insert("a".to_string(), 1);
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get("a"), None);
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let cache = TtlCache::new(Duration::ZERO);
+        cache.insert("a".to_string(), 1);
+        assert_eq!(cache.get("a"), None);
+    }
+
+    #[test]
+    fn test_cache_key_separates_credentials() {
+        let a = cache_key("account", "https://api.binance.com", "key-a");
+        let b = cache_key("account", "https://api.binance.com", "key-b");
+        assert_ne!(a, b);
+        assert!(!a.contains("key-a"));
+        assert_eq!(a, cache_key("account", "https://api.binance.com", "key-a"));
+    }
+
+    #[test]
+    fn test_parse_cache_ttl() {
+        assert_eq!(parse_cache_ttl(Some("500")), Duration::from_millis(500));
+        assert_eq!(parse_cache_ttl(Some("0")), Duration::ZERO);
+        assert_eq!(parse_cache_ttl(None), DEFAULT_ACCOUNT_CACHE_TTL);
+        assert_eq!(parse_cache_ttl(Some("soon")), DEFAULT_ACCOUNT_CACHE_TTL);
+    }
+
+    #[test]
+    fn test_parse_exchange_info_ttl() {
+        assert_eq!(parse_exchange_info_ttl(Some("60")), Duration::from_secs(60));
+        assert_eq!(parse_exchange_info_ttl(None), DEFAULT_EXCHANGE_INFO_TTL);
+        assert_eq!(
+            parse_exchange_info_ttl(Some("later")),
+            DEFAULT_EXCHANGE_INFO_TTL
+        );
+    }
+
+    #[test]
+    fn test_outage_with_cache_serves_stale() {
+        let fallback = LastKnownGood::new(true);
+        let fresh = serde_json::json!({"symbol": "BTCUSDT", "lastPrice": "67650.10"});
+
+        let served = fallback
+            .resolve("ticker|BTCUSDT", Ok(fresh.clone()))
+            .unwrap();
+        assert_eq!(served, fresh, "fresh data is returned unmarked");
+
+        let outage = McpError::ConnectionError("Binance server error (HTTP 503)".to_string());
+        let stale = fallback.resolve("ticker|BTCUSDT", Err(outage)).unwrap();
+        assert_eq!(stale["lastPrice"], "67650.10");
+        assert_eq!(stale["stale"], true);
+        assert!(stale["as_of"].as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_outage_without_cache_errors() {
+        let fallback = LastKnownGood::new(true);
+        fallback
+            .resolve("ticker|BTCUSDT", Ok(serde_json::json!({"lastPrice": "1"})))
+            .unwrap();
+
+        // Nothing cached for this key
+        let outage = McpError::ConnectionError("Request timeout".to_string());
+        assert!(fallback.resolve("ticker|ETHUSDT", Err(outage)).is_err());
+
+        // Non-outage errors are never masked
+        let invalid = McpError::InvalidRequest("Invalid symbol".to_string());
+        assert!(fallback.resolve("ticker|BTCUSDT", Err(invalid)).is_err());
+
+        // Disabled fallback keeps nothing
+        let disabled = LastKnownGood::new(false);
+        disabled
+            .resolve("ticker|BTCUSDT", Ok(serde_json::json!({"lastPrice": "1"})))
+            .unwrap();
+        let outage = McpError::ConnectionError("Request timeout".to_string());
+        assert!(disabled.resolve("ticker|BTCUSDT", Err(outage)).is_err());
+    }
+}
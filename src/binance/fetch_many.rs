@@ -0,0 +1,119 @@
+//! Bounded-concurrency fan-out for multi-symbol tools
+//!
+//! Tools that make one request per symbol (or per window, or per order)
+//! would otherwise each pick their own concurrency. [`fetch_many`] runs them
+//! at most `concurrency` at a time and returns every item's own `Result`, so
+//! one failing symbol never fails its siblings. Each request still takes a
+//! slot of the client's `BINANCE_MAX_CONCURRENT` semaphore; this cap only
+//! keeps a single call from claiming all of them.
+//!
+//! ## Configuration
+//!
+//! - `MULTI_FETCH_CONCURRENCY`: Requests in flight at once for one multi-symbol call (default: 5)
+
+use futures_util::stream::{self, StreamExt};
+use std::future::Future;
+
+/// Default requests in flight at once for one multi-symbol call
+pub const DEFAULT_MULTI_FETCH_CONCURRENCY: usize = 5;
+
+/// Parses `MULTI_FETCH_CONCURRENCY`, falling back to the default on missing, invalid or zero input
+pub fn parse_multi_fetch_concurrency(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_MULTI_FETCH_CONCURRENCY)
+}
+
+/// Runs `fetch` for every item, at most `concurrency` at a time
+///
+/// Results are paired with their item and returned in input order, whatever
+/// order the fetches complete in. Fetches run on the calling task, so audit
+/// scopes and request IDs carry over.
+pub async fn fetch_many<I, T, E, F, Fut>(
+    items: Vec<I>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<(I, Result<T, E>)>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut results: Vec<(usize, I, Result<T, E>)> = stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let call = fetch(item.clone());
+            async move { (index, item, call.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    results.sort_by_key(|(index, _, _)| *index);
+    results
+        .into_iter()
+        .map(|(_, item, result)| (item, result))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_multi_fetch_concurrency() {
+        assert_eq!(parse_multi_fetch_concurrency(Some("8")), 8);
+        assert_eq!(
+            parse_multi_fetch_concurrency(Some("0")),
+            DEFAULT_MULTI_FETCH_CONCURRENCY
+        );
+        assert_eq!(
+            parse_multi_fetch_concurrency(None),
+            DEFAULT_MULTI_FETCH_CONCURRENCY
+        );
+        assert_eq!(
+            parse_multi_fetch_concurrency(Some("many")),
+            DEFAULT_MULTI_FETCH_CONCURRENCY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded_and_failures_isolated() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let items: Vec<u64> = (0..12).collect();
+
+        let results = fetch_many(items, 3, |item| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Later items finish first, so completion order differs from input order
+                tokio::time::sleep(Duration::from_millis(20 - item)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if item % 4 == 0 {
+                    Err(format!("item {item} failed"))
+                } else {
+                    Ok(item * 10)
+                }
+            }
+        })
+        .await;
+
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        let order: Vec<u64> = results.iter().map(|(item, _)| *item).collect();
+        assert_eq!(order, (0..12).collect::<Vec<_>>());
+
+        let failed: Vec<u64> = results
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .map(|(item, _)| *item)
+            .collect();
+        assert_eq!(failed, vec![0, 4, 8]);
+        assert_eq!(results[5].1, Ok(50));
+    }
+}
@@ -0,0 +1,210 @@
+//! Tick size and step size rounding from `exchangeInfo` filters
+//!
+//! Binance rejects prices that aren't a multiple of the symbol's
+//! `PRICE_FILTER.tickSize` and quantities that aren't a multiple of
+//! `LOT_SIZE.stepSize` (-1013). Prices round to the nearest tick; quantities
+//! round down so an order never exceeds the amount the caller asked for.
+//! Increments come from the cached `exchangeInfo` (`EXCHANGE_INFO_TTL_SECS`).
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+
+use crate::binance::types::{SymbolFilter, SymbolInfo};
+use crate::binance::BinanceClient;
+use crate::error::McpError;
+
+/// Price and quantity increments of a symbol
+///
+/// `None` when the filter is missing or its increment is `0` (disabled).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolIncrements {
+    /// `PRICE_FILTER.tickSize`
+    pub tick_size: Option<Decimal>,
+    /// `LOT_SIZE.stepSize`
+    pub step_size: Option<Decimal>,
+}
+
+impl SymbolIncrements {
+    /// Reads the increments from a symbol's filters
+    pub fn from_symbol_info(info: &SymbolInfo) -> Self {
+        let increment = |value: &str| Decimal::from_str(value).ok().filter(|d| *d > Decimal::ZERO);
+
+        let mut increments = Self {
+            tick_size: None,
+            step_size: None,
+        };
+        for filter in &info.filters {
+            match filter {
+                SymbolFilter::PriceFilter { tick_size, .. } => {
+                    increments.tick_size = increment(tick_size)
+                }
+                SymbolFilter::LotSize { step_size, .. } => {
+                    increments.step_size = increment(step_size)
+                }
+                _ => {}
+            }
+        }
+        increments
+    }
+
+    /// Rounds a price to the nearest tick (unchanged without a tick size)
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        match self.tick_size {
+            Some(tick) => round_to_increment(price, tick, RoundingStrategy::MidpointAwayFromZero),
+            None => price,
+        }
+    }
+
+    /// Rounds a quantity down to the step size (unchanged without a step size)
+    pub fn floor_quantity(&self, quantity: Decimal) -> Decimal {
+        match self.step_size {
+            Some(step) => round_to_increment(quantity, step, RoundingStrategy::ToZero),
+            None => quantity,
+        }
+    }
+}
+
+/// Rounds `value` to a multiple of `increment`, without trailing zeros
+fn round_to_increment(value: Decimal, increment: Decimal, strategy: RoundingStrategy) -> Decimal {
+    ((value / increment).round_dp_with_strategy(0, strategy) * increment).normalize()
+}
+
+/// Fetches a symbol's increments from the cached `exchangeInfo`
+///
+/// # Errors
+/// `InvalidRequest` if the symbol isn't listed, or the `exchangeInfo` request error
+pub async fn symbol_increments(
+    client: &BinanceClient,
+    symbol: &str,
+) -> Result<SymbolIncrements, McpError> {
+    let exchange_info = client.get_exchange_info().await?;
+    exchange_info
+        .symbols
+        .iter()
+        .find(|s| s.symbol == symbol)
+        .map(SymbolIncrements::from_symbol_info)
+        .ok_or_else(|| McpError::InvalidRequest(format!("Unknown symbol '{}'", symbol)))
+}
+
+/// Rounds a price to the nearest multiple of the symbol's tick size
+pub async fn round_to_tick(
+    client: &BinanceClient,
+    price: Decimal,
+    symbol: &str,
+) -> Result<Decimal, McpError> {
+    Ok(symbol_increments(client, symbol).await?.round_price(price))
+}
+
+/// Rounds a quantity down to a multiple of the symbol's step size
+pub async fn round_to_step(
+    client: &BinanceClient,
+    quantity: Decimal,
+    symbol: &str,
+) -> Result<Decimal, McpError> {
+    Ok(symbol_increments(client, symbol)
+        .await?
+        .floor_quantity(quantity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn info(symbol: &str, tick_size: &str, step_size: &str) -> SymbolInfo {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "status": "TRADING",
+            "baseAsset": "BASE",
+            "quoteAsset": "USDT",
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0", "maxPrice": "0", "tickSize": tick_size},
+                {"filterType": "LOT_SIZE", "minQty": "0", "maxQty": "9000", "stepSize": step_size}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_known_symbol_increments() {
+        // BTCUSDT: tickSize 0.01, stepSize 0.00001
+        let btc = SymbolIncrements::from_symbol_info(&info("BTCUSDT", "0.01000000", "0.00001000"));
+        assert_eq!(btc.round_price(dec("65123.456")), dec("65123.46"));
+        assert_eq!(btc.round_price(dec("65123.455")), dec("65123.46"));
+        assert_eq!(btc.round_price(dec("65123.454")), dec("65123.45"));
+        assert_eq!(btc.floor_quantity(dec("0.0012345")), dec("0.00123"));
+        assert_eq!(btc.floor_quantity(dec("0.00129999")), dec("0.00129"));
+
+        // SHIBUSDT: tickSize 0.00000001, stepSize 1
+        let shib =
+            SymbolIncrements::from_symbol_info(&info("SHIBUSDT", "0.00000001", "1.00000000"));
+        assert_eq!(shib.round_price(dec("0.0000123456")), dec("0.00001235"));
+        assert_eq!(shib.floor_quantity(dec("1500000.9")), dec("1500000"));
+
+        // Non-decimal tick sizes round to that multiple
+        let five = SymbolIncrements::from_symbol_info(&info("FIVEUSDT", "5", "0.5"));
+        assert_eq!(five.round_price(dec("102.4")), dec("100"));
+        assert_eq!(five.round_price(dec("103")), dec("105"));
+        assert_eq!(five.floor_quantity(dec("2.99")), dec("2.5"));
+    }
+
+    #[test]
+    fn test_already_aligned_values_are_unchanged() {
+        let btc = SymbolIncrements::from_symbol_info(&info("BTCUSDT", "0.01000000", "0.00001000"));
+        assert_eq!(btc.round_price(dec("65000.10")), dec("65000.1"));
+        assert_eq!(btc.floor_quantity(dec("0.001")), dec("0.001"));
+    }
+
+    #[test]
+    fn test_disabled_filters_leave_values_alone() {
+        let disabled = SymbolIncrements::from_symbol_info(&info("ODDUSDT", "0.00000000", "0"));
+        assert_eq!(disabled.tick_size, None);
+        assert_eq!(disabled.step_size, None);
+        assert_eq!(disabled.round_price(dec("1.23456")), dec("1.23456"));
+        assert_eq!(disabled.floor_quantity(dec("7.777")), dec("7.777"));
+    }
+
+    #[tokio::test]
+    async fn test_round_using_exchange_info() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let symbol = serde_json::to_value(info("ETHUSDT", "0.01000000", "0.00010000")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "timezone": "UTC",
+                "serverTime": 1_700_000_000_000i64,
+                "symbols": [symbol]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = BinanceClient {
+            base_url: server.uri(),
+            ..BinanceClient::new()
+        };
+
+        assert_eq!(
+            round_to_tick(&client, dec("3012.3456"), "ETHUSDT")
+                .await
+                .unwrap(),
+            dec("3012.35")
+        );
+        // Served from the exchangeInfo cache
+        assert_eq!(
+            round_to_step(&client, dec("0.12345"), "ETHUSDT")
+                .await
+                .unwrap(),
+            dec("0.1234")
+        );
+        assert!(matches!(
+            round_to_tick(&client, dec("1"), "NOPEUSDT").await,
+            Err(McpError::InvalidRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+//! Binance USD-M Futures market data client
+//!
+//! Read-only client for perpetual futures data on `fapi.binance.com`, kept
+//! separate from the spot [`BinanceClient`](crate::binance::BinanceClient) so
+//! spot-only builds don't carry it. Only public market data endpoints are
+//! exposed, so no request signing is involved.
+//!
+//! ## Configuration
+//!
+//! - `BINANCE_FUTURES_BASE_URL`: Futures REST base URL (default: https://fapi.binance.com)
+//!
+//! Timeout and proxy settings come from the same [`ClientConfig`] as the spot client.
+
+use crate::binance::client::{
+    build_http_client, decode_json, response_error, send_with_request_id, ClientConfig,
+};
+use crate::error::McpError;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Default USD-M Futures REST base URL
+pub const DEFAULT_FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+
+/// Response from /fapi/v1/ticker/24hr
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTicker24hr {
+    /// Contract symbol (e.g., "BTCUSDT")
+    pub symbol: String,
+    /// Price change
+    pub price_change: String,
+    /// Price change percent
+    pub price_change_percent: String,
+    /// Weighted average price
+    pub weighted_avg_price: String,
+    /// Last price
+    pub last_price: String,
+    /// Last quantity
+    pub last_qty: String,
+    /// Open price
+    pub open_price: String,
+    /// High price
+    pub high_price: String,
+    /// Low price
+    pub low_price: String,
+    /// Total traded base asset volume
+    pub volume: String,
+    /// Total traded quote asset volume
+    pub quote_volume: String,
+    /// Open time
+    pub open_time: i64,
+    /// Close time
+    pub close_time: i64,
+    /// Total number of trades
+    pub count: i64,
+}
+
+/// Response from /fapi/v1/premiumIndex (current funding rate and mark price)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Contract symbol
+    pub symbol: String,
+    /// Mark price
+    pub mark_price: String,
+    /// Index price
+    pub index_price: String,
+    /// Funding rate of the current period (e.g., "0.00010000" = 0.01%)
+    pub last_funding_rate: String,
+    /// Interest rate component
+    pub interest_rate: String,
+    /// Next funding time (milliseconds since Unix epoch)
+    pub next_funding_time: i64,
+    /// Snapshot time (milliseconds since Unix epoch)
+    pub time: i64,
+}
+
+/// Response from /fapi/v1/openInterest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    /// Contract symbol
+    pub symbol: String,
+    /// Open interest in contracts (base asset)
+    pub open_interest: String,
+    /// Snapshot time (milliseconds since Unix epoch)
+    pub time: i64,
+}
+
+/// Binance USD-M Futures REST client (public market data)
+#[derive(Debug, Clone)]
+pub struct BinanceFuturesClient {
+    /// HTTP client for making requests
+    client: Client,
+    /// Base URL for the futures API
+    base_url: String,
+}
+
+impl BinanceFuturesClient {
+    /// Creates a futures client from environment configuration
+    ///
+    /// # Errors
+    /// Returns error if the proxy URL is invalid or the HTTP client cannot be built
+    pub fn from_env() -> Result<Self, McpError> {
+        let base_url = std::env::var("BINANCE_FUTURES_BASE_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_FUTURES_BASE_URL.to_string());
+        Self::with_config(ClientConfig::from_env(), base_url)
+    }
+
+    /// Creates a futures client with explicit configuration and base URL
+    ///
+    /// # Errors
+    /// Returns error if the proxy URL is invalid or the HTTP client cannot be built
+    pub fn with_config(
+        config: ClientConfig,
+        base_url: impl Into<String>,
+    ) -> Result<Self, McpError> {
+        Ok(Self {
+            client: build_http_client(&config)?,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Returns the configured base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get 24-hour ticker statistics for a perpetual contract
+    ///
+    /// Calls GET /fapi/v1/ticker/24hr
+    pub async fn get_futures_ticker(&self, symbol: &str) -> Result<FuturesTicker24hr, McpError> {
+        self.get("/fapi/v1/ticker/24hr", symbol).await
+    }
+
+    /// Get the current funding rate, mark price and next funding time
+    ///
+    /// Calls GET /fapi/v1/premiumIndex
+    pub async fn get_futures_funding_rate(&self, symbol: &str) -> Result<FundingRate, McpError> {
+        self.get("/fapi/v1/premiumIndex", symbol).await
+    }
+
+    /// Get current open interest for a perpetual contract
+    ///
+    /// Calls GET /fapi/v1/openInterest
+    pub async fn get_futures_open_interest(&self, symbol: &str) -> Result<OpenInterest, McpError> {
+        self.get("/fapi/v1/openInterest", symbol).await
+    }
+
+    /// Issues an unsigned GET with a `symbol` query and decodes the JSON body
+    async fn get<T: DeserializeOwned>(&self, path: &str, symbol: &str) -> Result<T, McpError> {
+        let url = format!("{}{}?symbol={}", self.base_url, path, symbol);
+        let response = send_with_request_id(self.client.get(&url)).await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response).await);
+        }
+
+        decode_json(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_funding_rate_and_open_interest() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/premiumIndex"))
+            .and(query_param("symbol", "BTCUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "symbol": "BTCUSDT",
+                "markPrice": "67650.10000000",
+                "indexPrice": "67648.52000000",
+                "estimatedSettlePrice": "67640.00000000",
+                "lastFundingRate": "0.00010000",
+                "interestRate": "0.00010000",
+                "nextFundingTime": 1_699_574_400_000_i64,
+                "time": 1_699_564_800_000_i64
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/openInterest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "openInterest": "81234.567",
+                "symbol": "BTCUSDT",
+                "time": 1_699_564_800_000_i64
+            })))
+            .mount(&server)
+            .await;
+
+        let client = BinanceFuturesClient::with_config(ClientConfig::default(), server.uri())
+            .expect("client should build");
+
+        let funding = client.get_futures_funding_rate("BTCUSDT").await.unwrap();
+        assert_eq!(funding.last_funding_rate, "0.00010000");
+        assert_eq!(funding.next_funding_time, 1_699_574_400_000);
+
+        let open_interest = client.get_futures_open_interest("BTCUSDT").await.unwrap();
+        assert_eq!(open_interest.open_interest, "81234.567");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_symbol_maps_to_invalid_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fapi/v1/ticker/24hr"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": -1121,
+                "msg": "Invalid symbol."
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            BinanceFuturesClient::with_config(ClientConfig::default(), server.uri()).unwrap();
+
+        let err = client.get_futures_ticker("NOPEUSDT").await.unwrap_err();
+        assert!(matches!(err, McpError::InvalidRequest(_)));
+    }
+}
@@ -0,0 +1,388 @@
+//! Request limit validation
+//!
+//! Binance rejects out-of-range `limit` values with an opaque -1100 error.
+//! These helpers validate or clamp limits before the request is sent so the
+//! caller gets a message that lists the accepted values instead. The same
+//! applies to the paging and time filters of history queries, and to the
+//! expensive all-symbol open orders query.
+
+use crate::error::McpError;
+
+/// Depth limits accepted by `GET /api/v3/depth`
+pub const VALID_DEPTH_LIMITS: [u32; 8] = [5, 10, 20, 50, 100, 500, 1000, 5000];
+
+/// Maximum `limit` for `GET /api/v3/trades`
+pub const MAX_TRADES_LIMIT: u32 = 1000;
+
+/// Maximum `limit` for `GET /api/v3/klines`
+pub const MAX_KLINES_LIMIT: u32 = 1000;
+
+/// Maximum `limit` for `GET /api/v3/allOrders` and `GET /api/v3/myTrades`
+pub const MAX_HISTORY_LIMIT: u32 = 1000;
+
+/// Page size Binance uses for history queries when `limit` is omitted
+pub const DEFAULT_HISTORY_LIMIT: u32 = 500;
+
+/// Longest `startTime`..`endTime` window accepted by history queries (24 hours)
+pub const MAX_HISTORY_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Maximum `limit` for the wallet deposit and withdrawal history endpoints
+pub const MAX_TRANSFER_HISTORY_LIMIT: u32 = 1000;
+
+/// Longest `startTime`..`endTime` window accepted by deposit/withdrawal history (90 days)
+pub const MAX_TRANSFER_HISTORY_WINDOW_MS: i64 = 90 * 24 * 60 * 60 * 1000;
+
+/// Request weight of `GET /api/v3/openOrders` for a single symbol
+pub const OPEN_ORDERS_WEIGHT: u32 = 6;
+
+/// Request weight of `GET /api/v3/openOrders` when `symbol` is omitted
+pub const OPEN_ORDERS_ALL_SYMBOLS_WEIGHT: u32 = 80;
+
+/// Paging and time filters for `GET /api/v3/allOrders` and `GET /api/v3/myTrades`
+///
+/// `from_id` is sent as `orderId` for orders and `fromId` for trades; both
+/// return entries with an ID greater than or equal to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryQuery {
+    /// Page size (default 500, max 1000)
+    pub limit: Option<u32>,
+    /// Only entries at or after this time (milliseconds since Unix epoch)
+    pub start_time: Option<i64>,
+    /// Only entries at or before this time (milliseconds since Unix epoch)
+    pub end_time: Option<i64>,
+    /// Only entries with an ID at or above this one
+    pub from_id: Option<i64>,
+}
+
+impl HistoryQuery {
+    /// Checks the parameter combinations Binance accepts
+    ///
+    /// # Errors
+    /// Returns `McpError::InvalidRequest` if `from_id` is combined with a time
+    /// filter, or the time window is inverted or longer than 24 hours
+    pub fn validate(&self) -> Result<(), McpError> {
+        if self.from_id.is_some() && (self.start_time.is_some() || self.end_time.is_some()) {
+            return Err(McpError::InvalidRequest(
+                "from_id cannot be combined with start_time/end_time; page either by ID or by time window"
+                    .to_string(),
+            ));
+        }
+
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if end < start {
+                return Err(McpError::InvalidRequest(format!(
+                    "end_time ({}) is before start_time ({})",
+                    end, start
+                )));
+            }
+            if end - start > MAX_HISTORY_WINDOW_MS {
+                return Err(McpError::InvalidRequest(
+                    "start_time..end_time can span at most 24 hours".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends the filters to a query string, naming the ID filter `id_param`
+    pub fn append_to(&self, query_string: &mut String, id_param: &str) {
+        if let Some(lim) = self.limit {
+            query_string.push_str(&format!("&limit={}", clamp_limit(lim, MAX_HISTORY_LIMIT)));
+        }
+        if let Some(start) = self.start_time {
+            query_string.push_str(&format!("&startTime={}", start));
+        }
+        if let Some(end) = self.end_time {
+            query_string.push_str(&format!("&endTime={}", end));
+        }
+        if let Some(id) = self.from_id {
+            query_string.push_str(&format!("&{}={}", id_param, id));
+        }
+    }
+
+    /// Returns the `from_id` for the next page, or `None` when this page wasn't full
+    ///
+    /// # Arguments
+    /// * `page_len` - Number of entries returned
+    /// * `max_id` - Highest ID on the page
+    pub fn next_from_id(&self, page_len: usize, max_id: Option<i64>) -> Option<i64> {
+        let page_size = clamp_limit(
+            self.limit.unwrap_or(DEFAULT_HISTORY_LIMIT),
+            MAX_HISTORY_LIMIT,
+        );
+        if page_len < page_size as usize {
+            return None;
+        }
+        max_id.map(|id| id + 1)
+    }
+}
+
+/// Coin and time filters for `GET /sapi/v1/capital/deposit/hisrec` and
+/// `GET /sapi/v1/capital/withdraw/history`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferHistoryQuery {
+    /// Only records for this coin (e.g., "USDT")
+    pub coin: Option<String>,
+    /// Only records at or after this time (milliseconds since Unix epoch)
+    pub start_time: Option<i64>,
+    /// Only records at or before this time (milliseconds since Unix epoch)
+    pub end_time: Option<i64>,
+    /// Maximum records returned (default 1000, max 1000)
+    pub limit: Option<u32>,
+}
+
+impl TransferHistoryQuery {
+    /// Checks the time window Binance accepts
+    ///
+    /// # Errors
+    /// Returns `McpError::InvalidRequest` if the window is inverted or longer than 90 days
+    pub fn validate(&self) -> Result<(), McpError> {
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if end < start {
+                return Err(McpError::InvalidRequest(format!(
+                    "end_time ({}) is before start_time ({})",
+                    end, start
+                )));
+            }
+            if end - start > MAX_TRANSFER_HISTORY_WINDOW_MS {
+                return Err(McpError::InvalidRequest(
+                    "start_time..end_time can span at most 90 days".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends the filters to a query string
+    pub fn append_to(&self, query_string: &mut String) {
+        if let Some(coin) = self
+            .coin
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        {
+            query_string.push_str(&format!("&coin={}", coin.to_uppercase()));
+        }
+        if let Some(start) = self.start_time {
+            query_string.push_str(&format!("&startTime={}", start));
+        }
+        if let Some(end) = self.end_time {
+            query_string.push_str(&format!("&endTime={}", end));
+        }
+        if let Some(lim) = self.limit {
+            query_string.push_str(&format!(
+                "&limit={}",
+                clamp_limit(lim, MAX_TRANSFER_HISTORY_LIMIT)
+            ));
+        }
+    }
+}
+
+/// Validates a depth limit, optionally snapping it to the nearest accepted value
+///
+/// With `snap`, values between two accepted limits go to the closer one (the
+/// larger on a tie), and values outside the range go to the nearest end.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` listing the accepted values when `limit`
+/// is not accepted and `snap` is false
+pub fn validate_depth_limit(limit: u32, snap: bool) -> Result<u32, McpError> {
+    if VALID_DEPTH_LIMITS.contains(&limit) {
+        return Ok(limit);
+    }
+    if snap {
+        let nearest = VALID_DEPTH_LIMITS
+            .iter()
+            .copied()
+            .min_by_key(|valid| (valid.abs_diff(limit), u32::MAX - valid))
+            .expect("VALID_DEPTH_LIMITS is not empty");
+        return Ok(nearest);
+    }
+
+    Err(McpError::InvalidRequest(format!(
+        "Invalid depth limit {}. Valid values: {} (or pass snap=true to use the nearest)",
+        limit,
+        VALID_DEPTH_LIMITS
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )))
+}
+
+/// Clamps a list limit into `1..=max`
+pub fn clamp_limit(limit: u32, max: u32) -> u32 {
+    limit.clamp(1, max)
+}
+
+/// Parses a boolean opt-in flag (`1`, `true`, `yes`, `on`); anything else is `false`
+pub fn parse_flag(value: Option<&str>) -> bool {
+    value
+        .map(|v| v.trim().to_ascii_lowercase())
+        .is_some_and(|v| matches!(v.as_str(), "1" | "true" | "yes" | "on"))
+}
+
+/// Guards `GET /api/v3/openOrders` against accidental all-symbol scans
+///
+/// Omitting the symbol costs [`OPEN_ORDERS_ALL_SYMBOLS_WEIGHT`] instead of
+/// [`OPEN_ORDERS_WEIGHT`], so it is only allowed when the caller opts in with
+/// `all_symbols` or the server allows it by default
+/// (`ALLOW_ALL_SYMBOL_OPEN_ORDERS`). Allowed scans are logged at WARN.
+pub fn check_open_orders_scope(
+    symbol: Option<&str>,
+    all_symbols: bool,
+    allowed_by_default: bool,
+) -> Result<(), McpError> {
+    if symbol.is_some() {
+        return Ok(());
+    }
+
+    if !all_symbols && !allowed_by_default {
+        return Err(McpError::InvalidRequest(format!(
+            "Listing open orders without a symbol costs {} request weight instead of {}. \
+             Pass a symbol, or set all_symbols=true to scan every pair.",
+            OPEN_ORDERS_ALL_SYMBOLS_WEIGHT, OPEN_ORDERS_WEIGHT
+        )));
+    }
+
+    tracing::warn!(
+        weight = OPEN_ORDERS_ALL_SYMBOLS_WEIGHT,
+        "Fetching open orders for all symbols"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_depth_limits_pass_through() {
+        for limit in VALID_DEPTH_LIMITS {
+            assert_eq!(validate_depth_limit(limit, false).unwrap(), limit);
+        }
+    }
+
+    #[test]
+    fn test_invalid_depth_limit_lists_valid_values() {
+        let err = validate_depth_limit(25, false).unwrap_err().to_string();
+        assert!(err.contains("25"));
+        assert!(err.contains("5, 10, 20, 50, 100, 500, 1000, 5000"));
+    }
+
+    #[test]
+    fn test_depth_limit_snapping() {
+        assert_eq!(validate_depth_limit(25, true).unwrap(), 20);
+        assert_eq!(validate_depth_limit(35, true).unwrap(), 50);
+        // Ties go to the larger limit
+        assert_eq!(validate_depth_limit(75, true).unwrap(), 100);
+        assert_eq!(validate_depth_limit(0, true).unwrap(), 5);
+        assert_eq!(validate_depth_limit(9000, true).unwrap(), 5000);
+    }
+
+    #[test]
+    fn test_history_query_validation() {
+        let by_id = HistoryQuery {
+            from_id: Some(42),
+            ..Default::default()
+        };
+        assert!(by_id.validate().is_ok());
+
+        let mixed = HistoryQuery {
+            from_id: Some(42),
+            start_time: Some(1_700_000_000_000),
+            ..Default::default()
+        };
+        assert!(mixed
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("from_id"));
+
+        let too_wide = HistoryQuery {
+            start_time: Some(0),
+            end_time: Some(MAX_HISTORY_WINDOW_MS + 1),
+            ..Default::default()
+        };
+        assert!(too_wide.validate().is_err());
+
+        let inverted = HistoryQuery {
+            start_time: Some(2),
+            end_time: Some(1),
+            ..Default::default()
+        };
+        assert!(inverted.validate().is_err());
+    }
+
+    #[test]
+    fn test_history_query_paging() {
+        let query = HistoryQuery {
+            limit: Some(2),
+            from_id: Some(10),
+            ..Default::default()
+        };
+        let mut query_string = String::from("symbol=BTCUSDT");
+        query.append_to(&mut query_string, "fromId");
+        assert_eq!(query_string, "symbol=BTCUSDT&limit=2&fromId=10");
+
+        assert_eq!(query.next_from_id(2, Some(11)), Some(12));
+        assert_eq!(query.next_from_id(1, Some(10)), None);
+        assert_eq!(HistoryQuery::default().next_from_id(499, Some(7)), None);
+    }
+
+    #[test]
+    fn test_transfer_history_query() {
+        let query = TransferHistoryQuery {
+            coin: Some("usdt".to_string()),
+            start_time: Some(0),
+            end_time: Some(MAX_TRANSFER_HISTORY_WINDOW_MS),
+            limit: Some(5000),
+        };
+        assert!(query.validate().is_ok());
+
+        let mut query_string = String::from("timestamp=1");
+        query.append_to(&mut query_string);
+        assert_eq!(
+            query_string,
+            format!(
+                "timestamp=1&coin=USDT&startTime=0&endTime={}&limit=1000",
+                MAX_TRANSFER_HISTORY_WINDOW_MS
+            )
+        );
+
+        let too_wide = TransferHistoryQuery {
+            end_time: Some(MAX_TRANSFER_HISTORY_WINDOW_MS + 1),
+            ..query
+        };
+        assert!(too_wide.validate().is_err());
+    }
+
+    #[test]
+    fn test_clamp_limit() {
+        assert_eq!(clamp_limit(0, MAX_TRADES_LIMIT), 1);
+        assert_eq!(clamp_limit(500, MAX_TRADES_LIMIT), 500);
+        assert_eq!(clamp_limit(5000, MAX_KLINES_LIMIT), 1000);
+    }
+
+    #[test]
+    fn test_open_orders_scope_requires_opt_in() {
+        assert!(check_open_orders_scope(Some("BTCUSDT"), false, false).is_ok());
+        assert!(check_open_orders_scope(None, true, false).is_ok());
+        assert!(check_open_orders_scope(None, false, true).is_ok());
+
+        let err = check_open_orders_scope(None, false, false).unwrap_err();
+        assert!(matches!(err, McpError::InvalidRequest(ref msg) if msg.contains("all_symbols")));
+    }
+
+    #[test]
+    fn test_parse_flag() {
+        assert!(parse_flag(Some("true")));
+        assert!(parse_flag(Some(" 1 ")));
+        assert!(parse_flag(Some("YES")));
+        assert!(!parse_flag(Some("false")));
+        assert!(!parse_flag(Some("")));
+        assert!(!parse_flag(None));
+    }
+}
@@ -2,18 +2,31 @@
 //!
 //! This module contains the HTTP client for Binance API integration.
 
+pub mod cache;
 pub mod client;
+pub mod fetch_many;
+pub mod filters;
+pub mod limits;
+pub mod signing;
+pub mod symbol;
 pub mod types;
 
+#[cfg(feature = "futures")]
+pub mod futures;
+
 #[cfg(feature = "websocket")]
 pub mod websocket;
 
 // Re-export commonly used types
 pub use client::BinanceClient;
+pub use symbol::normalize_symbol;
 pub use types::ServerTimeResponse;
 
+#[cfg(feature = "futures")]
+pub use futures::BinanceFuturesClient;
+
 #[cfg(feature = "websocket")]
 pub use websocket::{
     BalanceUpdate, BinanceWebSocketClient, DepthUpdate, ExecutionReport, OutboundAccountPosition,
-    TickerUpdate, UserDataEvent,
+    TickerUpdate, UserDataEvent, UserStreamHealth, UserStreamStatus,
 };
@@ -0,0 +1,94 @@
+//! Trading Symbol Normalization
+//!
+//! Normalizes user-supplied trading pair symbols before they reach Binance,
+//! so inputs like `btc-usdt` or `eth/usdt` work instead of failing with -1121.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::error::BinanceError;
+
+/// Valid Binance spot symbol: 5-20 uppercase alphanumeric characters
+static SYMBOL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Z0-9]{5,20}$").expect("Symbol regex compilation failed"));
+
+/// Separators commonly used in pair notation (BTC-USDT, BTC/USDT, BTC_USDT)
+const SEPARATORS: [char; 5] = ['-', '/', '_', ' ', ':'];
+
+/// Normalizes and validates a trading pair symbol
+///
+/// Trims whitespace, strips separators, and uppercases the input, then checks
+/// it against `[A-Z0-9]{5,20}`.
+///
+/// # Arguments
+/// * `input` - Symbol as provided by the caller (e.g., "btc-usdt")
+///
+/// # Returns
+/// Normalized symbol (e.g., "BTCUSDT")
+///
+/// # Errors
+/// Returns `BinanceError::InvalidSymbol` with format help and examples if the
+/// normalized symbol is not a valid Binance symbol
+///
+/// # Example
+/// ```
+/// use mcp_binance_server::binance::symbol::normalize_symbol;
+///
+/// assert_eq!(normalize_symbol("btc-usdt").unwrap(), "BTCUSDT");
+/// assert_eq!(normalize_symbol(" eth/usdt ").unwrap(), "ETHUSDT");
+/// assert!(normalize_symbol("BTC").is_err());
+/// ```
+pub fn normalize_symbol(input: &str) -> Result<String, BinanceError> {
+    let normalized: String = input
+        .trim()
+        .chars()
+        .filter(|c| !SEPARATORS.contains(c))
+        .collect::<String>()
+        .to_uppercase();
+
+    if SYMBOL_REGEX.is_match(&normalized) {
+        Ok(normalized)
+    } else {
+        Err(BinanceError::InvalidSymbol {
+            provided: input.to_string(),
+            format_help:
+                "Symbols are 5-20 uppercase letters/digits: base asset followed by quote asset."
+                    .to_string(),
+            examples: vec![
+                "BTCUSDT".to_string(),
+                "ETHUSDT".to_string(),
+                "BNBBTC".to_string(),
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_symbol_variants() {
+        assert_eq!(normalize_symbol("BTCUSDT").unwrap(), "BTCUSDT");
+        assert_eq!(normalize_symbol("btcusdt").unwrap(), "BTCUSDT");
+        assert_eq!(normalize_symbol("btc-usdt").unwrap(), "BTCUSDT");
+        assert_eq!(normalize_symbol("ETH/USDT").unwrap(), "ETHUSDT");
+        assert_eq!(normalize_symbol(" bnb_btc ").unwrap(), "BNBBTC");
+        assert_eq!(normalize_symbol("1000SATSUSDT").unwrap(), "1000SATSUSDT");
+    }
+
+    #[test]
+    fn test_normalize_symbol_invalid() {
+        for input in ["", "BTC", "BTC$USDT", "ABCDEFGHIJKLMNOPQRSTU"] {
+            match normalize_symbol(input) {
+                Err(BinanceError::InvalidSymbol {
+                    provided, examples, ..
+                }) => {
+                    assert_eq!(provided, input);
+                    assert!(!examples.is_empty());
+                }
+                other => panic!("expected InvalidSymbol for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+}
@@ -63,6 +63,164 @@ mod tests {
         let response = ServerTimeResponse { server_time: 0 };
         assert!(!response.is_valid());
     }
+
+    #[test]
+    fn test_avg_price_deviation_bps() {
+        let avg = AvgPrice {
+            mins: 5,
+            price: "100.00".to_string(),
+        };
+        assert!((avg.deviation_bps(100.5).unwrap() - 50.0).abs() < 1e-9);
+        assert!((avg.deviation_bps(99.0).unwrap() + 100.0).abs() < 1e-9);
+
+        let zero = AvgPrice {
+            mins: 5,
+            price: "0".to_string(),
+        };
+        assert_eq!(zero.deviation_bps(1.0), None);
+    }
+
+    #[test]
+    fn test_kline_from_positional_array() {
+        // Raw Binance format: positional columns, trailing "ignore" field
+        let json = r#"[[
+            1699564800000, "35000.00", "35100.50", "34950.00", "35050.25", "12.345",
+            1699564859999, "432100.75", 321, "6.789", "237890.12", "0"
+        ]]"#;
+        let klines: KlineData = serde_json::from_str(json).unwrap();
+        assert_eq!(klines.len(), 1);
+
+        let kline = &klines[0];
+        assert_eq!(kline.open_time, 1699564800000);
+        assert_eq!(kline.open, "35000.00");
+        assert_eq!(kline.high, "35100.50");
+        assert_eq!(kline.low, "34950.00");
+        assert_eq!(kline.close, "35050.25");
+        assert_eq!(kline.volume, "12.345");
+        assert_eq!(kline.close_time, 1699564859999);
+        assert_eq!(kline.quote_volume, "432100.75");
+        assert_eq!(kline.trades, 321);
+        assert_eq!(kline.taker_buy_base, "6.789");
+        assert_eq!(kline.taker_buy_quote, "237890.12");
+
+        // Serialized output uses named fields instead of the positional array
+        let value = serde_json::to_value(kline).unwrap();
+        assert_eq!(value["openTime"], 1699564800000_i64);
+        assert_eq!(value["close"], "35050.25");
+        assert_eq!(value["takerBuyQuote"], "237890.12");
+        assert!(value.is_object());
+    }
+
+    #[test]
+    fn test_symbol_filters_deserialization() {
+        let json = r#"{
+            "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT",
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+                {"filterType": "ICEBERG_PARTS", "limit": 10},
+                {"filterType": "NOTIONAL", "minNotional": "5.00", "applyMinToMarket": true,
+                 "maxNotional": "9000000.00", "applyMaxToMarket": false, "avgPriceMins": 5}
+            ]
+        }"#;
+        let info: SymbolInfo = serde_json::from_str(json).unwrap();
+
+        assert_eq!(info.filters.len(), 4);
+        assert!(matches!(
+            &info.filters[0],
+            SymbolFilter::PriceFilter { tick_size, .. } if tick_size == "0.01"
+        ));
+        assert!(matches!(
+            &info.filters[1],
+            SymbolFilter::LotSize { step_size, .. } if step_size == "0.00001"
+        ));
+        assert!(matches!(info.filters[2], SymbolFilter::Other));
+        assert!(matches!(
+            &info.filters[3],
+            SymbolFilter::Notional { min_notional, apply_min_to_market: true, .. } if min_notional == "5.00"
+        ));
+    }
+
+    #[test]
+    fn test_wallet_transfer_normalization() {
+        let deposit: DepositRecord = serde_json::from_str(
+            r#"{"id":"769800519366885376","amount":"0.001","coin":"BNB","network":"BNB",
+                "status":1,"address":"bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23",
+                "addressTag":"101764890","txId":"98A3EA560C6B3336D348B6C83F0F95ECE4F1F5919E94BD006E5BF3BF264FACFC",
+                "insertTime":1661493146000,"transferType":0,"confirmTimes":"1/1"}"#,
+        )
+        .unwrap();
+        let deposit = WalletTransfer::from(deposit);
+        assert_eq!(deposit.status, "success");
+        assert_eq!(deposit.time, 1661493146000);
+
+        let withdrawal: WithdrawRecord = serde_json::from_str(
+            r#"{"id":"b6ae22b3aa844210a7041aee7589627c","amount":"8.91000000",
+                "transactionFee":"0.004","coin":"USDT","status":6,
+                "address":"0x94df8b352de7f46f64b01d3666bf6e936e44ce60",
+                "txId":"0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268",
+                "applyTime":"2019-10-12 11:12:02","network":"ETH","transferType":0}"#,
+        )
+        .unwrap();
+        let withdrawal = WalletTransfer::from(withdrawal);
+        assert_eq!(withdrawal.status, "completed");
+        assert_eq!(withdrawal.time, 1570878722000);
+
+        let value = serde_json::to_value(&withdrawal).unwrap();
+        assert_eq!(value["txId"], withdrawal.tx_id.as_str());
+        assert_eq!(value["statusCode"], 6);
+    }
+
+    #[test]
+    fn test_api_trading_status_keeps_trigger_conditions() {
+        let status: ApiTradingStatus = serde_json::from_str(
+            r#"{"data":{"isLocked":true,"plannedRecoverTime":1700003600000,
+                "triggerCondition":{"GCR":150,"IFER":150,"UFR":300},
+                "updateTime":1700000000000}}"#,
+        )
+        .unwrap();
+        assert!(status.data.is_locked);
+        assert_eq!(status.data.planned_recover_time, 1700003600000);
+        assert_eq!(status.data.trigger_condition["UFR"], 300);
+
+        let value = serde_json::to_value(&status.data).unwrap();
+        assert_eq!(value["triggerCondition"]["GCR"], 150);
+        assert!(value.get("indicators").is_none());
+    }
+
+    #[test]
+    fn test_account_permissions_round_trip() {
+        let account: AccountInfo = serde_json::from_str(
+            r#"{"makerCommission":10,"takerCommission":10,"buyerCommission":0,
+                "sellerCommission":0,"canTrade":true,"canWithdraw":false,
+                "canDeposit":true,"updateTime":1700000000000,"accountType":"SPOT",
+                "balances":[],"permissions":["SPOT"]}"#,
+        )
+        .unwrap();
+
+        let summary = account.permission_summary();
+        assert!(summary.can_trade && !summary.can_withdraw && summary.can_deposit);
+        assert_eq!(summary.account_type, "SPOT");
+        assert_eq!(
+            summary.to_string(),
+            "SPOT account: trading enabled, withdrawals disabled, deposits enabled"
+        );
+
+        let value = serde_json::to_value(&account).unwrap();
+        assert_eq!(value["canTrade"], true);
+        assert_eq!(value["canWithdraw"], false);
+        assert_eq!(value["canDeposit"], true);
+        assert_eq!(value["accountType"], "SPOT");
+        let again: AccountInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(again.permission_summary(), summary);
+    }
+
+    #[test]
+    fn test_kline_rejects_short_array() {
+        let json = r#"[1699564800000, "35000.00", "35100.50"]"#;
+        let err = serde_json::from_str::<Kline>(json).unwrap_err();
+        assert!(err.to_string().contains("at least 11"));
+    }
 }
 
 /// Response from /api/v3/ticker/price endpoint
@@ -76,6 +234,46 @@ pub struct TickerPrice {
     pub price: String,
 }
 
+/// Response from /api/v3/avgPrice endpoint
+///
+/// Volume-weighted average price over a short window (typically 5 minutes).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvgPrice {
+    /// Averaging window in minutes
+    pub mins: i64,
+    /// Average price as string to preserve precision
+    pub price: String,
+}
+
+impl AvgPrice {
+    /// Deviation of `last_price` from the average, in basis points
+    ///
+    /// Positive when the last trade is above the average. Returns `None` if the
+    /// average is unparseable or not positive.
+    pub fn deviation_bps(&self, last_price: f64) -> Option<f64> {
+        let avg = self.price.parse::<f64>().ok().filter(|p| *p > 0.0)?;
+        Some((last_price - avg) / avg * 10_000.0)
+    }
+}
+
+/// Response from /api/v3/ticker/bookTicker endpoint
+///
+/// Best bid and ask currently on the order book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Best bid price
+    pub bid_price: String,
+    /// Quantity at the best bid
+    pub bid_qty: String,
+    /// Best ask price
+    pub ask_price: String,
+    /// Quantity at the best ask
+    pub ask_qty: String,
+}
+
 /// Response from /api/v3/ticker/24hr endpoint
 ///
 /// Returns 24-hour rolling window price statistics.
@@ -122,11 +320,244 @@ pub struct Ticker24hr {
     pub count: i64,
 }
 
+/// Response from /api/v3/ticker with a `windowSize`
+///
+/// Price statistics over a rolling window (e.g., the last 4 hours) rather than
+/// the fixed 24 hours of [`Ticker24hr`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTicker {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Price change over the window
+    pub price_change: String,
+    /// Price change percent over the window
+    pub price_change_percent: String,
+    /// Weighted average price
+    pub weighted_avg_price: String,
+    /// Open price
+    pub open_price: String,
+    /// High price
+    pub high_price: String,
+    /// Low price
+    pub low_price: String,
+    /// Last price
+    pub last_price: String,
+    /// Total traded base asset volume
+    pub volume: String,
+    /// Total traded quote asset volume
+    pub quote_volume: String,
+    /// Window open time
+    pub open_time: i64,
+    /// Window close time
+    pub close_time: i64,
+    /// First trade ID
+    pub first_id: i64,
+    /// Last trade ID
+    pub last_id: i64,
+    /// Total number of trades
+    pub count: i64,
+}
+
+/// One candlestick from the /api/v3/klines endpoint
+///
+/// Binance returns each kline as a positional array:
+/// `[open_time, open, high, low, close, volume, close_time, quote_volume, trades, taker_buy_base, taker_buy_quote, ignore]`.
+/// It is deserialized from that array and serialized with named fields, so
+/// callers never have to remember the column order. Trailing columns beyond
+/// `taker_buy_quote` are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    /// Kline open time (milliseconds since Unix epoch)
+    pub open_time: i64,
+    /// Open price
+    pub open: String,
+    /// High price
+    pub high: String,
+    /// Low price
+    pub low: String,
+    /// Close price
+    pub close: String,
+    /// Base asset volume
+    pub volume: String,
+    /// Kline close time (milliseconds since Unix epoch)
+    pub close_time: i64,
+    /// Quote asset volume
+    pub quote_volume: String,
+    /// Number of trades
+    pub trades: i64,
+    /// Taker buy base asset volume
+    pub taker_buy_base: String,
+    /// Taker buy quote asset volume
+    pub taker_buy_quote: String,
+}
+
+impl<'de> Deserialize<'de> for Kline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct KlineVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for KlineVisitor {
+            type Value = Kline;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a kline array of at least 11 elements")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Kline, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                fn element<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+                where
+                    A: serde::de::SeqAccess<'de>,
+                    T: Deserialize<'de>,
+                {
+                    seq.next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(index, &KlineVisitor))
+                }
+
+                let kline = Kline {
+                    open_time: element(&mut seq, 0)?,
+                    open: element(&mut seq, 1)?,
+                    high: element(&mut seq, 2)?,
+                    low: element(&mut seq, 3)?,
+                    close: element(&mut seq, 4)?,
+                    volume: element(&mut seq, 5)?,
+                    close_time: element(&mut seq, 6)?,
+                    quote_volume: element(&mut seq, 7)?,
+                    trades: element(&mut seq, 8)?,
+                    taker_buy_base: element(&mut seq, 9)?,
+                    taker_buy_quote: element(&mut seq, 10)?,
+                };
+
+                // Skip the unused "ignore" column and anything Binance appends later
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(kline)
+            }
+        }
+
+        deserializer.deserialize_seq(KlineVisitor)
+    }
+}
+
 /// Response from /api/v3/klines endpoint
+pub type KlineData = Vec<Kline>;
+
+/// Response from /api/v3/exchangeInfo endpoint
 ///
-/// Returns candlestick/kline data.
-/// Array format: [open_time, open, high, low, close, volume, close_time, quote_volume, trades, taker_buy_base, taker_buy_quote, ignore]
-pub type KlineData = Vec<serde_json::Value>;
+/// Only the fields needed for symbol discovery and order validation are
+/// deserialized; the rest of the payload (rate limits, permissions) is ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfo {
+    /// Server time when the snapshot was produced
+    pub server_time: i64,
+    /// Every listed symbol, including halted and delisted ones
+    pub symbols: Vec<SymbolInfo>,
+}
+
+/// Symbol entry from /api/v3/exchangeInfo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInfo {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    pub symbol: String,
+    /// Trading status (e.g., "TRADING", "BREAK", "HALT")
+    pub status: String,
+    /// Base asset (e.g., "BTC")
+    pub base_asset: String,
+    /// Quote asset (e.g., "USDT")
+    pub quote_asset: String,
+    /// Order types accepted (e.g., "LIMIT", "MARKET"); empty when not sent
+    #[serde(default)]
+    pub order_types: Vec<String>,
+    /// Order filters (price, quantity and notional bounds)
+    #[serde(default)]
+    pub filters: Vec<SymbolFilter>,
+}
+
+impl SymbolInfo {
+    /// Whether the symbol is currently open for trading
+    pub fn is_trading(&self) -> bool {
+        self.status == "TRADING"
+    }
+}
+
+/// Order filter from a symbol's `filters` array in /api/v3/exchangeInfo
+///
+/// Values are kept as the decimal strings Binance sends. A `"0"` bound means
+/// the bound is disabled. Filter types not listed here deserialize as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SymbolFilter {
+    /// Price bounds and tick size for limit prices
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        /// Lowest accepted price
+        min_price: String,
+        /// Highest accepted price
+        max_price: String,
+        /// Price increment
+        tick_size: String,
+    },
+    /// Quantity bounds and step size for all orders
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        /// Lowest accepted quantity
+        min_qty: String,
+        /// Highest accepted quantity
+        max_qty: String,
+        /// Quantity increment
+        step_size: String,
+    },
+    /// Quantity bounds and step size for MARKET orders
+    #[serde(rename_all = "camelCase")]
+    MarketLotSize {
+        /// Lowest accepted quantity
+        min_qty: String,
+        /// Highest accepted quantity
+        max_qty: String,
+        /// Quantity increment
+        step_size: String,
+    },
+    /// Legacy minimum order value (price * quantity)
+    #[serde(rename_all = "camelCase")]
+    MinNotional {
+        /// Lowest accepted order value in the quote asset
+        min_notional: String,
+        /// Whether the minimum also applies to MARKET orders
+        #[serde(default)]
+        apply_to_market: bool,
+    },
+    /// Order value bounds (price * quantity)
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        /// Lowest accepted order value in the quote asset
+        min_notional: String,
+        /// Whether the minimum also applies to MARKET orders
+        #[serde(default)]
+        apply_min_to_market: bool,
+        /// Highest accepted order value in the quote asset
+        max_notional: String,
+        /// Whether the maximum also applies to MARKET orders
+        #[serde(default)]
+        apply_max_to_market: bool,
+    },
+    /// Maximum open orders on the symbol per account
+    #[serde(rename_all = "camelCase")]
+    MaxNumOrders {
+        /// Open order cap
+        max_num_orders: u32,
+    },
+    /// Any other filter type (percent price, iceberg parts, algo orders, ...)
+    #[serde(other)]
+    Other,
+}
 
 /// Response from /api/v3/depth endpoint
 ///
@@ -206,6 +637,46 @@ pub struct AccountInfo {
     pub permissions: Vec<String>,
 }
 
+impl AccountInfo {
+    /// What the API key may do on this account
+    pub fn permission_summary(&self) -> AccountPermissions {
+        AccountPermissions {
+            account_type: self.account_type.clone(),
+            can_trade: self.can_trade,
+            can_withdraw: self.can_withdraw,
+            can_deposit: self.can_deposit,
+        }
+    }
+}
+
+/// Trading, withdrawal and deposit permissions of an account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountPermissions {
+    /// Account type (e.g., "SPOT")
+    pub account_type: String,
+    /// Orders can be placed
+    pub can_trade: bool,
+    /// Withdrawals are allowed
+    pub can_withdraw: bool,
+    /// Deposits are allowed
+    pub can_deposit: bool,
+}
+
+impl std::fmt::Display for AccountPermissions {
+    /// One line such as "SPOT account: trading enabled, withdrawals disabled, deposits enabled"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = |allowed: bool| if allowed { "enabled" } else { "disabled" };
+        write!(
+            f,
+            "{} account: trading {}, withdrawals {}, deposits {}",
+            self.account_type,
+            state(self.can_trade),
+            state(self.can_withdraw),
+            state(self.can_deposit)
+        )
+    }
+}
+
 /// Order fill information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -236,6 +707,9 @@ pub struct Order {
     /// Transaction time
     #[serde(default)]
     pub transact_time: i64,
+    /// Order creation time (query endpoints such as openOrders; absent on placement)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<i64>,
     /// Order price
     #[serde(default)]
     pub price: String,
@@ -295,3 +769,164 @@ pub struct MyTrade {
     /// Is best match
     pub is_best_match: bool,
 }
+
+/// Entry from /sapi/v1/capital/deposit/hisrec
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Deposit ID
+    #[serde(default)]
+    pub id: String,
+    /// Amount deposited
+    pub amount: String,
+    /// Coin (e.g., "USDT")
+    pub coin: String,
+    /// Network the deposit arrived on (e.g., "ETH", "TRX")
+    #[serde(default)]
+    pub network: String,
+    /// Status code (0 pending, 6 credited but locked, 7 wrong deposit, 8 waiting user confirmation, 1 success, 2 rejected)
+    pub status: i32,
+    /// Deposit address
+    #[serde(default)]
+    pub address: String,
+    /// On-chain transaction ID
+    #[serde(default)]
+    pub tx_id: String,
+    /// Time the deposit was recorded (milliseconds since Unix epoch)
+    pub insert_time: i64,
+}
+
+/// Entry from /sapi/v1/capital/withdraw/history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    /// Withdrawal ID
+    #[serde(default)]
+    pub id: String,
+    /// Amount withdrawn
+    pub amount: String,
+    /// Network fee charged
+    #[serde(default)]
+    pub transaction_fee: String,
+    /// Coin (e.g., "USDT")
+    pub coin: String,
+    /// Network the withdrawal was sent on
+    #[serde(default)]
+    pub network: String,
+    /// Status code (0 email sent, 1 cancelled, 2 awaiting approval, 3 rejected, 4 processing, 5 failure, 6 completed)
+    pub status: i32,
+    /// Destination address
+    #[serde(default)]
+    pub address: String,
+    /// On-chain transaction ID (empty until broadcast)
+    #[serde(default)]
+    pub tx_id: String,
+    /// Request time as "YYYY-MM-DD HH:MM:SS" (UTC)
+    pub apply_time: String,
+}
+
+/// Deposit or withdrawal with the fields common to both endpoints
+///
+/// `status` is a readable label; the raw Binance code is kept in `statusCode`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletTransfer {
+    /// Coin (e.g., "USDT")
+    pub coin: String,
+    /// Amount transferred
+    pub amount: String,
+    /// Network used
+    pub network: String,
+    /// Status label (e.g., "success", "processing")
+    pub status: String,
+    /// Raw Binance status code
+    pub status_code: i32,
+    /// On-chain transaction ID (empty if not yet broadcast)
+    pub tx_id: String,
+    /// Time the transfer was recorded (milliseconds since Unix epoch, 0 if unparseable)
+    pub time: i64,
+}
+
+impl From<DepositRecord> for WalletTransfer {
+    fn from(record: DepositRecord) -> Self {
+        let status = match record.status {
+            0 => "pending",
+            1 => "success",
+            2 => "rejected",
+            6 => "credited",
+            7 => "wrong_deposit",
+            8 => "waiting_user_confirm",
+            _ => "unknown",
+        };
+        Self {
+            coin: record.coin,
+            amount: record.amount,
+            network: record.network,
+            status: status.to_string(),
+            status_code: record.status,
+            tx_id: record.tx_id,
+            time: record.insert_time,
+        }
+    }
+}
+
+impl From<WithdrawRecord> for WalletTransfer {
+    fn from(record: WithdrawRecord) -> Self {
+        let status = match record.status {
+            0 => "email_sent",
+            1 => "cancelled",
+            2 => "awaiting_approval",
+            3 => "rejected",
+            4 => "processing",
+            5 => "failure",
+            6 => "completed",
+            _ => "unknown",
+        };
+        let time = chrono::NaiveDateTime::parse_from_str(&record.apply_time, "%Y-%m-%d %H:%M:%S")
+            .map(|t| t.and_utc().timestamp_millis())
+            .unwrap_or(0);
+        Self {
+            coin: record.coin,
+            amount: record.amount,
+            network: record.network,
+            status: status.to_string(),
+            status_code: record.status,
+            tx_id: record.tx_id,
+            time,
+        }
+    }
+}
+
+/// Response from /sapi/v1/account/status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStatus {
+    /// "Normal", or a description of the restriction on the account
+    pub data: String,
+}
+
+/// Response from /sapi/v1/account/apiTradingStatus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiTradingStatus {
+    /// Lock state and trigger counters
+    pub data: ApiTradingStatusData,
+}
+
+/// API trading lock state for the account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatusData {
+    /// Whether API trading is currently locked
+    pub is_locked: bool,
+    /// When the lock is lifted (milliseconds since Unix epoch, 0 when not locked)
+    #[serde(default)]
+    pub planned_recover_time: i64,
+    /// Thresholds for the lock triggers (e.g., "GCR", "IFER", "UFR")
+    #[serde(default)]
+    pub trigger_condition: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Per-symbol trigger counters, when Binance reports them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indicators: Option<serde_json::Value>,
+    /// Last update time (milliseconds since Unix epoch)
+    #[serde(default)]
+    pub update_time: i64,
+}
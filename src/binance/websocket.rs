@@ -13,6 +13,8 @@
 use crate::error::McpError;
 use futures_util::StreamExt;
 use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::broadcast;
 use tokio::time::sleep;
@@ -239,7 +241,25 @@ impl BinanceWebSocketClient {
         symbol: &str,
         tx: broadcast::Sender<DepthUpdate>,
     ) -> Result<(), McpError> {
-        let stream_name = format!("{}@depth", symbol.to_lowercase());
+        self.depth_stream_task_at(symbol, DEPTH_UPDATE_SPEED_MS, tx)
+            .await
+    }
+
+    /// Same as [`Self::depth_stream_task`] at a chosen update speed
+    ///
+    /// `update_speed_ms` of 100 subscribes to `<symbol>@depth@100ms`; any other
+    /// value uses the default 1000ms `<symbol>@depth` stream.
+    pub async fn depth_stream_task_at(
+        &self,
+        symbol: &str,
+        update_speed_ms: u32,
+        tx: broadcast::Sender<DepthUpdate>,
+    ) -> Result<(), McpError> {
+        let stream_name = if update_speed_ms == 100 {
+            format!("{}@depth@100ms", symbol.to_lowercase())
+        } else {
+            format!("{}@depth", symbol.to_lowercase())
+        };
 
         loop {
             tracing::info!("Starting depth stream for {}", symbol);
@@ -326,6 +346,20 @@ impl BinanceWebSocketClient {
         &self,
         listen_key: &str,
         tx: broadcast::Sender<UserDataEvent>,
+    ) -> Result<(), McpError> {
+        self.user_data_stream_task_with_status(listen_key, tx, &UserStreamStatus::default())
+            .await
+    }
+
+    /// Runs a user data stream and records its liveness in `status`
+    ///
+    /// Same as [`Self::user_data_stream_task`], but marks `status` connected
+    /// while a WebSocket connection is open and stamps every parsed event.
+    pub async fn user_data_stream_task_with_status(
+        &self,
+        listen_key: &str,
+        tx: broadcast::Sender<UserDataEvent>,
+        status: &UserStreamStatus,
     ) -> Result<(), McpError> {
         let stream_name = listen_key.to_string();
 
@@ -333,7 +367,14 @@ impl BinanceWebSocketClient {
             tracing::info!("Starting user data stream with listen key");
 
             // Connect with retry
-            let (_write, mut read) = self.connect_with_retry(&stream_name).await?;
+            let (_write, mut read) = match self.connect_with_retry(&stream_name).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    status.set_connected(false);
+                    return Err(e);
+                }
+            };
+            status.set_connected(true);
 
             // Read messages and broadcast to subscribers
             while let Some(msg_result) = read.next().await {
@@ -342,6 +383,7 @@ impl BinanceWebSocketClient {
                         // Parse user data event
                         match serde_json::from_str::<UserDataEvent>(&text) {
                             Ok(event) => {
+                                status.record_event();
                                 // Broadcast to all subscribers
                                 // Ignore send errors (no active receivers)
                                 let _ = tx.send(event);
@@ -372,6 +414,7 @@ impl BinanceWebSocketClient {
                 }
             }
 
+            status.set_connected(false);
             tracing::warn!("User data stream disconnected, reconnecting...");
             sleep(Duration::from_secs(1)).await;
         }
@@ -384,6 +427,53 @@ impl Default for BinanceWebSocketClient {
     }
 }
 
+/// Shared liveness state of one user data stream
+///
+/// Cloned into the stream task, which updates it, and read by health tools.
+/// Binance only pushes user data events on account activity, so a quiet
+/// account has an old (or absent) last event while still connected.
+#[derive(Debug, Clone, Default)]
+pub struct UserStreamStatus {
+    connected: Arc<AtomicBool>,
+    /// Local time of the last parsed event (milliseconds since Unix epoch, 0 = none)
+    last_event_ms: Arc<AtomicI64>,
+}
+
+impl UserStreamStatus {
+    /// Marks the WebSocket connection as open or closed
+    pub fn set_connected(&self, connected: bool) {
+        self.connected.store(connected, Ordering::Relaxed);
+    }
+
+    /// Records that an event was just received
+    pub fn record_event(&self) {
+        self.last_event_ms
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Current connection state and time since the last event
+    pub fn health(&self) -> UserStreamHealth {
+        let last_event_ms = self.last_event_ms.load(Ordering::Relaxed);
+        UserStreamHealth {
+            connected: self.connected.load(Ordering::Relaxed),
+            last_event_age_ms: (last_event_ms > 0)
+                .then(|| (chrono::Utc::now().timestamp_millis() - last_event_ms).max(0)),
+        }
+    }
+}
+
+/// Liveness snapshot of a user data stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct UserStreamHealth {
+    /// Whether the WebSocket connection is currently open
+    pub connected: bool,
+    /// Milliseconds since the last event (`None` if none has arrived yet)
+    pub last_event_age_ms: Option<i64>,
+}
+
+/// Default depth stream update speed (`<symbol>@depth`)
+pub const DEPTH_UPDATE_SPEED_MS: u32 = 1000;
+
 /// Order book depth update message from Binance WebSocket
 ///
 /// Received from the `<symbol>@depth` stream for bid/ask updates
@@ -419,6 +509,17 @@ pub struct DepthUpdate {
     pub asks: Vec<(String, String)>,
 }
 
+impl DepthUpdate {
+    /// Keeps only the best `levels` bids (highest first) and asks (lowest first)
+    pub fn truncate(&mut self, levels: usize) {
+        let price = |level: &(String, String)| level.0.parse::<f64>().unwrap_or(0.0);
+        self.bids.sort_by(|a, b| price(b).total_cmp(&price(a)));
+        self.asks.sort_by(|a, b| price(a).total_cmp(&price(b)));
+        self.bids.truncate(levels);
+        self.asks.truncate(levels);
+    }
+}
+
 /// Ticker price update message from Binance WebSocket
 ///
 /// Received from the `<symbol>@ticker` stream every 1000ms
@@ -648,6 +749,27 @@ mod tests {
         assert_eq!(client.base_url, BINANCE_WS_URL);
     }
 
+    #[test]
+    fn test_user_stream_status() {
+        let status = UserStreamStatus::default();
+        assert_eq!(
+            status.health(),
+            UserStreamHealth {
+                connected: false,
+                last_event_age_ms: None
+            }
+        );
+
+        // Clones share state with the stream task
+        let task_status = status.clone();
+        task_status.set_connected(true);
+        task_status.record_event();
+
+        let health = status.health();
+        assert!(health.connected);
+        assert!(health.last_event_age_ms.is_some_and(|age| age < 1000));
+    }
+
     #[test]
     fn test_ticker_update_deserialization() {
         let json = r#"{
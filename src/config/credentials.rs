@@ -1,9 +1,14 @@
 //! API Credential Management
 //!
-//! Secure handling of Binance API credentials loaded from environment variables.
+//! Secure handling of Binance API credentials loaded from environment variables
+//! or from a credentials file (`BINANCE_CREDENTIALS_FILE`).
 //! Credentials are never logged at INFO/WARN levels and are masked when displayed.
 
+use crate::tools::credentials::{validate_api_key, validate_api_secret};
+use crate::types::Environment;
+use serde::Deserialize;
 use std::fmt;
+use std::path::Path;
 
 /// Secure string wrapper that masks sensitive data in logs
 ///
@@ -59,7 +64,7 @@ impl From<String> for SecretString {
     }
 }
 
-/// Binance API credentials loaded from environment variables
+/// Binance API credentials loaded from environment variables or a file
 ///
 /// Credentials are stored as SecretString to prevent accidental logging.
 /// Use `is_some()` on `Credentials::from_env()` result to check if credentials
@@ -70,6 +75,37 @@ pub struct Credentials {
     pub api_key: SecretString,
     /// Binance secret key (private signing key)
     pub secret_key: SecretString,
+    /// Environment the keys belong to (env-var credentials are assumed mainnet)
+    pub environment: Environment,
+}
+
+/// On-disk credentials file layout (JSON or TOML)
+#[derive(Deserialize)]
+struct CredentialsFile {
+    environment: Environment,
+    api_key: String,
+    api_secret: String,
+}
+
+impl CredentialsFile {
+    /// Parses a flat TOML document with `environment`, `api_key` and `api_secret`
+    fn from_toml(contents: &str) -> Result<Self, String> {
+        let doc = contents
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| format!("invalid TOML: {}", e))?;
+        let field = |name: &str| {
+            doc.get(name)
+                .and_then(|item| item.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| format!("missing string field '{}'", name))
+        };
+
+        Ok(Self {
+            environment: field("environment")?.parse()?,
+            api_key: field("api_key")?,
+            api_secret: field("api_secret")?,
+        })
+    }
 }
 
 impl Credentials {
@@ -108,6 +144,139 @@ impl Credentials {
         Ok(Self {
             api_key: SecretString::new(api_key),
             secret_key: SecretString::new(secret_key),
+            environment: Environment::Mainnet,
+        })
+    }
+
+    /// Loads credentials from a JSON or TOML file
+    ///
+    /// The file holds `environment` ("testnet" or "mainnet"), `api_key` and
+    /// `api_secret`. Files ending in `.json` are parsed as JSON, anything else
+    /// as TOML. Key and secret must pass the same format validation as
+    /// `configure_credentials` (64 alphanumeric characters).
+    ///
+    /// Returns `Err` with a descriptive message if the file is missing, malformed,
+    /// or the key/secret format is invalid. Error messages never include the secret.
+    ///
+    /// # Example file (TOML)
+    ///
+    /// ```toml
+    /// environment = "testnet"
+    /// api_key = "..."
+    /// api_secret = "..."
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            format!(
+                "Failed to read credentials file '{}': {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let file = if is_json {
+            serde_json::from_str::<CredentialsFile>(&contents).map_err(|e| e.to_string())
+        } else {
+            CredentialsFile::from_toml(&contents)
+        }
+        .map_err(|e| {
+            format!(
+                "Failed to parse credentials file '{}': {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        let api_key = file.api_key.trim().to_string();
+        let secret_key = file.api_secret.trim().to_string();
+        validate_api_key(&api_key).map_err(|e| e.to_string())?;
+        validate_api_secret(&secret_key).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            api_key: SecretString::new(api_key),
+            secret_key: SecretString::new(secret_key),
+            environment: file.environment,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_file(suffix: &str, contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_from_file_toml() {
+        let file = write_file(
+            ".toml",
+            &format!(
+                "environment = \"testnet\"\napi_key = \"{}\"\napi_secret = \"{}\"\n",
+                "A".repeat(64),
+                "B".repeat(64)
+            ),
+        );
+
+        let creds = Credentials::from_file(file.path()).unwrap();
+        assert_eq!(creds.environment, Environment::Testnet);
+        assert_eq!(creds.api_key.expose_secret(), "A".repeat(64));
+        assert_eq!(creds.secret_key.expose_secret(), "B".repeat(64));
+    }
+
+    #[test]
+    fn test_from_file_json() {
+        let file = write_file(
+            ".json",
+            &serde_json::json!({
+                "environment": "mainnet",
+                "api_key": "C".repeat(64),
+                "api_secret": "D".repeat(64)
+            })
+            .to_string(),
+        );
+
+        let creds = Credentials::from_file(file.path()).unwrap();
+        assert_eq!(creds.environment, Environment::Mainnet);
+    }
+
+    #[test]
+    fn test_from_file_rejects_invalid_secret_without_leaking_it() {
+        let file = write_file(
+            ".toml",
+            &format!(
+                "environment = \"testnet\"\napi_key = \"{}\"\napi_secret = \"short-secret\"\n",
+                "A".repeat(64)
+            ),
+        );
+
+        let err = Credentials::from_file(file.path()).unwrap_err();
+        assert!(err.contains("API secret"));
+        assert!(!err.contains("short-secret"));
+    }
+
+    #[test]
+    fn test_from_file_invalid_environment_and_missing_file() {
+        let file = write_file(
+            ".toml",
+            &format!(
+                "environment = \"staging\"\napi_key = \"{}\"\napi_secret = \"{}\"\n",
+                "A".repeat(64),
+                "B".repeat(64)
+            ),
+        );
+        assert!(Credentials::from_file(file.path())
+            .unwrap_err()
+            .contains("Invalid environment"));
+
+        assert!(Credentials::from_file("/nonexistent/credentials.toml").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+//! Default trading pair configuration
+//!
+//! Operators with a primary instrument can set `DEFAULT_SYMBOL` so prompts and
+//! tools whose symbol is optional fall back to it, letting users say "analyze
+//! the market" without naming a pair. Tools that require a symbol are
+//! unaffected.
+//!
+//! ## Configuration
+//!
+//! - `DEFAULT_SYMBOL`: Fallback trading pair (e.g., `BTCUSDT`; unset = no default)
+
+use crate::binance::normalize_symbol;
+
+/// Parses a `DEFAULT_SYMBOL` value into a normalized symbol
+///
+/// Blank values mean no default; invalid symbols are ignored with a warning so
+/// a typo can't make every fallback call fail.
+pub fn parse_default_symbol(value: Option<&str>) -> Option<String> {
+    let value = value.map(str::trim).filter(|v| !v.is_empty())?;
+    match normalize_symbol(value) {
+        Ok(symbol) => Some(symbol),
+        Err(err) => {
+            tracing::warn!("Ignoring invalid DEFAULT_SYMBOL '{}': {}", value, err);
+            None
+        }
+    }
+}
+
+/// Reads `DEFAULT_SYMBOL`
+pub fn default_symbol_from_env() -> Option<String> {
+    parse_default_symbol(std::env::var("DEFAULT_SYMBOL").ok().as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_default_symbol() {
+        assert_eq!(
+            parse_default_symbol(Some("btc/usdt")),
+            Some("BTCUSDT".to_string())
+        );
+        assert_eq!(
+            parse_default_symbol(Some(" ETHUSDT ")),
+            Some("ETHUSDT".to_string())
+        );
+        assert_eq!(parse_default_symbol(Some("  ")), None);
+        assert_eq!(parse_default_symbol(Some("not a symbol!")), None);
+        assert_eq!(parse_default_symbol(None), None);
+    }
+}
@@ -2,7 +2,148 @@
 //!
 //! Configuration for HTTP REST API server and WebSocket connections.
 
-use std::net::SocketAddr;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+
+/// A bearer token entry parsed from `BEARER_TOKENS`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BearerTokenConfig {
+    /// Raw token value
+    pub token: String,
+    /// Label identifying the tenant (used for rate limiting and audit logs)
+    pub label: String,
+    /// Requests per minute for this token (None = `HTTP_RATE_LIMIT`)
+    pub rate_limit: Option<u32>,
+}
+
+/// JSON form of a `BEARER_TOKENS` entry
+#[derive(Debug, serde::Deserialize)]
+struct BearerTokenJson {
+    label: String,
+    #[serde(default)]
+    rate_limit: Option<u32>,
+}
+
+/// Parse the `BEARER_TOKENS` value
+///
+/// Two formats are accepted:
+///
+/// - Comma-separated `token[:label[:rate_limit]]` entries, e.g.
+///   `tokA:alice:200,tokB:bob`. Missing labels default to `token_<n>`.
+/// - A JSON object mapping token → `{"label": ..., "rate_limit": ...}`, e.g.
+///   `{"tokA": {"label": "alice", "rate_limit": 200}}`
+///
+/// # Errors
+///
+/// Returns error on malformed JSON, empty tokens, or non-positive rate limits
+pub fn parse_bearer_tokens(raw: &str) -> Result<Vec<BearerTokenConfig>, String> {
+    let raw = raw.trim();
+
+    let entries: Vec<BearerTokenConfig> = if raw.starts_with('{') {
+        let map: HashMap<String, BearerTokenJson> =
+            serde_json::from_str(raw).map_err(|e| format!("Invalid BEARER_TOKENS JSON: {}", e))?;
+        let mut entries: Vec<BearerTokenConfig> = map
+            .into_iter()
+            .map(|(token, entry)| BearerTokenConfig {
+                token,
+                label: entry.label,
+                rate_limit: entry.rate_limit,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.label.cmp(&b.label));
+        entries
+    } else {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .enumerate()
+            .map(|(idx, entry)| {
+                let mut parts = entry.splitn(3, ':');
+                let token = parts.next().unwrap_or_default().trim().to_string();
+                let label = parts
+                    .next()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("token_{}", idx + 1));
+                let rate_limit = parts
+                    .next()
+                    .map(|limit| {
+                        limit.trim().parse::<u32>().map_err(|_| {
+                            format!("Invalid rate limit '{}' for token '{}'", limit, label)
+                        })
+                    })
+                    .transpose()?;
+                Ok(BearerTokenConfig {
+                    token,
+                    label,
+                    rate_limit,
+                })
+            })
+            .collect::<Result<_, String>>()?
+    };
+
+    for entry in &entries {
+        if entry.token.is_empty() {
+            return Err(format!("Empty token for label '{}'", entry.label));
+        }
+        if entry.rate_limit == Some(0) {
+            return Err(format!(
+                "Rate limit for token '{}' must be greater than 0",
+                entry.label
+            ));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Parse the `CORS_ALLOWED_ORIGINS` value
+///
+/// Returns an empty list (any origin) when the value is blank or contains `*`.
+pub fn parse_cors_origins(raw: &str) -> Vec<String> {
+    let origins: Vec<String> = raw
+        .split(',')
+        .map(|o| o.trim().trim_end_matches('/'))
+        .filter(|o| !o.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    if origins.iter().any(|o| o == "*") {
+        Vec::new()
+    } else {
+        origins
+    }
+}
+
+/// Parse the `TRUSTED_PROXIES` value (comma-separated IP addresses)
+///
+/// # Errors
+///
+/// Returns error if an entry isn't an IP address
+pub fn parse_trusted_proxies(raw: &str) -> Result<Vec<IpAddr>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|ip| !ip.is_empty())
+        .map(|ip| {
+            ip.parse()
+                .map_err(|_| format!("Invalid TRUSTED_PROXIES entry '{}'", ip))
+        })
+        .collect()
+}
+
+/// Default capacity of each WebSocket stream's broadcast channel, in messages
+pub const DEFAULT_WS_BROADCAST_CAPACITY: usize = 100;
+
+/// Parse the `WS_BROADCAST_CAPACITY` value
+///
+/// Falls back to [`DEFAULT_WS_BROADCAST_CAPACITY`] when unset, zero or not a number.
+pub fn parse_ws_broadcast_capacity(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|capacity| *capacity > 0)
+        .unwrap_or(DEFAULT_WS_BROADCAST_CAPACITY)
+}
 
 /// HTTP server configuration
 ///
@@ -10,9 +151,13 @@ use std::net::SocketAddr;
 ///
 /// - `HTTP_HOST`: Server bind address (default: 127.0.0.1)
 /// - `HTTP_PORT`: Server port (default: 8080)
-/// - `HTTP_BEARER_TOKEN`: API authentication token (required)
+/// - `HTTP_BEARER_TOKEN`: API authentication token (required unless `BEARER_TOKENS` is set)
+/// - `BEARER_TOKENS`: Additional tokens with labels and per-token limits (see [`parse_bearer_tokens`])
 /// - `HTTP_RATE_LIMIT`: Requests per minute per client (default: 100)
 /// - `HTTP_MAX_WEBSOCKET_CONNECTIONS`: Max concurrent WebSocket connections (default: 50)
+/// - `WS_BROADCAST_CAPACITY`: Messages buffered per WebSocket stream before a slow client lags (default: 100)
+/// - `CORS_ALLOWED_ORIGINS`: Comma-separated browser origins allowed by CORS (default: `*`)
+/// - `TRUSTED_PROXIES`: Comma-separated proxy IPs whose `X-Forwarded-For` is honored (default: none)
 #[derive(Debug, Clone)]
 pub struct HttpConfig {
     /// Server bind address
@@ -20,13 +165,25 @@ pub struct HttpConfig {
 
     /// Bearer token for API authentication
     /// Clients must include `Authorization: Bearer <token>` header
-    pub bearer_token: String,
+    pub bearer_token: Option<String>,
+
+    /// Additional labeled tokens with optional per-token rate limits
+    pub bearer_tokens: Vec<BearerTokenConfig>,
 
     /// Rate limit: requests per minute per client
     pub rate_limit: u32,
 
     /// Maximum concurrent WebSocket connections
     pub max_websocket_connections: usize,
+
+    /// Messages buffered per WebSocket stream before a slow client lags
+    pub ws_broadcast_capacity: usize,
+
+    /// Allowed CORS origins (empty = any origin)
+    pub cors_allowed_origins: Vec<String>,
+
+    /// Reverse proxies whose `X-Forwarded-For` header is honored for rate limiting
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl HttpConfig {
@@ -34,7 +191,7 @@ impl HttpConfig {
     ///
     /// # Errors
     ///
-    /// Returns error if HTTP_BEARER_TOKEN is not set or invalid values provided
+    /// Returns error if neither HTTP_BEARER_TOKEN nor BEARER_TOKENS is set, or invalid values provided
     pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
         let host = std::env::var("HTTP_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
         let port: u16 = std::env::var("HTTP_PORT")
@@ -42,7 +199,19 @@ impl HttpConfig {
             .parse()?;
 
         let bearer_token = std::env::var("HTTP_BEARER_TOKEN")
-            .map_err(|_| "HTTP_BEARER_TOKEN environment variable is required")?;
+            .ok()
+            .filter(|t| !t.is_empty());
+
+        let bearer_tokens = match std::env::var("BEARER_TOKENS") {
+            Ok(raw) => parse_bearer_tokens(&raw)?,
+            Err(_) => Vec::new(),
+        };
+
+        if bearer_token.is_none() && bearer_tokens.is_empty() {
+            return Err(
+                "HTTP_BEARER_TOKEN or BEARER_TOKENS environment variable is required".into(),
+            );
+        }
 
         let rate_limit: u32 = std::env::var("HTTP_RATE_LIMIT")
             .unwrap_or_else(|_| "100".to_string())
@@ -52,11 +221,27 @@ impl HttpConfig {
             .unwrap_or_else(|_| "50".to_string())
             .parse()?;
 
+        let ws_broadcast_capacity =
+            parse_ws_broadcast_capacity(std::env::var("WS_BROADCAST_CAPACITY").ok().as_deref());
+
+        let cors_allowed_origins = std::env::var("CORS_ALLOWED_ORIGINS")
+            .map(|raw| parse_cors_origins(&raw))
+            .unwrap_or_default();
+
+        let trusted_proxies = match std::env::var("TRUSTED_PROXIES") {
+            Ok(raw) => parse_trusted_proxies(&raw)?,
+            Err(_) => Vec::new(),
+        };
+
         Ok(Self {
             addr: format!("{}:{}", host, port).parse()?,
             bearer_token,
+            bearer_tokens,
             rate_limit,
             max_websocket_connections,
+            ws_broadcast_capacity,
+            cors_allowed_origins,
+            trusted_proxies,
         })
     }
 }
@@ -71,17 +256,99 @@ mod tests {
         // SAFETY: Test-only code, single-threaded test environment
         unsafe {
             std::env::set_var("HTTP_BEARER_TOKEN", "test_token_12345");
+            std::env::remove_var("BEARER_TOKENS");
             std::env::remove_var("HTTP_HOST");
             std::env::remove_var("HTTP_PORT");
             std::env::remove_var("HTTP_RATE_LIMIT");
             std::env::remove_var("HTTP_MAX_WEBSOCKET_CONNECTIONS");
+            std::env::remove_var("WS_BROADCAST_CAPACITY");
+            std::env::remove_var("CORS_ALLOWED_ORIGINS");
+            std::env::remove_var("TRUSTED_PROXIES");
         }
 
         let config = HttpConfig::from_env().expect("Failed to load config");
 
         assert_eq!(config.addr.to_string(), "127.0.0.1:8080");
-        assert_eq!(config.bearer_token, "test_token_12345");
+        assert_eq!(config.bearer_token.as_deref(), Some("test_token_12345"));
+        assert!(config.bearer_tokens.is_empty());
         assert_eq!(config.rate_limit, 100);
         assert_eq!(config.max_websocket_connections, 50);
+        assert_eq!(config.ws_broadcast_capacity, DEFAULT_WS_BROADCAST_CAPACITY);
+        assert!(config.cors_allowed_origins.is_empty());
+        assert!(config.trusted_proxies.is_empty());
+    }
+
+    #[test]
+    fn test_parse_trusted_proxies() {
+        let proxies = parse_trusted_proxies("10.0.0.1, ::1,").unwrap();
+        assert_eq!(proxies.len(), 2);
+        assert_eq!(proxies[0].to_string(), "10.0.0.1");
+        assert_eq!(proxies[1].to_string(), "::1");
+
+        assert!(parse_trusted_proxies("10.0.0.0/8").is_err());
+    }
+
+    #[test]
+    fn test_parse_bearer_tokens_csv() {
+        let tokens = parse_bearer_tokens("tokA:alice:200, tokB:bob ,tokC").unwrap();
+
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].label, "alice");
+        assert_eq!(tokens[0].rate_limit, Some(200));
+        assert_eq!(tokens[1].label, "bob");
+        assert_eq!(tokens[1].rate_limit, None);
+        assert_eq!(tokens[2].token, "tokC");
+        assert_eq!(tokens[2].label, "token_3");
+    }
+
+    #[test]
+    fn test_parse_bearer_tokens_json() {
+        let tokens = parse_bearer_tokens(
+            r#"{"tokA": {"label": "alice", "rate_limit": 200}, "tokB": {"label": "bob"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].token, "tokA");
+        assert_eq!(tokens[0].rate_limit, Some(200));
+        assert_eq!(tokens[1].label, "bob");
+        assert_eq!(tokens[1].rate_limit, None);
+    }
+
+    #[test]
+    fn test_parse_bearer_tokens_invalid() {
+        assert!(parse_bearer_tokens("tokA:alice:abc").is_err());
+        assert!(parse_bearer_tokens("tokA:alice:0").is_err());
+        assert!(parse_bearer_tokens(":alice").is_err());
+        assert!(parse_bearer_tokens("{not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_cors_origins() {
+        assert_eq!(
+            parse_cors_origins("https://app.example.com, http://localhost:3000/"),
+            vec!["https://app.example.com", "http://localhost:3000"]
+        );
+        assert!(parse_cors_origins("*").is_empty());
+        assert!(parse_cors_origins("https://a.example.com,*").is_empty());
+        assert!(parse_cors_origins("  ").is_empty());
+    }
+
+    #[test]
+    fn test_parse_ws_broadcast_capacity() {
+        assert_eq!(
+            parse_ws_broadcast_capacity(None),
+            DEFAULT_WS_BROADCAST_CAPACITY
+        );
+        assert_eq!(parse_ws_broadcast_capacity(Some(" 1024 ")), 1024);
+        // A zero-capacity broadcast channel can't be created
+        assert_eq!(
+            parse_ws_broadcast_capacity(Some("0")),
+            DEFAULT_WS_BROADCAST_CAPACITY
+        );
+        assert_eq!(
+            parse_ws_broadcast_capacity(Some("lots")),
+            DEFAULT_WS_BROADCAST_CAPACITY
+        );
     }
 }
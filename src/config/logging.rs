@@ -0,0 +1,132 @@
+//! Logging configuration
+//!
+//! Selects the log output format and which metadata is included. Logs always
+//! go to stderr, since stdout carries the MCP protocol in stdio mode.
+//!
+//! ## Configuration
+//!
+//! - `LOG_FORMAT`: `text` (default) or `json` for structured logs (Loki, Datadog, ...)
+//! - `LOG_TARGET`: Include the module target in each line (default: true)
+//! - `LOG_THREAD_IDS`: Include the thread ID in each line (default: false)
+//! - `RUST_LOG`: Level filter (default: info)
+
+use crate::binance::limits::parse_flag;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+/// Log output format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable single-line text
+    #[default]
+    Text,
+    /// One JSON object per event
+    Json,
+}
+
+/// Parses `LOG_FORMAT`, falling back to text on missing or unknown values
+pub fn parse_log_format(value: Option<&str>) -> LogFormat {
+    match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+        Some("json") => LogFormat::Json,
+        _ => LogFormat::Text,
+    }
+}
+
+/// Parses an on/off variable, keeping `default` when it is unset or blank
+fn parse_toggle(value: Option<&str>, default: bool) -> bool {
+    match value.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(v) => parse_flag(Some(v)),
+        None => default,
+    }
+}
+
+/// Logging settings read from the environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogConfig {
+    /// Output format
+    pub format: LogFormat,
+    /// Include the event target (module path)
+    pub with_target: bool,
+    /// Include the emitting thread's ID
+    pub with_thread_ids: bool,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Text,
+            with_target: true,
+            with_thread_ids: false,
+        }
+    }
+}
+
+impl LogConfig {
+    /// Loads `LOG_FORMAT`, `LOG_TARGET` and `LOG_THREAD_IDS`
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            format: parse_log_format(std::env::var("LOG_FORMAT").ok().as_deref()),
+            with_target: parse_toggle(
+                std::env::var("LOG_TARGET").ok().as_deref(),
+                defaults.with_target,
+            ),
+            with_thread_ids: parse_toggle(
+                std::env::var("LOG_THREAD_IDS").ok().as_deref(),
+                defaults.with_thread_ids,
+            ),
+        }
+    }
+
+    /// Installs the global tracing subscriber writing to stderr
+    ///
+    /// The level filter comes from `RUST_LOG` (default: info). Must be called once,
+    /// before any other subscriber is set.
+    pub fn init(&self) {
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+        let text = (self.format == LogFormat::Text).then(|| {
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_ansi(false) // No ANSI colors for cleaner logs
+                .with_target(self.with_target)
+                .with_thread_ids(self.with_thread_ids)
+        });
+        let json = (self.format == LogFormat::Json).then(|| {
+            tracing_subscriber::fmt::layer()
+                .json()
+                .flatten_event(true)
+                .with_current_span(true)
+                .with_writer(std::io::stderr)
+                .with_target(self.with_target)
+                .with_thread_ids(self.with_thread_ids)
+        });
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(text)
+            .with(json)
+            .init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(parse_log_format(Some("json")), LogFormat::Json);
+        assert_eq!(parse_log_format(Some(" JSON ")), LogFormat::Json);
+        assert_eq!(parse_log_format(Some("text")), LogFormat::Text);
+        assert_eq!(parse_log_format(Some("yaml")), LogFormat::Text);
+        assert_eq!(parse_log_format(None), LogFormat::Text);
+    }
+
+    #[test]
+    fn test_parse_toggle_keeps_default_when_unset() {
+        assert!(parse_toggle(None, true));
+        assert!(!parse_toggle(Some(""), false));
+        assert!(!parse_toggle(Some("false"), true));
+        assert!(parse_toggle(Some("1"), false));
+    }
+}
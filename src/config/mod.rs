@@ -3,12 +3,20 @@
 //! This module handles loading and managing configuration including API credentials.
 
 pub mod credentials;
+pub mod default_symbol;
+pub mod logging;
+pub mod protocol;
+pub mod server_identity;
+pub mod tools;
 
 #[cfg(feature = "http-api")]
 pub mod http;
 
 // Re-export
 pub use credentials::Credentials;
+pub use protocol::ProtocolNegotiation;
+pub use server_identity::ServerIdentity;
+pub use tools::ToolFilter;
 
 #[cfg(feature = "http-api")]
 pub use http::HttpConfig;
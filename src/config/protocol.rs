@@ -0,0 +1,127 @@
+//! MCP protocol version negotiation at initialization
+//!
+//! Clients send the protocol version they want in the `initialize` params.
+//! A version this server implements is echoed back; anything else (a newer
+//! draft, a typo, no version at all) gets the configured default, and the
+//! client decides whether it can work with that.
+//!
+//! ## Configuration
+//!
+//! - `MCP_PROTOCOL_VERSION`: Version answered when the requested one isn't supported (default: `2024-11-05`)
+
+use rmcp::model::ProtocolVersion;
+
+/// Protocol versions this server implements, newest first
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[ProtocolVersion] =
+    &[ProtocolVersion::V_2025_03_26, ProtocolVersion::V_2024_11_05];
+
+/// Version answered when the client's request can't be honored
+pub const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V_2024_11_05;
+
+/// Supported version matching `version`, if any
+fn supported(version: &str) -> Option<ProtocolVersion> {
+    SUPPORTED_PROTOCOL_VERSIONS
+        .iter()
+        .find(|supported| supported.to_string() == version.trim())
+        .cloned()
+}
+
+/// Picks the protocol version of each `initialize` response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolNegotiation {
+    /// Version answered for unsupported or missing requests
+    pub default: ProtocolVersion,
+}
+
+impl Default for ProtocolNegotiation {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_PROTOCOL_VERSION,
+        }
+    }
+}
+
+impl ProtocolNegotiation {
+    /// Builds the negotiation from an optional default override
+    ///
+    /// Blank or unsupported overrides keep [`DEFAULT_PROTOCOL_VERSION`].
+    pub fn parse(default: Option<&str>) -> Self {
+        match default.map(str::trim).filter(|v| !v.is_empty()) {
+            None => Self::default(),
+            Some(version) => match supported(version) {
+                Some(default) => Self { default },
+                None => {
+                    tracing::warn!(
+                        "Unsupported MCP_PROTOCOL_VERSION '{}'; using {} (supported: {})",
+                        version,
+                        DEFAULT_PROTOCOL_VERSION,
+                        SUPPORTED_PROTOCOL_VERSIONS
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                    Self::default()
+                }
+            },
+        }
+    }
+
+    /// Reads `MCP_PROTOCOL_VERSION`
+    pub fn from_env() -> Self {
+        Self::parse(std::env::var("MCP_PROTOCOL_VERSION").ok().as_deref())
+    }
+
+    /// Version to answer a client that requested `requested`
+    pub fn negotiate(&self, requested: Option<&str>) -> ProtocolVersion {
+        requested
+            .and_then(supported)
+            .unwrap_or_else(|| self.default.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_version_is_echoed() {
+        let negotiation = ProtocolNegotiation::default();
+        assert_eq!(
+            negotiation.negotiate(Some("2025-03-26")),
+            ProtocolVersion::V_2025_03_26
+        );
+        assert_eq!(
+            negotiation.negotiate(Some("2024-11-05")),
+            ProtocolVersion::V_2024_11_05
+        );
+    }
+
+    #[test]
+    fn test_unknown_version_gets_default() {
+        let negotiation = ProtocolNegotiation::default();
+        assert_eq!(
+            negotiation.negotiate(Some("2099-01-01")),
+            DEFAULT_PROTOCOL_VERSION
+        );
+        assert_eq!(negotiation.negotiate(None), DEFAULT_PROTOCOL_VERSION);
+
+        let newer_default = ProtocolNegotiation::parse(Some("2025-03-26"));
+        assert_eq!(
+            newer_default.negotiate(Some("draft")),
+            ProtocolVersion::V_2025_03_26
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported_default() {
+        assert_eq!(
+            ProtocolNegotiation::parse(Some("1.0")),
+            ProtocolNegotiation::default()
+        );
+        assert_eq!(
+            ProtocolNegotiation::parse(Some("  ")),
+            ProtocolNegotiation::default()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Server name and instructions advertised at MCP initialization
+//!
+//! Operators deploying a branded or restricted instance can replace what the
+//! client and model see in the `initialize` response, e.g. to inject usage
+//! guidance such as "testnet only, do not place mainnet orders".
+//!
+//! ## Configuration
+//!
+//! - `MCP_SERVER_NAME`: Server name and title (default: `mcp-binance-server` / `Binance MCP Server`)
+//! - `MCP_SERVER_INSTRUCTIONS`: Instructions string (default: built-in server description)
+
+/// Default server name reported in `serverInfo.name`
+pub const DEFAULT_SERVER_NAME: &str = "mcp-binance-server";
+
+/// Default server title reported in `serverInfo.title`
+pub const DEFAULT_SERVER_TITLE: &str = "Binance MCP Server";
+
+/// Default instructions reported at initialization
+pub const DEFAULT_INSTRUCTIONS: &str = "Binance MCP Server for trading and market data. \
+    Provides tools for market data/account/trading operations, \
+    prompts for AI-guided analysis, and resources for efficient data access.";
+
+/// Name, title and instructions returned to clients at initialization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerIdentity {
+    /// Server name (`serverInfo.name`)
+    pub name: String,
+    /// Human-readable title (`serverInfo.title`)
+    pub title: String,
+    /// Instructions for the model
+    pub instructions: String,
+}
+
+impl Default for ServerIdentity {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_SERVER_NAME.to_string(),
+            title: DEFAULT_SERVER_TITLE.to_string(),
+            instructions: DEFAULT_INSTRUCTIONS.to_string(),
+        }
+    }
+}
+
+impl ServerIdentity {
+    /// Builds the identity from optional overrides (blank values keep the defaults)
+    ///
+    /// A custom name replaces both the name and the title.
+    pub fn parse(name: Option<&str>, instructions: Option<&str>) -> Self {
+        let non_blank = |value: Option<&str>| {
+            value
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+
+        let mut identity = Self::default();
+        if let Some(name) = non_blank(name) {
+            identity.title = name.clone();
+            identity.name = name;
+        }
+        if let Some(instructions) = non_blank(instructions) {
+            identity.instructions = instructions;
+        }
+        identity
+    }
+
+    /// Reads `MCP_SERVER_NAME` and `MCP_SERVER_INSTRUCTIONS`
+    pub fn from_env() -> Self {
+        Self::parse(
+            std::env::var("MCP_SERVER_NAME").ok().as_deref(),
+            std::env::var("MCP_SERVER_INSTRUCTIONS").ok().as_deref(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_server_identity() {
+        assert_eq!(ServerIdentity::parse(None, None), ServerIdentity::default());
+        assert_eq!(
+            ServerIdentity::parse(Some("  "), Some("")),
+            ServerIdentity::default()
+        );
+
+        let custom = ServerIdentity::parse(
+            Some(" Acme Testnet Desk "),
+            Some("Testnet only, do not place mainnet orders."),
+        );
+        assert_eq!(custom.name, "Acme Testnet Desk");
+        assert_eq!(custom.title, "Acme Testnet Desk");
+        assert_eq!(
+            custom.instructions,
+            "Testnet only, do not place mainnet orders."
+        );
+
+        let instructions_only = ServerIdentity::parse(None, Some("Read-only deployment."));
+        assert_eq!(instructions_only.name, DEFAULT_SERVER_NAME);
+        assert_eq!(instructions_only.title, DEFAULT_SERVER_TITLE);
+    }
+}
@@ -0,0 +1,101 @@
+//! Exposed tool configuration
+//!
+//! Lets operators hide tools without recompiling, e.g. to run a read-only
+//! public endpoint without any order or account tools. Hidden tools are absent
+//! from `tools/list` and calling them returns a `TOOL_DISABLED` error.
+//!
+//! ## Configuration
+//!
+//! - `TOOLS_ENABLED`: Comma-separated allowlist; when set, only these tools are exposed
+//! - `TOOLS_DISABLED`: Comma-separated denylist, applied after the allowlist
+
+use rmcp::ErrorData;
+use std::collections::HashSet;
+
+/// Which tools the server exposes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolFilter {
+    /// Allowlist (`None` allows every tool)
+    pub enabled: Option<HashSet<String>>,
+    /// Denylist
+    pub disabled: HashSet<String>,
+}
+
+/// Splits a comma-separated tool list, dropping blanks
+fn parse_tool_list(value: &str) -> HashSet<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+impl ToolFilter {
+    /// Builds a filter from `TOOLS_ENABLED` / `TOOLS_DISABLED` values
+    ///
+    /// A missing or blank allowlist allows every tool.
+    pub fn parse(enabled: Option<&str>, disabled: Option<&str>) -> Self {
+        Self {
+            enabled: enabled
+                .map(parse_tool_list)
+                .filter(|names| !names.is_empty()),
+            disabled: disabled.map(parse_tool_list).unwrap_or_default(),
+        }
+    }
+
+    /// Reads `TOOLS_ENABLED` and `TOOLS_DISABLED`
+    pub fn from_env() -> Self {
+        Self::parse(
+            std::env::var("TOOLS_ENABLED").ok().as_deref(),
+            std::env::var("TOOLS_DISABLED").ok().as_deref(),
+        )
+    }
+
+    /// Whether `tool` is exposed
+    pub fn allows(&self, tool: &str) -> bool {
+        self.enabled
+            .as_ref()
+            .map_or(true, |enabled| enabled.contains(tool))
+            && !self.disabled.contains(tool)
+    }
+
+    /// Rejects calls to hidden tools
+    ///
+    /// # Errors
+    /// Returns an invalid-request error with `error_code: TOOL_DISABLED`
+    pub fn check(&self, tool: &str) -> Result<(), ErrorData> {
+        if self.allows(tool) {
+            return Ok(());
+        }
+        Err(ErrorData::invalid_request(
+            format!("Tool '{}' is disabled on this server", tool),
+            Some(serde_json::json!({
+                "error_code": "TOOL_DISABLED",
+                "tool": tool,
+            })),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowlist_and_denylist() {
+        let all = ToolFilter::parse(None, Some(" "));
+        assert!(all.allows("place_order"));
+
+        let read_only = ToolFilter::parse(Some("get_ticker, get_order_book,"), None);
+        assert!(read_only.allows("get_ticker"));
+        assert!(!read_only.allows("place_order"));
+
+        let filter = ToolFilter::parse(Some("get_ticker,place_order"), Some("place_order"));
+        assert!(filter.allows("get_ticker"));
+        assert!(!filter.allows("place_order"));
+
+        let err = filter.check("place_order").unwrap_err();
+        assert_eq!(err.data.unwrap()["error_code"], "TOOL_DISABLED");
+    }
+}
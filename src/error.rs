@@ -67,6 +67,9 @@ pub fn mask_api_key(key: &str) -> String {
     format!("{}****{}", &key[..4], &key[key.len() - 4..])
 }
 
+/// Wait suggested for a Binance 429 whose response has no `Retry-After` header
+pub const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
+
 /// Main error type for MCP Binance Server
 ///
 /// All errors in the system are represented by this enum. Error messages are
@@ -79,8 +82,12 @@ pub enum McpError {
     ConnectionError(String),
 
     /// HTTP 429 responses from Binance (rate limit exceeded)
-    #[error("Rate limit exceeded: {0}")]
-    RateLimitError(String),
+    #[error("Rate limit exceeded: {message}")]
+    RateLimitError {
+        message: String,
+        /// Seconds to wait before retrying (`Retry-After`, or [`DEFAULT_RETRY_AFTER_SECS`])
+        retry_after_secs: u64,
+    },
 
     /// JSON deserialization or parsing failures
     #[error("Parse error: {0}")]
@@ -100,19 +107,57 @@ pub enum McpError {
 }
 
 impl McpError {
+    /// Rate limit error for a 429, waiting `retry_after_secs` or [`DEFAULT_RETRY_AFTER_SECS`]
+    pub fn rate_limited(retry_after_secs: Option<u64>) -> Self {
+        let retry_after_secs = retry_after_secs.unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+        McpError::RateLimitError {
+            message: format!(
+                "Too many requests to Binance API. Retry after {} seconds.",
+                retry_after_secs
+            ),
+            retry_after_secs,
+        }
+    }
+
+    /// Replaces a rate limit error's default wait with the response's `Retry-After`
+    ///
+    /// Other errors, and a missing header, leave the error unchanged.
+    pub fn with_retry_after(self, retry_after_secs: Option<u64>) -> Self {
+        match (self, retry_after_secs) {
+            (McpError::RateLimitError { .. }, Some(secs)) => McpError::rate_limited(Some(secs)),
+            (err, _) => err,
+        }
+    }
+
+    /// Seconds to wait before retrying, for rate limit errors
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            McpError::RateLimitError {
+                retry_after_secs, ..
+            } => Some(*retry_after_secs),
+            _ => None,
+        }
+    }
+
     /// Returns true if this error type should trigger retry logic
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
-            McpError::ConnectionError(_) | McpError::RateLimitError(_)
+            McpError::ConnectionError(_) | McpError::RateLimitError { .. }
         )
     }
 
+    /// Returns true for Binance-side outages (connection failures, timeouts
+    /// and 5xx responses), which all map to `ConnectionError`
+    pub fn is_outage(&self) -> bool {
+        matches!(self, McpError::ConnectionError(_))
+    }
+
     /// Returns error type string for MCP protocol responses
     pub fn error_type(&self) -> &'static str {
         match self {
             McpError::ConnectionError(_) => "connection_error",
-            McpError::RateLimitError(_) => "rate_limit",
+            McpError::RateLimitError { .. } => "rate_limit",
             McpError::ParseError(_) => "parse_error",
             McpError::InvalidRequest(_) => "invalid_request",
             McpError::NotReady(_) => "not_ready",
@@ -135,11 +180,10 @@ impl From<reqwest::Error> for McpError {
             )
         } else if let Some(status) = err.status() {
             match status.as_u16() {
-                429 => McpError::RateLimitError(
-                    "Too many requests to Binance API. Retry after 60 seconds.".to_string(),
-                ),
+                429 => McpError::rate_limited(None),
                 418 => McpError::ConnectionError(
-                    "IP address banned by Binance. Please contact support.".to_string(),
+                    "IP address banned by Binance (-418). Please wait for the ban to expire."
+                        .to_string(),
                 ),
                 403 => McpError::ConnectionError(
                     "WAF limit violated. Please reduce request frequency.".to_string(),
@@ -187,7 +231,7 @@ impl axum::response::IntoResponse for McpError {
             McpError::ConnectionError(_) => {
                 (StatusCode::BAD_GATEWAY, self.error_type(), self.to_string())
             }
-            McpError::RateLimitError(_) => (
+            McpError::RateLimitError { .. } => (
                 StatusCode::TOO_MANY_REQUESTS,
                 self.error_type(),
                 self.to_string(),
@@ -245,6 +289,10 @@ pub enum CredentialError {
     #[error("Invalid environment: {0}")]
     InvalidEnvironment(String),
 
+    /// Session to store credentials in doesn't exist (or has expired)
+    #[error("Session {0} not found. Ensure SSE connection is active.")]
+    SessionNotFound(String),
+
     /// Binance API rejected credentials
     #[error("Binance API error: {message}")]
     BinanceApiError { message: String, code: i32 },
@@ -265,6 +313,7 @@ impl CredentialError {
     /// - `INVALID_API_KEY_FORMAT`: API key not 64 alphanumeric → Fix format
     /// - `INVALID_API_SECRET_FORMAT`: API secret not 64 alphanumeric → Fix format
     /// - `INVALID_ENVIRONMENT`: Environment not testnet/mainnet → Use valid value
+    /// - `SESSION_NOT_FOUND`: Session missing or expired → Reconnect the SSE session
     /// - `BINANCE_API_ERROR`: Binance API rejected credentials → Check permissions
     /// - `BINANCE_RATE_LIMIT`: Rate limit exceeded → Wait retry_after seconds
     ///
@@ -359,6 +408,10 @@ impl CredentialError {
                 "error_code": "INVALID_ENVIRONMENT",
                 "message": reason
             }),
+            Self::SessionNotFound(_) => json!({
+                "error_code": "SESSION_NOT_FOUND",
+                "message": self.to_string()
+            }),
             Self::BinanceApiError { message, code } => json!({
                 "error_code": "BINANCE_API_ERROR",
                 "message": message,
@@ -373,6 +426,32 @@ impl CredentialError {
     }
 }
 
+// Credential failures are tool errors carrying the `to_json` payload as data,
+// so clients can branch on the call failing and still read `error_code`
+impl From<CredentialError> for rmcp::ErrorData {
+    fn from(err: CredentialError) -> Self {
+        use rmcp::model::ErrorCode;
+
+        let data = Some(err.to_json());
+        match err {
+            CredentialError::InvalidApiKeyFormat(_)
+            | CredentialError::InvalidApiSecretFormat(_)
+            | CredentialError::InvalidEnvironment(_) => {
+                rmcp::ErrorData::invalid_params(err.to_string(), data)
+            }
+            CredentialError::NotConfigured | CredentialError::SessionNotFound(_) => {
+                rmcp::ErrorData::invalid_request(err.to_string(), data)
+            }
+            CredentialError::RateLimitExceeded { .. } => {
+                rmcp::ErrorData::new(ErrorCode(-32001), err.to_string(), data)
+            }
+            CredentialError::BinanceApiError { .. } => {
+                rmcp::ErrorData::internal_error(err.to_string(), data)
+            }
+        }
+    }
+}
+
 // MCP ErrorData conversion for enhanced error reporting
 impl From<BinanceError> for rmcp::ErrorData {
     fn from(err: BinanceError) -> Self {
@@ -436,3 +515,295 @@ impl From<BinanceError> for rmcp::ErrorData {
         }
     }
 }
+
+/// Plain-language explanation of a Binance error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct BinanceErrorExplanation {
+    /// Binance error code (e.g., -2010)
+    pub code: i32,
+    /// Binance's name for the code (e.g., NEW_ORDER_REJECTED)
+    pub name: &'static str,
+    /// What the error means
+    pub meaning: &'static str,
+    /// Usual reasons a request gets it
+    pub common_causes: &'static [&'static str],
+    /// What to do next
+    pub recovery: &'static str,
+}
+
+/// Explanations of the most common spot trading error codes
+///
+/// -418 is not a Binance `code` but the HTTP 418 auto-ban, listed so it
+/// can be looked up the same way.
+const BINANCE_ERRORS: &[BinanceErrorExplanation] = &[
+    BinanceErrorExplanation {
+        code: -418,
+        name: "IP_BANNED",
+        meaning: "The IP address was automatically banned for continuing to send requests after rate limit (429) responses.",
+        common_causes: &[
+            "Retrying immediately after 429 responses instead of waiting",
+            "Several clients sharing one IP and its request weight",
+        ],
+        recovery: "Stop sending requests until the ban expires (2 minutes up to 3 days, growing with repeat offenses), then honor Retry-After on every 429.",
+    },
+    BinanceErrorExplanation {
+        code: -1003,
+        name: "TOO_MANY_REQUESTS",
+        meaning: "Too much request weight was used in the current window; the IP may be banned until a given time.",
+        common_causes: &[
+            "Polling endpoints faster than the weight limit allows",
+            "Heavy requests such as all-symbol tickers or deep order books",
+        ],
+        recovery: "Wait for the Retry-After period, then lower the request rate or switch to WebSocket streams.",
+    },
+    BinanceErrorExplanation {
+        code: -1013,
+        name: "FILTER_FAILURE",
+        meaning: "The order failed one of the symbol's filters (LOT_SIZE, PRICE_FILTER, NOTIONAL, ...).",
+        common_causes: &[
+            "Quantity below the minimum or not a multiple of the step size",
+            "Price not a multiple of the tick size",
+            "Order value (price × quantity) below the minimum notional",
+        ],
+        recovery: "Check the symbol's rules with explain_symbol_rules, round quantity and price to the allowed increments and resubmit.",
+    },
+    BinanceErrorExplanation {
+        code: -1015,
+        name: "TOO_MANY_ORDERS",
+        meaning: "Too many new orders were placed in the current interval.",
+        common_causes: &[
+            "Placing orders in a tight loop",
+            "Exceeding the per-10-second or daily order count limit",
+        ],
+        recovery: "Wait for the interval in the message to pass, then space orders out.",
+    },
+    BinanceErrorExplanation {
+        code: -1021,
+        name: "INVALID_TIMESTAMP",
+        meaning: "The request timestamp is outside the recvWindow or ahead of the server time.",
+        common_causes: &[
+            "Local clock drift from Binance server time",
+            "A slow network delaying the request past recvWindow",
+        ],
+        recovery: "Retry the request; the server resynchronizes its clock offset after this error. Raise recvWindow if it persists.",
+    },
+    BinanceErrorExplanation {
+        code: -1100,
+        name: "ILLEGAL_CHARS",
+        meaning: "A parameter contains characters Binance does not accept.",
+        common_causes: &[
+            "Spaces, separators or lowercase letters in a symbol (e.g., BTC-USDT)",
+            "Unsupported characters in a client order ID",
+        ],
+        recovery: "Use uppercase symbols without separators and client order IDs of letters, digits, '-' and '_' only.",
+    },
+    BinanceErrorExplanation {
+        code: -1111,
+        name: "BAD_PRECISION",
+        meaning: "A quantity or price has more decimal places than the asset allows.",
+        common_causes: &[
+            "Sending a computed quantity without rounding it to the step size",
+            "Floating point artifacts such as 0.30000000000000004",
+        ],
+        recovery: "Round quantity to the LOT_SIZE step and price to the PRICE_FILTER tick before submitting.",
+    },
+    BinanceErrorExplanation {
+        code: -1121,
+        name: "BAD_SYMBOL",
+        meaning: "The symbol does not exist on this exchange.",
+        common_causes: &[
+            "Typos or reversed assets (USDTBTC instead of BTCUSDT)",
+            "Pairs listed on mainnet but not on testnet, or delisted",
+        ],
+        recovery: "Check the symbol against exchangeInfo and use the exact pair name.",
+    },
+    BinanceErrorExplanation {
+        code: -2010,
+        name: "NEW_ORDER_REJECTED",
+        meaning: "The matching engine rejected the new order.",
+        common_causes: &[
+            "Insufficient balance for the order",
+            "A LIMIT_MAKER order that would immediately match",
+            "The symbol is not trading or the order type is not allowed",
+        ],
+        recovery: "Read the message for the exact reason, check the balance with get_account_info and the symbol's status and order types, then resubmit.",
+    },
+    BinanceErrorExplanation {
+        code: -2011,
+        name: "CANCEL_REJECTED",
+        meaning: "The cancel request was rejected, usually because the order is unknown.",
+        common_causes: &[
+            "The order already filled, was canceled or expired",
+            "Wrong symbol or order ID",
+        ],
+        recovery: "Query the order to see its final status; there is nothing left to cancel if it is no longer open.",
+    },
+    BinanceErrorExplanation {
+        code: -2013,
+        name: "NO_SUCH_ORDER",
+        meaning: "The order does not exist.",
+        common_causes: &[
+            "Wrong order ID or client order ID, or the wrong symbol",
+            "Querying an order placed on a different account or environment",
+        ],
+        recovery: "Check the symbol and ID, or list orders with get_all_orders to find it.",
+    },
+];
+
+/// Looks up the explanation of a Binance error code
+///
+/// Codes are negative; a positive code (e.g., 2011 or 418) is treated as its
+/// negative counterpart.
+pub fn explain_binance_error(code: i32) -> Option<BinanceErrorExplanation> {
+    let code = -code.saturating_abs();
+    BINANCE_ERRORS.iter().find(|e| e.code == code).copied()
+}
+
+/// Codes [`explain_binance_error`] knows, ascending by magnitude
+pub fn known_binance_error_codes() -> Vec<i32> {
+    BINANCE_ERRORS.iter().map(|e| e.code).collect()
+}
+
+/// Binance error code quoted in an error message as "(-2010)"
+fn binance_error_code_in(message: &str) -> Option<i32> {
+    message.match_indices("(-").find_map(|(start, _)| {
+        let rest = &message[start + 1..];
+        let end = rest.find(')')?;
+        rest[..end].parse::<i32>().ok()
+    })
+}
+
+/// Converts a Binance client error into a tool error with a matching code
+///
+/// Rejected requests (invalid symbol, bad parameters, other 4xx) become
+/// `invalid_params`, rate limits the -32001 code used for
+/// [`BinanceError::RateLimited`], and connection failures, parse errors and
+/// other server faults `internal_error`. The data carries the
+/// [`McpError::error_type`], so clients can still tell an outage
+/// (`connection_error`) from an unexpected fault, and for rate limits the
+/// `retry_after_secs` Binance asked for. When the message quotes a Binance
+/// code [`explain_binance_error`] knows, its `binance_code` and
+/// `explanation` are attached too.
+pub fn map_binance_err(err: McpError) -> rmcp::ErrorData {
+    use rmcp::model::ErrorCode;
+    use serde_json::json;
+
+    let mut data = json!({ "error_type": err.error_type() });
+    if let McpError::RateLimitError {
+        retry_after_secs, ..
+    } = &err
+    {
+        data["retry_after_secs"] = json!(retry_after_secs);
+    }
+    if let Some(explanation) =
+        binance_error_code_in(&err.to_string()).and_then(explain_binance_error)
+    {
+        data["binance_code"] = json!(explanation.code);
+        data["explanation"] = json!(explanation);
+    }
+
+    let data = Some(data);
+    match err {
+        McpError::InvalidRequest(_) => rmcp::ErrorData::invalid_params(err.to_string(), data),
+        McpError::RateLimitError { .. } => {
+            rmcp::ErrorData::new(ErrorCode(-32001), err.to_string(), data)
+        }
+        McpError::ConnectionError(_)
+        | McpError::ParseError(_)
+        | McpError::NotReady(_)
+        | McpError::InternalError(_) => rmcp::ErrorData::internal_error(err.to_string(), data),
+    }
+}
+
+/// Machine-readable error code for a failed tool call
+///
+/// Prefers an explicit `error_code` in the error data, then maps the codes
+/// produced from [`BinanceError`] and the standard JSON-RPC codes.
+pub fn tool_error_code(err: &rmcp::ErrorData) -> String {
+    if let Some(code) = err
+        .data
+        .as_ref()
+        .and_then(|data| data.get("error_code"))
+        .and_then(|code| code.as_str())
+    {
+        return code.to_string();
+    }
+
+    match err.code.0 {
+        -32001 => "RATE_LIMITED",
+        -32002 => "INVALID_CREDENTIALS",
+        -32003 => "INVALID_SYMBOL",
+        -32004 => "INSUFFICIENT_BALANCE",
+        -32600 => "INVALID_REQUEST",
+        -32602 => "INVALID_PARAMS",
+        -32603 => "INTERNAL_ERROR",
+        _ => "TOOL_ERROR",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_common_codes() {
+        let rejected = explain_binance_error(-2010).unwrap();
+        assert_eq!(rejected.name, "NEW_ORDER_REJECTED");
+        assert!(rejected
+            .common_causes
+            .iter()
+            .any(|cause| cause.contains("Insufficient balance")));
+
+        assert_eq!(explain_binance_error(-1111).unwrap().name, "BAD_PRECISION");
+        assert_eq!(
+            explain_binance_error(-1021).unwrap().name,
+            "INVALID_TIMESTAMP"
+        );
+        assert_eq!(
+            explain_binance_error(-2011).unwrap().name,
+            "CANCEL_REJECTED"
+        );
+
+        // Positive codes are looked up as their negative counterparts
+        assert_eq!(explain_binance_error(418).unwrap().code, -418);
+        assert_eq!(explain_binance_error(-9999), None);
+    }
+
+    #[test]
+    fn test_every_listed_code_is_explained() {
+        for code in [
+            -1013, -1021, -1100, -1111, -1121, -2010, -2011, -2013, -1003, -1015, -418,
+        ] {
+            let explanation = explain_binance_error(code).unwrap();
+            assert_eq!(explanation.code, code);
+            assert!(!explanation.common_causes.is_empty());
+            assert!(!explanation.recovery.is_empty());
+        }
+        assert_eq!(known_binance_error_codes().len(), 11);
+    }
+
+    #[test]
+    fn test_tool_error_carries_explanation() {
+        let err = map_binance_err(McpError::InvalidRequest(
+            "Binance rejected the request: Account has insufficient balance for requested action. (-2010)"
+                .to_string(),
+        ));
+        let data = err.data.unwrap();
+        assert_eq!(data["binance_code"], -2010);
+        assert_eq!(data["explanation"]["name"], "NEW_ORDER_REJECTED");
+        assert_eq!(data["error_type"], "invalid_request");
+
+        let limited = map_binance_err(McpError::RateLimitError {
+            message: "Too many new orders (-1015). Retry after 10 seconds.".to_string(),
+            retry_after_secs: 10,
+        });
+        let data = limited.data.unwrap();
+        assert_eq!(data["retry_after_secs"], 10);
+        assert_eq!(data["explanation"]["name"], "TOO_MANY_ORDERS");
+
+        // No quoted code, no explanation
+        let outage = map_binance_err(McpError::ConnectionError("timeout".to_string()));
+        assert!(outage.data.unwrap().get("explanation").is_none());
+    }
+}
@@ -1,11 +1,15 @@
 //! Bearer Token Authentication Middleware
 //!
 //! Validates Authorization: Bearer <token> headers against configured tokens.
-//! Tokens are loaded from HTTP_BEARER_TOKEN environment variable.
+//! Tokens are loaded from the HTTP_BEARER_TOKEN and BEARER_TOKENS environment variables.
+//!
+//! WebSocket upgrades (`/ws/*`) are authenticated by the upgrade handlers
+//! instead, via [`authorize_websocket`], because browsers can't set an
+//! Authorization header on a WebSocket handshake.
 
 use axum::{
-    extract::Request,
-    http::{HeaderMap, StatusCode},
+    extract::{Query, Request},
+    http::{header, HeaderMap, StatusCode, Uri},
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -19,6 +23,8 @@ pub struct TokenMetadata {
     pub name: String,
     /// When this token was created (for auditing)
     pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Per-token rate limit in requests per minute (None = server default)
+    pub rate_limit: Option<u32>,
 }
 
 /// Thread-safe store for valid authentication tokens
@@ -46,10 +52,22 @@ impl TokenStore {
     /// - `token`: The raw token string (will be hashed)
     /// - `name`: Human-readable identifier for this token
     pub fn add_token(&self, token: &str, name: String) {
+        self.add_token_with_limit(token, name, None);
+    }
+
+    /// Add a token with its own rate limit
+    ///
+    /// ## Arguments
+    ///
+    /// - `token`: The raw token string (will be hashed)
+    /// - `name`: Human-readable identifier for this token (used as rate limit key)
+    /// - `rate_limit`: Requests per minute for this token (None = server default)
+    pub fn add_token_with_limit(&self, token: &str, name: String, rate_limit: Option<u32>) {
         let hash = Self::hash_token(token);
         let metadata = TokenMetadata {
             name,
             created_at: chrono::Utc::now(),
+            rate_limit,
         };
 
         let mut tokens = self.tokens.write().expect("Token store lock poisoned");
@@ -66,6 +84,16 @@ impl TokenStore {
         tokens.get(&hash).cloned().ok_or(StatusCode::UNAUTHORIZED)
     }
 
+    /// Number of registered tokens
+    pub fn len(&self) -> usize {
+        self.tokens.read().expect("Token store lock poisoned").len()
+    }
+
+    /// Returns true if no tokens are registered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Hash a token using SHA-256
     fn hash_token(token: &str) -> String {
         use sha2::{Digest, Sha256};
@@ -126,8 +154,82 @@ fn extract_bearer_token(headers: &HeaderMap) -> Result<String, Response> {
     Ok(token)
 }
 
+/// Path prefix of the WebSocket routes, which authenticate in their upgrade handlers
+pub const WEBSOCKET_PATH_PREFIX: &str = "/ws/";
+
+/// Query parameter carrying the token on WebSocket upgrades
+pub const WEBSOCKET_TOKEN_PARAM: &str = "access_token";
+
+/// Subprotocol offered together with the token on WebSocket upgrades
+///
+/// Clients send `Sec-WebSocket-Protocol: bearer, <token>`; the server selects
+/// `bearer`, so the token is never echoed back.
+pub const WEBSOCKET_BEARER_PROTOCOL: &str = "bearer";
+
+/// Extract the token of a WebSocket upgrade request
+///
+/// Checked in order: the Authorization header, the `access_token` query
+/// parameter, then the entry following `bearer` in `Sec-WebSocket-Protocol`.
+fn extract_websocket_token(headers: &HeaderMap, uri: &Uri) -> Option<String> {
+    if let Ok(token) = extract_bearer_token(headers) {
+        return Some(token);
+    }
+
+    if let Ok(Query(params)) = Query::<HashMap<String, String>>::try_from_uri(uri) {
+        if let Some(token) = params.get(WEBSOCKET_TOKEN_PARAM) {
+            if !token.is_empty() {
+                return Some(token.clone());
+            }
+        }
+    }
+
+    let protocols = headers.get(header::SEC_WEBSOCKET_PROTOCOL)?.to_str().ok()?;
+    let mut protocols = protocols.split(',').map(str::trim);
+    protocols.find(|protocol| protocol.eq_ignore_ascii_case(WEBSOCKET_BEARER_PROTOCOL))?;
+    protocols
+        .next()
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+}
+
+/// Validate the token of a WebSocket upgrade request before upgrading
+///
+/// Accepts the token from an Authorization header, an `access_token` query
+/// parameter or a `Sec-WebSocket-Protocol: bearer, <token>` header, and
+/// rejects with 401 if it is missing or not in the store.
+#[allow(clippy::result_large_err)]
+pub fn authorize_websocket(
+    token_store: &TokenStore,
+    headers: &HeaderMap,
+    uri: &Uri,
+) -> Result<TokenMetadata, Response> {
+    let token = extract_websocket_token(headers, uri).ok_or_else(|| {
+        (
+            StatusCode::UNAUTHORIZED,
+            "Missing bearer token (Authorization header, access_token query parameter or bearer subprotocol)",
+        )
+            .into_response()
+    })?;
+
+    token_store.validate(&token).map_err(|status| {
+        tracing::warn!(
+            "Rejected WebSocket upgrade with invalid token: {}",
+            uri.path()
+        );
+        (status, "Invalid or expired token").into_response()
+    })
+}
+
 /// Axum middleware function to validate bearer tokens
 ///
+/// Requests under [`WEBSOCKET_PATH_PREFIX`] are passed through: the upgrade
+/// handlers call [`authorize_websocket`] themselves, which also accepts the
+/// token outside the Authorization header.
+///
+/// On success the token's [`TokenMetadata`] is inserted into the request
+/// extensions (used by the rate limiter) and the rest of the request runs
+/// inside an `http_request` span tagged with the token label for auditing.
+///
 /// ## Usage
 ///
 /// ```rust,no_run
@@ -146,18 +248,27 @@ fn extract_bearer_token(headers: &HeaderMap) -> Result<String, Response> {
 /// ```
 pub async fn validate_bearer_token(
     axum::extract::State(token_store): axum::extract::State<TokenStore>,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, Response> {
+    use tracing::Instrument;
+
+    if request.uri().path().starts_with(WEBSOCKET_PATH_PREFIX) {
+        return Ok(next.run(request).await);
+    }
+
     let token = extract_bearer_token(request.headers())?;
 
     // Validate token against store
-    token_store
+    let metadata = token_store
         .validate(&token)
         .map_err(|status| (status, "Invalid or expired token").into_response())?;
 
+    let span = tracing::info_span!("http_request", token = %metadata.name);
+    request.extensions_mut().insert(metadata);
+
     // Token is valid, proceed with request
-    Ok(next.run(request).await)
+    Ok(next.run(request).instrument(span).await)
 }
 
 #[cfg(test)]
@@ -176,6 +287,50 @@ mod tests {
         assert_eq!(store.validate("wrong_token"), Err(StatusCode::UNAUTHORIZED));
     }
 
+    #[test]
+    fn test_token_with_limit() {
+        let store = TokenStore::new();
+        store.add_token_with_limit("tenant_a_token", "tenant-a".to_string(), Some(500));
+        store.add_token("tenant_b_token", "tenant-b".to_string());
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(
+            store.validate("tenant_a_token").unwrap().rate_limit,
+            Some(500)
+        );
+        assert_eq!(store.validate("tenant_b_token").unwrap().rate_limit, None);
+    }
+
+    #[test]
+    fn test_authorize_websocket_token_sources() {
+        let store = TokenStore::new();
+        store.add_token("ws_secret", "ws-client".to_string());
+        let plain: Uri = "/ws/ticker/btcusdt".parse().unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer ws_secret".parse().unwrap());
+        assert!(authorize_websocket(&store, &headers, &plain).is_ok());
+
+        let with_query: Uri = "/ws/depth/btcusdt?levels=5&access_token=ws_secret"
+            .parse()
+            .unwrap();
+        assert!(authorize_websocket(&store, &HeaderMap::new(), &with_query).is_ok());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::SEC_WEBSOCKET_PROTOCOL,
+            "bearer, ws_secret".parse().unwrap(),
+        );
+        assert!(authorize_websocket(&store, &headers, &plain).is_ok());
+
+        let missing = authorize_websocket(&store, &HeaderMap::new(), &plain).unwrap_err();
+        assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+
+        let wrong: Uri = "/ws/user?access_token=nope".parse().unwrap();
+        let invalid = authorize_websocket(&store, &HeaderMap::new(), &wrong).unwrap_err();
+        assert_eq!(invalid.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[test]
     fn test_token_hashing() {
         let hash1 = TokenStore::hash_token("same_token");
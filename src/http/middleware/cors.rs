@@ -2,24 +2,30 @@
 //!
 //! Configures CORS headers to allow browser-based clients to access the API.
 
-use tower_http::cors::{Any, CorsLayer};
+use axum::http::{header, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 
-/// Create CORS middleware layer with permissive settings for development
+/// HTTP methods used by the REST API
+const ALLOWED_METHODS: [Method; 5] = [
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::DELETE,
+    Method::OPTIONS,
+];
+
+/// Create CORS middleware layer
 ///
 /// ## Configuration
 ///
-/// - Allows all origins (`Access-Control-Allow-Origin: *`)
-/// - Allows all methods (GET, POST, DELETE, OPTIONS, etc.)
-/// - Allows all headers
-/// - Exposes all headers to clients
+/// - Origins: explicit allowlist from `allowed_origins` (see `CORS_ALLOWED_ORIGINS`),
+///   or any origin (`Access-Control-Allow-Origin: *`) when the list is empty
+/// - Methods: GET, POST, PUT, DELETE, OPTIONS
+/// - Headers: Authorization, Content-Type, Accept
+/// - Exposes `Retry-After` so browser clients can honor rate limits
 /// - Max age: 3600 seconds (1 hour) for preflight cache
 ///
-/// ## Production Note
-///
-/// For production, consider restricting:
-/// - `allow_origin()` to specific domains
-/// - `allow_headers()` to required headers only
-/// - `allow_methods()` to needed methods only
+/// Origins that are not valid header values are skipped with a warning.
 ///
 /// ## Example
 ///
@@ -27,22 +33,39 @@ use tower_http::cors::{Any, CorsLayer};
 /// use axum::Router;
 /// use mcp_binance_server::http::middleware::create_cors_layer;
 ///
+/// let origins = vec!["https://app.example.com".to_string()];
 /// let app = Router::new()
 ///     .route("/api/endpoint", axum::routing::get(handler))
-///     .layer(create_cors_layer());
+///     .layer(create_cors_layer(&origins));
 /// ```
-pub fn create_cors_layer() -> CorsLayer {
-    CorsLayer::new()
-        // Allow all origins (permissive for development)
-        .allow_origin(Any)
-        // Allow all HTTP methods
-        .allow_methods(Any)
-        // Allow all headers
-        .allow_headers(Any)
-        // Expose all response headers to browser
-        .expose_headers(Any)
+pub fn create_cors_layer(allowed_origins: &[String]) -> CorsLayer {
+    let layer = CorsLayer::new()
+        // Only the methods the API actually serves
+        .allow_methods(ALLOWED_METHODS)
+        // Only the headers clients need to send
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT])
+        // Let browsers read rate limit hints
+        .expose_headers([header::RETRY_AFTER])
         // Cache preflight for 1 hour
-        .max_age(std::time::Duration::from_secs(3600))
+        .max_age(std::time::Duration::from_secs(3600));
+
+    if allowed_origins.is_empty() {
+        // No allowlist configured: permissive (development default)
+        return layer.allow_origin(Any);
+    }
+
+    let origins: Vec<HeaderValue> = allowed_origins
+        .iter()
+        .filter_map(|origin| match HeaderValue::from_str(origin) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                tracing::warn!("Ignoring invalid CORS origin: {}", origin);
+                None
+            }
+        })
+        .collect();
+
+    layer.allow_origin(AllowOrigin::list(origins))
 }
 
 #[cfg(test)]
@@ -52,6 +75,15 @@ mod tests {
     #[test]
     fn test_cors_layer_creation() {
         // Just verify it doesn't panic
-        let _layer = create_cors_layer();
+        let _layer = create_cors_layer(&[]);
+    }
+
+    #[test]
+    fn test_cors_layer_with_allowlist() {
+        let origins = vec![
+            "https://app.example.com".to_string(),
+            "http://localhost:3000".to_string(),
+        ];
+        let _layer = create_cors_layer(&origins);
     }
 }
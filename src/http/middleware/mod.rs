@@ -14,7 +14,7 @@ pub mod cors;
 pub mod rate_limit;
 
 #[cfg(feature = "http-api")]
-pub use auth::{validate_bearer_token, TokenStore};
+pub use auth::{authorize_websocket, validate_bearer_token, TokenStore};
 #[cfg(feature = "http-api")]
 pub use cors::create_cors_layer;
 #[cfg(feature = "http-api")]
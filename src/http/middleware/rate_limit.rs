@@ -2,31 +2,86 @@
 //!
 //! Limits requests per client to prevent abuse and ensure fair resource usage.
 
+use super::auth::TokenMetadata;
+use axum::{
+    extract::ConnectInfo,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
 use governor::{
-    clock::DefaultClock,
+    clock::{Clock, DefaultClock},
     state::{InMemoryState, NotKeyed},
     Quota, RateLimiter as GovernorRateLimiter,
 };
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
 use std::num::NonZeroU32;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// Error type for rate limit exceeded
 #[derive(Debug, Clone, Copy)]
-pub struct RateLimitExceeded;
+pub struct RateLimitExceeded {
+    /// Time until the bucket refills enough to allow the next request
+    pub retry_after: Duration,
+}
+
+impl RateLimitExceeded {
+    /// Retry-After value in whole seconds (rounded up, minimum 1)
+    pub fn retry_after_secs(&self) -> u64 {
+        let secs = self.retry_after.as_secs();
+        if self.retry_after.subsec_nanos() > 0 || secs == 0 {
+            secs + 1
+        } else {
+            secs
+        }
+    }
+}
+
+impl IntoResponse for RateLimitExceeded {
+    fn into_response(self) -> Response {
+        let mut response = (StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded").into_response();
+        if let Ok(value) = HeaderValue::from_str(&self.retry_after_secs().to_string()) {
+            response.headers_mut().insert(header::RETRY_AFTER, value);
+        }
+        response
+    }
+}
+
+/// Token bucket for a single rate limit key
+type Bucket = GovernorRateLimiter<NotKeyed, InMemoryState, DefaultClock>;
 
-/// Rate limiter using governor crate for per-server limits
+/// A keyed bucket plus the last time it was used (for idle eviction)
+struct BucketEntry {
+    bucket: Arc<Bucket>,
+    last_seen: Instant,
+}
+
+/// Rate limiter using governor crate with one bucket per client IP and per token
 ///
 /// ## Configuration
 ///
-/// Rate limit is set via `HTTP_RATE_LIMIT` environment variable (default: 100 req/min).
-/// Applied globally across all clients.
+/// Default rate limit is set via `HTTP_RATE_LIMIT` environment variable (default: 100 req/min).
+/// Each client IP gets its own bucket at the default limit, so one noisy client
+/// cannot starve everyone else. Each authenticated token additionally gets its own
+/// bucket; tokens registered with an explicit limit (see `BEARER_TOKENS`) override
+/// the default. Requests with neither an IP nor a token share a fallback bucket.
 ///
-/// ## Future Enhancement
+/// The client IP is the TCP peer address. `X-Forwarded-For` is only honored when
+/// the peer is one of the trusted proxies (see [`RateLimiter::with_trusted_proxies`]),
+/// so clients can't pick their own bucket by spoofing the header.
 ///
-/// Consider per-IP or per-token rate limiting for finer control.
+/// Idle buckets are dropped by [`RateLimiter::evict_idle`] to bound memory.
 #[derive(Clone)]
 pub struct RateLimiter {
-    inner: Arc<GovernorRateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+    /// Default requests per minute for keys without an explicit limit
+    default_limit: NonZeroU32,
+    /// Shared bucket for requests without a client key
+    inner: Arc<Bucket>,
+    /// Per-key buckets (`ip:<addr>` and `token:<label>`)
+    buckets: Arc<Mutex<HashMap<String, BucketEntry>>>,
+    /// Reverse proxies whose `X-Forwarded-For` header is honored
+    trusted_proxies: Arc<Vec<IpAddr>>,
 }
 
 impl RateLimiter {
@@ -34,31 +89,168 @@ impl RateLimiter {
     ///
     /// ## Arguments
     ///
-    /// - `requests_per_minute`: Maximum requests allowed per minute
+    /// - `requests_per_minute`: Default maximum requests allowed per minute per key
     ///
     /// ## Panics
     ///
     /// Panics if `requests_per_minute` is 0
     pub fn new(requests_per_minute: u32) -> Self {
-        let quota = Quota::per_minute(
-            NonZeroU32::new(requests_per_minute).expect("Rate limit must be greater than 0"),
-        );
+        let default_limit =
+            NonZeroU32::new(requests_per_minute).expect("Rate limit must be greater than 0");
 
         Self {
-            inner: Arc::new(GovernorRateLimiter::direct(quota)),
+            default_limit,
+            inner: Arc::new(GovernorRateLimiter::direct(Quota::per_minute(
+                default_limit,
+            ))),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+            trusted_proxies: Arc::new(Vec::new()),
         }
     }
 
-    /// Check if a request is allowed
+    /// Honor `X-Forwarded-For` from these peers (see `TRUSTED_PROXIES`)
+    pub fn with_trusted_proxies(mut self, proxies: Vec<IpAddr>) -> Self {
+        self.trusted_proxies = Arc::new(proxies);
+        self
+    }
+
+    /// Reverse proxies whose `X-Forwarded-For` header is honored
+    pub fn trusted_proxies(&self) -> &[IpAddr] {
+        &self.trusted_proxies
+    }
+
+    /// Check if a request is allowed against the shared fallback bucket
     ///
     /// Returns `Ok(())` if allowed, `Err(RateLimitExceeded)` if rate limit exceeded
     pub fn check(&self) -> Result<(), RateLimitExceeded> {
-        self.inner.check().map_err(|_| RateLimitExceeded)
+        Self::check_bucket(&self.inner)
+    }
+
+    /// Check if a request is allowed for a specific key
+    ///
+    /// The bucket for `key` is created on first use with `requests_per_minute`,
+    /// or the default limit when `None` (or 0).
+    ///
+    /// Returns `Ok(())` if allowed, `Err(RateLimitExceeded)` if rate limit exceeded
+    pub fn check_key(
+        &self,
+        key: &str,
+        requests_per_minute: Option<u32>,
+    ) -> Result<(), RateLimitExceeded> {
+        let bucket = self.bucket(key, requests_per_minute);
+        Self::check_bucket(&bucket)
+    }
+
+    /// Check if a request from `ip` is allowed (default limit per IP)
+    pub fn check_ip(&self, ip: &str) -> Result<(), RateLimitExceeded> {
+        self.check_key(&format!("ip:{}", ip), None)
+    }
+
+    /// Check if a request authenticated with `token` is allowed
+    pub fn check_token(&self, token: &TokenMetadata) -> Result<(), RateLimitExceeded> {
+        self.check_key(&format!("token:{}", token.name), token.rate_limit)
+    }
+
+    /// Drop buckets that haven't been used for `max_idle`
+    ///
+    /// Returns the number of evicted keys.
+    pub fn evict_idle(&self, max_idle: Duration) -> usize {
+        let mut buckets = self.buckets.lock().expect("Rate limiter lock poisoned");
+        let before = buckets.len();
+        buckets.retain(|_, entry| entry.last_seen.elapsed() < max_idle);
+        before - buckets.len()
+    }
+
+    /// Number of tracked keys
+    pub fn key_count(&self) -> usize {
+        self.buckets
+            .lock()
+            .expect("Rate limiter lock poisoned")
+            .len()
+    }
+
+    /// Spawn a background task that evicts idle keys every `interval`
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn_eviction_task(
+        &self,
+        interval: Duration,
+        max_idle: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let limiter = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let evicted = limiter.evict_idle(max_idle);
+                if evicted > 0 {
+                    tracing::debug!("Evicted {} idle rate limit keys", evicted);
+                }
+            }
+        })
+    }
+
+    /// Get or create the bucket for a key, refreshing its last-seen time
+    fn bucket(&self, key: &str, requests_per_minute: Option<u32>) -> Arc<Bucket> {
+        let limit = requests_per_minute
+            .and_then(NonZeroU32::new)
+            .unwrap_or(self.default_limit);
+
+        let mut buckets = self.buckets.lock().expect("Rate limiter lock poisoned");
+        let entry = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| BucketEntry {
+                bucket: Arc::new(GovernorRateLimiter::direct(Quota::per_minute(limit))),
+                last_seen: Instant::now(),
+            });
+        entry.last_seen = Instant::now();
+        entry.bucket.clone()
+    }
+
+    /// Check a bucket, converting a denial into the time until refill
+    fn check_bucket(bucket: &Bucket) -> Result<(), RateLimitExceeded> {
+        bucket.check().map_err(|not_until| RateLimitExceeded {
+            retry_after: not_until.wait_time_from(DefaultClock::default().now()),
+        })
     }
 }
 
+/// Resolve the client IP for rate limiting
+///
+/// Uses the TCP peer address from `ConnectInfo`. When the peer is a trusted
+/// proxy, `X-Forwarded-For` is walked from the right (each proxy appends the
+/// address it received from) and the first address that isn't itself a trusted
+/// proxy wins. Entries that aren't IP addresses are never used as keys.
+pub fn client_ip(
+    headers: &HeaderMap,
+    peer: Option<SocketAddr>,
+    trusted_proxies: &[IpAddr],
+) -> Option<String> {
+    let peer = peer?.ip();
+    if !trusted_proxies.contains(&peer) {
+        return Some(peer.to_string());
+    }
+
+    let forwarded: Vec<IpAddr> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect();
+    let client = forwarded
+        .into_iter()
+        .rev()
+        .find(|ip| !trusted_proxies.contains(ip))
+        .unwrap_or(peer);
+    Some(client.to_string())
+}
+
 /// Create rate limiter middleware from configuration
 ///
+/// Applies the per-IP limit and, for authenticated requests, the per-token limit.
+/// Denied requests get `429 Too Many Requests` with a `Retry-After` header.
+///
 /// ## Usage
 ///
 /// ```rust,no_run
@@ -78,10 +270,25 @@ pub async fn check_rate_limit(
     axum::extract::State(limiter): axum::extract::State<RateLimiter>,
     request: axum::extract::Request,
     next: axum::middleware::Next,
-) -> Result<axum::response::Response, axum::http::StatusCode> {
-    // Check rate limit
-    if limiter.check().is_err() {
-        return Err(axum::http::StatusCode::TOO_MANY_REQUESTS);
+) -> Result<Response, RateLimitExceeded> {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+    let ip = client_ip(request.headers(), peer, limiter.trusted_proxies());
+    let token = request.extensions().get::<TokenMetadata>();
+
+    match (&ip, token) {
+        (None, None) => limiter.check()?,
+        _ => {
+            if let Some(ip) = &ip {
+                limiter.check_ip(ip)?;
+            }
+            // Token label comes from the auth middleware
+            if let Some(token) = token {
+                limiter.check_token(token)?;
+            }
+        }
     }
 
     // Request allowed, proceed
@@ -100,6 +307,115 @@ mod tests {
         assert!(limiter.check().is_ok());
     }
 
+    #[test]
+    fn test_per_key_buckets_are_independent() {
+        let limiter = RateLimiter::new(1);
+
+        assert!(limiter.check_key("alice", None).is_ok());
+        assert!(limiter.check_key("alice", None).is_err());
+
+        // Exhausting alice's bucket doesn't affect bob
+        assert!(limiter.check_key("bob", None).is_ok());
+    }
+
+    #[test]
+    fn test_per_key_limit_override() {
+        let limiter = RateLimiter::new(1);
+
+        assert!(limiter.check_key("premium", Some(3)).is_ok());
+        assert!(limiter.check_key("premium", Some(3)).is_ok());
+        assert!(limiter.check_key("premium", Some(3)).is_ok());
+        assert!(limiter.check_key("premium", Some(3)).is_err());
+    }
+
+    #[test]
+    fn test_ip_limit_is_isolated() {
+        let limiter = RateLimiter::new(2);
+
+        let proxy: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let mut noisy = HeaderMap::new();
+        noisy.insert("x-forwarded-for", "203.0.113.7".parse().unwrap());
+        let quiet_peer: SocketAddr = "198.51.100.2:50000".parse().unwrap();
+
+        let noisy_ip = client_ip(&noisy, Some(proxy), &[proxy.ip()]).unwrap();
+        let quiet_ip = client_ip(&HeaderMap::new(), Some(quiet_peer), &[proxy.ip()]).unwrap();
+        assert_eq!(noisy_ip, "203.0.113.7");
+        assert_eq!(quiet_ip, "198.51.100.2");
+
+        assert!(limiter.check_ip(&noisy_ip).is_ok());
+        assert!(limiter.check_ip(&noisy_ip).is_ok());
+        let denied = limiter.check_ip(&noisy_ip).unwrap_err();
+        assert!(denied.retry_after_secs() >= 1);
+
+        // The limited client doesn't affect anyone else
+        assert!(limiter.check_ip(&quiet_ip).is_ok());
+    }
+
+    #[test]
+    fn test_forwarded_for_from_untrusted_peer_is_ignored() {
+        let limiter = RateLimiter::new(1);
+        let peer: SocketAddr = "198.51.100.2:50000".parse().unwrap();
+
+        // Rotating a spoofed header still lands in the peer's bucket
+        let spoofed = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-forwarded-for", value.parse().unwrap());
+            client_ip(&headers, Some(peer), &[]).unwrap()
+        };
+        assert_eq!(spoofed("203.0.113.7"), "198.51.100.2");
+        assert!(limiter.check_ip(&spoofed("203.0.113.7")).is_ok());
+        assert!(limiter.check_ip(&spoofed("203.0.113.8")).is_err());
+        assert_eq!(limiter.key_count(), 1);
+    }
+
+    #[test]
+    fn test_forwarded_for_skips_trusted_hops() {
+        let proxy: SocketAddr = "10.0.0.1:443".parse().unwrap();
+        let trusted = ["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()];
+
+        // The client-supplied leftmost entry is ignored; the last untrusted hop wins
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            "1.2.3.4, 203.0.113.7, 10.0.0.2".parse().unwrap(),
+        );
+        assert_eq!(
+            client_ip(&headers, Some(proxy), &trusted).as_deref(),
+            Some("203.0.113.7")
+        );
+
+        // Garbage entries fall back to the proxy itself
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "not-an-ip".parse().unwrap());
+        assert_eq!(
+            client_ip(&headers, Some(proxy), &trusted).as_deref(),
+            Some("10.0.0.1")
+        );
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let response = RateLimitExceeded {
+            retry_after: Duration::from_millis(29_500),
+        }
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+    }
+
+    #[test]
+    fn test_evict_idle() {
+        let limiter = RateLimiter::new(10);
+        limiter.check_ip("192.0.2.1").unwrap();
+        limiter.check_ip("192.0.2.2").unwrap();
+        assert_eq!(limiter.key_count(), 2);
+
+        assert_eq!(limiter.evict_idle(Duration::from_secs(60)), 0);
+        assert_eq!(limiter.evict_idle(Duration::ZERO), 2);
+        assert_eq!(limiter.key_count(), 0);
+    }
+
     #[test]
     #[should_panic(expected = "Rate limit must be greater than 0")]
     fn test_zero_rate_limit_panics() {
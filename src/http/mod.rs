@@ -25,21 +25,20 @@ use crate::binance::client::BinanceClient;
 use axum::Router;
 #[cfg(feature = "http-api")]
 pub use middleware::{
-    check_rate_limit, create_cors_layer, validate_bearer_token, RateLimiter, TokenStore,
+    authorize_websocket, check_rate_limit, create_cors_layer, validate_bearer_token, RateLimiter,
+    TokenStore,
 };
 
-/// Maximum concurrent WebSocket connections (per SC-003 requirement)
-#[cfg(all(feature = "http-api", feature = "websocket"))]
-const MAX_WS_CONNECTIONS: usize = 50;
-
 /// Shared application state passed to all HTTP handlers
 ///
 /// ## Fields
 ///
 /// - `binance_client`: Arc-wrapped Binance API client for making requests
 /// - `token_store`: Arc-wrapped authentication token store
-/// - `rate_limiter`: Arc-wrapped rate limiter for global request limiting
-/// - `ws_connections`: Semaphore for limiting concurrent WebSocket connections (max 50)
+/// - `rate_limiter`: Rate limiter with per-IP and per-token buckets
+/// - `ws_connections`: Semaphore for limiting concurrent WebSocket connections
+/// - `ws_connection_limit`: Total permits in `ws_connections` (`HTTP_MAX_WEBSOCKET_CONNECTIONS`)
+/// - `ws_broadcast_capacity`: Messages buffered per WebSocket stream (`WS_BROADCAST_CAPACITY`)
 ///
 /// ## Usage
 ///
@@ -62,12 +61,20 @@ pub struct AppState {
     /// Authentication token store
     pub token_store: TokenStore,
 
-    /// Rate limiter for global request limiting
+    /// Rate limiter with per-IP and per-token buckets
     pub rate_limiter: RateLimiter,
 
-    /// WebSocket connection limit semaphore (max 50 concurrent)
+    /// WebSocket connection limit semaphore
     #[cfg(feature = "websocket")]
     pub ws_connections: Arc<tokio::sync::Semaphore>,
+
+    /// Maximum concurrent WebSocket connections (reported in 503 rejections)
+    #[cfg(feature = "websocket")]
+    pub ws_connection_limit: usize,
+
+    /// Broadcast channel capacity of each WebSocket stream
+    #[cfg(feature = "websocket")]
+    pub ws_broadcast_capacity: usize,
 }
 
 /// Create the main HTTP router with all middleware and routes
@@ -76,6 +83,9 @@ pub struct AppState {
 ///
 /// - `token_store`: Authentication token store
 /// - `rate_limiter`: Rate limiter instance
+/// - `cors_origins`: Allowed CORS origins (empty = any origin)
+/// - `max_websocket_connections`: Concurrent WebSocket limit shared by all `/ws` streams
+/// - `ws_broadcast_capacity`: Messages buffered per `/ws` stream before a slow client lags
 ///
 /// ## Returns
 ///
@@ -95,7 +105,7 @@ pub struct AppState {
 /// let token_store = TokenStore::new();
 /// let rate_limiter = RateLimiter::new(100);
 ///
-/// let app = create_router(token_store, rate_limiter);
+/// let app = create_router(token_store, rate_limiter, &[], 50, 100);
 ///
 /// let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
 ///     .await
@@ -104,7 +114,13 @@ pub struct AppState {
 /// # }
 /// ```
 #[cfg(feature = "http-api")]
-pub fn create_router(token_store: TokenStore, rate_limiter: RateLimiter) -> Router {
+pub fn create_router(
+    token_store: TokenStore,
+    rate_limiter: RateLimiter,
+    cors_origins: &[String],
+    max_websocket_connections: usize,
+    ws_broadcast_capacity: usize,
+) -> Router {
     use axum::middleware;
 
     // Create shared application state
@@ -113,8 +129,14 @@ pub fn create_router(token_store: TokenStore, rate_limiter: RateLimiter) -> Rout
         token_store: token_store.clone(),
         rate_limiter: rate_limiter.clone(),
         #[cfg(feature = "websocket")]
-        ws_connections: Arc::new(tokio::sync::Semaphore::new(MAX_WS_CONNECTIONS)),
+        ws_connections: Arc::new(tokio::sync::Semaphore::new(max_websocket_connections)),
+        #[cfg(feature = "websocket")]
+        ws_connection_limit: max_websocket_connections,
+        #[cfg(feature = "websocket")]
+        ws_broadcast_capacity,
     };
+    #[cfg(not(feature = "websocket"))]
+    let _ = (max_websocket_connections, ws_broadcast_capacity);
 
     // Create API v1 routes (protected by auth)
     let api_routes = Router::new()
@@ -200,15 +222,71 @@ pub fn create_router(token_store: TokenStore, rate_limiter: RateLimiter) -> Rout
     }
 
     router
-        // Apply middleware layers (order matters: outer → inner)
-        .layer(create_cors_layer()) // CORS (outermost)
+        // Apply middleware layers (each `.layer` wraps the ones before it,
+        // so the last one added runs first)
         .layer(middleware::from_fn_with_state(
             rate_limiter,
             check_rate_limit,
-        )) // Rate limiting
+        )) // Rate limiting (innermost, reads the token set by auth)
         .layer(middleware::from_fn_with_state(
             token_store,
             validate_bearer_token,
-        )) // Authentication (innermost for protected routes)
+        )) // Authentication
+        .layer(create_cors_layer(cors_origins)) // CORS (outermost, answers preflights before auth)
         .with_state(state)
 }
+
+#[cfg(all(test, feature = "http-api"))]
+mod tests {
+    use super::*;
+
+    const ORIGIN: &str = "https://app.example.com";
+
+    async fn serve(router: Router) -> std::net::SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_preflight_from_allowed_origin_skips_auth() {
+        let token_store = TokenStore::new();
+        token_store.add_token("cors_test_token", "cors-test".to_string());
+        let router = create_router(
+            token_store,
+            RateLimiter::new(100),
+            &[ORIGIN.to_string()],
+            1,
+            1,
+        );
+        let addr = serve(router).await;
+        let url = format!("http://{}/api/v1/ticker/price", addr);
+        let client = reqwest::Client::new();
+
+        // Preflights never carry Authorization
+        let preflight = client
+            .request(reqwest::Method::OPTIONS, &url)
+            .header("Origin", ORIGIN)
+            .header("Access-Control-Request-Method", "GET")
+            .header("Access-Control-Request-Headers", "authorization")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(preflight.status(), 200);
+        assert_eq!(preflight.headers()["access-control-allow-origin"], ORIGIN);
+
+        // The actual request is still authenticated, with CORS headers on the rejection
+        let unauthorized = client
+            .get(&url)
+            .header("Origin", ORIGIN)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(unauthorized.status(), 401);
+        assert_eq!(
+            unauthorized.headers()["access-control-allow-origin"],
+            ORIGIN
+        );
+    }
+}
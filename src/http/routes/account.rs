@@ -10,6 +10,7 @@ use axum::{
 };
 use serde::Deserialize;
 
+use crate::binance::limits::HistoryQuery;
 use crate::error::McpError;
 use crate::http::AppState;
 
@@ -123,7 +124,14 @@ pub async fn get_my_trades(
 
     let trades = state
         .binance_client
-        .get_my_trades(&params.symbol, params.limit, None)
+        .get_my_trades(
+            &params.symbol,
+            &HistoryQuery {
+                limit: params.limit,
+                ..Default::default()
+            },
+            None,
+        )
         .await?;
 
     Ok(Json(serde_json::to_value(trades)?))
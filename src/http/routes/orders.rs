@@ -13,8 +13,11 @@ use axum::{
 };
 use serde::Deserialize;
 
+use crate::binance::limits::HistoryQuery;
 use crate::error::McpError;
 use crate::http::AppState;
+use crate::tools::idempotency::validate_client_order_id;
+use crate::tools::time_in_force::resolve_time_in_force;
 
 /// Request body for order creation endpoint
 #[derive(Debug, Deserialize)]
@@ -31,6 +34,10 @@ pub struct CreateOrderRequest {
     pub quantity: String,
     /// Price (required for LIMIT orders)
     pub price: Option<String>,
+    /// Time in force: "GTC", "IOC" or "FOK" (LIMIT orders only, default "GTC")
+    pub time_in_force: Option<String>,
+    /// Optional client order ID passed through as `newClientOrderId`
+    pub new_client_order_id: Option<String>,
 }
 
 /// Query parameters for cancel order endpoint
@@ -120,6 +127,12 @@ pub async fn create_order(
         ));
     }
 
+    if let Some(id) = &req.new_client_order_id {
+        validate_client_order_id(id)?;
+    }
+
+    let time_in_force = resolve_time_in_force(&req.order_type, req.time_in_force.as_deref())?;
+
     // Call Binance API
     let order = state
         .binance_client
@@ -129,6 +142,8 @@ pub async fn create_order(
             &req.order_type,
             &req.quantity,
             req.price.as_deref(),
+            time_in_force,
+            req.new_client_order_id.as_deref(),
             None,
         )
         .await?;
@@ -252,7 +267,14 @@ pub async fn get_all_orders(
 
     let orders = state
         .binance_client
-        .get_all_orders(&params.symbol, params.limit, None)
+        .get_all_orders(
+            &params.symbol,
+            &HistoryQuery {
+                limit: params.limit,
+                ..Default::default()
+            },
+            None,
+        )
         .await?;
 
     Ok(Json(serde_json::to_value(orders)?))
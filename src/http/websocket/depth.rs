@@ -5,18 +5,29 @@
 //!
 //! ## Features
 //! - Real-time bid/ask price level updates
+//! - Optional top-N truncation (`levels`) and 100ms update speed (`interval`)
 //! - Automatic subscription to Binance depth stream
 //! - Client connection management and cleanup
-//! - Authentication via Bearer token in upgrade request
+//! - Gap notice (`"type": "gap"`) when a slow client drops updates, so it can resnapshot
+//! - Authentication via bearer token (header, query parameter or subprotocol) before upgrade
 
 #[cfg(feature = "http-api")]
 use axum::{
-    extract::{Path, State, WebSocketUpgrade},
-    response::Response,
+    extract::{Path, Query, State, WebSocketUpgrade},
+    http::{HeaderMap, Uri},
+    response::{IntoResponse, Response},
 };
+#[cfg(feature = "http-api")]
+use serde::Deserialize;
 
 #[cfg(all(feature = "http-api", feature = "websocket"))]
-use crate::binance::websocket::{BinanceWebSocketClient, DepthUpdate};
+use super::{
+    accept_bearer_protocol, acquire_connection_permit, authorize_upgrade, recv_or_gap, StreamKind,
+};
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use crate::binance::websocket::{BinanceWebSocketClient, DepthUpdate, DEPTH_UPDATE_SPEED_MS};
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use crate::error::McpError;
 #[cfg(feature = "http-api")]
 use crate::http::AppState;
 #[cfg(all(feature = "http-api", feature = "websocket"))]
@@ -26,6 +37,65 @@ use futures_util::{SinkExt, StreamExt};
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 use tokio::sync::broadcast;
 
+/// Maximum `levels` accepted by the depth stream
+pub const MAX_DEPTH_STREAM_LEVELS: usize = 1000;
+
+/// Query parameters for `/ws/depth/:symbol`
+#[cfg(feature = "http-api")]
+#[derive(Debug, Default, Deserialize)]
+pub struct DepthStreamQuery {
+    /// Forward only the best N bids and asks of each update (1-1000; default: all)
+    pub levels: Option<usize>,
+    /// Binance update speed: "100ms" or "1000ms" (default; the "ms" is optional)
+    pub interval: Option<String>,
+}
+
+/// Validated depth stream options
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepthStreamOptions {
+    /// Levels per side to forward (`None` forwards full updates)
+    pub levels: Option<usize>,
+    /// Binance update speed in milliseconds (100 or 1000)
+    pub update_speed_ms: u32,
+}
+
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+impl DepthStreamQuery {
+    /// Checks bounds, defaulting to full depth at 1000ms
+    ///
+    /// # Errors
+    /// Returns `McpError::InvalidRequest` for `levels` outside 1-1000 or an
+    /// `interval` other than 100ms/1000ms
+    pub fn validate(&self) -> Result<DepthStreamOptions, McpError> {
+        if let Some(levels) = self.levels {
+            if !(1..=MAX_DEPTH_STREAM_LEVELS).contains(&levels) {
+                return Err(McpError::InvalidRequest(format!(
+                    "levels must be between 1 and {}",
+                    MAX_DEPTH_STREAM_LEVELS
+                )));
+            }
+        }
+
+        let update_speed_ms = match self.interval.as_deref().map(str::trim) {
+            None => DEPTH_UPDATE_SPEED_MS,
+            Some(interval) => match interval.trim_end_matches("ms") {
+                "100" => 100,
+                "1000" => 1000,
+                _ => {
+                    return Err(McpError::InvalidRequest(
+                        "interval must be 100ms or 1000ms".to_string(),
+                    ))
+                }
+            },
+        };
+
+        Ok(DepthStreamOptions {
+            levels: self.levels,
+            update_speed_ms,
+        })
+    }
+}
+
 /// WebSocket upgrade handler for depth stream
 ///
 /// Upgrades HTTP connection to WebSocket and starts forwarding
@@ -35,39 +105,54 @@ use tokio::sync::broadcast;
 /// `GET /ws/depth/:symbol`
 ///
 /// ## Authentication
-/// Requires valid Bearer token in Authorization header
+/// Requires a valid bearer token in the Authorization header, the
+/// `access_token` query parameter, or `Sec-WebSocket-Protocol: bearer, <token>`.
+/// Returns HTTP 401 before upgrading otherwise.
+///
+/// ## Query Parameters
+/// - `levels`: Forward only the best N bids/asks of each update (1-1000; default: all)
+/// - `interval`: `100ms` or `1000ms` (default) Binance update speed
+///
+/// Invalid values return HTTP 400.
 ///
 /// ## Connection Limit
-/// Maximum 50 concurrent WebSocket connections (SC-003 requirement).
-/// Returns HTTP 503 if limit exceeded.
+/// Shares the `HTTP_MAX_WEBSOCKET_CONNECTIONS` limit (default 50) with the other
+/// streams. Returns HTTP 503 with a JSON body if the limit is reached.
 ///
 /// ## Example
 /// ```bash
-/// wscat -c 'ws://localhost:3000/ws/depth/btcusdt' \
+/// wscat -c 'ws://localhost:3000/ws/depth/btcusdt?levels=5&interval=100ms' \
 ///   -H "Authorization: Bearer test_token"
 /// ```
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 pub async fn depth_handler(
     State(state): State<AppState>,
     Path(symbol): Path<String>,
+    Query(query): Query<DepthStreamQuery>,
+    headers: HeaderMap,
+    uri: Uri,
     ws: WebSocketUpgrade,
 ) -> Response {
     tracing::info!("WebSocket upgrade request for depth: {}", symbol);
 
+    if let Err(rejection) = authorize_upgrade(&state, &headers, &uri) {
+        return rejection;
+    }
+
+    let options = match query.validate() {
+        Ok(options) => options,
+        Err(e) => return e.into_response(),
+    };
+
     // Try to acquire connection permit (non-blocking)
-    let permit = match state.ws_connections.try_acquire_owned() {
+    let permit = match acquire_connection_permit(&state) {
         Ok(permit) => permit,
-        Err(_) => {
-            tracing::warn!("WebSocket connection limit reached (50 concurrent)");
-            return axum::response::Response::builder()
-                .status(503)
-                .header("Retry-After", "30")
-                .body("Service Unavailable: Maximum WebSocket connections reached".into())
-                .unwrap();
-        }
+        Err(rejection) => return rejection.into_response(),
     };
 
-    ws.on_upgrade(move |socket| handle_depth_socket(socket, symbol, permit))
+    let capacity = state.ws_broadcast_capacity;
+    accept_bearer_protocol(ws)
+        .on_upgrade(move |socket| handle_depth_socket(socket, symbol, options, capacity, permit))
 }
 
 /// Handle individual depth WebSocket connection
@@ -78,24 +163,30 @@ pub async fn depth_handler(
 /// ## Arguments
 /// - `socket`: WebSocket connection to the client
 /// - `symbol`: Trading pair symbol (e.g., "btcusdt")
+/// - `options`: Validated `levels`/`interval` options
+/// - `capacity`: Broadcast channel capacity (`WS_BROADCAST_CAPACITY`)
 /// - `_permit`: Connection permit from semaphore (held until socket closes)
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 async fn handle_depth_socket(
     socket: WebSocket,
     symbol: String,
+    options: DepthStreamOptions,
+    capacity: usize,
     _permit: tokio::sync::OwnedSemaphorePermit,
 ) {
     tracing::info!("Depth WebSocket connected for {} (permit acquired)", symbol);
 
-    // Create broadcast channel for this symbol
-    // Channel size of 100 messages to handle bursts
-    let (tx, mut rx) = broadcast::channel::<DepthUpdate>(100);
+    // Create broadcast channel for this symbol, sized to absorb bursts
+    let (tx, mut rx) = broadcast::channel::<DepthUpdate>(capacity);
 
     // Start Binance stream task
     let ws_client = BinanceWebSocketClient::new();
     let symbol_clone = symbol.clone();
-    tokio::spawn(async move {
-        if let Err(e) = ws_client.depth_stream_task(&symbol_clone, tx).await {
+    let binance_task = tokio::spawn(async move {
+        if let Err(e) = ws_client
+            .depth_stream_task_at(&symbol_clone, options.update_speed_ms, tx)
+            .await
+        {
             tracing::error!("Depth stream task failed: {}", e);
         }
     });
@@ -106,36 +197,41 @@ async fn handle_depth_socket(
     // Spawn task to forward broadcast messages to client
     let symbol_for_task = symbol.clone();
     let mut send_task = tokio::spawn(async move {
+        let mut lagged_total = 0;
         loop {
-            match rx.recv().await {
-                Ok(update) => {
-                    // Serialize depth update to JSON
-                    match serde_json::to_string(&update) {
-                        Ok(json) => {
-                            // Send to client
-                            if sender.send(Message::Text(json.into())).await.is_err() {
-                                tracing::info!("Client disconnected");
-                                break;
-                            }
-                        }
-                        Err(e) => {
-                            tracing::warn!("Failed to serialize depth update: {}", e);
-                        }
+            let json = match recv_or_gap(
+                &mut rx,
+                StreamKind::Depth,
+                Some(&symbol_for_task),
+                &mut lagged_total,
+            )
+            .await
+            {
+                Some(Ok(mut update)) => {
+                    if let Some(levels) = options.levels {
+                        update.truncate(levels);
                     }
+                    serde_json::to_string(&update)
                 }
-                Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                    // Client is falling behind - log warning for T068
-                    tracing::warn!(
-                        "Depth stream lagging for {}: {} messages skipped",
-                        symbol_for_task,
-                        skipped
-                    );
-                    // Continue receiving after lag
-                }
-                Err(broadcast::error::RecvError::Closed) => {
+                // Dropped deltas break the client's book, so it is told to resnapshot
+                Some(Err(gap)) => serde_json::to_string(&gap),
+                None => {
                     tracing::info!("Depth broadcast channel closed");
                     break;
                 }
+            };
+
+            match json {
+                Ok(json) => {
+                    // Send to client
+                    if sender.send(Message::Text(json.into())).await.is_err() {
+                        tracing::info!("Client disconnected");
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to serialize depth update: {}", e);
+                }
             }
         }
     });
@@ -172,9 +268,61 @@ async fn handle_depth_socket(
         },
     }
 
+    // Stop the upstream stream so it doesn't outlive the client
+    binance_task.abort();
+
     tracing::info!(
         "Depth WebSocket disconnected for {} (permit released)",
         symbol
     );
     // Permit is automatically released when _permit is dropped
 }
+
+#[cfg(all(test, feature = "http-api", feature = "websocket"))]
+mod tests {
+    use super::*;
+
+    fn query(levels: Option<usize>, interval: Option<&str>) -> DepthStreamQuery {
+        DepthStreamQuery {
+            levels,
+            interval: interval.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_options_default_to_full_depth_at_1000ms() {
+        let options = DepthStreamQuery::default().validate().unwrap();
+        assert_eq!(options.levels, None);
+        assert_eq!(options.update_speed_ms, 1000);
+
+        let options = query(Some(5), Some("100ms")).validate().unwrap();
+        assert_eq!(options.levels, Some(5));
+        assert_eq!(options.update_speed_ms, 100);
+
+        assert!(query(Some(0), None).validate().is_err());
+        assert!(query(Some(1001), None).validate().is_err());
+        assert!(query(None, Some("250ms")).validate().is_err());
+    }
+
+    #[test]
+    fn test_levels_5_subscription_receives_trimmed_updates() {
+        let options = query(Some(5), None).validate().unwrap();
+        let level = |price: u32| (format!("{}.00", price), "1.0".to_string());
+
+        let mut update = DepthUpdate {
+            event_type: "depthUpdate".to_string(),
+            event_time: 1_699_564_800_000,
+            symbol: "BTCUSDT".to_string(),
+            first_update_id: 1,
+            final_update_id: 2,
+            bids: (90..100).map(level).collect(),
+            asks: (100..110).rev().map(level).collect(),
+        };
+        update.truncate(options.levels.unwrap());
+
+        let bids: Vec<&str> = update.bids.iter().map(|(p, _)| p.as_str()).collect();
+        let asks: Vec<&str> = update.asks.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(bids, ["99.00", "98.00", "97.00", "96.00", "95.00"]);
+        assert_eq!(asks, ["100.00", "101.00", "102.00", "103.00", "104.00"]);
+    }
+}
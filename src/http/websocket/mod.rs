@@ -10,6 +10,21 @@
 //! - Single WebSocket connection per stream type
 //! - tokio::sync::broadcast for fan-out to multiple subscribers
 //! - Automatic reconnection with exponential backoff
+//!
+//! ## Lagging Clients
+//!
+//! Each stream buffers `WS_BROADCAST_CAPACITY` messages (default 100). A
+//! client that falls further behind loses the oldest ones; instead of
+//! skipping them silently, the stream sends a [`StreamGap`] notice first:
+//!
+//! ```json
+//! {"type": "gap", "stream": "depth", "symbol": "btcusdt", "skipped": 37,
+//!  "resync_required": true, "message": "..."}
+//! ```
+//!
+//! Depth and user data clients must resync after a gap (fetch a fresh
+//! `/api/v1/depth` snapshot, or re-query orders and balances); ticker clients
+//! can wait for the next update.
 
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 pub mod depth;
@@ -24,3 +39,349 @@ pub use depth::depth_handler;
 pub use ticker::ticker_handler;
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 pub use user_data::user_data_handler;
+
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use crate::http::AppState;
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use axum::response::{IntoResponse, Response};
+
+/// WebSocket stream a [`StreamGap`] occurred on
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamKind {
+    /// `/ws/ticker/{symbol}`
+    Ticker,
+    /// `/ws/depth/{symbol}`
+    Depth,
+    /// `/ws/user`
+    UserData,
+}
+
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+impl StreamKind {
+    /// Whether skipped messages leave the client's state inconsistent
+    ///
+    /// Depth updates are deltas and user data events are fills and balance
+    /// changes, so a gap in either invalidates what the client has built.
+    /// Each ticker update replaces the previous one.
+    pub fn resync_required(self) -> bool {
+        !matches!(self, StreamKind::Ticker)
+    }
+
+    fn resync_hint(self) -> &'static str {
+        match self {
+            StreamKind::Ticker => "The next update carries current prices.",
+            StreamKind::Depth => {
+                "The local order book is now inconsistent: fetch a fresh snapshot from /api/v1/depth and apply updates from there."
+            }
+            StreamKind::UserData => {
+                "Order and balance events were lost: re-query open orders and the account before relying on local state."
+            }
+        }
+    }
+}
+
+/// Notice sent to a client that lagged behind its stream's broadcast buffer
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct StreamGap {
+    /// Always `"gap"`, distinguishing the notice from stream data
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// Stream the messages were dropped from
+    pub stream: StreamKind,
+    /// Symbol of the stream (`None` for user data)
+    pub symbol: Option<String>,
+    /// Messages dropped since the previous one delivered
+    pub skipped: u64,
+    /// Whether the client must resync before trusting further updates
+    pub resync_required: bool,
+    /// What the client should do about it
+    pub message: String,
+}
+
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+impl StreamGap {
+    /// Notice for `skipped` messages dropped from `stream`
+    pub fn new(stream: StreamKind, symbol: Option<&str>, skipped: u64) -> Self {
+        Self {
+            kind: "gap",
+            stream,
+            symbol: symbol.map(str::to_string),
+            skipped,
+            resync_required: stream.resync_required(),
+            message: format!(
+                "Client fell behind and {} messages were dropped. {}",
+                skipped,
+                stream.resync_hint()
+            ),
+        }
+    }
+}
+
+/// Receives the next message for a WebSocket client
+///
+/// Returns `None` once the channel is closed and `Err` with a [`StreamGap`]
+/// when the client lagged, which is logged along with the client's running
+/// total of dropped messages in `lagged_total`. Receiving continues from the
+/// oldest message still buffered.
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+pub(crate) async fn recv_or_gap<T: Clone>(
+    rx: &mut tokio::sync::broadcast::Receiver<T>,
+    stream: StreamKind,
+    symbol: Option<&str>,
+    lagged_total: &mut u64,
+) -> Option<Result<T, StreamGap>> {
+    use tokio::sync::broadcast::error::RecvError;
+
+    match rx.recv().await {
+        Ok(message) => Some(Ok(message)),
+        Err(RecvError::Lagged(skipped)) => {
+            *lagged_total += skipped;
+            tracing::warn!(
+                stream = ?stream,
+                symbol = symbol.unwrap_or("-"),
+                skipped,
+                lagged_total = *lagged_total,
+                "WebSocket client lagging; sending gap notice"
+            );
+            Some(Err(StreamGap::new(stream, symbol, skipped)))
+        }
+        Err(RecvError::Closed) => None,
+    }
+}
+
+/// Rejection returned when every WebSocket connection permit is in use
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+#[derive(Debug, Clone, Copy)]
+pub struct WebSocketLimitReached {
+    /// Configured concurrent connection limit
+    pub limit: usize,
+}
+
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+impl IntoResponse for WebSocketLimitReached {
+    fn into_response(self) -> Response {
+        use axum::http::{header, StatusCode};
+
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, "30")],
+            axum::Json(serde_json::json!({
+                "error": {
+                    "type": "websocket_limit",
+                    "message": format!(
+                        "Maximum WebSocket connections reached ({} concurrent). Retry after 30 seconds.",
+                        self.limit
+                    ),
+                },
+                "max_connections": self.limit,
+            })),
+        )
+            .into_response()
+    }
+}
+
+/// Authenticates a WebSocket upgrade request before it is upgraded
+///
+/// The `/ws` routes sit outside the bearer-token middleware so the token can
+/// also come from the `access_token` query parameter or a
+/// `Sec-WebSocket-Protocol: bearer, <token>` header. Unauthenticated
+/// upgrades are rejected with HTTP 401.
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+#[allow(clippy::result_large_err)]
+pub(crate) fn authorize_upgrade(
+    state: &AppState,
+    headers: &axum::http::HeaderMap,
+    uri: &axum::http::Uri,
+) -> Result<(), Response> {
+    crate::http::authorize_websocket(&state.token_store, headers, uri).map(|_| ())
+}
+
+/// Selects the `bearer` subprotocol if the client authenticated through it
+///
+/// Browsers fail the handshake when they offer subprotocols and the server
+/// selects none.
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+pub(crate) fn accept_bearer_protocol(
+    ws: axum::extract::WebSocketUpgrade,
+) -> axum::extract::WebSocketUpgrade {
+    ws.protocols([crate::http::middleware::auth::WEBSOCKET_BEARER_PROTOCOL])
+}
+
+/// Acquires a WebSocket connection permit without waiting
+///
+/// When all `ws_connection_limit` permits are in use, the upgrade should be
+/// rejected with the returned [`WebSocketLimitReached`] (HTTP 503 with
+/// `Retry-After` and a JSON body) instead of being left hanging. The permit
+/// is released when the returned guard is dropped.
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+pub(crate) fn acquire_connection_permit(
+    state: &AppState,
+) -> Result<tokio::sync::OwnedSemaphorePermit, WebSocketLimitReached> {
+    state
+        .ws_connections
+        .clone()
+        .try_acquire_owned()
+        .map_err(|_| {
+            tracing::warn!(
+                "WebSocket connection limit reached ({} concurrent)",
+                state.ws_connection_limit
+            );
+            WebSocketLimitReached {
+                limit: state.ws_connection_limit,
+            }
+        })
+}
+
+#[cfg(all(test, feature = "http-api", feature = "websocket"))]
+mod tests {
+    use super::*;
+    use crate::binance::client::BinanceClient;
+    use crate::http::{RateLimiter, TokenStore};
+    use std::sync::Arc;
+
+    const TEST_TOKEN: &str = "ws_test_token";
+
+    fn state_with_limit(limit: usize) -> AppState {
+        let token_store = TokenStore::new();
+        token_store.add_token(TEST_TOKEN, "ws-test".to_string());
+        AppState {
+            binance_client: Arc::new(BinanceClient::new()),
+            token_store,
+            rate_limiter: RateLimiter::new(100),
+            ws_connections: Arc::new(tokio::sync::Semaphore::new(limit)),
+            ws_connection_limit: limit,
+            ws_broadcast_capacity: 100,
+        }
+    }
+
+    async fn serve(state: AppState) -> std::net::SocketAddr {
+        let app = axum::Router::new()
+            .route("/ws/ticker/{symbol}", axum::routing::get(ticker_handler))
+            .with_state(state);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        addr
+    }
+
+    fn http_rejection(err: tokio_tungstenite::tungstenite::Error) -> u16 {
+        let tokio_tungstenite::tungstenite::Error::Http(response) = err else {
+            panic!("expected HTTP rejection, got {err:?}");
+        };
+        response.status().as_u16()
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_upgrade_is_rejected() {
+        let state = state_with_limit(2);
+        let addr = serve(state.clone()).await;
+
+        let url = format!("ws://{}/ws/ticker/btcusdt", addr);
+        let err = tokio_tungstenite::connect_async(&url).await.unwrap_err();
+        assert_eq!(http_rejection(err), 401);
+
+        let url = format!("ws://{}/ws/ticker/btcusdt?access_token=wrong", addr);
+        let err = tokio_tungstenite::connect_async(&url).await.unwrap_err();
+        assert_eq!(http_rejection(err), 401);
+
+        // Rejected before upgrading, so no connection slot was taken
+        assert_eq!(state.ws_connections.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connection_over_limit_is_rejected() {
+        let state = state_with_limit(2);
+        let addr = serve(state.clone()).await;
+
+        // Occupy every slot, as two open connections would
+        let first = acquire_connection_permit(&state).unwrap();
+        let _second = acquire_connection_permit(&state).unwrap();
+
+        let url = format!(
+            "ws://{}/ws/ticker/btcusdt?access_token={}",
+            addr, TEST_TOKEN
+        );
+        let err = tokio_tungstenite::connect_async(&url).await.unwrap_err();
+        let tokio_tungstenite::tungstenite::Error::Http(response) = err else {
+            panic!("expected HTTP rejection, got {err:?}");
+        };
+        assert_eq!(response.status(), 503);
+        assert_eq!(response.headers()["retry-after"], "30");
+        let body: serde_json::Value =
+            serde_json::from_slice(response.body().as_deref().unwrap()).unwrap();
+        assert_eq!(body["error"]["type"], "websocket_limit");
+        assert_eq!(body["max_connections"], 2);
+
+        // Dropping a connection's permit frees its slot
+        drop(first);
+        assert_eq!(state.ws_connections.available_permits(), 1);
+        assert!(acquire_connection_permit(&state).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_gets_gap_notice() {
+        let (tx, mut rx) = tokio::sync::broadcast::channel::<u32>(4);
+        let mut lagged_total = 0;
+
+        // Ten updates published while the client reads nothing
+        for update in 0..10 {
+            tx.send(update).unwrap();
+        }
+
+        let gap = recv_or_gap(
+            &mut rx,
+            StreamKind::Depth,
+            Some("btcusdt"),
+            &mut lagged_total,
+        )
+        .await
+        .unwrap()
+        .unwrap_err();
+        assert_eq!(gap.skipped, 6);
+        assert!(gap.resync_required);
+        assert_eq!(lagged_total, 6);
+
+        let notice = serde_json::to_value(&gap).unwrap();
+        assert_eq!(notice["type"], "gap");
+        assert_eq!(notice["stream"], "depth");
+        assert_eq!(notice["symbol"], "btcusdt");
+        assert!(notice["message"]
+            .as_str()
+            .unwrap()
+            .contains("fresh snapshot"));
+
+        // The buffered updates still arrive, in order
+        for expected in 6..10 {
+            let update = recv_or_gap(
+                &mut rx,
+                StreamKind::Depth,
+                Some("btcusdt"),
+                &mut lagged_total,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            assert_eq!(update, expected);
+        }
+
+        drop(tx);
+        assert!(recv_or_gap(
+            &mut rx,
+            StreamKind::Depth,
+            Some("btcusdt"),
+            &mut lagged_total
+        )
+        .await
+        .is_none());
+    }
+
+    #[test]
+    fn test_ticker_gap_needs_no_resync() {
+        let gap = StreamGap::new(StreamKind::Ticker, Some("ethusdt"), 3);
+        assert!(!gap.resync_required);
+        assert!(StreamGap::new(StreamKind::UserData, None, 1).resync_required);
+    }
+}
@@ -7,14 +7,19 @@
 //! - Real-time price updates every ~1000ms
 //! - Automatic subscription to Binance ticker stream
 //! - Client connection management and cleanup
-//! - Authentication via Bearer token in upgrade request
+//! - Authentication via bearer token (header, query parameter or subprotocol) before upgrade
 
 #[cfg(feature = "http-api")]
 use axum::{
     extract::{Path, State, WebSocketUpgrade},
-    response::Response,
+    http::{HeaderMap, Uri},
+    response::{IntoResponse, Response},
 };
 
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use super::{
+    accept_bearer_protocol, acquire_connection_permit, authorize_upgrade, recv_or_gap, StreamKind,
+};
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 use crate::binance::websocket::{BinanceWebSocketClient, TickerUpdate};
 #[cfg(feature = "http-api")]
@@ -35,11 +40,13 @@ use tokio::sync::broadcast;
 /// `GET /ws/ticker/:symbol`
 ///
 /// ## Authentication
-/// Requires valid Bearer token in Authorization header
+/// Requires a valid bearer token in the Authorization header, the
+/// `access_token` query parameter, or `Sec-WebSocket-Protocol: bearer, <token>`.
+/// Returns HTTP 401 before upgrading otherwise.
 ///
 /// ## Connection Limit
-/// Maximum 50 concurrent WebSocket connections (SC-003 requirement).
-/// Returns HTTP 503 if limit exceeded.
+/// Shares the `HTTP_MAX_WEBSOCKET_CONNECTIONS` limit (default 50) with the other
+/// streams. Returns HTTP 503 with a JSON body if the limit is reached.
 ///
 /// ## Example
 /// ```bash
@@ -50,24 +57,25 @@ use tokio::sync::broadcast;
 pub async fn ticker_handler(
     State(state): State<AppState>,
     Path(symbol): Path<String>,
+    headers: HeaderMap,
+    uri: Uri,
     ws: WebSocketUpgrade,
 ) -> Response {
     tracing::info!("WebSocket upgrade request for ticker: {}", symbol);
 
+    if let Err(rejection) = authorize_upgrade(&state, &headers, &uri) {
+        return rejection;
+    }
+
     // Try to acquire connection permit (non-blocking)
-    let permit = match state.ws_connections.try_acquire_owned() {
+    let permit = match acquire_connection_permit(&state) {
         Ok(permit) => permit,
-        Err(_) => {
-            tracing::warn!("WebSocket connection limit reached (50 concurrent)");
-            return axum::response::Response::builder()
-                .status(503)
-                .header("Retry-After", "30")
-                .body("Service Unavailable: Maximum WebSocket connections reached".into())
-                .unwrap();
-        }
+        Err(rejection) => return rejection.into_response(),
     };
 
-    ws.on_upgrade(move |socket| handle_ticker_socket(socket, symbol, permit))
+    let capacity = state.ws_broadcast_capacity;
+    accept_bearer_protocol(ws)
+        .on_upgrade(move |socket| handle_ticker_socket(socket, symbol, capacity, permit))
 }
 
 /// Handle individual ticker WebSocket connection
@@ -78,11 +86,13 @@ pub async fn ticker_handler(
 /// ## Arguments
 /// - `socket`: WebSocket connection to the client
 /// - `symbol`: Trading pair symbol (e.g., "btcusdt")
+/// - `capacity`: Broadcast channel capacity (`WS_BROADCAST_CAPACITY`)
 /// - `_permit`: Connection permit from semaphore (held until socket closes)
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 async fn handle_ticker_socket(
     socket: WebSocket,
     symbol: String,
+    capacity: usize,
     _permit: tokio::sync::OwnedSemaphorePermit,
 ) {
     tracing::info!(
@@ -90,14 +100,13 @@ async fn handle_ticker_socket(
         symbol
     );
 
-    // Create broadcast channel for this symbol
-    // Channel size of 100 messages to handle bursts
-    let (tx, mut rx) = broadcast::channel::<TickerUpdate>(100);
+    // Create broadcast channel for this symbol, sized to absorb bursts
+    let (tx, mut rx) = broadcast::channel::<TickerUpdate>(capacity);
 
     // Start Binance stream task
     let ws_client = BinanceWebSocketClient::new();
     let symbol_clone = symbol.clone();
-    tokio::spawn(async move {
+    let binance_task = tokio::spawn(async move {
         if let Err(e) = ws_client.ticker_stream_task(&symbol_clone, tx).await {
             tracing::error!("Ticker stream task failed: {}", e);
         }
@@ -107,10 +116,27 @@ async fn handle_ticker_socket(
     let (mut sender, mut receiver) = socket.split();
 
     // Spawn task to forward broadcast messages to client
+    let symbol_for_task = symbol.clone();
     let mut send_task = tokio::spawn(async move {
-        while let Ok(update) = rx.recv().await {
-            // Serialize ticker update to JSON
-            match serde_json::to_string(&update) {
+        let mut lagged_total = 0;
+        loop {
+            let json = match recv_or_gap(
+                &mut rx,
+                StreamKind::Ticker,
+                Some(&symbol_for_task),
+                &mut lagged_total,
+            )
+            .await
+            {
+                Some(Ok(update)) => serde_json::to_string(&update),
+                Some(Err(gap)) => serde_json::to_string(&gap),
+                None => {
+                    tracing::info!("Ticker broadcast channel closed");
+                    break;
+                }
+            };
+
+            match json {
                 Ok(json) => {
                     // Send to client
                     if sender.send(Message::Text(json.into())).await.is_err() {
@@ -157,6 +183,9 @@ async fn handle_ticker_socket(
         },
     }
 
+    // Stop the upstream stream so it doesn't outlive the client
+    binance_task.abort();
+
     tracing::info!(
         "Ticker WebSocket disconnected for {} (permit released)",
         symbol
@@ -8,14 +8,19 @@
 //! - Real-time balance updates from trades
 //! - Automatic listen key creation and renewal
 //! - Client connection management and cleanup
-//! - Authentication via Bearer token in upgrade request
+//! - Authentication via bearer token (header, query parameter or subprotocol) before upgrade
 
 #[cfg(feature = "http-api")]
 use axum::{
     extract::{State, WebSocketUpgrade},
-    response::Response,
+    http::{HeaderMap, Uri},
+    response::{IntoResponse, Response},
 };
 
+#[cfg(all(feature = "http-api", feature = "websocket"))]
+use super::{
+    accept_bearer_protocol, acquire_connection_permit, authorize_upgrade, recv_or_gap, StreamKind,
+};
 #[cfg(all(feature = "http-api", feature = "websocket"))]
 use crate::binance::websocket::{BinanceWebSocketClient, UserDataEvent};
 #[cfg(feature = "http-api")]
@@ -43,11 +48,13 @@ const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
 /// `GET /ws/user`
 ///
 /// ## Authentication
-/// Requires valid Bearer token in Authorization header
+/// Requires a valid bearer token in the Authorization header, the
+/// `access_token` query parameter, or `Sec-WebSocket-Protocol: bearer, <token>`.
+/// Returns HTTP 401 before upgrading otherwise.
 ///
 /// ## Connection Limit
-/// Maximum 50 concurrent WebSocket connections (SC-003 requirement).
-/// Returns HTTP 503 if limit exceeded.
+/// Shares the `HTTP_MAX_WEBSOCKET_CONNECTIONS` limit (default 50) with the other
+/// streams. Returns HTTP 503 with a JSON body if the limit is reached.
 ///
 /// ## Example
 /// ```bash
@@ -55,23 +62,26 @@ const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
 ///   -H "Authorization: Bearer test_token"
 /// ```
 #[cfg(all(feature = "http-api", feature = "websocket"))]
-pub async fn user_data_handler(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+pub async fn user_data_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    uri: Uri,
+    ws: WebSocketUpgrade,
+) -> Response {
     tracing::info!("WebSocket upgrade request for user data");
 
+    if let Err(rejection) = authorize_upgrade(&state, &headers, &uri) {
+        return rejection;
+    }
+
     // Try to acquire connection permit (non-blocking)
-    let permit = match state.ws_connections.clone().try_acquire_owned() {
+    let permit = match acquire_connection_permit(&state) {
         Ok(permit) => permit,
-        Err(_) => {
-            tracing::warn!("WebSocket connection limit reached (50 concurrent)");
-            return axum::response::Response::builder()
-                .status(503)
-                .header("Retry-After", "30")
-                .body("Service Unavailable: Maximum WebSocket connections reached".into())
-                .unwrap();
-        }
+        Err(rejection) => return rejection.into_response(),
     };
 
-    ws.on_upgrade(move |socket| handle_user_data_socket(socket, state, permit))
+    accept_bearer_protocol(ws)
+        .on_upgrade(move |socket| handle_user_data_socket(socket, state, permit))
 }
 
 /// Handle individual user data WebSocket connection
@@ -103,9 +113,8 @@ async fn handle_user_data_socket(
         }
     };
 
-    // Create broadcast channel for this user
-    // Channel size of 100 messages to handle bursts
-    let (tx, mut rx) = broadcast::channel::<UserDataEvent>(100);
+    // Create broadcast channel for this user, sized to absorb bursts
+    let (tx, mut rx) = broadcast::channel::<UserDataEvent>(state.ws_broadcast_capacity);
 
     // Start Binance stream task
     let ws_client = BinanceWebSocketClient::new();
@@ -140,31 +149,29 @@ async fn handle_user_data_socket(
 
     // Spawn task to forward broadcast messages to client
     let mut send_task = tokio::spawn(async move {
+        let mut lagged_total = 0;
         loop {
-            match rx.recv().await {
-                Ok(event) => {
-                    // Serialize user data event to JSON
-                    match serde_json::to_string(&event) {
-                        Ok(json) => {
-                            // Send to client
-                            if sender.send(Message::Text(json.into())).await.is_err() {
-                                tracing::info!("Client disconnected");
-                                break;
-                            }
-                        }
-                        Err(e) => {
-                            tracing::warn!("Failed to serialize user data event: {}", e);
-                        }
+            let json =
+                match recv_or_gap(&mut rx, StreamKind::UserData, None, &mut lagged_total).await {
+                    Some(Ok(event)) => serde_json::to_string(&event),
+                    // Lost fills and balance changes must be re-queried by the client
+                    Some(Err(gap)) => serde_json::to_string(&gap),
+                    None => {
+                        tracing::info!("User data broadcast channel closed");
+                        break;
+                    }
+                };
+
+            match json {
+                Ok(json) => {
+                    // Send to client
+                    if sender.send(Message::Text(json.into())).await.is_err() {
+                        tracing::info!("Client disconnected");
+                        break;
                     }
                 }
-                Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                    // Client is falling behind - log warning for T068
-                    tracing::warn!("User data stream lagging: {} messages skipped", skipped);
-                    // Continue receiving after lag
-                }
-                Err(broadcast::error::RecvError::Closed) => {
-                    tracing::info!("User data broadcast channel closed");
-                    break;
+                Err(e) => {
+                    tracing::warn!("Failed to serialize user data event: {}", e);
                 }
             }
         }
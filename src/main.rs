@@ -5,10 +5,10 @@
 //! - HTTP server (--http flag): REST API + WebSocket server
 //! - SSE transport (--mode sse): Server-Sent Events for cloud deployment
 
+use mcp_binance_server::config::logging::LogConfig;
 use mcp_binance_server::server::BinanceServer;
 use rmcp::transport::stdio;
 use rmcp::ServiceExt;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 /// Standard main entry point (stdio or standalone SSE server)
 ///
@@ -16,16 +16,9 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 #[cfg(not(feature = "shuttle-runtime"))]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing subscriber with env filter
+    // Initialize tracing subscriber (text or JSON per LOG_FORMAT)
     // Logs go to stderr (not stdout, which is used for MCP protocol)
-    tracing_subscriber::registry()
-        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
-        .with(
-            tracing_subscriber::fmt::layer()
-                .with_writer(std::io::stderr)
-                .with_ansi(false), // No ANSI colors for cleaner logs
-        )
-        .init();
+    LogConfig::from_env().init();
 
     tracing::info!("Starting MCP Binance Server v{}", env!("CARGO_PKG_VERSION"));
 
@@ -85,15 +78,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// Run MCP server with stdio transport (default mode)
 async fn run_stdio_server() -> Result<(), Box<dyn std::error::Error>> {
     // Create BinanceServer instance and serve with stdio transport
-    let service = BinanceServer::new().serve(stdio()).await?;
+    let server = BinanceServer::new();
+    server.spawn_time_sync();
+    server.spawn_market_cache_refresh();
+    #[cfg(feature = "orderbook")]
+    server.spawn_orderbook_preload();
+    #[cfg(feature = "orderbook_analytics")]
+    server.spawn_snapshot_flush();
+    let service = server.clone().serve(stdio()).await?;
 
     tracing::info!("MCP server initialized with stdio transport, waiting for requests");
 
-    // Wait for the service to complete (blocks until stdin closes)
-    service.waiting().await?;
+    // Wait for the service to complete (stdin closes) or a shutdown signal
+    tokio::select! {
+        result = service.waiting() => {
+            result?;
+        }
+        () = shutdown_signal() => {}
+    }
 
     // Graceful shutdown
     tracing::info!("MCP server shutting down gracefully");
+    server.shutdown().await;
 
     Ok(())
 }
@@ -108,28 +114,77 @@ async fn run_http_server() -> Result<(), Box<dyn std::error::Error>> {
     let config = HttpConfig::from_env()?;
 
     tracing::info!("Starting HTTP server on {}", config.addr);
-    tracing::info!("Rate limit: {} req/min per client", config.rate_limit);
+    tracing::info!(
+        "Default rate limit: {} req/min per token",
+        config.rate_limit
+    );
     tracing::info!(
         "Max WebSocket connections: {}",
         config.max_websocket_connections
     );
+    tracing::info!(
+        "WebSocket broadcast capacity: {} messages per stream",
+        config.ws_broadcast_capacity
+    );
+    if config.cors_allowed_origins.is_empty() {
+        tracing::warn!("CORS_ALLOWED_ORIGINS not set; allowing any origin");
+    } else {
+        tracing::info!("CORS allowed origins: {:?}", config.cors_allowed_origins);
+    }
+
+    if !config.trusted_proxies.is_empty() {
+        tracing::info!(
+            "Honoring X-Forwarded-For from trusted proxies: {:?}",
+            config.trusted_proxies
+        );
+    }
 
     // Initialize token store and load tokens from environment
     let token_store = TokenStore::new();
-    token_store.add_token(&config.bearer_token, "env_token".to_string());
-    tracing::info!("Loaded 1 bearer token from environment");
+    if let Some(token) = &config.bearer_token {
+        token_store.add_token(token, "env_token".to_string());
+    }
+    for entry in &config.bearer_tokens {
+        token_store.add_token_with_limit(&entry.token, entry.label.clone(), entry.rate_limit);
+        match entry.rate_limit {
+            Some(limit) => {
+                tracing::info!("Loaded bearer token '{}' ({} req/min)", entry.label, limit)
+            }
+            None => tracing::info!("Loaded bearer token '{}'", entry.label),
+        }
+    }
+    tracing::info!(
+        "Loaded {} bearer token(s) from environment",
+        token_store.len()
+    );
 
-    // Create rate limiter
-    let rate_limiter = RateLimiter::new(config.rate_limit);
+    // Create rate limiter (per client IP / per token) and evict idle keys every 5 minutes
+    let rate_limiter =
+        RateLimiter::new(config.rate_limit).with_trusted_proxies(config.trusted_proxies.clone());
+    rate_limiter.spawn_eviction_task(
+        std::time::Duration::from_secs(300),
+        std::time::Duration::from_secs(600),
+    );
 
     // Create HTTP router with middleware
-    let app = create_router(token_store, rate_limiter);
+    let app = create_router(
+        token_store,
+        rate_limiter,
+        &config.cors_allowed_origins,
+        config.max_websocket_connections,
+        config.ws_broadcast_capacity,
+    );
 
     // Start HTTP server
     let listener = tokio::net::TcpListener::bind(config.addr).await?;
     tracing::info!("HTTP server listening on {}", config.addr);
 
-    axum::serve(listener, app).await?;
+    // Expose peer addresses to the per-IP rate limiter
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -160,7 +215,8 @@ async fn run_sse_server() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Starting SSE server on {}", addr);
 
     // Create router
-    let app = create_sse_router();
+    let mcp_server = BinanceServer::new();
+    let app = create_sse_router(mcp_server.clone());
 
     // Start HTTP server
     let listener = tokio::net::TcpListener::bind(&addr).await?;
@@ -171,24 +227,64 @@ async fn run_sse_server() -> Result<(), Box<dyn std::error::Error>> {
     );
     tracing::info!("Health check: http://{}/health", addr);
 
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    tracing::info!("SSE server shutting down gracefully");
+    mcp_server.shutdown().await;
 
     Ok(())
 }
 
+/// Resolves on Ctrl+C, or SIGTERM on Unix (sent by orchestrators before a restart)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!(error = %e, "Failed to listen for Ctrl+C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => tracing::info!("Received Ctrl+C"),
+        () = terminate => tracing::info!("Received SIGTERM"),
+    }
+}
+
 /// Creates SSE router with all endpoints
 ///
 /// Used by both standalone server (`run_sse_server`) and Shuttle runtime.
 #[cfg(feature = "sse")]
-fn create_sse_router() -> axum::Router {
-    use mcp_binance_server::server::BinanceServer;
+fn create_sse_router(mcp_server: BinanceServer) -> axum::Router {
     use mcp_binance_server::transport::sse::{
-        message_post, server_info, tools_list, SessionManager, SseState,
+        message_post, notification_stream, server_info, tools_list, SseState,
     };
 
-    // Create session manager and MCP server
-    let session_manager = SessionManager::new();
-    let mcp_server = BinanceServer::new();
+    // Handlers share the MCP server's session manager so per-session
+    // credentials and notifications are visible to the tool handlers
+    mcp_server.spawn_time_sync();
+    mcp_server.spawn_market_cache_refresh();
+    #[cfg(feature = "orderbook")]
+    mcp_server.spawn_orderbook_preload();
+    #[cfg(feature = "orderbook_analytics")]
+    mcp_server.spawn_snapshot_flush();
+    let session_manager = mcp_server.session_manager.clone();
     let state = SseState::new(session_manager, mcp_server);
 
     // Create router with Streamable HTTP endpoints (March 2025 spec)
@@ -196,8 +292,12 @@ fn create_sse_router() -> axum::Router {
     // Consolidated to single POST /mcp endpoint with Mcp-Session-Id header
     axum::Router::new()
         .route("/", axum::routing::get(server_info))
-        // Streamable HTTP transport (March 2025 spec) - POST only
-        .route("/mcp", axum::routing::post(message_post))
+        // Streamable HTTP transport (March 2025 spec)
+        // POST for requests, GET for the session's server-initiated notifications
+        .route(
+            "/mcp",
+            axum::routing::post(message_post).get(notification_stream),
+        )
         // Backward compatibility - alias to /mcp
         .route("/messages", axum::routing::post(message_post))
         // Additional endpoints
@@ -248,7 +348,7 @@ async fn shuttle_main(
     tracing::info!("Starting MCP Binance Server on Shuttle.dev");
 
     // Create SSE router (reuses same router as standalone mode)
-    let router = create_sse_router();
+    let router = create_sse_router(BinanceServer::new());
 
     // T035: Shuttle handles graceful shutdown automatically
     Ok(router.into())
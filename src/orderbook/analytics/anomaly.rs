@@ -6,8 +6,10 @@
 //! - Flash crash risk: >80% depth loss + >10x spread + >90% cancellation rate
 
 use super::{
+    quality::snapshot_shortfall,
+    replay::analysis_window,
     storage::{query::query_snapshots_in_window, SnapshotStorage},
-    types::{AnomalyType, MarketMicrostructureAnomaly, Severity},
+    types::{AnomalyReport, AnomalyType, DataSufficiency, MarketMicrostructureAnomaly, Severity},
 };
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -23,10 +25,13 @@ use uuid::Uuid;
 /// # Parameters
 /// - `storage`: RocksDB snapshot storage
 /// - `symbol`: Trading pair (e.g., "BTCUSDT")
-/// - `window_duration_secs`: Analysis window (default: 60 seconds)
+/// - `window_duration_secs`: Analysis window (default: 60 seconds), replaced
+///   by the recorded range in [`replay`](super::replay) mode
 ///
 /// # Returns
-/// Vector of detected anomalies, each with:
+/// [`AnomalyReport`] flagged with its data sufficiency. Windows below the
+/// [`quality`](super::quality) minimums report no anomalies and an
+/// `insufficient` flag rather than an all-clear. Each detected anomaly has:
 /// - anomaly_id: Unique identifier
 /// - anomaly_type: QuoteStuffing | IcebergOrder | FlashCrashRisk
 /// - timestamp: Detection time
@@ -38,8 +43,8 @@ use uuid::Uuid;
 /// ```no_run
 /// # use mcp_binance_server::orderbook::analytics::{anomaly::*, storage::*};
 /// # async fn example(storage: SnapshotStorage) -> anyhow::Result<()> {
-/// let anomalies = detect_anomalies(&storage, "BTCUSDT", 60).await?;
-/// for anomaly in anomalies {
+/// let report = detect_anomalies(&storage, "BTCUSDT", 60).await?;
+/// for anomaly in report.anomalies {
 ///     println!("{:?}: {:?}", anomaly.severity, anomaly.anomaly_type);
 /// }
 /// # Ok(())
@@ -49,17 +54,28 @@ pub async fn detect_anomalies(
     storage: &SnapshotStorage,
     symbol: &str,
     window_duration_secs: u32,
-) -> Result<Vec<MarketMicrostructureAnomaly>> {
-    let end = Utc::now();
-    let start = end - chrono::Duration::seconds(window_duration_secs as i64);
+) -> Result<AnomalyReport> {
+    let window = analysis_window(window_duration_secs);
+    let window_duration_secs = window.duration_secs;
 
     // Query snapshots for analysis window
-    let snapshots = query_snapshots_in_window(storage, symbol, start.timestamp(), end.timestamp())
-        .await
-        .context("Failed to query snapshots for anomaly detection")?;
-
-    if snapshots.is_empty() {
-        return Ok(Vec::new());
+    let snapshots = query_snapshots_in_window(
+        storage,
+        symbol,
+        window.start.timestamp(),
+        window.end.timestamp(),
+    )
+    .await
+    .context("Failed to query snapshots for anomaly detection")?;
+
+    if let Some(note) = snapshot_shortfall(snapshots.len(), window_duration_secs) {
+        return Ok(AnomalyReport {
+            symbol: symbol.to_string(),
+            window_duration_secs,
+            anomalies: Vec::new(),
+            data_sufficiency: DataSufficiency::Insufficient,
+            data_note: Some(note),
+        });
     }
 
     let mut anomalies = Vec::new();
@@ -77,7 +93,13 @@ pub async fn detect_anomalies(
         anomalies.push(anomaly);
     }
 
-    Ok(anomalies)
+    Ok(AnomalyReport {
+        symbol: symbol.to_string(),
+        window_duration_secs,
+        anomalies,
+        data_sufficiency: DataSufficiency::Sufficient,
+        data_note: None,
+    })
 }
 
 /// Detect quote stuffing: High update rate with low fill rate (T037, FR-003)
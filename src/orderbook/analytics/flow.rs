@@ -4,8 +4,10 @@
 //! Target window: 60 seconds (60 snapshots at 1/sec capture rate).
 
 use super::{
+    quality::snapshot_shortfall,
+    replay::{analysis_window, AnalysisWindow},
     storage::{query::query_snapshots_in_window, SnapshotStorage},
-    types::{FlowDirection, OrderFlowSnapshot},
+    types::{DataSufficiency, FlowDirection, OrderFlowSnapshot},
 };
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -19,11 +21,15 @@ use chrono::{DateTime, Utc};
 /// 4. Determine flow direction based on bid/ask ratio
 /// 5. Track cumulative delta over window
 ///
+/// Windows below the [`quality`](super::quality) minimums return zeroed rates
+/// flagged `data_sufficiency: "insufficient"`.
+///
 /// # Parameters
 /// - `storage`: RocksDB snapshot storage
 /// - `symbol`: Trading pair (e.g., "BTCUSDT")
 /// - `window_duration_secs`: Analysis window (default: 60 seconds)
-/// - `end_time`: Window end timestamp (default: now)
+/// - `end_time`: Window end timestamp (default: now, or the recorded range in
+///   [`replay`](super::replay) mode, which replaces the window)
 ///
 /// # Example
 /// ```no_run
@@ -41,8 +47,12 @@ pub async fn calculate_order_flow(
     window_duration_secs: u32,
     end_time: Option<DateTime<Utc>>,
 ) -> Result<OrderFlowSnapshot> {
-    let end = end_time.unwrap_or_else(Utc::now);
-    let start = end - chrono::Duration::seconds(window_duration_secs as i64);
+    let window = match end_time {
+        Some(end) => AnalysisWindow::ending_at(end, window_duration_secs),
+        None => analysis_window(window_duration_secs),
+    };
+    let (start, end) = (window.start, window.end);
+    let window_duration_secs = window.duration_secs;
 
     let start_timestamp_sec = start.timestamp();
     let end_timestamp_sec = end.timestamp();
@@ -53,8 +63,8 @@ pub async fn calculate_order_flow(
             .await
             .context("Failed to query snapshots for order flow")?;
 
-    if snapshots.is_empty() {
-        // Return neutral flow if no data available
+    if let Some(note) = snapshot_shortfall(snapshots.len(), window_duration_secs) {
+        // Too sparse for meaningful rates: flag it instead of reporting a direction
         return Ok(OrderFlowSnapshot {
             symbol: symbol.to_string(),
             time_window_start: start,
@@ -65,6 +75,8 @@ pub async fn calculate_order_flow(
             net_flow: 0.0,
             flow_direction: FlowDirection::Neutral,
             cumulative_delta: 0.0,
+            data_sufficiency: DataSufficiency::Insufficient,
+            data_note: Some(note),
         });
     }
 
@@ -91,6 +103,8 @@ pub async fn calculate_order_flow(
         net_flow: bid_flow_rate - ask_flow_rate,
         flow_direction,
         cumulative_delta,
+        data_sufficiency: DataSufficiency::Sufficient,
+        data_note: None,
     })
 }
 
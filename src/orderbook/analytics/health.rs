@@ -5,13 +5,22 @@
 //! - Liquidity depth (thick orderbook = healthy)
 //! - Flow balance (neutral flow = healthy)
 //! - Update rate (moderate activity = healthy)
+//!
+//! Windows with missing data (snapshot gaps or @aggTrade disconnects) are
+//! reported as `data_gap_secs` and the overall score is discounted by the
+//! missing share, so a high score isn't reported on stale data.
 
 use super::{
+    quality::snapshot_shortfall,
+    replay::analysis_window,
     storage::{query::query_snapshots_in_window, SnapshotStorage},
-    types::MicrostructureHealth,
+    trade_stream::stream_gaps,
+    types::{DataSufficiency, MicrostructureHealth},
 };
 use anyhow::{Context, Result};
-use chrono::Utc;
+
+/// Health level reported when the window is too sparse to score
+pub const INSUFFICIENT_DATA_LEVEL: &str = "Insufficient Data";
 
 /// Calculate market microstructure health score (T042, FR-010)
 ///
@@ -28,10 +37,14 @@ use chrono::Utc;
 /// - 20-39: Poor (reduce position sizes)
 /// - 0-19: Critical (halt trading, high risk)
 ///
+/// Windows below the [`quality`](super::quality) minimums are not scored and
+/// report [`INSUFFICIENT_DATA_LEVEL`] instead.
+///
 /// # Parameters
 /// - `storage`: RocksDB snapshot storage
 /// - `symbol`: Trading pair (e.g., "BTCUSDT")
-/// - `window_duration_secs`: Analysis window (default: 300 seconds / 5 minutes)
+/// - `window_duration_secs`: Analysis window (default: 300 seconds / 5 minutes),
+///   replaced by the recorded range in [`replay`](super::replay) mode
 ///
 /// # Example
 /// ```no_run
@@ -47,16 +60,17 @@ pub async fn calculate_health_score(
     symbol: &str,
     window_duration_secs: u32,
 ) -> Result<MicrostructureHealth> {
-    let end = Utc::now();
-    let start = end - chrono::Duration::seconds(window_duration_secs as i64);
+    let window = analysis_window(window_duration_secs);
+    let (start, end) = (window.start, window.end);
+    let window_duration_secs = window.duration_secs;
 
     // Query snapshots
     let snapshots = query_snapshots_in_window(storage, symbol, start.timestamp(), end.timestamp())
         .await
         .context("Failed to query snapshots for health score")?;
 
-    if snapshots.is_empty() {
-        // Return critical health if no data
+    if let Some(note) = snapshot_shortfall(snapshots.len(), window_duration_secs) {
+        // Too sparse to score: report the shortfall rather than a made-up number
         return Ok(MicrostructureHealth {
             symbol: symbol.to_string(),
             timestamp: end,
@@ -65,8 +79,16 @@ pub async fn calculate_health_score(
             liquidity_depth_score: 0.0,
             flow_balance_score: 0.0,
             update_rate_score: 0.0,
-            health_level: "Critical".to_string(),
-            recommended_action: "No market data available. HALT TRADING.".to_string(),
+            data_gap_secs: if snapshots.is_empty() {
+                window_duration_secs as f64
+            } else {
+                calculate_snapshot_gap_secs(&snapshots, end.timestamp())
+                    .min(window_duration_secs as f64)
+            },
+            health_level: INSUFFICIENT_DATA_LEVEL.to_string(),
+            recommended_action: format!("{} Do not trade on this score.", note),
+            data_sufficiency: DataSufficiency::Insufficient,
+            data_note: Some(note),
         });
     }
 
@@ -76,11 +98,24 @@ pub async fn calculate_health_score(
     let flow_balance = calculate_flow_balance_score(&snapshots);
     let update_rate = calculate_update_rate_score(&snapshots, window_duration_secs);
 
-    // Composite score with weighted components
-    let overall_score = (spread_stability * 0.25)
+    // Missing data: snapshot gaps or upstream trade stream disconnects, whichever is larger
+    let window_secs = window_duration_secs.max(1) as f64;
+    let stream_gap = stream_gaps().gap_secs_in_window(
+        symbol,
+        start.timestamp_millis().max(0) as u64,
+        end.timestamp_millis().max(0) as u64,
+    );
+    let data_gap_secs = calculate_snapshot_gap_secs(&snapshots, end.timestamp())
+        .max(stream_gap)
+        .min(window_secs);
+    let coverage = 1.0 - data_gap_secs / window_secs;
+
+    // Composite score with weighted components, discounted by missing data
+    let overall_score = ((spread_stability * 0.25)
         + (liquidity_depth * 0.35)
         + (flow_balance * 0.25)
-        + (update_rate * 0.15);
+        + (update_rate * 0.15))
+        * coverage;
 
     let (health_level, recommended_action) = classify_health(overall_score);
 
@@ -92,8 +127,11 @@ pub async fn calculate_health_score(
         liquidity_depth_score: liquidity_depth,
         flow_balance_score: flow_balance,
         update_rate_score: update_rate,
+        data_gap_secs,
         health_level,
         recommended_action,
+        data_sufficiency: DataSufficiency::Sufficient,
+        data_note: None,
     })
 }
 
@@ -219,6 +257,26 @@ fn calculate_update_rate_score(
     score.clamp(0.0, 100.0)
 }
 
+/// Seconds without snapshots, from gaps between captures and since the last one
+///
+/// Snapshots are captured every second, so only the excess over one second counts.
+fn calculate_snapshot_gap_secs(
+    snapshots: &[super::storage::snapshot::OrderBookSnapshot],
+    window_end: i64,
+) -> f64 {
+    let Some(last) = snapshots.last() else {
+        return 0.0;
+    };
+
+    let internal: i64 = snapshots
+        .windows(2)
+        .map(|pair| (pair[1].timestamp - pair[0].timestamp - 1).max(0))
+        .sum();
+    let trailing = (window_end - last.timestamp - 1).max(0);
+
+    (internal + trailing) as f64
+}
+
 /// Classify health score into levels
 fn classify_health(score: f64) -> (String, String) {
     if score >= 80.0 {
@@ -334,4 +392,27 @@ mod tests {
         let (level, _) = classify_health(10.0);
         assert_eq!(level, "Critical");
     }
+
+    #[test]
+    fn test_calculate_snapshot_gap_secs() {
+        let snapshot = |timestamp: i64| OrderBookSnapshot {
+            bids: vec![("100.0".to_string(), "1.0".to_string())],
+            asks: vec![("101.0".to_string(), "1.0".to_string())],
+            update_id: timestamp as u64,
+            timestamp,
+        };
+
+        // Contiguous 1-second captures up to the window end: no gap
+        let contiguous: Vec<OrderBookSnapshot> = (1000..1010).map(snapshot).collect();
+        assert_eq!(calculate_snapshot_gap_secs(&contiguous, 1010), 0.0);
+
+        // 30s outage mid-window plus 5s since the last capture
+        let with_gap = vec![
+            snapshot(1000),
+            snapshot(1001),
+            snapshot(1032),
+            snapshot(1033),
+        ];
+        assert_eq!(calculate_snapshot_gap_secs(&with_gap, 1039), 30.0 + 5.0);
+    }
 }
@@ -0,0 +1,261 @@
+//! Orderbook imbalance time series
+//!
+//! Computes the bid/ask volume ratio at each stored snapshot in a window, so
+//! the trend can be read alongside the instantaneous `imbalance_ratio` from
+//! the orderbook metrics. Long windows are downsampled by averaging
+//! consecutive snapshots to bound the number of returned points.
+
+use super::{
+    quality::{snapshot_shortfall, sufficiency},
+    storage::{query::query_snapshots_in_window, snapshot::OrderBookSnapshot, SnapshotStorage},
+    types::{DataSufficiency, ImbalancePoint, ImbalanceSeries},
+};
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+/// Points returned when the caller doesn't ask for a specific number
+pub const DEFAULT_MAX_POINTS: usize = 60;
+
+/// Upper bound on returned points, whatever the caller asks for
+pub const MAX_POINTS_LIMIT: usize = 500;
+
+/// Clamps a requested point count to `1..=MAX_POINTS_LIMIT`
+pub fn resolve_max_points(requested: Option<u32>) -> usize {
+    requested
+        .map(|n| n as usize)
+        .unwrap_or(DEFAULT_MAX_POINTS)
+        .clamp(1, MAX_POINTS_LIMIT)
+}
+
+/// Calculate the imbalance series for a symbol over a window
+///
+/// Windows below the [`quality`](super::quality) minimums return no points
+/// and zeroed statistics, with a `data_note` explaining the shortfall.
+///
+/// # Parameters
+/// - `storage`: RocksDB snapshot storage
+/// - `symbol`: Trading pair (e.g., "BTCUSDT")
+/// - `window_duration_secs`: Analysis window in seconds
+/// - `max_points`: Most points to return (see [`resolve_max_points`])
+pub async fn calculate_imbalance_series(
+    storage: &SnapshotStorage,
+    symbol: &str,
+    window_duration_secs: u32,
+    max_points: usize,
+) -> Result<ImbalanceSeries> {
+    let end = Utc::now();
+    let start = end - chrono::Duration::seconds(window_duration_secs as i64);
+
+    let snapshots = query_snapshots_in_window(storage, symbol, start.timestamp(), end.timestamp())
+        .await
+        .context("Failed to query snapshots for imbalance series")?;
+
+    Ok(build_series(
+        symbol,
+        window_duration_secs,
+        &snapshots,
+        max_points,
+    ))
+}
+
+/// Bid/ask volume ratio of a snapshot, or `None` when the ask side is empty
+///
+/// Unparseable quantities count as zero, as in the orderbook metrics.
+pub fn snapshot_imbalance(snapshot: &OrderBookSnapshot) -> Option<f64> {
+    let volume = |levels: &[(String, String)]| {
+        levels
+            .iter()
+            .map(|(_, qty)| qty.parse::<f64>().unwrap_or(0.0))
+            .sum::<f64>()
+    };
+
+    let ask_volume = volume(&snapshot.asks);
+    if ask_volume > 0.0 {
+        Some(volume(&snapshot.bids) / ask_volume)
+    } else {
+        None
+    }
+}
+
+/// Reduces a series to at most `max_points` by averaging consecutive buckets
+///
+/// Each bucket keeps the timestamp of its last point, so the final point
+/// always ends at the most recent snapshot.
+pub fn downsample(points: &[ImbalancePoint], max_points: usize) -> Vec<ImbalancePoint> {
+    let max_points = max_points.max(1);
+    if points.len() <= max_points {
+        return points.to_vec();
+    }
+
+    let bucket_size = points.len().div_ceil(max_points);
+    points
+        .chunks(bucket_size)
+        .map(|bucket| ImbalancePoint {
+            ts: bucket[bucket.len() - 1].ts,
+            imbalance: bucket.iter().map(|p| p.imbalance).sum::<f64>() / bucket.len() as f64,
+        })
+        .collect()
+}
+
+/// Builds the series and summary statistics from the window's snapshots
+fn build_series(
+    symbol: &str,
+    window_duration_secs: u32,
+    snapshots: &[OrderBookSnapshot],
+    max_points: usize,
+) -> ImbalanceSeries {
+    let mut points: Vec<ImbalancePoint> = snapshots
+        .iter()
+        .filter_map(|snap| {
+            snapshot_imbalance(snap).map(|imbalance| ImbalancePoint {
+                ts: snap.timestamp,
+                imbalance,
+            })
+        })
+        .collect();
+    points.sort_by_key(|p| p.ts);
+
+    let shortfall = snapshot_shortfall(points.len(), window_duration_secs);
+    if shortfall.is_some() {
+        return ImbalanceSeries {
+            symbol: symbol.to_string(),
+            window_duration_secs,
+            snapshot_count: points.len(),
+            points: Vec::new(),
+            mean: 0.0,
+            min: 0.0,
+            max: 0.0,
+            current: 0.0,
+            data_sufficiency: DataSufficiency::Insufficient,
+            data_note: shortfall,
+        };
+    }
+
+    let values = points.iter().map(|p| p.imbalance);
+    let mean = values.clone().sum::<f64>() / points.len() as f64;
+    let min = values.clone().fold(f64::INFINITY, f64::min);
+    let max = values.fold(f64::NEG_INFINITY, f64::max);
+    let current = points[points.len() - 1].imbalance;
+
+    ImbalanceSeries {
+        symbol: symbol.to_string(),
+        window_duration_secs,
+        snapshot_count: points.len(),
+        points: downsample(&points, max_points),
+        mean,
+        min,
+        max,
+        current,
+        data_sufficiency: sufficiency(&shortfall),
+        data_note: shortfall,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One snapshot per second with bid volume rising from 10 to 10 + n - 1 against 10 on the ask side
+    fn rising_snapshots(n: usize) -> Vec<OrderBookSnapshot> {
+        (0..n)
+            .map(|i| OrderBookSnapshot {
+                bids: vec![
+                    ("100.0".to_string(), (5.0 + i as f64 / 2.0).to_string()),
+                    ("99.9".to_string(), (5.0 + i as f64 / 2.0).to_string()),
+                ],
+                asks: vec![("100.1".to_string(), "10.0".to_string())],
+                update_id: i as u64,
+                timestamp: 1000 + i as i64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_snapshot_imbalance() {
+        let snapshots = rising_snapshots(3);
+        assert_eq!(snapshot_imbalance(&snapshots[0]), Some(1.0));
+        assert_eq!(snapshot_imbalance(&snapshots[2]), Some(1.2));
+
+        let no_asks = OrderBookSnapshot {
+            asks: Vec::new(),
+            ..snapshots[0].clone()
+        };
+        assert_eq!(snapshot_imbalance(&no_asks), None);
+    }
+
+    #[test]
+    fn test_series_follows_rising_trend() {
+        let series = build_series("BTCUSDT", 20, &rising_snapshots(20), 60);
+
+        assert_eq!(series.data_sufficiency, DataSufficiency::Sufficient);
+        assert_eq!(series.snapshot_count, 20);
+        assert_eq!(series.points.len(), 20);
+        assert!(series
+            .points
+            .windows(2)
+            .all(|w| w[1].imbalance > w[0].imbalance && w[1].ts > w[0].ts));
+        assert_eq!(series.min, 1.0);
+        assert!((series.max - 2.9).abs() < 1e-9);
+        assert!((series.current - 2.9).abs() < 1e-9);
+        assert!((series.mean - 1.95).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_series_downsampled_to_max_points() {
+        let series = build_series("BTCUSDT", 100, &rising_snapshots(100), 10);
+
+        assert_eq!(series.snapshot_count, 100);
+        assert_eq!(series.points.len(), 10);
+        assert_eq!(series.points[9].ts, 1099);
+        assert!(series
+            .points
+            .windows(2)
+            .all(|w| w[1].imbalance > w[0].imbalance));
+        // Stats come from every snapshot, not the averaged buckets
+        assert!((series.current - 10.9).abs() < 1e-9);
+        assert!(series.max > series.points[9].imbalance);
+    }
+
+    #[test]
+    fn test_downsample_averages_buckets() {
+        let points: Vec<ImbalancePoint> = (0..5)
+            .map(|i| ImbalancePoint {
+                ts: i,
+                imbalance: i as f64,
+            })
+            .collect();
+
+        assert_eq!(downsample(&points, 10), points);
+        assert_eq!(
+            downsample(&points, 2),
+            vec![
+                ImbalancePoint {
+                    ts: 2,
+                    imbalance: 1.0
+                },
+                ImbalancePoint {
+                    ts: 4,
+                    imbalance: 3.5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sparse_window_is_insufficient() {
+        let series = build_series("BTCUSDT", 300, &rising_snapshots(5), 60);
+
+        assert_eq!(series.data_sufficiency, DataSufficiency::Insufficient);
+        assert!(series.data_note.is_some());
+        assert!(series.points.is_empty());
+        assert_eq!(series.current, 0.0);
+    }
+
+    #[test]
+    fn test_resolve_max_points() {
+        assert_eq!(resolve_max_points(None), DEFAULT_MAX_POINTS);
+        assert_eq!(resolve_max_points(Some(0)), 1);
+        assert_eq!(resolve_max_points(Some(25)), 25);
+        assert_eq!(resolve_max_points(Some(10_000)), MAX_POINTS_LIMIT);
+    }
+}
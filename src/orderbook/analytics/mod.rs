@@ -4,6 +4,8 @@
 //! - Order flow analysis (bid/ask pressure dynamics)
 //! - Volume profile generation (POC, VAH, VAL support/resistance zones)
 //! - Anomaly detection (quote stuffing, iceberg orders, flash crash precursors)
+//! - Imbalance series (bid/ask volume ratio over stored snapshots)
+//! - Replay mode (analytics over a fixed recorded range, for testing)
 //!
 //! **Feature Gate**: `orderbook_analytics` (extends `orderbook` feature)
 //!
@@ -27,7 +29,10 @@ pub use types::*;
 pub mod anomaly;
 pub mod flow;
 pub mod health;
+pub mod imbalance;
 pub mod profile;
+pub mod quality;
+pub mod replay;
 pub mod storage;
 pub mod tools;
 pub mod trade_stream;
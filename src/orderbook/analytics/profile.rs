@@ -4,8 +4,9 @@
 //! VAH (Value Area High), VAL (Value Area Low) for support/resistance identification.
 
 use super::{
+    quality::trade_shortfall,
     trade_stream::{connect_trade_stream, AggTrade},
-    types::{VolumeBin, VolumeProfile},
+    types::{DataSufficiency, VolumeBin, VolumeProfile},
 };
 use anyhow::{Context, Result};
 use chrono::Utc;
@@ -24,7 +25,9 @@ use std::str::FromStr;
 /// - `tick_size`: Price tick size for adaptive binning (e.g., 0.01 for BTCUSDT)
 ///
 /// # Returns
-/// VolumeProfile with histogram, POC, VAH (70% volume upper bound), VAL (70% volume lower bound)
+/// VolumeProfile with histogram, POC, VAH (70% volume upper bound), VAL (70% volume lower bound).
+/// With fewer than [`MIN_TRADES`](super::quality::MIN_TRADES) trades the profile is empty and
+/// flagged `data_sufficiency: "insufficient"`.
 ///
 /// # Example
 /// ```no_run
@@ -70,8 +73,24 @@ pub async fn generate_volume_profile(
     // Abort background task
     handle.abort();
 
-    if trades.is_empty() {
-        return Err(anyhow::anyhow!("No trades received for {}", symbol));
+    if let Some(note) = trade_shortfall(trades.len()) {
+        // Too few trades to locate POC/VAH/VAL: flag it instead of binning noise
+        return Ok(VolumeProfile {
+            symbol: symbol.to_string(),
+            time_period_start: start_time,
+            time_period_end: end_time,
+            price_range_low: Decimal::ZERO,
+            price_range_high: Decimal::ZERO,
+            bin_size: Decimal::ZERO,
+            bin_count: 0,
+            histogram: Vec::new(),
+            total_volume: Decimal::ZERO,
+            point_of_control: Decimal::ZERO,
+            value_area_high: Decimal::ZERO,
+            value_area_low: Decimal::ZERO,
+            data_sufficiency: DataSufficiency::Insufficient,
+            data_note: Some(note),
+        });
     }
 
     // Determine price range from trades
@@ -104,6 +123,8 @@ pub async fn generate_volume_profile(
         point_of_control,
         value_area_high,
         value_area_low,
+        data_sufficiency: DataSufficiency::Sufficient,
+        data_note: None,
     })
 }
 
@@ -0,0 +1,95 @@
+//! Minimum data requirements for analytics results
+//!
+//! Snapshots are captured once per second, so a window of `N` seconds should
+//! hold about `N` snapshots. Below [`MIN_SNAPSHOTS`] or [`MIN_WINDOW_COVERAGE`]
+//! the flow, health and anomaly numbers are statistically meaningless, and the
+//! entry points return a result flagged [`DataSufficiency::Insufficient`]
+//! instead. Volume profiles need at least [`MIN_TRADES`] trades.
+
+use super::types::DataSufficiency;
+
+/// Fewest snapshots in a window for a meaningful result
+pub const MIN_SNAPSHOTS: usize = 10;
+
+/// Smallest share of the window (0.0-1.0) that must be covered by snapshots
+pub const MIN_WINDOW_COVERAGE: f64 = 0.5;
+
+/// Fewest trades for a meaningful volume profile
+pub const MIN_TRADES: usize = 20;
+
+/// Checks that a snapshot window is dense enough to analyze
+///
+/// Returns `None` when sufficient, otherwise a note explaining the shortfall.
+pub fn snapshot_shortfall(snapshot_count: usize, window_duration_secs: u32) -> Option<String> {
+    let window = window_duration_secs.max(1) as f64;
+    let coverage = (snapshot_count as f64 / window).min(1.0);
+
+    if snapshot_count >= MIN_SNAPSHOTS && coverage >= MIN_WINDOW_COVERAGE {
+        return None;
+    }
+
+    Some(format!(
+        "Not enough data: {} snapshots cover {:.0}% of the {}s window \
+         (need at least {} snapshots and {:.0}% coverage).",
+        snapshot_count,
+        coverage * 100.0,
+        window_duration_secs,
+        MIN_SNAPSHOTS,
+        MIN_WINDOW_COVERAGE * 100.0
+    ))
+}
+
+/// Checks that enough trades were collected for a volume profile
+///
+/// Returns `None` when sufficient, otherwise a note explaining the shortfall.
+pub fn trade_shortfall(trade_count: usize) -> Option<String> {
+    if trade_count >= MIN_TRADES {
+        return None;
+    }
+
+    Some(format!(
+        "Not enough data: only {} trades collected (need at least {}).",
+        trade_count, MIN_TRADES
+    ))
+}
+
+/// Maps a shortfall note to the sufficiency flag
+pub fn sufficiency(shortfall: &Option<String>) -> DataSufficiency {
+    if shortfall.is_some() {
+        DataSufficiency::Insufficient
+    } else {
+        DataSufficiency::Sufficient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_snapshots_are_insufficient() {
+        // A handful of snapshots in a 60s window
+        let note = snapshot_shortfall(4, 60).unwrap();
+        assert!(note.contains("4 snapshots"));
+        assert_eq!(sufficiency(&Some(note)), DataSufficiency::Insufficient);
+
+        // Enough snapshots but only a quarter of a 300s window
+        assert!(snapshot_shortfall(75, 300).is_some());
+
+        // Empty windows are insufficient too
+        assert!(snapshot_shortfall(0, 60).is_some());
+    }
+
+    #[test]
+    fn test_dense_snapshots_are_sufficient() {
+        assert_eq!(snapshot_shortfall(60, 60), None);
+        assert_eq!(snapshot_shortfall(150, 300), None);
+        assert_eq!(sufficiency(&None), DataSufficiency::Sufficient);
+    }
+
+    #[test]
+    fn test_trade_shortfall() {
+        assert!(trade_shortfall(3).unwrap().contains("3 trades"));
+        assert_eq!(trade_shortfall(MIN_TRADES), None);
+    }
+}
@@ -0,0 +1,127 @@
+//! Replay mode - analytics over a fixed stored range
+//!
+//! By default `calculate_order_flow`, `detect_anomalies` and
+//! `calculate_health_score` analyze the trailing window ending now. With
+//! `ANALYTICS_REPLAY=<from>..<to>` (Unix seconds, inclusive) they read that
+//! recorded range instead, so a detection can be reproduced against stored
+//! snapshots and the pipeline can be tested without live data. The range
+//! replaces the caller's window: its length becomes the window duration.
+//!
+//! This is a testing aid and is not exposed through any tool.
+
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+/// Recorded range the analytics read in replay mode (Unix seconds, inclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayRange {
+    /// First second of the range
+    pub from: i64,
+    /// Last second of the range
+    pub to: i64,
+}
+
+/// Time window an analytics entry point reads snapshots from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalysisWindow {
+    /// Window start
+    pub start: DateTime<Utc>,
+    /// Window end
+    pub end: DateTime<Utc>,
+    /// Window length in seconds
+    pub duration_secs: u32,
+}
+
+impl AnalysisWindow {
+    /// Window of `duration_secs` ending at `end`
+    pub fn ending_at(end: DateTime<Utc>, duration_secs: u32) -> Self {
+        Self {
+            start: end - Duration::seconds(duration_secs as i64),
+            end,
+            duration_secs,
+        }
+    }
+}
+
+/// Parses `ANALYTICS_REPLAY` (`<from>..<to>`, Unix seconds)
+///
+/// Returns `None` when unset or malformed, or when `to` is before `from`.
+pub fn parse_replay_range(value: Option<&str>) -> Option<ReplayRange> {
+    let (from, to) = value?.trim().split_once("..")?;
+    let from = from.trim().parse::<i64>().ok()?;
+    let to = to.trim().parse::<i64>().ok()?;
+    (from <= to).then_some(ReplayRange { from, to })
+}
+
+/// Process-wide replay range, seeded from `ANALYTICS_REPLAY`
+static REPLAY_RANGE: Lazy<RwLock<Option<ReplayRange>>> = Lazy::new(|| {
+    RwLock::new(parse_replay_range(
+        std::env::var("ANALYTICS_REPLAY").ok().as_deref(),
+    ))
+});
+
+/// Returns the active replay range, if replay mode is on
+pub fn replay_range() -> Option<ReplayRange> {
+    *REPLAY_RANGE.read().expect("Replay range lock poisoned")
+}
+
+/// Turns replay mode on for `range`, or off with `None`
+///
+/// Overrides `ANALYTICS_REPLAY`; meant for tests.
+#[doc(hidden)]
+pub fn set_replay_range(range: Option<ReplayRange>) {
+    *REPLAY_RANGE.write().expect("Replay range lock poisoned") = range;
+}
+
+/// Resolves the window to analyze
+///
+/// The replay range when replay mode is on, otherwise the trailing
+/// `window_duration_secs` ending now.
+pub fn analysis_window(window_duration_secs: u32) -> AnalysisWindow {
+    match replay_range() {
+        Some(range) => window_for_range(range),
+        None => AnalysisWindow::ending_at(Utc::now(), window_duration_secs),
+    }
+}
+
+/// Window covering a replay range
+fn window_for_range(range: ReplayRange) -> AnalysisWindow {
+    let at = |secs: i64| DateTime::from_timestamp(secs, 0).unwrap_or_default();
+    AnalysisWindow {
+        start: at(range.from),
+        end: at(range.to),
+        duration_secs: u32::try_from(range.to - range.from).unwrap_or(u32::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_replay_range() {
+        assert_eq!(
+            parse_replay_range(Some("1737158400..1737158460")),
+            Some(ReplayRange {
+                from: 1737158400,
+                to: 1737158460
+            })
+        );
+        assert_eq!(parse_replay_range(Some("1737158460..1737158400")), None);
+        assert_eq!(parse_replay_range(Some("1737158400")), None);
+        assert_eq!(parse_replay_range(Some("yesterday..today")), None);
+        assert_eq!(parse_replay_range(None), None);
+    }
+
+    #[test]
+    fn test_window_for_range() {
+        let window = window_for_range(ReplayRange {
+            from: 1737158400,
+            to: 1737158460,
+        });
+        assert_eq!(window.start.timestamp(), 1737158400);
+        assert_eq!(window.end.timestamp(), 1737158460);
+        assert_eq!(window.duration_secs, 60);
+    }
+}
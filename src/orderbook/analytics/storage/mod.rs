@@ -6,14 +6,30 @@
 //! - **Retention**: 7 days (background cleanup task deletes keys older than 7 days)
 //! - **Compression**: Zstd for ~500MB-1GB storage (12M snapshots for 20 pairs)
 //! - **Query pattern**: Prefix scan for time-range queries (<200ms target)
+//! - **Durability**: Memtables and the WAL are flushed every
+//!   `ORDERBOOK_FLUSH_INTERVAL_SECS` (default 30, `0` disables) and on graceful
+//!   shutdown, so a crash loses at most one interval of snapshots
 
 pub mod query;
 pub mod snapshot;
 
 use anyhow::{Context, Result};
-use rocksdb::{Options, WriteBatch, DB};
+use rocksdb::{Direction, IteratorMode, Options, WriteBatch, DB};
+use snapshot::OrderBookSnapshot;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Default interval between periodic flushes
+pub const DEFAULT_FLUSH_INTERVAL_SECS: u64 = 30;
+
+/// Parses `ORDERBOOK_FLUSH_INTERVAL_SECS` (`0` disables periodic flushes)
+pub fn parse_flush_interval(value: Option<&str>) -> Duration {
+    let secs = value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_FLUSH_INTERVAL_SECS);
+    Duration::from_secs(secs)
+}
 
 /// RocksDB storage handle for orderbook snapshots
 #[derive(Clone)]
@@ -85,6 +101,60 @@ impl SnapshotStorage {
         .await?
     }
 
+    /// Iterate stored snapshots for `symbol` from `from` to `to` (Unix seconds, inclusive)
+    ///
+    /// Yields `(timestamp_sec, snapshot)` in key order, which is timestamp
+    /// order. Reads RocksDB synchronously, so call it from blocking code
+    /// (`spawn_blocking`) inside the async runtime.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use mcp_binance_server::orderbook::analytics::storage::SnapshotStorage;
+    /// # fn example(storage: &SnapshotStorage) -> anyhow::Result<()> {
+    /// for item in storage.replay("BTCUSDT", 1737158400, 1737158460) {
+    ///     let (timestamp, snapshot) = item?;
+    ///     println!("{}: {} bid levels", timestamp, snapshot.bids.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replay(
+        &self,
+        symbol: &str,
+        from: i64,
+        to: i64,
+    ) -> impl Iterator<Item = Result<(i64, OrderBookSnapshot)>> + '_ {
+        // Prefix scan: iterate all keys starting with "{symbol}:"
+        let prefix = format!("{}:", symbol);
+        let iter = self
+            .db
+            .iterator(IteratorMode::From(prefix.as_bytes(), Direction::Forward));
+
+        iter.map_while(move |item| {
+            let (key, value) = match item.context("Failed to read snapshot from RocksDB") {
+                Ok(entry) => entry,
+                Err(e) => return Some(Some(Err(e))),
+            };
+            let key_str = String::from_utf8_lossy(&key);
+
+            // Moved past our symbol, stop iteration
+            let timestamp_str = key_str.strip_prefix(prefix.as_str())?;
+
+            match timestamp_str.parse::<i64>() {
+                Err(_) => Some(None),
+                // Passed the end of the range
+                Ok(timestamp) if timestamp > to => None,
+                Ok(timestamp) if timestamp < from => Some(None),
+                Ok(timestamp) => Some(Some(
+                    OrderBookSnapshot::from_bytes(&value)
+                        .context("Failed to deserialize snapshot")
+                        .map(|snapshot| (timestamp, snapshot)),
+                )),
+            }
+        })
+        .flatten()
+    }
+
     /// Delete keys older than retention period (7 days)
     ///
     /// Called by background cleanup task (hourly).
@@ -124,9 +194,37 @@ impl SnapshotStorage {
         .await?
     }
 
-    /// Get database handle for advanced queries (prefix scans)
-    pub(crate) fn db(&self) -> &Arc<DB> {
-        &self.db
+    /// Force buffered writes to disk
+    ///
+    /// Syncs the write-ahead log, then flushes memtables to SST files so the
+    /// snapshots survive a crash or restart.
+    pub async fn flush(&self) -> Result<()> {
+        let db = self.db.clone();
+
+        tokio::task::spawn_blocking(move || {
+            db.flush_wal(true)
+                .context("Failed to sync RocksDB write-ahead log")?;
+            db.flush().context("Failed to flush RocksDB memtables")
+        })
+        .await?
+    }
+
+    /// Starts flushing every `interval` in the background
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn_flush_task(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let storage = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick completes immediately
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = storage.flush().await {
+                    tracing::warn!(error = %e, "Periodic snapshot storage flush failed");
+                }
+            }
+        })
     }
 }
 
@@ -149,6 +247,74 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_flushed_snapshots_survive_reopen() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        {
+            let storage = SnapshotStorage::new(temp_dir.path())?;
+            storage.put("BTCUSDT", 1737158400, b"first").await?;
+            storage.put("BTCUSDT", 1737158401, b"second").await?;
+            storage.flush().await?;
+        }
+
+        // Reopening releases and re-acquires the DB lock
+        let reopened = SnapshotStorage::new(temp_dir.path())?;
+        assert_eq!(
+            reopened.get("BTCUSDT", 1737158400).await?.as_deref(),
+            Some(b"first".as_ref())
+        );
+        assert_eq!(
+            reopened.get("BTCUSDT", 1737158401).await?.as_deref(),
+            Some(b"second".as_ref())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replay_yields_range_in_order() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let storage = SnapshotStorage::new(temp_dir.path())?;
+
+        for timestamp in 1737158400..1737158405 {
+            let snapshot = OrderBookSnapshot {
+                bids: vec![("100.0".to_string(), "1.0".to_string())],
+                asks: vec![("101.0".to_string(), "1.0".to_string())],
+                update_id: timestamp as u64,
+                timestamp,
+            };
+            storage
+                .put("BTCUSDT", timestamp, &snapshot.to_bytes()?)
+                .await?;
+        }
+        storage.put("ETHUSDT", 1737158401, b"other_symbol").await?;
+
+        let replayed = storage
+            .replay("BTCUSDT", 1737158401, 1737158403)
+            .collect::<Result<Vec<_>>>()?;
+        let timestamps: Vec<i64> = replayed.iter().map(|(ts, _)| *ts).collect();
+        assert_eq!(timestamps, vec![1737158401, 1737158402, 1737158403]);
+        assert_eq!(replayed[0].1.update_id, 1737158401);
+
+        assert_eq!(storage.replay("BTCUSDT", 2000000000, 2000000010).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_flush_interval() {
+        assert_eq!(parse_flush_interval(Some("5")), Duration::from_secs(5));
+        assert_eq!(parse_flush_interval(Some("0")), Duration::ZERO);
+        assert_eq!(
+            parse_flush_interval(Some("soon")),
+            Duration::from_secs(DEFAULT_FLUSH_INTERVAL_SECS)
+        );
+        assert_eq!(
+            parse_flush_interval(None),
+            Duration::from_secs(DEFAULT_FLUSH_INTERVAL_SECS)
+        );
+    }
+
     #[tokio::test]
     async fn test_cleanup_old_snapshots() -> Result<()> {
         let temp_dir = TempDir::new()?;
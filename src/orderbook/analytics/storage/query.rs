@@ -4,12 +4,11 @@
 //! Target latency: <200ms for typical 60-second window queries.
 
 use super::{snapshot::OrderBookSnapshot, SnapshotStorage};
-use anyhow::{Context, Result};
-use rocksdb::IteratorMode;
+use anyhow::Result;
 
 /// Query snapshots within a time range for a symbol
 ///
-/// Uses RocksDB prefix scan with key format `{symbol}:{timestamp}`.
+/// Uses [`SnapshotStorage::replay`], a RocksDB prefix scan over keys `{symbol}:{timestamp}`.
 /// Target performance: <200ms for 60-second window (60 snapshots).
 ///
 /// # Example
@@ -30,44 +29,14 @@ pub async fn query_snapshots_in_window(
     end_timestamp_sec: i64,
 ) -> Result<Vec<OrderBookSnapshot>> {
     let symbol_owned = symbol.to_string();
-    let db = storage.db().clone();
+    let storage = storage.clone();
 
     // Spawn blocking to avoid blocking async runtime
     tokio::task::spawn_blocking(move || {
-        let mut snapshots = Vec::new();
-
-        // Prefix scan: iterate all keys starting with "{symbol}:"
-        let prefix = format!("{}:", symbol_owned);
-        let mode = IteratorMode::From(prefix.as_bytes(), rocksdb::Direction::Forward);
-
-        for item in db.iterator(mode) {
-            let (key, value) = item?;
-            let key_str = String::from_utf8_lossy(&key);
-
-            // Check if key still matches symbol prefix
-            if !key_str.starts_with(&prefix) {
-                break; // Moved past our symbol, stop iteration
-            }
-
-            // Parse timestamp from key "{symbol}:{timestamp}"
-            if let Some(timestamp_str) = key_str.split(':').nth(1) {
-                if let Ok(timestamp) = timestamp_str.parse::<i64>() {
-                    // Filter by time range
-                    if timestamp >= start_timestamp_sec && timestamp <= end_timestamp_sec {
-                        let snapshot = OrderBookSnapshot::from_bytes(&value)
-                            .context("Failed to deserialize snapshot")?;
-                        snapshots.push(snapshot);
-                    }
-
-                    // Optimization: stop if we've passed end timestamp
-                    if timestamp > end_timestamp_sec {
-                        break;
-                    }
-                }
-            }
-        }
-
-        Ok(snapshots)
+        storage
+            .replay(&symbol_owned, start_timestamp_sec, end_timestamp_sec)
+            .map(|item| item.map(|(_, snapshot)| snapshot))
+            .collect()
     })
     .await?
 }
@@ -4,12 +4,18 @@
 //! and anomaly detection features.
 
 use super::{
-    anomaly::detect_anomalies, flow::calculate_order_flow, health::calculate_health_score,
-    profile::generate_volume_profile, storage::SnapshotStorage, types::LiquidityVacuum,
+    anomaly::detect_anomalies,
+    flow::calculate_order_flow,
+    health::calculate_health_score,
+    imbalance::{calculate_imbalance_series, resolve_max_points},
+    profile::generate_volume_profile,
+    storage::SnapshotStorage,
+    types::LiquidityVacuum,
 };
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{CallToolResult, Content};
 use rmcp::{tool, ErrorData};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -77,12 +83,27 @@ pub struct GetMicrostructureHealthInput {
     pub window_duration_secs: Option<u32>,
 }
 
+/// Input parameters for get_imbalance_series tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetImbalanceSeriesInput {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    pub symbol: String,
+
+    /// Analysis window duration in seconds (default: 300)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_duration_secs: Option<u32>,
+
+    /// Most points to return; longer windows are downsampled (default: 60, max: 500)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_points: Option<u32>,
+}
+
 /// Get Order Flow Analysis (T022, FR-001 to FR-006)
 ///
 /// Analyzes bid/ask pressure and flow direction over a time window.
 /// Returns flow rates, net flow, direction classification, and cumulative delta.
 #[tool(
-    description = "Analyze order flow direction and bid/ask pressure over time window. Returns flow rates, net flow, direction classification, and cumulative delta."
+    description = "Analyze order flow direction and bid/ask pressure over time window. Returns flow rates, net flow, direction classification, and cumulative delta. Sparse windows return data_sufficiency \"insufficient\" with a data_note instead of a direction."
 )]
 pub async fn get_order_flow(
     params: Parameters<GetOrderFlowInput>,
@@ -107,7 +128,7 @@ pub async fn get_order_flow(
 /// Generates volume distribution histogram with POC/VAH/VAL for support/resistance identification.
 /// Returns POC (Point of Control), VAH/VAL (Value Area High/Low) for identifying support/resistance.
 #[tool(
-    description = "Generate volume profile histogram showing volume distribution across price levels. Returns POC (Point of Control), VAH/VAL (Value Area High/Low) for support/resistance identification."
+    description = "Generate volume profile histogram showing volume distribution across price levels. Returns POC (Point of Control), VAH/VAL (Value Area High/Low) for support/resistance identification. Too few trades return data_sufficiency \"insufficient\" with a data_note and an empty histogram."
 )]
 pub async fn get_volume_profile(
     params: Parameters<GetVolumeProfileInput>,
@@ -134,7 +155,7 @@ pub async fn get_volume_profile(
 /// iceberg orders (hidden institutional orders), and flash crash risk (extreme liquidity deterioration).
 /// Returns anomalies with severity levels and recommended actions.
 #[tool(
-    description = "Detect market microstructure anomalies including quote stuffing (HFT manipulation), iceberg orders (hidden institutional orders), and flash crash risk (extreme liquidity deterioration). Returns anomalies with severity levels and recommended actions."
+    description = "Detect market microstructure anomalies including quote stuffing (HFT manipulation), iceberg orders (hidden institutional orders), and flash crash risk (extreme liquidity deterioration). Returns {anomalies, data_sufficiency, data_note}; an \"insufficient\" window means no verdict, not an all-clear."
 )]
 pub async fn detect_market_anomalies(
     params: Parameters<DetectMarketAnomaliesInput>,
@@ -142,11 +163,11 @@ pub async fn detect_market_anomalies(
 ) -> Result<CallToolResult, ErrorData> {
     let window_duration = params.0.window_duration_secs.unwrap_or(60);
 
-    let anomalies = detect_anomalies(&storage, &params.0.symbol, window_duration)
+    let report = detect_anomalies(&storage, &params.0.symbol, window_duration)
         .await
         .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
 
-    let response_json = serde_json::to_value(&anomalies)
+    let response_json = serde_json::to_value(&report)
         .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
     Ok(CallToolResult::success(vec![Content::text(
@@ -206,23 +227,24 @@ pub async fn get_liquidity_vacuums(
             let price_range_low = profile.histogram[start_idx].price_level;
             let price_range_high = bin.price_level;
 
-            let avg_volume_in_range = profile.histogram[start_idx..idx]
+            // Binance quantities carry at most 8 decimals; the division otherwise
+            // yields 28 digits of repeating fraction
+            let avg_volume_in_range = (profile.histogram[start_idx..idx]
                 .iter()
                 .map(|b| b.volume)
                 .sum::<Decimal>()
-                / Decimal::from(idx - start_idx);
-
-            let volume_deficit_pct_decimal = ((median_volume - avg_volume_in_range)
-                / median_volume)
-                * Decimal::from_str("100.0").map_err(|e| {
-                    ErrorData::internal_error(format!("Decimal conversion error: {}", e), None)
+                / Decimal::from(idx - start_idx))
+            .round_dp(8);
+
+            // Round in Decimal and convert directly, so the JSON number has no float noise
+            let volume_deficit_pct = (((median_volume - avg_volume_in_range) / median_volume)
+                * Decimal::ONE_HUNDRED)
+                .round_dp(2)
+                .to_f64()
+                .ok_or_else(|| {
+                    ErrorData::internal_error("Volume deficit out of range".to_string(), None)
                 })?;
 
-            let volume_deficit_pct = volume_deficit_pct_decimal
-                .to_string()
-                .parse::<f64>()
-                .map_err(|e| ErrorData::internal_error(format!("Parse error: {}", e), None))?;
-
             let expected_impact = if volume_deficit_pct > 80.0 {
                 super::types::ImpactLevel::FastMovement
             } else if volume_deficit_pct > 50.0 {
@@ -261,7 +283,7 @@ pub async fn get_liquidity_vacuums(
 /// flow balance, and update rate. Returns overall score, component breakdown, health level,
 /// and recommended actions.
 #[tool(
-    description = "Calculate market microstructure health score (0-100) combining spread stability, liquidity depth, flow balance, and update rate. Returns overall score, component breakdown, health level, and recommended actions."
+    description = "Calculate market microstructure health score (0-100) combining spread stability, liquidity depth, flow balance, and update rate. Returns overall score, component breakdown, data_gap_secs (seconds of the window with no market data; the score is discounted accordingly), health level, and recommended actions. Sparse windows are not scored: data_sufficiency is \"insufficient\" and health_level is \"Insufficient Data\"."
 )]
 pub async fn get_microstructure_health(
     params: Parameters<GetMicrostructureHealthInput>,
@@ -280,3 +302,30 @@ pub async fn get_microstructure_health(
         response_json.to_string(),
     )]))
 }
+
+/// Get Orderbook Imbalance Series
+///
+/// Bid/ask volume ratio at each stored snapshot over a time window, downsampled to at
+/// most `max_points` points, with mean/min/max/current statistics over every snapshot.
+#[tool(
+    description = "Track orderbook imbalance (bid_volume / ask_volume, >1 = more buy pressure) over a time window of stored snapshots. Returns a downsampled series [{ts, imbalance}] (at most max_points, default 60) plus mean, min, max and current imbalance. Sparse windows return data_sufficiency \"insufficient\" with a data_note and no points."
+)]
+pub async fn get_imbalance_series(
+    params: Parameters<GetImbalanceSeriesInput>,
+    storage: Arc<SnapshotStorage>,
+) -> Result<CallToolResult, ErrorData> {
+    let window_duration = params.0.window_duration_secs.unwrap_or(300);
+    let max_points = resolve_max_points(params.0.max_points);
+
+    let series =
+        calculate_imbalance_series(&storage, &params.0.symbol, window_duration, max_points)
+            .await
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+
+    let response_json = serde_json::to_value(&series)
+        .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        response_json.to_string(),
+    )]))
+}
@@ -2,10 +2,17 @@
 //!
 //! Connects to Binance aggregate trade stream (wss://stream.binance.com:9443/ws/<symbol>@aggTrade)
 //! with exponential backoff reconnection (1s, 2s, 4s, 8s, max 60s).
+//!
+//! The time of the last delivered trade is tracked per symbol. When the stream
+//! resumes after a disconnect, the silent period is logged and recorded in
+//! [`stream_gaps`] so health scoring can discount windows with missing data.
 
 use anyhow::{Context, Result};
 use futures_util::StreamExt;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use tokio::time::{sleep, Duration};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
@@ -53,6 +60,136 @@ pub struct AggTrade {
     pub is_buyer_maker: bool,
 }
 
+/// Recorded gaps kept per symbol (oldest are dropped first)
+const MAX_GAPS_PER_SYMBOL: usize = 100;
+
+/// A period with no trades delivered because the upstream stream was down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataGap {
+    /// Time of the last trade before the disconnect (milliseconds)
+    pub start_ms: u64,
+    /// Time of the first trade after the reconnect (milliseconds)
+    pub end_ms: u64,
+}
+
+impl DataGap {
+    /// Gap length in seconds
+    pub fn duration_secs(&self) -> f64 {
+        self.end_ms.saturating_sub(self.start_ms) as f64 / 1000.0
+    }
+
+    /// Seconds of this gap that fall inside `[window_start_ms, window_end_ms]`
+    fn overlap_secs(&self, window_start_ms: u64, window_end_ms: u64) -> f64 {
+        let start = self.start_ms.max(window_start_ms);
+        let end = self.end_ms.min(window_end_ms);
+        end.saturating_sub(start) as f64 / 1000.0
+    }
+}
+
+/// Per-symbol stream state
+#[derive(Debug, Default)]
+struct SymbolStream {
+    /// Trade time of the last trade handed to the receiver
+    last_trade_ms: Option<u64>,
+    /// Set after a disconnect until the next trade arrives
+    disconnected: bool,
+    /// Completed gaps, oldest first
+    gaps: VecDeque<DataGap>,
+}
+
+/// Tracks upstream disconnects of @aggTrade streams per symbol
+#[derive(Debug, Default)]
+pub struct StreamGaps {
+    symbols: Mutex<HashMap<String, SymbolStream>>,
+}
+
+/// Process-wide gap registry fed by [`connect_trade_stream`]
+static STREAM_GAPS: Lazy<Arc<StreamGaps>> = Lazy::new(|| Arc::new(StreamGaps::default()));
+
+/// Returns the process-wide gap registry
+pub fn stream_gaps() -> Arc<StreamGaps> {
+    STREAM_GAPS.clone()
+}
+
+impl StreamGaps {
+    /// Records a delivered trade
+    ///
+    /// Returns the gap closed by this trade when it is the first one after a disconnect.
+    pub fn record_trade(&self, symbol: &str, trade_time_ms: u64) -> Option<DataGap> {
+        let mut symbols = self.symbols.lock().expect("Stream gap lock poisoned");
+        let stream = symbols.entry(symbol.to_uppercase()).or_default();
+
+        let gap = match stream.last_trade_ms {
+            Some(last) if stream.disconnected && trade_time_ms > last => Some(DataGap {
+                start_ms: last,
+                end_ms: trade_time_ms,
+            }),
+            _ => None,
+        };
+
+        if let Some(gap) = gap {
+            if stream.gaps.len() == MAX_GAPS_PER_SYMBOL {
+                stream.gaps.pop_front();
+            }
+            stream.gaps.push_back(gap);
+        }
+
+        stream.disconnected = false;
+        stream.last_trade_ms = Some(
+            stream
+                .last_trade_ms
+                .map_or(trade_time_ms, |last| last.max(trade_time_ms)),
+        );
+        gap
+    }
+
+    /// Marks the stream for `symbol` as disconnected
+    pub fn record_disconnect(&self, symbol: &str) {
+        let mut symbols = self.symbols.lock().expect("Stream gap lock poisoned");
+        symbols
+            .entry(symbol.to_uppercase())
+            .or_default()
+            .disconnected = true;
+    }
+
+    /// Trade time of the last delivered trade for `symbol`
+    pub fn last_trade_ms(&self, symbol: &str) -> Option<u64> {
+        let symbols = self.symbols.lock().expect("Stream gap lock poisoned");
+        symbols.get(&symbol.to_uppercase())?.last_trade_ms
+    }
+
+    /// Seconds without stream data inside `[window_start_ms, window_end_ms]`
+    ///
+    /// Includes the still-open gap when the stream is currently disconnected.
+    pub fn gap_secs_in_window(
+        &self,
+        symbol: &str,
+        window_start_ms: u64,
+        window_end_ms: u64,
+    ) -> f64 {
+        let symbols = self.symbols.lock().expect("Stream gap lock poisoned");
+        let Some(stream) = symbols.get(&symbol.to_uppercase()) else {
+            return 0.0;
+        };
+
+        let mut total: f64 = stream
+            .gaps
+            .iter()
+            .map(|gap| gap.overlap_secs(window_start_ms, window_end_ms))
+            .sum();
+
+        if let (true, Some(last)) = (stream.disconnected, stream.last_trade_ms) {
+            total += DataGap {
+                start_ms: last,
+                end_ms: window_end_ms,
+            }
+            .overlap_secs(window_start_ms, window_end_ms);
+        }
+
+        total
+    }
+}
+
 /// Connect to Binance @aggTrade WebSocket stream with exponential backoff (T026-T027)
 ///
 /// Implements reconnection logic:
@@ -60,6 +197,8 @@ pub struct AggTrade {
 /// - Exponential backoff: 2x each retry (2s, 4s, 8s, 16s...)
 /// - Maximum delay: 60 seconds
 ///
+/// Disconnects and the resulting data gaps are recorded in [`stream_gaps`].
+///
 /// # Example
 /// ```no_run
 /// # use mcp_binance_server::orderbook::analytics::trade_stream::*;
@@ -81,6 +220,22 @@ pub async fn connect_trade_stream(
     let symbol_lower = symbol.to_lowercase();
     let url = format!("wss://stream.binance.com:9443/ws/{}@aggTrade", symbol_lower);
 
+    Ok(spawn_trade_stream(
+        url,
+        symbol.to_uppercase(),
+        stream_gaps(),
+    ))
+}
+
+/// Internal: Run the reconnect loop for one stream URL, recording gaps in `gaps`
+fn spawn_trade_stream(
+    url: String,
+    symbol: String,
+    gaps: Arc<StreamGaps>,
+) -> (
+    tokio::sync::mpsc::Receiver<AggTrade>,
+    tokio::task::JoinHandle<()>,
+) {
     let (tx, rx) = tokio::sync::mpsc::channel(1000);
 
     let handle = tokio::spawn(async move {
@@ -88,7 +243,7 @@ pub async fn connect_trade_stream(
         let max_delay = Duration::from_secs(60);
 
         loop {
-            match connect_and_stream(&url, tx.clone()).await {
+            match connect_and_stream(&url, &symbol, &gaps, tx.clone()).await {
                 Ok(_) => {
                     tracing::info!("@aggTrade stream disconnected gracefully");
                     retry_delay = Duration::from_secs(1); // Reset on clean disconnect
@@ -102,6 +257,11 @@ pub async fn connect_trade_stream(
                 }
             }
 
+            if tx.is_closed() {
+                break;
+            }
+            gaps.record_disconnect(&symbol);
+
             sleep(retry_delay).await;
 
             // Exponential backoff with max cap
@@ -109,11 +269,16 @@ pub async fn connect_trade_stream(
         }
     });
 
-    Ok((rx, handle))
+    (rx, handle)
 }
 
 /// Internal: Connect and stream trades until error or disconnect
-async fn connect_and_stream(url: &str, tx: tokio::sync::mpsc::Sender<AggTrade>) -> Result<()> {
+async fn connect_and_stream(
+    url: &str,
+    symbol: &str,
+    gaps: &StreamGaps,
+    tx: tokio::sync::mpsc::Sender<AggTrade>,
+) -> Result<()> {
     let (ws_stream, _) = connect_async(url)
         .await
         .context("Failed to connect to @aggTrade WebSocket")?;
@@ -128,10 +293,18 @@ async fn connect_and_stream(url: &str, tx: tokio::sync::mpsc::Sender<AggTrade>)
         if let Message::Text(text) = msg {
             match serde_json::from_str::<AggTrade>(&text) {
                 Ok(trade) => {
+                    let trade_time = trade.trade_time;
                     if tx.send(trade).await.is_err() {
                         tracing::warn!("Trade receiver dropped, closing stream");
                         break;
                     }
+                    if let Some(gap) = gaps.record_trade(symbol, trade_time) {
+                        tracing::warn!(
+                            symbol = %symbol,
+                            gap_secs = gap.duration_secs(),
+                            "@aggTrade stream resumed after data gap"
+                        );
+                    }
                 }
                 Err(e) => {
                     tracing::warn!("Failed to parse @aggTrade event: {}", e);
@@ -168,4 +341,70 @@ mod tests {
         assert_eq!(trade.quantity, "0.5");
         assert!(!trade.is_buyer_maker);
     }
+
+    #[test]
+    fn test_gap_recorded_only_after_disconnect() {
+        let gaps = StreamGaps::default();
+
+        assert_eq!(gaps.record_trade("BTCUSDT", 1_000), None);
+        assert_eq!(gaps.record_trade("BTCUSDT", 2_000), None);
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 0, 10_000), 0.0);
+
+        gaps.record_disconnect("BTCUSDT");
+        // Still disconnected: the open gap runs to the end of the window
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 0, 5_000), 3.0);
+
+        let gap = gaps.record_trade("BTCUSDT", 7_000).unwrap();
+        assert_eq!(gap.duration_secs(), 5.0);
+        assert_eq!(gaps.last_trade_ms("btcusdt"), Some(7_000));
+
+        // Only the part of the gap inside the window counts
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 0, 10_000), 5.0);
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 4_000, 10_000), 3.0);
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 8_000, 10_000), 0.0);
+        assert_eq!(gaps.gap_secs_in_window("ETHUSDT", 0, 10_000), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_records_gap() {
+        use futures_util::SinkExt;
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Serve one trade, drop the connection, then serve a later trade on reconnect
+        tokio::spawn(async move {
+            for trade_time in [1_000_u64, 6_000] {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                let event = serde_json::json!({
+                    "e": "aggTrade", "E": trade_time, "s": "BTCUSDT", "a": trade_time,
+                    "p": "50000.00", "q": "0.1", "f": 1, "l": 1, "T": trade_time, "m": false
+                });
+                ws.send(Message::Text(event.to_string().into()))
+                    .await
+                    .unwrap();
+                ws.close(None).await.ok();
+            }
+        });
+
+        let gaps = Arc::new(StreamGaps::default());
+        let (mut trade_rx, handle) = spawn_trade_stream(url, "BTCUSDT".to_string(), gaps.clone());
+
+        let first = trade_rx.recv().await.unwrap();
+        assert_eq!(first.trade_time, 1_000);
+        let second = tokio::time::timeout(Duration::from_secs(5), trade_rx.recv())
+            .await
+            .expect("stream should reconnect")
+            .unwrap();
+        assert_eq!(second.trade_time, 6_000);
+
+        // The gap is recorded right after the trade is delivered (the server
+        // has hung up again by now, so stop the window at the resumed trade)
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(gaps.gap_secs_in_window("BTCUSDT", 0, 6_000), 5.0);
+
+        handle.abort();
+    }
 }
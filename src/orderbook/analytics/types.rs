@@ -128,6 +128,17 @@ pub enum Direction {
     Distribution,
 }
 
+/// Whether enough data backed an analytics result
+///
+/// `Insufficient` results carry zeroed metrics and a `data_note` explaining
+/// what was missing; they must not be read as a market signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSufficiency {
+    Sufficient,
+    Insufficient,
+}
+
 // ============================================================================
 // Core Entities (data-model.md lines 25-210)
 // ============================================================================
@@ -168,6 +179,13 @@ pub struct OrderFlowSnapshot {
 
     /// Running sum of (buy volume - sell volume)
     pub cumulative_delta: f64,
+
+    /// Whether enough data was available for a meaningful result
+    pub data_sufficiency: DataSufficiency,
+
+    /// Why the data was insufficient (absent when sufficient)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_note: Option<String>,
 }
 
 /// Volume profile histogram with POC/VAH/VAL (FR-002, data-model.md lines 54-90)
@@ -189,16 +207,19 @@ pub struct VolumeProfile {
     /// Lowest price in histogram
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_range_low: Decimal,
 
     /// Highest price in histogram
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_range_high: Decimal,
 
     /// Price bin width (adaptive tick-based)
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub bin_size: Decimal,
 
     /// Number of bins in histogram (1-200)
@@ -211,22 +232,33 @@ pub struct VolumeProfile {
     /// Sum of all bin volumes
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub total_volume: Decimal,
 
     /// Price level with highest volume (POC)
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub point_of_control: Decimal,
 
     /// Upper boundary of value area (70% volume)
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub value_area_high: Decimal,
 
     /// Lower boundary of value area (70% volume)
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub value_area_low: Decimal,
+
+    /// Whether enough data was available for a meaningful result
+    pub data_sufficiency: DataSufficiency,
+
+    /// Why the data was insufficient (absent when sufficient)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_note: Option<String>,
 }
 
 /// Single bin in volume profile histogram
@@ -235,17 +267,39 @@ pub struct VolumeBin {
     /// Center price of bin
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_level: Decimal,
 
     /// Total volume traded at this level
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub volume: Decimal,
 
     /// Number of trades in bin
     pub trade_count: u64,
 }
 
+/// Result of anomaly detection over one window
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AnomalyReport {
+    /// Trading pair symbol
+    pub symbol: String,
+
+    /// Analysis window duration in seconds
+    pub window_duration_secs: u32,
+
+    /// Detected anomalies (always empty when data is insufficient)
+    pub anomalies: Vec<MarketMicrostructureAnomaly>,
+
+    /// Whether enough data was available for a meaningful result
+    pub data_sufficiency: DataSufficiency,
+
+    /// Why the data was insufficient (absent when sufficient)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_note: Option<String>,
+}
+
 /// Market microstructure anomaly (FR-003, FR-004, FR-005, data-model.md lines 93-141)
 ///
 /// Detected abnormal market behavior including:
@@ -300,6 +354,7 @@ pub enum AnomalyType {
     },
     IcebergOrder {
         #[schemars(with = "String")]
+        #[serde(with = "normalized_decimal")]
         price_level: Decimal,
         /// >5x median triggers
         refill_rate_multiplier: f64,
@@ -332,11 +387,13 @@ pub struct LiquidityVacuum {
     /// Lower boundary
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_range_low: Decimal,
 
     /// Upper boundary (> price_range_low)
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_range_high: Decimal,
 
     /// Volume <20% of median (0.0-100.0)
@@ -346,11 +403,13 @@ pub struct LiquidityVacuum {
     /// Median volume for comparison
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub median_volume: Decimal,
 
     /// Volume in vacuum range
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub actual_volume: Decimal,
 
     /// Predicted price movement speed
@@ -376,11 +435,13 @@ pub struct AbsorptionEvent {
     /// Exact price of absorption
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub price_level: Decimal,
 
     /// Cumulative volume absorbed
 
     #[schemars(with = "String")]
+    #[serde(with = "normalized_decimal")]
     pub absorbed_volume: Decimal,
 
     /// Number of refills observed (≥ 1)
@@ -444,6 +505,18 @@ pub struct MicrostructureHealth {
     #[schemars(range(min = 0.0, max = 100.0))]
     pub update_rate_score: f64,
 
+    /// Seconds of the window with no market data (snapshot gaps or stream disconnects)
+    ///
+    /// The overall score is scaled down by the share of the window that is missing.
+    pub data_gap_secs: f64,
+
+    /// Whether enough data was available for a meaningful result
+    pub data_sufficiency: DataSufficiency,
+
+    /// Why the data was insufficient (absent when sufficient)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_note: Option<String>,
+
     /// Health level classification
     pub health_level: String,
 
@@ -451,6 +524,79 @@ pub struct MicrostructureHealth {
     pub recommended_action: String,
 }
 
+/// One point of an orderbook imbalance series
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ImbalancePoint {
+    /// Snapshot timestamp (Unix seconds; the last snapshot of a downsampled bucket)
+    pub ts: i64,
+
+    /// Bid/ask volume ratio (bid_volume / ask_volume). >1 = more buy pressure
+    pub imbalance: f64,
+}
+
+/// Orderbook imbalance evolution over a window of stored snapshots
+///
+/// Uses the same bid/ask volume ratio as the instantaneous orderbook metrics,
+/// computed at each snapshot. Summary statistics cover every snapshot, while
+/// `points` is downsampled to at most the requested number of points.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImbalanceSeries {
+    /// Trading pair symbol
+    #[schemars(regex(pattern = r"^[A-Z]{4,12}$"))]
+    pub symbol: String,
+
+    /// Analysis window duration in seconds
+    pub window_duration_secs: u32,
+
+    /// Snapshots with a usable imbalance in the window
+    pub snapshot_count: usize,
+
+    /// Imbalance over time, oldest first
+    pub points: Vec<ImbalancePoint>,
+
+    /// Mean imbalance across the window
+    pub mean: f64,
+
+    /// Lowest imbalance in the window
+    pub min: f64,
+
+    /// Highest imbalance in the window
+    pub max: f64,
+
+    /// Imbalance at the most recent snapshot
+    pub current: f64,
+
+    /// Whether enough data was available for a meaningful result
+    pub data_sufficiency: DataSufficiency,
+
+    /// Why the data was insufficient (absent when sufficient)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_note: Option<String>,
+}
+
+// ============================================================================
+// Serialization helpers
+// ============================================================================
+
+/// Serializes a `Decimal` as its normalized decimal string
+///
+/// Decimal arithmetic carries the scale of its inputs, so the same price can
+/// come out as `"50030"` or `"50030.00"` depending on how it was computed.
+/// Normalizing strips trailing zeros so equal values always serialize the
+/// same, without going through `f64`.
+pub(crate) mod normalized_decimal {
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.normalize().to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+        Decimal::deserialize(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,4 +645,41 @@ mod tests {
         );
         assert_eq!(ImpactLevel::from_deficit_pct(40.0), ImpactLevel::Negligible);
     }
+
+    #[test]
+    fn test_decimal_fields_serialize_exactly() {
+        use rust_decimal_macros::dec;
+
+        // 50000 + 10.00 * 3 carries scale 2; 0.1 + 0.2 is exact in Decimal
+        let bin = VolumeBin {
+            price_level: dec!(50000) + dec!(10.00) * Decimal::from(3),
+            volume: dec!(0.1) + dec!(0.2),
+            trade_count: 2,
+        };
+        let value = serde_json::to_value(&bin).unwrap();
+        assert_eq!(value["price_level"], "50030");
+        assert_eq!(value["volume"], "0.3");
+
+        let round_trip: VolumeBin = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.price_level, dec!(50030));
+        assert_eq!(round_trip.volume, dec!(0.3));
+
+        let vacuum = LiquidityVacuum {
+            vacuum_id: Uuid::new_v4(),
+            symbol: "BTCUSDT".to_string(),
+            price_range_low: dec!(123.45000000),
+            price_range_high: dec!(123.55),
+            volume_deficit_pct: 85.33,
+            median_volume: dec!(12.50),
+            actual_volume: dec!(1.87500000),
+            expected_impact: ImpactLevel::FastMovement,
+            detection_timestamp: Utc::now(),
+        };
+        let value = serde_json::to_value(&vacuum).unwrap();
+        assert_eq!(value["price_range_low"], "123.45");
+        assert_eq!(value["price_range_high"], "123.55");
+        assert_eq!(value["median_volume"], "12.5");
+        assert_eq!(value["actual_volume"], "1.875");
+        assert_eq!(value["volume_deficit_pct"].to_string(), "85.33");
+    }
 }
@@ -1,27 +1,61 @@
 //! Order book manager for tracking multiple symbols
 //!
 //! Implements lazy initialization, WebSocket streaming, REST API fallback,
-//! and symbol limit enforcement (max 20 concurrent symbols).
+//! and symbol limit enforcement with least-recently-used eviction.
+//!
+//! ## Configuration
+//!
+//! - `ORDERBOOK_MAX_SYMBOLS`: Maximum concurrently tracked symbols (default: 20)
+//! - `ORDERBOOK_METRICS_TTL_MS`: How long computed metrics are reused (default: 100, `0` disables)
+//! - `ORDERBOOK_DEPTH_TTL_MS`: How long extracted depth is reused (default: 100, `0` disables)
+//! - `ORDERBOOK_LOCAL_DEPTH`: Price levels kept per side of each local book (default: 100, max: 5000)
+//! - `ORDERBOOK_PRELOAD_SYMBOLS`: Comma-separated symbols warmed at startup (default: none)
+//!
+//! Levels beyond the local depth are dropped after every snapshot and diff,
+//! so metrics, depth and slippage estimates reaching past them are approximate.
 
 use crate::binance::client::BinanceClient;
 use crate::orderbook::rate_limiter::{RateLimiter, RateLimiterError};
-use crate::orderbook::types::{HealthStatus, OrderBook, OrderBookHealth};
+use crate::orderbook::types::{
+    HealthStatus, OrderBook, OrderBookDepthResponse, OrderBookHealth, OrderBookMetrics,
+    TrackedSymbol,
+};
 use crate::orderbook::websocket::{DepthUpdateEvent, DepthWebSocketClient};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
-/// Maximum number of concurrent symbols that can be tracked
-const MAX_CONCURRENT_SYMBOLS: usize = 20;
+/// Default maximum number of concurrent symbols that can be tracked
+pub const MAX_CONCURRENT_SYMBOLS: usize = 20;
 
 /// Staleness threshold in milliseconds (5 seconds)
 const STALENESS_THRESHOLD_MS: i64 = 5000;
 
+/// Minimum interval between forced refreshes of the same symbol (1 second)
+pub const FORCE_REFRESH_INTERVAL_MS: i64 = 1000;
+
+/// Default reuse window for computed metrics and depth (one depth stream update)
+pub const DEFAULT_RESULT_TTL_MS: u64 = 100;
+
+/// Default price levels retained per side of a local book
+pub const DEFAULT_LOCAL_DEPTH: usize = 100;
+
+/// Deepest local book Binance can seed (largest `/api/v3/depth` limit)
+pub const MAX_LOCAL_DEPTH: usize = 5000;
+
+/// Longest a preloaded symbol is waited on for its first stream update
+pub const PRELOAD_WARMUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Poll interval while waiting for a preloaded book to leave warmup
+const PRELOAD_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// Order book manager errors
 #[derive(Debug, Error)]
 pub enum ManagerError {
@@ -45,57 +79,289 @@ pub enum ManagerError {
 
     #[error("WebSocket error: {0}")]
     WebSocketError(String),
+
+    #[error("Forced refresh for {symbol} rejected: retry in {retry_after_ms}ms")]
+    RefreshTooSoon { symbol: String, retry_after_ms: i64 },
 }
 
+/// Source of per-subscription IDs (distinguishes a re-added symbol from its evicted predecessor)
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Internal state for a tracked order book
 struct OrderBookState {
+    /// ID of the WebSocket subscription feeding this book
+    subscription_id: u64,
+
     /// Current order book snapshot
     order_book: OrderBook,
 
-    /// WebSocket client task handle (kept alive to prevent task cancellation)
-    #[allow(dead_code)]
+    /// WebSocket client task handle (aborted when the state is dropped)
     websocket_handle: Option<JoinHandle<()>>,
 
     /// Last successful update timestamp
     last_update_time: i64,
 
+    /// Last time a caller read this book (for LRU eviction)
+    ///
+    /// Atomic so cache hits can refresh it under the read lock.
+    last_access_time: AtomicI64,
+
     /// Whether WebSocket is currently connected
     websocket_connected: bool,
 }
 
+impl OrderBookState {
+    /// Mark the book as read now
+    fn touch(&self) {
+        self.last_access_time
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+}
+
+impl Drop for OrderBookState {
+    fn drop(&mut self) {
+        // Evicted or replaced books must not keep streaming
+        if let Some(handle) = self.websocket_handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Parses `ORDERBOOK_MAX_SYMBOLS`, falling back to the default on missing, zero or invalid input
+fn parse_max_symbols(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(MAX_CONCURRENT_SYMBOLS)
+}
+
+/// Parses `ORDERBOOK_LOCAL_DEPTH`, falling back to the default on missing, zero or invalid input
+///
+/// Values above [`MAX_LOCAL_DEPTH`] are clamped.
+fn parse_local_depth(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .map_or(DEFAULT_LOCAL_DEPTH, |n| n.min(MAX_LOCAL_DEPTH))
+}
+
+/// Parses a result cache TTL in milliseconds, falling back to the default on missing or invalid input
+///
+/// `0` is kept: it disables the cache.
+fn parse_ttl_ms(value: Option<&str>) -> u64 {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_RESULT_TTL_MS)
+}
+
+/// Parses `ORDERBOOK_PRELOAD_SYMBOLS` (comma-separated)
+///
+/// Symbols are uppercased; blanks and duplicates are dropped, keeping the
+/// first occurrence.
+pub fn parse_preload_symbols(value: Option<&str>) -> Vec<String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for symbol in value.unwrap_or_default().split(',') {
+        let symbol = symbol.trim().to_uppercase();
+        if !symbol.is_empty() && !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    symbols
+}
+
+/// Recently computed results, reused until they are older than the TTL
+///
+/// Keys start with the symbol. A TTL of 0 disables the cache: every lookup
+/// misses and nothing is stored.
+struct ResultCache<T> {
+    ttl_ms: u64,
+    entries: std::sync::Mutex<HashMap<String, (i64, T)>>,
+}
+
+impl<T: Clone> ResultCache<T> {
+    fn new(ttl_ms: u64) -> Self {
+        Self {
+            ttl_ms,
+            entries: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Value stored under `key` less than `ttl_ms` before `now`
+    fn get(&self, key: &str, now: i64) -> Option<T> {
+        if self.ttl_ms == 0 {
+            return None;
+        }
+        let entries = self.entries.lock().expect("result cache lock poisoned");
+        entries
+            .get(key)
+            .filter(|(computed_at, _)| now - computed_at < self.ttl_ms as i64)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Stores `value` as computed at `now`, dropping expired entries
+    fn insert(&self, key: String, now: i64, value: T) {
+        if self.ttl_ms == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("result cache lock poisoned");
+        entries.retain(|_, (computed_at, _)| now - *computed_at < self.ttl_ms as i64);
+        entries.insert(key, (now, value));
+    }
+}
+
 /// Manager for multiple order book subscriptions
 ///
-/// Tracks up to 20 symbols with lazy initialization:
+/// Tracks up to `max_symbols` symbols with lazy initialization:
 /// 1. First request triggers REST API snapshot + WebSocket subscription
 /// 2. Subsequent requests use cached data (updated via WebSocket)
 /// 3. REST API fallback when data is stale (>5s old)
+/// 4. When the cap is hit, a new symbol evicts the least-recently-accessed one
 pub struct OrderBookManager {
     /// Map of symbol → order book state
     states: Arc<RwLock<HashMap<String, OrderBookState>>>,
 
+    /// Maximum concurrently tracked symbols
+    max_symbols: usize,
+
+    /// Price levels retained per side of each local book
+    local_depth: usize,
+
     /// Rate limiter for REST API requests
     rate_limiter: Arc<RateLimiter>,
 
     /// Binance API client (for REST fallback)
     binance_client: Arc<BinanceClient>,
+
+    /// Last forced refresh time per symbol (throttles `refresh_order_book`)
+    last_forced_refresh: std::sync::Mutex<HashMap<String, i64>>,
+
+    /// Metrics computed from the local books (`ORDERBOOK_METRICS_TTL_MS`)
+    metrics_cache: ResultCache<OrderBookMetrics>,
+
+    /// Depth extracted from the local books (`ORDERBOOK_DEPTH_TTL_MS`)
+    depth_cache: ResultCache<OrderBookDepthResponse>,
 }
 
 impl OrderBookManager {
     /// Create a new order book manager
+    ///
+    /// The symbol cap is read from `ORDERBOOK_MAX_SYMBOLS` (default: 20), and
+    /// the metrics/depth reuse windows from `ORDERBOOK_METRICS_TTL_MS` and
+    /// `ORDERBOOK_DEPTH_TTL_MS` (default: 100ms each). Books keep
+    /// `ORDERBOOK_LOCAL_DEPTH` levels per side (default: 100).
     pub fn new(binance_client: Arc<BinanceClient>) -> Self {
+        let max_symbols = parse_max_symbols(std::env::var("ORDERBOOK_MAX_SYMBOLS").ok().as_deref());
+        Self::with_max_symbols(binance_client, max_symbols)
+            .with_result_ttls(
+                parse_ttl_ms(std::env::var("ORDERBOOK_METRICS_TTL_MS").ok().as_deref()),
+                parse_ttl_ms(std::env::var("ORDERBOOK_DEPTH_TTL_MS").ok().as_deref()),
+            )
+            .with_local_depth(parse_local_depth(
+                std::env::var("ORDERBOOK_LOCAL_DEPTH").ok().as_deref(),
+            ))
+    }
+
+    /// Create a new order book manager with an explicit symbol cap (minimum 1)
+    pub fn with_max_symbols(binance_client: Arc<BinanceClient>, max_symbols: usize) -> Self {
         Self {
             states: Arc::new(RwLock::new(HashMap::new())),
+            max_symbols: max_symbols.max(1),
+            local_depth: DEFAULT_LOCAL_DEPTH,
             rate_limiter: Arc::new(RateLimiter::new()),
             binance_client,
+            last_forced_refresh: std::sync::Mutex::new(HashMap::new()),
+            metrics_cache: ResultCache::new(DEFAULT_RESULT_TTL_MS),
+            depth_cache: ResultCache::new(DEFAULT_RESULT_TTL_MS),
+        }
+    }
+
+    /// Sets how long computed metrics and depth are reused (`0` always recomputes)
+    pub fn with_result_ttls(mut self, metrics_ttl_ms: u64, depth_ttl_ms: u64) -> Self {
+        self.metrics_cache = ResultCache::new(metrics_ttl_ms);
+        self.depth_cache = ResultCache::new(depth_ttl_ms);
+        self
+    }
+
+    /// Sets how many price levels each side of a local book retains (1 to [`MAX_LOCAL_DEPTH`])
+    pub fn with_local_depth(mut self, local_depth: usize) -> Self {
+        self.local_depth = local_depth.clamp(1, MAX_LOCAL_DEPTH);
+        self
+    }
+
+    /// Maximum number of concurrently tracked symbols
+    pub fn max_symbols(&self) -> usize {
+        self.max_symbols
+    }
+
+    /// Price levels retained per side of each local book
+    pub fn local_depth(&self) -> usize {
+        self.local_depth
+    }
+
+    /// Metrics computed within the metrics TTL for a tracked symbol
+    ///
+    /// Counts as an access of the symbol's book. Misses once the symbol has
+    /// been evicted, even if the entry is still within its TTL.
+    pub async fn cached_metrics(&self, symbol: &str) -> Option<OrderBookMetrics> {
+        let metrics = self
+            .metrics_cache
+            .get(symbol, chrono::Utc::now().timestamp_millis())?;
+        self.touch(symbol).await.then_some(metrics)
+    }
+
+    /// Stores metrics just computed from `symbol`'s local book
+    pub fn cache_metrics(&self, symbol: &str, metrics: &OrderBookMetrics) {
+        self.metrics_cache.insert(
+            symbol.to_string(),
+            chrono::Utc::now().timestamp_millis(),
+            metrics.clone(),
+        );
+    }
+
+    /// Depth extracted within the depth TTL for a tracked symbol
+    ///
+    /// `variant` distinguishes level counts and encodings of the same book.
+    /// Counts as an access of the symbol's book.
+    pub async fn cached_depth(
+        &self,
+        symbol: &str,
+        variant: &str,
+    ) -> Option<OrderBookDepthResponse> {
+        let depth = self.depth_cache.get(
+            &format!("{}|{}", symbol, variant),
+            chrono::Utc::now().timestamp_millis(),
+        )?;
+        self.touch(symbol).await.then_some(depth)
+    }
+
+    /// Stores depth just extracted from `symbol`'s local book
+    pub fn cache_depth(&self, symbol: &str, variant: &str, depth: &OrderBookDepthResponse) {
+        self.depth_cache.insert(
+            format!("{}|{}", symbol, variant),
+            chrono::Utc::now().timestamp_millis(),
+            depth.clone(),
+        );
+    }
+
+    /// Marks a tracked symbol's book as read now; `false` if it isn't tracked
+    async fn touch(&self, symbol: &str) -> bool {
+        match self.states.read().await.get(symbol) {
+            Some(state) => {
+                state.touch();
+                true
+            }
+            None => false,
         }
     }
 
     /// Get order book for a symbol (lazy initialization)
     ///
     /// On first request:
-    /// - Checks symbol limit (20 max)
+    /// - Evicts the least-recently-accessed symbol if the cap is reached
     /// - Fetches REST API snapshot
     /// - Starts WebSocket subscription
+    /// - Returns the snapshot right away with `warming` set; the first applied
+    ///   WebSocket delta clears it
     ///
     /// On subsequent requests:
     /// - Returns cached data if fresh (<5s old)
@@ -112,6 +378,7 @@ impl OrderBookManager {
                 let age_ms = now - state.last_update_time;
 
                 if age_ms < STALENESS_THRESHOLD_MS {
+                    state.touch();
                     debug!(
                         symbol = %symbol_upper,
                         age_ms,
@@ -129,24 +396,156 @@ impl OrderBookManager {
         }
 
         // Need to initialize or refresh
+        self.resnapshot(&symbol_upper).await
+    }
+
+    /// Warm the order books for `symbols` ahead of their first tool call
+    ///
+    /// Each book gets its REST snapshot and WebSocket subscription, then is
+    /// waited on for up to `warmup_timeout` until the first applied delta
+    /// clears `warming`. Only the first `max_symbols` symbols are loaded, so
+    /// preloading never evicts a preloaded book. Failures are logged and
+    /// skipped. Returns the symbols whose books are ready.
+    pub async fn preload(&self, symbols: &[String], warmup_timeout: Duration) -> Vec<String> {
+        if symbols.len() > self.max_symbols {
+            warn!(
+                requested = symbols.len(),
+                max_symbols = self.max_symbols,
+                skipped = %symbols[self.max_symbols..].join(","),
+                "More preload symbols than the symbol cap, skipping the rest"
+            );
+        }
+        let symbols = &symbols[..symbols.len().min(self.max_symbols)];
+        let total = symbols.len();
+
+        let mut subscribed = Vec::new();
+        for (index, symbol) in symbols.iter().enumerate() {
+            info!(symbol = %symbol, progress = %format!("{}/{}", index + 1, total), "Preloading order book");
+            match self.get_order_book(symbol).await {
+                Ok(_) => subscribed.push(symbol.to_uppercase()),
+                Err(e) => warn!(symbol = %symbol, error = %e, "Order book preload failed"),
+            }
+        }
+
+        let mut ready = Vec::new();
+        for symbol in subscribed {
+            if self.wait_until_ready(&symbol, warmup_timeout).await {
+                ready.push(symbol);
+            } else {
+                warn!(
+                    symbol = %symbol,
+                    timeout_ms = warmup_timeout.as_millis() as u64,
+                    "Preloaded order book still warming, first call may be served from the snapshot"
+                );
+            }
+        }
+
+        info!(
+            ready = ready.len(),
+            requested = total,
+            "Order book preload finished"
+        );
+        ready
+    }
+
+    /// Waits until `symbol`'s book has applied a stream delta; `false` on timeout or eviction
+    async fn wait_until_ready(&self, symbol: &str, timeout: Duration) -> bool {
+        let ready = async {
+            loop {
+                match self.states.read().await.get(symbol) {
+                    Some(state) if !state.order_book.warming => return true,
+                    Some(_) => {}
+                    None => return false,
+                }
+                tokio::time::sleep(PRELOAD_POLL_INTERVAL).await;
+            }
+        };
+        tokio::time::timeout(timeout, ready).await.unwrap_or(false)
+    }
+
+    /// Force a fresh REST snapshot for a symbol, bypassing the cache
+    ///
+    /// Rebuilds the local book and restarts its WebSocket subscription, so the
+    /// call takes as long as a first request (2-3s). Limited to one forced refresh
+    /// per symbol every [`FORCE_REFRESH_INTERVAL_MS`]; earlier calls fail with
+    /// [`ManagerError::RefreshTooSoon`].
+    pub async fn refresh_order_book(&self, symbol: &str) -> Result<OrderBook, ManagerError> {
+        let symbol_upper = symbol.to_uppercase();
+        self.claim_forced_refresh(&symbol_upper, chrono::Utc::now().timestamp_millis())?;
+
+        info!(symbol = %symbol_upper, "Forced order book refresh");
+        self.resnapshot(&symbol_upper).await
+    }
+
+    /// Record a forced refresh of `symbol` at `now`, unless one happened too recently
+    fn claim_forced_refresh(&self, symbol: &str, now: i64) -> Result<(), ManagerError> {
+        let mut last_refresh = self
+            .last_forced_refresh
+            .lock()
+            .expect("forced refresh lock poisoned");
+
+        if let Some(&last) = last_refresh.get(symbol) {
+            let elapsed = now - last;
+            if elapsed < FORCE_REFRESH_INTERVAL_MS {
+                return Err(ManagerError::RefreshTooSoon {
+                    symbol: symbol.to_string(),
+                    retry_after_ms: FORCE_REFRESH_INTERVAL_MS - elapsed,
+                });
+            }
+        }
+
+        last_refresh.insert(symbol.to_string(), now);
+        Ok(())
+    }
+
+    /// Fetch a REST snapshot and (re)start the stream for a symbol, returning the new book
+    async fn resnapshot(&self, symbol_upper: &str) -> Result<OrderBook, ManagerError> {
         let mut states = self.states.write().await;
 
-        // Check symbol limit (only for new symbols)
-        if !states.contains_key(&symbol_upper) && states.len() >= MAX_CONCURRENT_SYMBOLS {
-            return Err(ManagerError::SymbolLimitReached);
+        // Make room for new symbols by evicting the least-recently-accessed one
+        if !states.contains_key(symbol_upper) {
+            self.make_room(&mut states);
         }
 
         // Initialize or refresh order book
-        self.initialize_order_book(&mut states, &symbol_upper)
+        self.initialize_order_book(&mut states, symbol_upper)
             .await?;
 
         // Return the freshly initialized order book
         let state = states
-            .get(&symbol_upper)
+            .get(symbol_upper)
             .expect("order book should exist after initialization");
+        state.touch();
         Ok(state.order_book.clone())
     }
 
+    /// Evict least-recently-accessed symbols until a new one fits under the cap
+    ///
+    /// Dropping the state aborts its WebSocket task. Returns the evicted symbols.
+    fn make_room(&self, states: &mut HashMap<String, OrderBookState>) -> Vec<String> {
+        let mut evicted = Vec::new();
+
+        while states.len() >= self.max_symbols {
+            let Some(oldest) = states
+                .iter()
+                .min_by_key(|(_, state)| state.last_access_time.load(Ordering::Relaxed))
+                .map(|(symbol, _)| symbol.clone())
+            else {
+                break;
+            };
+
+            states.remove(&oldest);
+            info!(
+                symbol = %oldest,
+                max_symbols = self.max_symbols,
+                "Symbol cap reached, evicted least-recently-accessed order book"
+            );
+            evicted.push(oldest);
+        }
+
+        evicted
+    }
+
     /// Initialize order book for a symbol (called with write lock held)
     async fn initialize_order_book(
         &self,
@@ -166,10 +565,14 @@ impl OrderBookManager {
         let websocket_handle = ws_client.start();
 
         // Store initial state
+        let now = chrono::Utc::now().timestamp_millis();
+        let subscription_id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
         let state = OrderBookState {
+            subscription_id,
             order_book: order_book.clone(),
             websocket_handle: Some(websocket_handle),
-            last_update_time: chrono::Utc::now().timestamp_millis(),
+            last_update_time: now,
+            last_access_time: AtomicI64::new(now),
             websocket_connected: true,
         };
 
@@ -178,10 +581,12 @@ impl OrderBookManager {
         // Spawn task to process WebSocket updates
         let states_clone = Arc::clone(&self.states);
         let symbol_owned = symbol.to_string();
+        let local_depth = self.local_depth;
         tokio::spawn(async move {
             while let Some(update) = update_receiver.recv().await {
                 if let Err(e) =
-                    Self::process_depth_update(&states_clone, &symbol_owned, update).await
+                    Self::process_depth_update(&states_clone, &symbol_owned, update, local_depth)
+                        .await
                 {
                     error!(
                         symbol = %symbol_owned,
@@ -191,11 +596,14 @@ impl OrderBookManager {
                 }
             }
 
-            // WebSocket receiver closed - mark as disconnected
+            // WebSocket receiver closed - mark as disconnected unless the book was
+            // evicted or replaced by a newer subscription
             warn!(symbol = %symbol_owned, "WebSocket receiver closed");
             let mut states = states_clone.write().await;
             if let Some(state) = states.get_mut(&symbol_owned) {
-                state.websocket_connected = false;
+                if state.subscription_id == subscription_id {
+                    state.websocket_connected = false;
+                }
             }
         });
 
@@ -210,7 +618,7 @@ impl OrderBookManager {
         // Use BinanceClient to fetch depth
         let snapshot = self
             .binance_client
-            .get_order_book(symbol, Some(100))
+            .get_order_book(symbol, Some(self.local_depth as u32))
             .await
             .map_err(|e| ManagerError::RestApiError(e.to_string()))?;
 
@@ -218,6 +626,9 @@ impl OrderBookManager {
         let mut order_book = OrderBook::new(symbol.to_string());
         order_book.last_update_id = snapshot.last_update_id;
         order_book.timestamp = chrono::Utc::now().timestamp_millis();
+        order_book.last_applied_ms = order_book.timestamp;
+        // Served as-is until the WebSocket stream delivers its first delta
+        order_book.warming = true;
 
         // Parse bids - Binance API returns Vec<(String, String)>
         for (price_str, qty_str) in &snapshot.bids {
@@ -236,6 +647,7 @@ impl OrderBookManager {
                 .map_err(|e| ManagerError::RestApiError(format!("Invalid ask qty: {}", e)))?;
             order_book.asks.insert(price, qty);
         }
+        order_book.trim_to_depth(self.local_depth);
 
         debug!(
             symbol = %symbol,
@@ -248,10 +660,13 @@ impl OrderBookManager {
     }
 
     /// Process a depth update from WebSocket
+    ///
+    /// Levels beyond `local_depth` per side are trimmed once the diff is applied.
     async fn process_depth_update(
         states: &Arc<RwLock<HashMap<String, OrderBookState>>>,
         symbol: &str,
         update: DepthUpdateEvent,
+        local_depth: usize,
     ) -> Result<(), ManagerError> {
         let mut states = states.write().await;
         let state = states
@@ -287,11 +702,14 @@ impl OrderBookManager {
 
             state.order_book.update_ask(price, qty);
         }
+        state.order_book.trim_to_depth(local_depth);
 
         // Update metadata
         state.order_book.last_update_id = update.final_update_id;
         state.order_book.timestamp = update.event_time;
         state.last_update_time = chrono::Utc::now().timestamp_millis();
+        state.order_book.last_applied_ms = state.last_update_time;
+        state.order_book.warming = false;
 
         debug!(
             symbol = %symbol,
@@ -347,9 +765,25 @@ impl OrderBookManager {
             (HealthStatus::Ok, None)
         };
 
+        let mut tracked_symbols: Vec<TrackedSymbol> = states
+            .iter()
+            .map(|(symbol, state)| TrackedSymbol {
+                symbol: symbol.clone(),
+                last_access_age_ms: now - state.last_access_time.load(Ordering::Relaxed),
+                last_update_age_ms: now - state.last_update_time,
+                websocket_connected: state.websocket_connected,
+            })
+            .collect();
+        tracked_symbols.sort_by_key(|t| t.last_access_age_ms);
+
         OrderBookHealth {
             status,
             orderbook_symbols_active: active_count,
+            max_symbols: self.max_symbols,
+            metrics_ttl_ms: self.metrics_cache.ttl_ms,
+            depth_ttl_ms: self.depth_cache.ttl_ms,
+            local_depth: self.local_depth,
+            tracked_symbols,
             last_update_age_ms: max_age_ms,
             websocket_connected: connected_count > 0,
             timestamp: now,
@@ -371,4 +805,347 @@ mod tests {
     fn test_staleness_threshold() {
         assert_eq!(STALENESS_THRESHOLD_MS, 5000);
     }
+
+    #[test]
+    fn test_parse_max_symbols() {
+        assert_eq!(parse_max_symbols(Some("5")), 5);
+        assert_eq!(parse_max_symbols(Some("0")), MAX_CONCURRENT_SYMBOLS);
+        assert_eq!(parse_max_symbols(Some("many")), MAX_CONCURRENT_SYMBOLS);
+        assert_eq!(parse_max_symbols(None), MAX_CONCURRENT_SYMBOLS);
+    }
+
+    #[test]
+    fn test_parse_local_depth() {
+        assert_eq!(parse_local_depth(Some("20")), 20);
+        assert_eq!(parse_local_depth(Some("0")), DEFAULT_LOCAL_DEPTH);
+        assert_eq!(parse_local_depth(Some("deep")), DEFAULT_LOCAL_DEPTH);
+        assert_eq!(parse_local_depth(Some("10000")), MAX_LOCAL_DEPTH);
+        assert_eq!(parse_local_depth(None), DEFAULT_LOCAL_DEPTH);
+    }
+
+    #[tokio::test]
+    async fn test_local_book_never_exceeds_depth_cap() {
+        let manager = OrderBookManager::with_max_symbols(Arc::new(BinanceClient::new()), 1)
+            .with_local_depth(100);
+
+        let mut state = tracked_state("BTCUSDT", 1_000);
+        for i in 0..100 {
+            state
+                .order_book
+                .update_bid(Decimal::from(10_000 - i), Decimal::ONE);
+            state
+                .order_book
+                .update_ask(Decimal::from(11_001 + i), Decimal::ONE);
+        }
+        manager
+            .states
+            .write()
+            .await
+            .insert("BTCUSDT".to_string(), state);
+
+        // Diffs adding 50 deeper and 50 better levels per side
+        for batch in 0..4 {
+            let bids = (0..25)
+                .map(|i| {
+                    let offset = batch * 25 + i;
+                    let price = if batch < 2 {
+                        9_000 - offset
+                    } else {
+                        10_001 + offset - 50
+                    };
+                    [price.to_string(), "2".to_string()]
+                })
+                .collect();
+            let asks = (0..25)
+                .map(|i| {
+                    let offset = batch * 25 + i;
+                    let price = if batch < 2 {
+                        12_000 + offset
+                    } else {
+                        11_000 - (offset - 50)
+                    };
+                    [price.to_string(), "2".to_string()]
+                })
+                .collect();
+            let update = DepthUpdateEvent {
+                event_type: "depthUpdate".to_string(),
+                event_time: 2_000 + batch,
+                symbol: "BTCUSDT".to_string(),
+                first_update_id: batch + 1,
+                final_update_id: batch + 1,
+                bids,
+                asks,
+            };
+            OrderBookManager::process_depth_update(
+                &manager.states,
+                "BTCUSDT",
+                update,
+                manager.local_depth(),
+            )
+            .await
+            .unwrap();
+
+            let states = manager.states.read().await;
+            let book = &states["BTCUSDT"].order_book;
+            assert!(book.bids.len() <= 100);
+            assert!(book.asks.len() <= 100);
+        }
+
+        let states = manager.states.read().await;
+        let book = &states["BTCUSDT"].order_book;
+        assert_eq!(book.bids.len(), 100);
+        assert_eq!(book.asks.len(), 100);
+        // The best prices survive; the worst retained levels are from the original book
+        assert_eq!(book.best_bid(), Some(&Decimal::from(10_050)));
+        assert_eq!(book.bids.keys().next(), Some(&Decimal::from(9_951)));
+        assert_eq!(book.best_ask(), Some(&Decimal::from(10_951)));
+        assert_eq!(book.asks.keys().next_back(), Some(&Decimal::from(11_050)));
+        drop(states);
+
+        assert_eq!(manager.get_health().await.local_depth, 100);
+    }
+
+    #[tokio::test]
+    async fn test_first_call_is_warming_until_stream_applies() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/depth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "lastUpdateId": 10,
+                "bids": [["100.00", "1.0"]],
+                "asks": [["101.00", "2.0"]]
+            })))
+            .mount(&mock)
+            .await;
+        let client = BinanceClient {
+            base_url: mock.uri(),
+            ..BinanceClient::new()
+        };
+        let manager = OrderBookManager::new(Arc::new(client));
+
+        // First call is served from the REST snapshot
+        let first = manager.get_order_book("WARMUSDT").await.unwrap();
+        assert!(first.warming);
+        assert!(crate::orderbook::types::SnapshotFreshness::of(&first).warming);
+        assert_eq!(first.best_bid(), Some(&Decimal::from(100)));
+
+        let update = DepthUpdateEvent {
+            event_type: "depthUpdate".to_string(),
+            event_time: 2_000,
+            symbol: "WARMUSDT".to_string(),
+            first_update_id: 11,
+            final_update_id: 11,
+            bids: vec![["100.50".to_string(), "3.0".to_string()]],
+            asks: vec![],
+        };
+        OrderBookManager::process_depth_update(
+            &manager.states,
+            "WARMUSDT",
+            update,
+            manager.local_depth(),
+        )
+        .await
+        .unwrap();
+
+        // Once a delta is applied, later calls come from the live book
+        let later = manager.get_order_book("WARMUSDT").await.unwrap();
+        assert!(!later.warming);
+        assert!(!crate::orderbook::types::SnapshotFreshness::of(&later).warming);
+        assert_eq!(later.last_update_id, 11);
+        assert_eq!(later.best_bid(), Some(&Decimal::new(10050, 2)));
+        assert_eq!(mock.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_preload_symbols() {
+        assert_eq!(
+            parse_preload_symbols(Some(" btcusdt, ETHUSDT,,BTCUSDT ")),
+            vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()]
+        );
+        assert!(parse_preload_symbols(Some("")).is_empty());
+        assert!(parse_preload_symbols(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_preloaded_symbol_is_ready_on_first_call() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/depth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "lastUpdateId": 10,
+                "bids": [["100.00", "1.0"]],
+                "asks": [["101.00", "2.0"]]
+            })))
+            .mount(&mock)
+            .await;
+        let client = BinanceClient {
+            base_url: mock.uri(),
+            ..BinanceClient::new()
+        };
+        // Cap of 1: the second preload symbol is skipped rather than evicting the first
+        let manager = OrderBookManager::with_max_symbols(Arc::new(client), 1);
+
+        // Stands in for the stream's first delta once the book is subscribed
+        let first_delta = async {
+            loop {
+                let update = DepthUpdateEvent {
+                    event_type: "depthUpdate".to_string(),
+                    event_time: 2_000,
+                    symbol: "PRELOADUSDT".to_string(),
+                    first_update_id: 11,
+                    final_update_id: 11,
+                    bids: vec![["100.50".to_string(), "3.0".to_string()]],
+                    asks: vec![],
+                };
+                let applied = OrderBookManager::process_depth_update(
+                    &manager.states,
+                    "PRELOADUSDT",
+                    update,
+                    manager.local_depth(),
+                )
+                .await;
+                if applied.is_ok() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        let symbols = parse_preload_symbols(Some("PRELOADUSDT,OTHERUSDT"));
+        let (ready, ()) = tokio::join!(
+            manager.preload(&symbols, Duration::from_secs(5)),
+            first_delta
+        );
+        assert_eq!(ready, vec!["PRELOADUSDT".to_string()]);
+
+        // The first tool call is served from the live book without another snapshot
+        let first = manager.get_order_book("PRELOADUSDT").await.unwrap();
+        assert!(!first.warming);
+        assert_eq!(first.last_update_id, 11);
+        assert_eq!(mock.received_requests().await.unwrap().len(), 1);
+        assert_eq!(manager.get_health().await.orderbook_symbols_active, 1);
+    }
+
+    #[test]
+    fn test_parse_ttl_ms() {
+        assert_eq!(parse_ttl_ms(Some("250")), 250);
+        assert_eq!(parse_ttl_ms(Some("0")), 0);
+        assert_eq!(parse_ttl_ms(Some("-5")), DEFAULT_RESULT_TTL_MS);
+        assert_eq!(parse_ttl_ms(None), DEFAULT_RESULT_TTL_MS);
+    }
+
+    #[test]
+    fn test_result_cache_serves_within_ttl_then_recomputes() {
+        let cache = ResultCache::new(100);
+        let mut computations = 0;
+        let mut lookup = |now: i64| {
+            cache.get("BTCUSDT", now).unwrap_or_else(|| {
+                computations += 1;
+                let value = format!("computed at {}", now);
+                cache.insert("BTCUSDT".to_string(), now, value.clone());
+                value
+            })
+        };
+
+        assert_eq!(lookup(1_000), "computed at 1000");
+        // Within the TTL the cached value is returned without recomputing
+        assert_eq!(lookup(1_099), "computed at 1000");
+        // Once the TTL has elapsed the value is recomputed
+        assert_eq!(lookup(1_100), "computed at 1100");
+        drop(lookup);
+        assert_eq!(computations, 2);
+
+        // A TTL of 0 always recomputes
+        let disabled = ResultCache::new(0);
+        disabled.insert("BTCUSDT".to_string(), 1_000, 1);
+        assert_eq!(disabled.get("BTCUSDT", 1_000), None);
+    }
+
+    #[tokio::test]
+    async fn test_cached_metrics_miss_after_eviction() {
+        let manager = OrderBookManager::with_max_symbols(Arc::new(BinanceClient::new()), 1)
+            .with_result_ttls(60_000, 0);
+
+        let mut book = OrderBook::new("BTCUSDT".to_string());
+        book.update_bid(Decimal::from(100), Decimal::ONE);
+        book.update_ask(Decimal::from(101), Decimal::ONE);
+        let metrics = crate::orderbook::metrics::calculate_metrics(&book).unwrap();
+
+        manager
+            .states
+            .write()
+            .await
+            .insert("BTCUSDT".to_string(), tracked_state("BTCUSDT", 1_000));
+        manager.cache_metrics("BTCUSDT", &metrics);
+        assert!(manager.cached_metrics("BTCUSDT").await.is_some());
+
+        manager.states.write().await.remove("BTCUSDT");
+        assert!(manager.cached_metrics("BTCUSDT").await.is_none());
+
+        let health = manager.get_health().await;
+        assert_eq!(health.metrics_ttl_ms, 60_000);
+        assert_eq!(health.depth_ttl_ms, 0);
+    }
+
+    fn tracked_state(symbol: &str, last_access_time: i64) -> OrderBookState {
+        OrderBookState {
+            subscription_id: NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed),
+            order_book: OrderBook::new(symbol.to_string()),
+            websocket_handle: None,
+            last_update_time: last_access_time,
+            last_access_time: AtomicI64::new(last_access_time),
+            websocket_connected: true,
+        }
+    }
+
+    #[test]
+    fn test_forced_refresh_is_throttled_per_symbol() {
+        let manager = OrderBookManager::new(Arc::new(BinanceClient::new()));
+
+        assert!(manager.claim_forced_refresh("BTCUSDT", 10_000).is_ok());
+        match manager.claim_forced_refresh("BTCUSDT", 10_400) {
+            Err(ManagerError::RefreshTooSoon { retry_after_ms, .. }) => {
+                assert_eq!(retry_after_ms, 600)
+            }
+            other => panic!("expected RefreshTooSoon, got {:?}", other),
+        }
+
+        // Other symbols have their own budget, and the interval expires
+        assert!(manager.claim_forced_refresh("ETHUSDT", 10_400).is_ok());
+        assert!(manager
+            .claim_forced_refresh("BTCUSDT", 10_000 + FORCE_REFRESH_INTERVAL_MS)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_exceeding_cap_evicts_least_recently_accessed() {
+        let manager = OrderBookManager::with_max_symbols(Arc::new(BinanceClient::new()), 2);
+        assert_eq!(manager.max_symbols(), 2);
+
+        let mut states = manager.states.write().await;
+        states.insert("BTCUSDT".to_string(), tracked_state("BTCUSDT", 1_000));
+        states.insert("ETHUSDT".to_string(), tracked_state("ETHUSDT", 2_000));
+
+        // Reading BTCUSDT makes ETHUSDT the oldest
+        states["BTCUSDT"].touch();
+
+        let evicted = manager.make_room(&mut states);
+        assert_eq!(evicted, vec!["ETHUSDT".to_string()]);
+        assert!(states.contains_key("BTCUSDT"));
+        assert!(!states.contains_key("ETHUSDT"));
+
+        // Under the cap, nothing is evicted
+        assert!(manager.make_room(&mut states).is_empty());
+        drop(states);
+
+        let health = manager.get_health().await;
+        assert_eq!(health.max_symbols, 2);
+        assert_eq!(health.tracked_symbols.len(), 1);
+        assert_eq!(health.tracked_symbols[0].symbol, "BTCUSDT");
+    }
 }
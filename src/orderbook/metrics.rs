@@ -7,13 +7,19 @@
 //! - Walls detection (large levels)
 //! - VWAP-based slippage estimates
 //! - Compact integer encoding for L2 depth
+//! - Decimal string encoding for human-readable L2 depth
+//! - Price-bucket aggregation for liquidity views
+//! - Key support/resistance levels from book walls
+//! - Liquidity gaps between adjacent levels
+//! - Volume-weighted depth statistics over the top levels
 
 use crate::orderbook::types::{
-    OrderBook, OrderBookDepth, OrderBookMetrics, SlippageEstimate, SlippageEstimates, Wall,
-    WallSide, Walls,
+    AggregatedDepth, BookGap, BookGaps, DecimalOrderBookDepth, DepthStats, KeyLevel, KeyLevels,
+    OrderBook, OrderBookDepth, OrderBookMetrics, SlippageEstimate, SlippageEstimates,
+    SnapshotFreshness, Wall, WallSide, Walls,
 };
 use rust_decimal::prelude::ToPrimitive;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use std::collections::BTreeMap;
 
 /// Number of top levels to analyze for volume calculations
@@ -22,6 +28,15 @@ const TOP_LEVELS: usize = 20;
 /// Target USD amounts for slippage estimates
 const SLIPPAGE_TARGETS_USD: [f64; 3] = [10_000.0, 25_000.0, 50_000.0];
 
+/// Decimal places of `spread_bps`
+const SPREAD_BPS_DP: u32 = 8;
+
+/// Decimal places of `microprice`
+const MICROPRICE_DP: u32 = 8;
+
+/// Decimal places of the depth stats averages and imbalance
+const DEPTH_STATS_DP: u32 = 8;
+
 /// Scaling factor for prices (fixed at 100)
 const PRICE_SCALE: i32 = 100;
 
@@ -36,7 +51,7 @@ pub fn calculate_metrics(order_book: &OrderBook) -> Option<OrderBookMetrics> {
     let best_bid = order_book.best_bid()?;
     let best_ask = order_book.best_ask()?;
 
-    // Calculate spread in basis points: ((ask - bid) / bid) * 10000
+    // Calculate spread in basis points: ((ask - bid) / mid) * 10000
     let spread_bps = calculate_spread_bps(*best_bid, *best_ask)?;
 
     // Get top 20 levels for volume calculations
@@ -49,19 +64,14 @@ pub fn calculate_metrics(order_book: &OrderBook) -> Option<OrderBookMetrics> {
 
     let top_asks: Vec<(&Decimal, &Decimal)> = order_book.asks.iter().take(TOP_LEVELS).collect();
 
-    // Calculate total volumes
-    let bid_volume = top_bids
-        .iter()
-        .map(|(_, qty)| qty.to_f64().unwrap_or(0.0))
-        .sum::<f64>();
-
-    let ask_volume = top_asks
-        .iter()
-        .map(|(_, qty)| qty.to_f64().unwrap_or(0.0))
-        .sum::<f64>();
+    // Calculate total volumes (exact, for the microprice)
+    let bid_total: Decimal = top_bids.iter().map(|(_, qty)| **qty).sum();
+    let ask_total: Decimal = top_asks.iter().map(|(_, qty)| **qty).sum();
+    let bid_volume = bid_total.to_f64().unwrap_or(0.0);
+    let ask_volume = ask_total.to_f64().unwrap_or(0.0);
 
     // Calculate microprice
-    let microprice = calculate_microprice(*best_bid, *best_ask, bid_volume, ask_volume)?;
+    let microprice = calculate_microprice(*best_bid, *best_ask, bid_total, ask_total)?;
 
     // Calculate imbalance ratio
     let imbalance_ratio = if ask_volume > 0.0 {
@@ -80,8 +90,8 @@ pub fn calculate_metrics(order_book: &OrderBook) -> Option<OrderBookMetrics> {
     Some(OrderBookMetrics {
         symbol: order_book.symbol.clone(),
         timestamp: order_book.timestamp,
-        spread_bps,
-        microprice,
+        spread_bps: spread_bps.to_string(),
+        microprice: microprice.to_string(),
         bid_volume,
         ask_volume,
         imbalance_ratio,
@@ -89,46 +99,48 @@ pub fn calculate_metrics(order_book: &OrderBook) -> Option<OrderBookMetrics> {
         best_ask: best_ask.to_string(),
         walls,
         slippage_estimates,
+        freshness: SnapshotFreshness::of(order_book),
     })
 }
 
-/// Calculate spread in basis points
+/// Calculate spread in basis points, exact to [`SPREAD_BPS_DP`] places
 ///
-/// Formula: ((best_ask - best_bid) / best_bid) * 10000
-/// Accuracy: within 0.01 bps
-fn calculate_spread_bps(best_bid: Decimal, best_ask: Decimal) -> Option<f64> {
-    if best_bid.is_zero() {
+/// Formula: ((best_ask - best_bid) / mid) * 10000, with mid = (best_bid + best_ask) / 2
+fn calculate_spread_bps(best_bid: Decimal, best_ask: Decimal) -> Option<Decimal> {
+    let mid = (best_bid + best_ask) / Decimal::TWO;
+    if mid.is_zero() {
         return None;
     }
 
-    let spread = best_ask - best_bid;
-    let spread_ratio = spread / best_bid;
-    let spread_bps = (spread_ratio * Decimal::from(10000))
-        .to_f64()
-        .unwrap_or(0.0);
-
-    Some(spread_bps)
+    let spread_bps = (best_ask - best_bid).checked_mul(Decimal::from(10_000))? / mid;
+    Some(round_half_up(spread_bps, SPREAD_BPS_DP))
 }
 
-/// Calculate microprice (volume-weighted fair price)
+/// Calculate microprice (volume-weighted fair price), exact to [`MICROPRICE_DP`] places
 ///
 /// Formula: (best_bid * ask_vol + best_ask * bid_vol) / (bid_vol + ask_vol)
 fn calculate_microprice(
     best_bid: Decimal,
     best_ask: Decimal,
-    bid_volume: f64,
-    ask_volume: f64,
-) -> Option<f64> {
-    let total_volume = bid_volume + ask_volume;
-    if total_volume == 0.0 {
+    bid_volume: Decimal,
+    ask_volume: Decimal,
+) -> Option<Decimal> {
+    let total_volume = bid_volume.checked_add(ask_volume)?;
+    if total_volume.is_zero() {
         return None;
     }
 
-    let bid_f64 = best_bid.to_f64()?;
-    let ask_f64 = best_ask.to_f64()?;
+    let weighted = best_bid
+        .checked_mul(ask_volume)?
+        .checked_add(best_ask.checked_mul(bid_volume)?)?;
+    Some(round_half_up(weighted / total_volume, MICROPRICE_DP))
+}
 
-    let microprice = (bid_f64 * ask_volume + ask_f64 * bid_volume) / total_volume;
-    Some(microprice)
+/// Rounds half away from zero to `dp` places, without trailing zeros
+fn round_half_up(value: Decimal, dp: u32) -> Decimal {
+    value
+        .round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero)
+        .normalize()
 }
 
 /// Detect walls (levels with qty > 2x median of top 20 levels)
@@ -301,6 +313,63 @@ fn calculate_slippage_for_amount(
     })
 }
 
+/// Estimate the VWAP fill for a market order of `quantity` base units
+///
+/// Walks bids for a sell or asks for a buy, best level first. `target_usd`
+/// is the order's notional at the touch; `filled_qty` falls short of
+/// `quantity` when the book is too thin.
+pub fn estimate_slippage_for_quantity(
+    order_book: &OrderBook,
+    quantity: f64,
+    is_sell: bool,
+) -> Option<SlippageEstimate> {
+    let (levels, best_price) = if is_sell {
+        (&order_book.bids, order_book.best_bid()?)
+    } else {
+        (&order_book.asks, order_book.best_ask()?)
+    };
+    let best_price = best_price.to_f64().unwrap_or(0.0);
+    if quantity <= 0.0 || best_price <= 0.0 {
+        return None;
+    }
+
+    let iter: Box<dyn Iterator<Item = (&Decimal, &Decimal)>> = if is_sell {
+        Box::new(levels.iter().rev())
+    } else {
+        Box::new(levels.iter())
+    };
+
+    let mut filled_qty = 0.0;
+    let mut filled_usd = 0.0;
+    for (price, qty) in iter {
+        let price_f64 = price.to_f64().unwrap_or(0.0);
+        let qty_f64 = qty.to_f64().unwrap_or(0.0);
+        if price_f64 <= 0.0 || qty_f64 <= 0.0 {
+            continue;
+        }
+
+        let take = qty_f64.min(quantity - filled_qty);
+        filled_qty += take;
+        filled_usd += take * price_f64;
+        if filled_qty >= quantity {
+            break;
+        }
+    }
+
+    if filled_qty <= 0.0 {
+        return None;
+    }
+
+    let avg_price = filled_usd / filled_qty;
+    Some(SlippageEstimate {
+        target_usd: quantity * best_price,
+        avg_price,
+        slippage_bps: ((avg_price - best_price) / best_price).abs() * 10_000.0,
+        filled_qty,
+        filled_usd,
+    })
+}
+
 /// Extract L2 depth with compact integer encoding
 ///
 /// Reduces JSON size by ~40% using scaled integers:
@@ -331,6 +400,291 @@ pub fn extract_depth(order_book: &OrderBook, levels: usize) -> OrderBookDepth {
         qty_scale: QTY_SCALE,
         bids,
         asks,
+        freshness: SnapshotFreshness::of(order_book),
+    }
+}
+
+/// Extract L2 depth with decimal string encoding
+///
+/// Reads the same cached book as [`extract_depth`] but keeps full precision:
+/// prices and quantities are the normalized decimal strings (e.g., "67650.5", "1.234").
+pub fn extract_depth_decimal(order_book: &OrderBook, levels: usize) -> DecimalOrderBookDepth {
+    let format_level = |(price, qty): (&Decimal, &Decimal)| {
+        [price.normalize().to_string(), qty.normalize().to_string()]
+    };
+
+    // Extract top N bid levels (highest first)
+    let bids: Vec<[String; 2]> = order_book
+        .bids
+        .iter()
+        .rev()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(levels)
+        .map(format_level)
+        .collect();
+
+    // Extract top N ask levels (lowest first)
+    let asks: Vec<[String; 2]> = order_book
+        .asks
+        .iter()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(levels)
+        .map(format_level)
+        .collect();
+
+    DecimalOrderBookDepth {
+        symbol: order_book.symbol.clone(),
+        timestamp: order_book.timestamp,
+        bids,
+        asks,
+        freshness: SnapshotFreshness::of(order_book),
+    }
+}
+
+/// Aggregate L2 depth into price buckets of width `bucket`
+///
+/// Bids are grouped by `floor(price / bucket) * bucket` and asks by
+/// `ceil(price / bucket) * bucket`; quantities within a bucket are summed.
+/// At most `max_buckets` buckets are returned per side, nearest the spread first.
+pub fn aggregate_depth(
+    order_book: &OrderBook,
+    bucket: Decimal,
+    max_buckets: usize,
+) -> AggregatedDepth {
+    let bids = aggregate_levels(
+        order_book.bids.iter().rev(),
+        |price| (price / bucket).floor() * bucket,
+        max_buckets,
+    );
+    let asks = aggregate_levels(
+        order_book.asks.iter(),
+        |price| (price / bucket).ceil() * bucket,
+        max_buckets,
+    );
+
+    AggregatedDepth {
+        symbol: order_book.symbol.clone(),
+        timestamp: order_book.timestamp,
+        price_bucket: bucket.normalize().to_string(),
+        bids,
+        asks,
+    }
+}
+
+/// Sum consecutive levels that map to the same bucket price
+///
+/// `levels` must be ordered from the best price outwards so buckets come out in
+/// the same order and iteration can stop once `max_buckets` are complete.
+fn aggregate_levels<'a>(
+    levels: impl Iterator<Item = (&'a Decimal, &'a Decimal)>,
+    bucket_of: impl Fn(Decimal) -> Decimal,
+    max_buckets: usize,
+) -> Vec<[String; 2]> {
+    let mut buckets: Vec<(Decimal, Decimal)> = Vec::new();
+
+    for (price, qty) in levels.filter(|(_, qty)| !qty.is_zero()) {
+        let bucket_price = bucket_of(*price);
+        match buckets.last_mut() {
+            Some((last_price, total)) if *last_price == bucket_price => *total += *qty,
+            _ => {
+                if buckets.len() == max_buckets {
+                    break;
+                }
+                buckets.push((bucket_price, *qty));
+            }
+        }
+    }
+
+    buckets
+        .into_iter()
+        .map(|(price, qty)| [price.normalize().to_string(), qty.normalize().to_string()])
+        .collect()
+}
+
+/// Find support and resistance walls in the book
+///
+/// Scans the best `scan_levels` levels per side and keeps those whose quantity
+/// exceeds `wall_multiple` times the median quantity of all scanned levels. At
+/// most `max_levels` walls are returned per side, largest first. The volume
+/// profile fields are left empty for the caller to fill in.
+pub fn find_key_levels(
+    order_book: &OrderBook,
+    scan_levels: usize,
+    wall_multiple: Decimal,
+    max_levels: usize,
+) -> KeyLevels {
+    let bids: Vec<(Decimal, Decimal)> = order_book
+        .bids
+        .iter()
+        .rev()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(scan_levels)
+        .map(|(price, qty)| (*price, *qty))
+        .collect();
+    let asks: Vec<(Decimal, Decimal)> = order_book
+        .asks
+        .iter()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(scan_levels)
+        .map(|(price, qty)| (*price, *qty))
+        .collect();
+
+    let mut qtys: Vec<Decimal> = bids.iter().chain(asks.iter()).map(|(_, q)| *q).collect();
+    qtys.sort();
+    let median_qty = match qtys.len() {
+        0 => Decimal::ZERO,
+        n if n % 2 == 0 => (qtys[n / 2 - 1] + qtys[n / 2]) / Decimal::TWO,
+        n => qtys[n / 2],
+    };
+    let threshold = median_qty * wall_multiple;
+
+    let walls = |levels: &[(Decimal, Decimal)]| {
+        let mut walls: Vec<&(Decimal, Decimal)> =
+            levels.iter().filter(|(_, qty)| *qty > threshold).collect();
+        // Largest first; ties go to the level nearest the spread
+        walls.sort_by_key(|(_, qty)| std::cmp::Reverse(*qty));
+        walls
+            .into_iter()
+            .take(max_levels)
+            .map(|(price, qty)| KeyLevel {
+                price: price.normalize().to_string(),
+                qty: qty.normalize().to_string(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    KeyLevels {
+        symbol: order_book.symbol.clone(),
+        support: walls(&bids),
+        resistance: walls(&asks),
+        median_qty: median_qty.normalize().to_string(),
+        wall_multiple: wall_multiple.to_f64().unwrap_or_default(),
+        poc: None,
+        vah: None,
+        val: None,
+        profile_note: None,
+    }
+}
+
+/// Find gaps in the book wider than `gap_multiple` times the median level spacing
+///
+/// Scans the best `scan_levels` levels per side and measures the price
+/// distance between each pair of adjacent levels. The median distance across
+/// both sides is the typical spacing; pairs further apart than
+/// `gap_multiple` times it are reported, at most `max_gaps` per side, nearest
+/// the spread first (where a market order would reach them soonest).
+pub fn find_book_gaps(
+    order_book: &OrderBook,
+    scan_levels: usize,
+    gap_multiple: Decimal,
+    max_gaps: usize,
+) -> BookGaps {
+    let bids: Vec<(Decimal, Decimal)> = order_book
+        .bids
+        .iter()
+        .rev()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(scan_levels)
+        .map(|(price, qty)| (*price, *qty))
+        .collect();
+    let asks: Vec<(Decimal, Decimal)> = order_book
+        .asks
+        .iter()
+        .filter(|(_, qty)| !qty.is_zero())
+        .take(scan_levels)
+        .map(|(price, qty)| (*price, *qty))
+        .collect();
+
+    let spacing = |levels: &[(Decimal, Decimal)]| {
+        levels
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0).abs())
+            .collect::<Vec<Decimal>>()
+    };
+    let mut spacings: Vec<Decimal> = spacing(&bids).into_iter().chain(spacing(&asks)).collect();
+    spacings.sort();
+    let median_spacing = match spacings.len() {
+        0 => Decimal::ZERO,
+        n if n % 2 == 0 => (spacings[n / 2 - 1] + spacings[n / 2]) / Decimal::TWO,
+        n => spacings[n / 2],
+    };
+    let threshold = median_spacing * gap_multiple;
+
+    let gaps = |levels: &[(Decimal, Decimal)]| {
+        if median_spacing.is_zero() {
+            return Vec::new();
+        }
+        levels
+            .windows(2)
+            .filter_map(|pair| {
+                let ((from_price, from_qty), (to_price, to_qty)) = (pair[0], pair[1]);
+                let width = (to_price - from_price).abs();
+                (width > threshold).then(|| BookGap {
+                    from_price: from_price.normalize().to_string(),
+                    to_price: to_price.normalize().to_string(),
+                    width: width.normalize().to_string(),
+                    spacing_multiple: (width / median_spacing)
+                        .round_dp(2)
+                        .to_f64()
+                        .unwrap_or_default(),
+                    from_qty: from_qty.normalize().to_string(),
+                    to_qty: to_qty.normalize().to_string(),
+                })
+            })
+            .take(max_gaps)
+            .collect::<Vec<_>>()
+    };
+
+    BookGaps {
+        symbol: order_book.symbol.clone(),
+        bid_gaps: gaps(&bids),
+        ask_gaps: gaps(&asks),
+        median_spacing: median_spacing.normalize().to_string(),
+        gap_multiple: gap_multiple.to_f64().unwrap_or_default(),
+        freshness: SnapshotFreshness::of(order_book),
+    }
+}
+
+/// Sum volume and notional over the best `levels` levels per side
+///
+/// The weighted-average prices are each side's notional divided by its
+/// volume. The imbalance is the volume difference over the total, so it
+/// reads 1 when only bids rest in the window and -1 when only asks do.
+pub fn calculate_depth_stats(order_book: &OrderBook, levels: usize) -> DepthStats {
+    let side = |book_levels: &mut dyn Iterator<Item = (&Decimal, &Decimal)>| {
+        book_levels
+            .filter(|(_, qty)| !qty.is_zero())
+            .take(levels)
+            .fold(
+                (0, Decimal::ZERO, Decimal::ZERO),
+                |(count, volume, notional), (price, qty)| {
+                    (count + 1, volume + *qty, notional + *price * *qty)
+                },
+            )
+    };
+    let (bid_levels, bid_volume, bid_notional) = side(&mut order_book.bids.iter().rev());
+    let (ask_levels, ask_volume, ask_notional) = side(&mut order_book.asks.iter());
+
+    let vwap = |volume: Decimal, notional: Decimal| {
+        (!volume.is_zero()).then(|| round_half_up(notional / volume, DEPTH_STATS_DP).to_string())
+    };
+    let total_volume = bid_volume + ask_volume;
+
+    DepthStats {
+        symbol: order_book.symbol.clone(),
+        levels,
+        bid_levels,
+        ask_levels,
+        bid_volume: bid_volume.normalize().to_string(),
+        ask_volume: ask_volume.normalize().to_string(),
+        bid_notional: bid_notional.normalize().to_string(),
+        ask_notional: ask_notional.normalize().to_string(),
+        bid_vwap: vwap(bid_volume, bid_notional),
+        ask_vwap: vwap(ask_volume, ask_notional),
+        imbalance: (!total_volume.is_zero()).then(|| {
+            round_half_up((bid_volume - ask_volume) / total_volume, DEPTH_STATS_DP).to_string()
+        }),
+        freshness: SnapshotFreshness::of(order_book),
     }
 }
 
@@ -352,6 +706,7 @@ fn encode_level(price: Decimal, qty: Decimal) -> Option<[i64; 2]> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orderbook::types::STALE_SNAPSHOT_THRESHOLD_MS;
     use std::str::FromStr;
 
     #[test]
@@ -360,20 +715,60 @@ mod tests {
         let ask = Decimal::from_str("67651.00").unwrap();
 
         let spread = calculate_spread_bps(bid, ask).unwrap();
-        // Expected: ((67651 - 67650) / 67650) * 10000 = 0.1478 bps
-        assert!((spread - 0.1478).abs() < 0.01);
+        // Expected: ((67651 - 67650) / 67650.5) * 10000 = 0.1478185674...
+        assert_eq!(spread.to_string(), "0.14781857");
     }
 
     #[test]
     fn test_microprice_calculation() {
         let bid = Decimal::from_str("67650.00").unwrap();
         let ask = Decimal::from_str("67651.00").unwrap();
-        let bid_volume = 10.0;
-        let ask_volume = 15.0;
+        let bid_volume = Decimal::from(10);
+        let ask_volume = Decimal::from(15);
 
         let microprice = calculate_microprice(bid, ask, bid_volume, ask_volume).unwrap();
         // Expected: (67650 * 15 + 67651 * 10) / 25 = 67650.4
-        assert!((microprice - 67650.4).abs() < 0.01);
+        assert_eq!(microprice.to_string(), "67650.4");
+    }
+
+    #[test]
+    fn test_high_price_spread_and_microprice_are_exact() {
+        // One-tick spread on BTC at ~95k: f64 arithmetic gives
+        // 0.0010526301948879779 bps and 95000.12666666666, not the exact values
+        let mut ob = OrderBook::new("BTCUSDT".to_string());
+        ob.update_bid(
+            Decimal::from_str("95000.12").unwrap(),
+            Decimal::from_str("1.5").unwrap(),
+        );
+        ob.update_ask(
+            Decimal::from_str("95000.13").unwrap(),
+            Decimal::from_str("0.75").unwrap(),
+        );
+
+        let metrics = calculate_metrics(&ob).unwrap();
+
+        // 0.01 / 95000.125 * 10000 = 0.00105263019390...
+        assert_eq!(metrics.spread_bps, "0.00105263");
+        // (95000.12 * 0.75 + 95000.13 * 1.5) / 2.25 = 95000.1266666...
+        assert_eq!(metrics.microprice, "95000.12666667");
+
+        // Balanced queues put the microprice exactly at mid
+        ob.update_ask(
+            Decimal::from_str("95000.13").unwrap(),
+            Decimal::from_str("1.5").unwrap(),
+        );
+        let metrics = calculate_metrics(&ob).unwrap();
+        assert_eq!(metrics.microprice, "95000.125");
+
+        // Wide spread on a high-priced book: 95000.12 / 95100.12
+        ob.update_ask(Decimal::from_str("95000.13").unwrap(), Decimal::ZERO);
+        ob.update_ask(
+            Decimal::from_str("95100.12").unwrap(),
+            Decimal::from_str("1.5").unwrap(),
+        );
+        let metrics = calculate_metrics(&ob).unwrap();
+        // 100 / 95050.12 * 10000 = 10.5207652552...
+        assert_eq!(metrics.spread_bps, "10.52076526");
     }
 
     #[test]
@@ -386,6 +781,46 @@ mod tests {
         assert_eq!(encoded[1], 123400); // qty * 100000
     }
 
+    #[test]
+    fn test_decimal_depth_extraction() {
+        let mut book = OrderBook::new("BTCUSDT".to_string());
+        book.update_bid(
+            Decimal::from_str("67650.00").unwrap(),
+            Decimal::from_str("1.234").unwrap(),
+        );
+        book.update_bid(
+            Decimal::from_str("67649.50").unwrap(),
+            Decimal::from_str("0.5").unwrap(),
+        );
+        book.update_ask(
+            Decimal::from_str("67651.25").unwrap(),
+            Decimal::from_str("2.00").unwrap(),
+        );
+
+        let depth = extract_depth_decimal(&book, 1);
+        assert_eq!(depth.bids, vec![["67650".to_string(), "1.234".to_string()]]);
+        assert_eq!(depth.asks, vec![["67651.25".to_string(), "2".to_string()]]);
+    }
+
+    #[test]
+    fn test_depth_reports_snapshot_freshness() {
+        let mut book = OrderBook::new("BTCUSDT".to_string());
+        book.last_update_id = 42;
+        book.update_bid(
+            Decimal::from_str("67650.00").unwrap(),
+            Decimal::from_str("1.0").unwrap(),
+        );
+
+        let fresh = extract_depth(&book, 5).freshness;
+        assert_eq!(fresh.last_update_id, 42);
+        assert!(!fresh.stale);
+
+        book.last_applied_ms -= STALE_SNAPSHOT_THRESHOLD_MS + 1000;
+        let stale = extract_depth_decimal(&book, 5).freshness;
+        assert!(stale.snapshot_age_ms > STALE_SNAPSHOT_THRESHOLD_MS);
+        assert!(stale.stale);
+    }
+
     #[test]
     fn test_walls_detection() {
         // Create test data with owned values
@@ -418,4 +853,236 @@ mod tests {
         let walls = detect_walls(&bids, &asks);
         assert!(!walls.bids.is_empty(), "Should detect bid wall");
     }
+
+    #[test]
+    fn test_aggregate_depth_buckets() {
+        let mut order_book = OrderBook::new("BTCUSDT".to_string());
+        for (price, qty) in [
+            ("67651", "1"),
+            ("67649.5", "2"),
+            ("67641", "0.5"),
+            ("67635", "3"),
+        ] {
+            order_book.update_bid(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+        for (price, qty) in [("67652", "1.5"), ("67659", "0.5"), ("67661", "4")] {
+            order_book.update_ask(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+
+        let depth = aggregate_depth(&order_book, Decimal::from(10), 2);
+
+        assert_eq!(depth.price_bucket, "10");
+        assert_eq!(
+            depth.bids,
+            vec![
+                ["67650".to_string(), "1".to_string()],
+                ["67640".to_string(), "2.5".to_string()],
+            ]
+        );
+        assert_eq!(
+            depth.asks,
+            vec![
+                ["67660".to_string(), "2".to_string()],
+                ["67670".to_string(), "4".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_estimate_slippage_for_quantity() {
+        let mut order_book = OrderBook::new("BTCUSDT".to_string());
+        for (price, qty) in [("100", "1"), ("99", "2")] {
+            order_book.update_bid(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+        for (price, qty) in [("101", "1"), ("102", "1")] {
+            order_book.update_ask(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+
+        // Buy 1.5: 1 @ 101 + 0.5 @ 102 = 152 / 1.5
+        let buy = estimate_slippage_for_quantity(&order_book, 1.5, false).unwrap();
+        assert!((buy.avg_price - 152.0 / 1.5).abs() < 1e-9);
+        assert!((buy.slippage_bps - (152.0 / 1.5 - 101.0) / 101.0 * 10_000.0).abs() < 1e-6);
+        assert_eq!(buy.filled_qty, 1.5);
+
+        // Sell more than the book holds: fills only the 3 available
+        let sell = estimate_slippage_for_quantity(&order_book, 5.0, true).unwrap();
+        assert_eq!(sell.filled_qty, 3.0);
+        assert!((sell.filled_usd - 298.0).abs() < 1e-9);
+
+        assert!(estimate_slippage_for_quantity(&order_book, 0.0, false).is_none());
+    }
+
+    #[test]
+    fn test_find_book_gaps() {
+        let mut order_book = OrderBook::new("BTCUSDT".to_string());
+        // Bids every 0.1 with a deliberate 1.0 hole between 99.7 and 98.7
+        for (price, qty) in [
+            ("100.0", "1"),
+            ("99.9", "2"),
+            ("99.8", "1"),
+            ("99.7", "4"),
+            ("98.7", "9"),
+            ("98.6", "1"),
+        ] {
+            order_book.update_bid(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+        // Asks every 0.1 with a 0.5 hole between 100.3 and 100.8
+        for (price, qty) in [
+            ("100.1", "1"),
+            ("100.2", "1"),
+            ("100.3", "3"),
+            ("100.8", "6"),
+            ("100.9", "1"),
+        ] {
+            order_book.update_ask(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+
+        let gaps = find_book_gaps(&order_book, 100, Decimal::from(3), 5);
+
+        assert_eq!(gaps.median_spacing, "0.1");
+        assert_eq!(gaps.gap_multiple, 3.0);
+        assert_eq!(
+            gaps.bid_gaps,
+            [BookGap {
+                from_price: "99.7".to_string(),
+                to_price: "98.7".to_string(),
+                width: "1".to_string(),
+                spacing_multiple: 10.0,
+                from_qty: "4".to_string(),
+                to_qty: "9".to_string(),
+            }]
+        );
+        assert_eq!(gaps.ask_gaps.len(), 1);
+        assert_eq!(gaps.ask_gaps[0].from_price, "100.3");
+        assert_eq!(gaps.ask_gaps[0].to_price, "100.8");
+        assert_eq!(gaps.ask_gaps[0].spacing_multiple, 5.0);
+
+        // A higher multiple keeps only the wider bid-side hole
+        let wide = find_book_gaps(&order_book, 100, Decimal::from(6), 5);
+        assert_eq!(wide.bid_gaps.len(), 1);
+        assert!(wide.ask_gaps.is_empty());
+
+        // Scanning only the top three levels per side sees no holes
+        let shallow = find_book_gaps(&order_book, 3, Decimal::from(3), 5);
+        assert!(shallow.bid_gaps.is_empty() && shallow.ask_gaps.is_empty());
+
+        // An empty book has no spacing and no gaps
+        let empty = find_book_gaps(&OrderBook::new("BTCUSDT".to_string()), 100, Decimal::TWO, 5);
+        assert_eq!(empty.median_spacing, "0");
+        assert!(empty.bid_gaps.is_empty() && empty.ask_gaps.is_empty());
+    }
+
+    #[test]
+    fn test_calculate_depth_stats() {
+        let mut order_book = OrderBook::new("BTCUSDT".to_string());
+        for (price, qty) in [("100", "1"), ("99", "2"), ("98", "3"), ("90", "50")] {
+            order_book.update_bid(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+        for (price, qty) in [("101", "1"), ("102", "1"), ("103", "2"), ("110", "50")] {
+            order_book.update_ask(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+
+        // Top three levels: bids 100x1 + 99x2 + 98x3, asks 101x1 + 102x1 + 103x2
+        let stats = calculate_depth_stats(&order_book, 3);
+        assert_eq!((stats.bid_levels, stats.ask_levels), (3, 3));
+        assert_eq!(stats.bid_volume, "6");
+        assert_eq!(stats.ask_volume, "4");
+        assert_eq!(stats.bid_notional, "592");
+        assert_eq!(stats.ask_notional, "409");
+        assert_eq!(stats.bid_vwap.as_deref(), Some("98.66666667"));
+        assert_eq!(stats.ask_vwap.as_deref(), Some("102.25"));
+        assert_eq!(stats.imbalance.as_deref(), Some("0.2"));
+
+        // A window wider than the book sums every level, including the far walls
+        let all = calculate_depth_stats(&order_book, 20);
+        assert_eq!(all.levels, 20);
+        assert_eq!((all.bid_levels, all.ask_levels), (4, 4));
+        assert_eq!(all.bid_volume, "56");
+        assert_eq!(all.ask_notional, "5909");
+        assert_eq!(all.imbalance.as_deref(), Some("0.01818182"));
+
+        // One-sided and empty books
+        let mut bids_only = OrderBook::new("BTCUSDT".to_string());
+        bids_only.update_bid(Decimal::from(100), Decimal::ONE);
+        let stats = calculate_depth_stats(&bids_only, 20);
+        assert_eq!(stats.ask_vwap, None);
+        assert_eq!(stats.imbalance.as_deref(), Some("1"));
+
+        let empty = calculate_depth_stats(&OrderBook::new("BTCUSDT".to_string()), 20);
+        assert_eq!(empty.bid_volume, "0");
+        assert_eq!((empty.bid_vwap, empty.imbalance), (None, None));
+    }
+
+    #[test]
+    fn test_find_key_levels() {
+        let mut order_book = OrderBook::new("BTCUSDT".to_string());
+        for (price, qty) in [
+            ("100.0", "1"),
+            ("99.9", "12"),
+            ("99.8", "1"),
+            ("99.7", "5"),
+            ("99.6", "20"),
+        ] {
+            order_book.update_bid(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+        for (price, qty) in [
+            ("100.1", "1"),
+            ("100.2", "1"),
+            ("100.3", "8"),
+            ("100.4", "1"),
+        ] {
+            order_book.update_ask(
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(qty).unwrap(),
+            );
+        }
+
+        // Median of [1, 1, 1, 1, 1, 5, 8, 12, 20] is 1, so walls are > 3
+        let levels = find_key_levels(&order_book, 100, Decimal::from(3), 2);
+
+        assert_eq!(levels.median_qty, "1");
+        let prices = |levels: &[KeyLevel]| {
+            levels
+                .iter()
+                .map(|l| l.price.clone())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(prices(&levels.support), ["99.6", "99.9"]);
+        assert_eq!(prices(&levels.resistance), ["100.3"]);
+        assert!(levels.poc.is_none());
+
+        // A higher multiple and a shallower scan both drop walls
+        let levels = find_key_levels(&order_book, 100, Decimal::from(10), 5);
+        assert_eq!(prices(&levels.support), ["99.6", "99.9"]);
+        assert!(levels.resistance.is_empty());
+        let levels = find_key_levels(&order_book, 2, Decimal::from(3), 5);
+        assert_eq!(prices(&levels.support), ["99.9"]);
+    }
 }
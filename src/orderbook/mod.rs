@@ -4,11 +4,12 @@
 //! - L1 aggregated metrics (spread, microprice, imbalance, walls, slippage)
 //! - L2 depth data with compact integer encoding (token-efficient)
 //! - Service health monitoring for data freshness validation
+//! - Support/resistance levels from book walls
 //!
 //! Architecture: WebSocket + Local L2 Cache with REST API fallback
 //! - Sub-100ms latency for warm requests via local cache
 //! - Lazy initialization: subscribe on first request per symbol
-//! - Up to 20 concurrent symbols with client-side rate limiting
+//! - Up to 20 concurrent symbols (`ORDERBOOK_MAX_SYMBOLS`) with LRU eviction and client-side rate limiting
 
 #[cfg(feature = "orderbook")]
 pub mod types;
@@ -41,4 +42,7 @@ pub use types::{
 pub use manager::OrderBookManager;
 
 #[cfg(feature = "orderbook")]
-pub use tools::{get_orderbook_depth, get_orderbook_health, get_orderbook_metrics};
+pub use tools::{
+    get_aggregated_depth, get_key_levels, get_orderbook_depth, get_orderbook_health,
+    get_orderbook_metrics,
+};
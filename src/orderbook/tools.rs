@@ -1,13 +1,22 @@
 //! MCP tool handlers for order book depth analysis
 //!
-//! Provides three tools following progressive disclosure strategy:
+//! Provides seven tools following progressive disclosure strategy:
 //! - get_orderbook_metrics: L1 aggregated metrics (15% token cost)
-//! - get_orderbook_depth: L2 depth with compact encoding (50-100% token cost)
+//! - get_orderbook_depth: L2 depth with compact or decimal encoding (50-100% token cost)
+//! - get_aggregated_depth: L2 depth grouped into price buckets
+//! - get_key_levels: Support/resistance walls (the router adds the volume profile overlay)
+//! - get_book_gaps: Thin-liquidity gaps between adjacent levels in the current book
+//! - get_depth_stats: Volume, notional, weighted prices and imbalance over the top levels
 //! - get_orderbook_health: Service health monitoring
 
-use crate::orderbook::manager::{ManagerError, OrderBookManager};
+use crate::orderbook::manager::{ManagerError, OrderBookManager, MAX_CONCURRENT_SYMBOLS};
 use crate::orderbook::metrics;
-use crate::orderbook::types::{OrderBookDepth, OrderBookHealth, OrderBookMetrics};
+use crate::orderbook::types::{
+    AggregatedDepth, BookGaps, DepthStats, KeyLevels, OrderBook, OrderBookDepthResponse,
+    OrderBookHealth, OrderBookMetrics,
+};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
@@ -19,7 +28,7 @@ pub enum OrderBookToolError {
     #[error("Symbol not found: {0}")]
     SymbolNotFound(String),
 
-    #[error("Symbol limit reached: cannot track more than 20 symbols")]
+    #[error("Symbol limit reached: cannot track more than {MAX_CONCURRENT_SYMBOLS} symbols")]
     SymbolLimitReached,
 
     #[error("Rate limit exceeded: {0}")]
@@ -31,6 +40,28 @@ pub enum OrderBookToolError {
     #[error("Invalid levels parameter: {0}. Must be between 1 and 100")]
     InvalidLevels(usize),
 
+    #[error("Invalid max_buckets parameter: {0}. Must be between 1 and {MAX_BUCKETS}")]
+    InvalidMaxBuckets(usize),
+
+    #[error("Invalid price_bucket: {0}")]
+    InvalidPriceBucket(String),
+
+    #[error(
+        "Invalid wall_multiple: {0}. Must be between {MIN_WALL_MULTIPLE} and {MAX_WALL_MULTIPLE}"
+    )]
+    InvalidWallMultiple(f64),
+
+    #[error("Invalid max_levels parameter: {0}. Must be between 1 and {MAX_KEY_LEVELS}")]
+    InvalidMaxLevels(usize),
+
+    #[error(
+        "Invalid gap_multiple: {0}. Must be between {MIN_GAP_MULTIPLE} and {MAX_GAP_MULTIPLE}"
+    )]
+    InvalidGapMultiple(f64),
+
+    #[error("Invalid max_gaps parameter: {0}. Must be between 1 and {MAX_BOOK_GAPS}")]
+    InvalidMaxGaps(usize),
+
     #[error("Failed to calculate metrics: {0}")]
     MetricsCalculationFailed(String),
 }
@@ -49,6 +80,9 @@ impl From<ManagerError> for OrderBookToolError {
                     message: source.to_string(),
                 }
             }
+            ManagerError::RefreshTooSoon { .. } => {
+                OrderBookToolError::RateLimitExceeded(err.to_string())
+            }
             ManagerError::RestApiError(e) | ManagerError::WebSocketError(e) => {
                 OrderBookToolError::InitializationFailed {
                     symbol: "unknown".to_string(),
@@ -59,12 +93,44 @@ impl From<ManagerError> for OrderBookToolError {
     }
 }
 
+impl From<OrderBookToolError> for rmcp::ErrorData {
+    fn from(err: OrderBookToolError) -> Self {
+        match err {
+            OrderBookToolError::SymbolNotFound(_)
+            | OrderBookToolError::InvalidLevels(_)
+            | OrderBookToolError::InvalidMaxBuckets(_)
+            | OrderBookToolError::InvalidPriceBucket(_)
+            | OrderBookToolError::InvalidWallMultiple(_)
+            | OrderBookToolError::InvalidMaxLevels(_)
+            | OrderBookToolError::InvalidGapMultiple(_)
+            | OrderBookToolError::InvalidMaxGaps(_) => {
+                rmcp::ErrorData::invalid_params(err.to_string(), None)
+            }
+            OrderBookToolError::RateLimitExceeded(_) => {
+                rmcp::ErrorData::new(rmcp::model::ErrorCode(-32001), err.to_string(), None)
+            }
+            OrderBookToolError::SymbolLimitReached
+            | OrderBookToolError::InitializationFailed { .. }
+            | OrderBookToolError::MetricsCalculationFailed(_) => {
+                rmcp::ErrorData::internal_error(err.to_string(), None)
+            }
+        }
+    }
+}
+
 /// Parameters for get_orderbook_metrics tool
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct GetOrderBookMetricsParams {
     /// Trading pair symbol (e.g., "BTCUSDT")
     #[schemars(description = "Trading pair symbol (e.g., 'BTCUSDT', 'ETHUSDT')")]
     pub symbol: String,
+
+    /// Bypass the cache and rebuild the book from a fresh REST snapshot
+    #[schemars(
+        description = "Bypass the cache and rebuild the local book from a fresh REST snapshot (adds 2-3s latency; max one forced refresh per symbol per second). Default: false"
+    )]
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 /// Parameters for get_orderbook_depth tool
@@ -80,12 +146,211 @@ pub struct GetOrderBookDepthParams {
     )]
     #[serde(default = "default_levels")]
     pub levels: usize,
+
+    /// Level encoding: "compact" (scaled integers, default) or "decimal" (strings)
+    #[schemars(
+        description = "Level encoding: 'compact' (scaled integers, smallest output, default) or 'decimal' (human-readable price/qty strings)"
+    )]
+    #[serde(default)]
+    pub encoding: DepthEncoding,
+
+    /// Bypass the cache and rebuild the book from a fresh REST snapshot
+    #[schemars(
+        description = "Bypass the cache and rebuild the local book from a fresh REST snapshot (adds 2-3s latency; max one forced refresh per symbol per second). Default: false"
+    )]
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 fn default_levels() -> usize {
     20
 }
 
+/// Encoding for L2 depth levels
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DepthEncoding {
+    /// Scaled integers with price_scale/qty_scale
+    #[default]
+    Compact,
+    /// Decimal strings
+    Decimal,
+}
+
+/// Maximum buckets returned per side by get_aggregated_depth
+pub const MAX_BUCKETS: usize = 100;
+
+/// Largest allowed bucket width as a fraction of the mid price (10%)
+const MAX_BUCKET_FRACTION: Decimal = Decimal::from_parts(1, 0, 0, false, 1);
+
+/// Smallest allowed bucket width as a fraction of the mid price (0.1 bps)
+const MIN_BUCKET_FRACTION: Decimal = Decimal::from_parts(1, 0, 0, false, 5);
+
+/// Parameters for get_aggregated_depth tool
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetAggregatedDepthParams {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    #[schemars(description = "Trading pair symbol (e.g., 'BTCUSDT', 'ETHUSDT')")]
+    pub symbol: String,
+
+    /// Bucket width in quote currency (e.g., 10 groups BTCUSDT levels into $10 bands)
+    #[schemars(
+        description = "Bucket width in quote currency (e.g., 10 to group all levels within $10). Must be between 0.001% and 10% of the mid price"
+    )]
+    pub price_bucket: f64,
+
+    /// Maximum buckets per side (1-100, default: 20)
+    #[schemars(description = "Maximum buckets to return per side (1-100). Default: 20")]
+    #[serde(default = "default_levels")]
+    pub max_buckets: usize,
+}
+
+/// Default multiple of the median level size that makes a level a wall
+pub const DEFAULT_WALL_MULTIPLE: f64 = 3.0;
+
+/// Smallest accepted wall multiple (anything lower flags ordinary levels)
+pub const MIN_WALL_MULTIPLE: f64 = 1.5;
+
+/// Largest accepted wall multiple
+pub const MAX_WALL_MULTIPLE: f64 = 100.0;
+
+/// Maximum support or resistance levels returned per side by get_key_levels
+pub const MAX_KEY_LEVELS: usize = 20;
+
+/// Levels scanned per side when looking for walls
+const KEY_LEVEL_SCAN_DEPTH: usize = 100;
+
+/// Parameters for get_key_levels tool
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetKeyLevelsParams {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    #[schemars(description = "Trading pair symbol (e.g., 'BTCUSDT', 'ETHUSDT')")]
+    pub symbol: String,
+
+    /// Multiple of the median level size a level must exceed to count as a wall (default: 3)
+    #[schemars(
+        description = "A level counts as a wall when its quantity exceeds this multiple of the median level size across the top 100 levels per side (1.5-100). Default: 3"
+    )]
+    #[serde(default)]
+    pub wall_multiple: Option<f64>,
+
+    /// Maximum levels per side (1-20, default: 5)
+    #[schemars(description = "Maximum support and resistance levels per side (1-20). Default: 5")]
+    #[serde(default = "default_max_key_levels")]
+    pub max_levels: usize,
+
+    /// Price bin width for the volume profile overlay (orderbook_analytics only)
+    #[schemars(
+        description = "Price bin width for the volume profile overlay (e.g., '10'). Defaults to the symbol's tickSize. Ignored without the orderbook_analytics feature"
+    )]
+    #[serde(default)]
+    pub tick_size: Option<String>,
+}
+
+fn default_max_key_levels() -> usize {
+    5
+}
+
+/// Validate the wall multiple, defaulting to [`DEFAULT_WALL_MULTIPLE`]
+fn resolve_wall_multiple(wall_multiple: Option<f64>) -> Result<Decimal, OrderBookToolError> {
+    let multiple = wall_multiple.unwrap_or(DEFAULT_WALL_MULTIPLE);
+    if !(MIN_WALL_MULTIPLE..=MAX_WALL_MULTIPLE).contains(&multiple) {
+        return Err(OrderBookToolError::InvalidWallMultiple(multiple));
+    }
+    Decimal::from_f64(multiple).ok_or(OrderBookToolError::InvalidWallMultiple(multiple))
+}
+
+/// Default multiple of the median level spacing that makes a gap
+pub const DEFAULT_GAP_MULTIPLE: f64 = 3.0;
+
+/// Smallest accepted gap multiple (anything lower flags ordinary spacing)
+pub const MIN_GAP_MULTIPLE: f64 = 1.5;
+
+/// Largest accepted gap multiple
+pub const MAX_GAP_MULTIPLE: f64 = 1000.0;
+
+/// Maximum gaps returned per side by get_book_gaps
+pub const MAX_BOOK_GAPS: usize = 20;
+
+/// Levels scanned per side when looking for gaps
+const BOOK_GAP_SCAN_DEPTH: usize = 100;
+
+/// Parameters for get_book_gaps tool
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetBookGapsParams {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    #[schemars(description = "Trading pair symbol (e.g., 'BTCUSDT', 'ETHUSDT')")]
+    pub symbol: String,
+
+    /// Multiple of the median level spacing a gap must exceed (default: 3)
+    #[schemars(
+        description = "Report adjacent levels whose price distance exceeds this multiple of the median spacing between levels across the top 100 levels per side (1.5-1000). Default: 3"
+    )]
+    #[serde(default)]
+    pub gap_multiple: Option<f64>,
+
+    /// Maximum gaps per side (1-20, default: 5)
+    #[schemars(description = "Maximum gaps per side, nearest the spread first (1-20). Default: 5")]
+    #[serde(default = "default_max_book_gaps")]
+    pub max_gaps: usize,
+}
+
+fn default_max_book_gaps() -> usize {
+    5
+}
+
+/// Validate the gap multiple, defaulting to [`DEFAULT_GAP_MULTIPLE`]
+fn resolve_gap_multiple(gap_multiple: Option<f64>) -> Result<Decimal, OrderBookToolError> {
+    let multiple = gap_multiple.unwrap_or(DEFAULT_GAP_MULTIPLE);
+    if !(MIN_GAP_MULTIPLE..=MAX_GAP_MULTIPLE).contains(&multiple) {
+        return Err(OrderBookToolError::InvalidGapMultiple(multiple));
+    }
+    Decimal::from_f64(multiple).ok_or(OrderBookToolError::InvalidGapMultiple(multiple))
+}
+
+/// Validate a bucket width against the current mid price
+fn validate_price_bucket(
+    price_bucket: f64,
+    mid_price: Decimal,
+) -> Result<Decimal, OrderBookToolError> {
+    let bucket = Decimal::from_f64(price_bucket)
+        .filter(|b| b.is_sign_positive() && !b.is_zero())
+        .ok_or_else(|| {
+            OrderBookToolError::InvalidPriceBucket(format!(
+                "{} (must be a positive number)",
+                price_bucket
+            ))
+        })?;
+
+    let min = mid_price * MIN_BUCKET_FRACTION;
+    let max = mid_price * MAX_BUCKET_FRACTION;
+    if bucket < min || bucket > max {
+        return Err(OrderBookToolError::InvalidPriceBucket(format!(
+            "{} (must be between {} and {} for mid price {})",
+            bucket.normalize(),
+            min.normalize(),
+            max.normalize(),
+            mid_price.normalize()
+        )));
+    }
+
+    Ok(bucket)
+}
+
+/// Read the cached book, or rebuild it from a fresh snapshot when `force_refresh` is set
+async fn fetch_order_book(
+    manager: &OrderBookManager,
+    symbol: &str,
+    force_refresh: bool,
+) -> Result<OrderBook, OrderBookToolError> {
+    let order_book = if force_refresh {
+        manager.refresh_order_book(symbol).await?
+    } else {
+        manager.get_order_book(symbol).await?
+    };
+    Ok(order_book)
+}
+
 /// Get L1 aggregated metrics for quick spread assessment
 ///
 /// Provides lightweight analysis (15% token cost vs L2-full):
@@ -95,8 +360,9 @@ fn default_levels() -> usize {
 /// - Wall detection (large levels)
 /// - VWAP-based slippage estimates
 ///
-/// First request: 2-3s (lazy initialization)
-/// Subsequent requests: <200ms (cached data)
+/// First request: one REST snapshot, returned with `warming: true`
+/// Subsequent requests: <200ms (cached data, live once the stream is applied)
+/// With `force_refresh`: 2-3s (fresh REST snapshot)
 pub async fn get_orderbook_metrics(
     manager: Arc<OrderBookManager>,
     params: GetOrderBookMetricsParams,
@@ -104,8 +370,16 @@ pub async fn get_orderbook_metrics(
     let symbol_upper = params.symbol.to_uppercase();
     info!(symbol = %symbol_upper, "Fetching order book metrics");
 
-    // Get order book (lazy initialization on first request)
-    let order_book = manager.get_order_book(&symbol_upper).await?;
+    // Reuse metrics computed within ORDERBOOK_METRICS_TTL_MS unless a resnapshot was asked for
+    if !params.force_refresh {
+        if let Some(metrics) = manager.cached_metrics(&symbol_upper).await {
+            debug!(symbol = %symbol_upper, "Serving cached order book metrics");
+            return Ok(metrics);
+        }
+    }
+
+    // Get order book (lazy initialization on first request, or a forced resnapshot)
+    let order_book = fetch_order_book(&manager, &symbol_upper, params.force_refresh).await?;
 
     // Calculate metrics
     let metrics = metrics::calculate_metrics(&order_book).ok_or_else(|| {
@@ -117,28 +391,32 @@ pub async fn get_orderbook_metrics(
 
     debug!(
         symbol = %symbol_upper,
-        spread_bps = metrics.spread_bps,
+        spread_bps = %metrics.spread_bps,
         imbalance_ratio = metrics.imbalance_ratio,
         "Calculated order book metrics"
     );
 
+    manager.cache_metrics(&symbol_upper, &metrics);
     Ok(metrics)
 }
 
-/// Get L2 depth with compact integer encoding
+/// Get L2 depth with compact integer or decimal string encoding
 ///
 /// Token cost: 50% (L2-lite with 20 levels) or 100% (L2-full with 100 levels)
 ///
-/// Compact encoding:
+/// Compact encoding (default):
 /// - price_scale = 100 (e.g., 67650.00 → 6765000)
 /// - qty_scale = 100000 (e.g., 1.234 → 123400)
 ///
-/// First request: 2-3s (lazy initialization)
-/// Subsequent requests: <300ms (cached data)
+/// Decimal encoding returns `["67650.00", "1.234"]` string tuples instead.
+///
+/// First request: one REST snapshot, returned with `warming: true`
+/// Subsequent requests: <300ms (cached data, live once the stream is applied)
+/// With `force_refresh`: 2-3s (fresh REST snapshot)
 pub async fn get_orderbook_depth(
     manager: Arc<OrderBookManager>,
     params: GetOrderBookDepthParams,
-) -> Result<OrderBookDepth, OrderBookToolError> {
+) -> Result<OrderBookDepthResponse, OrderBookToolError> {
     let symbol_upper = params.symbol.to_uppercase();
     let levels = params.levels;
 
@@ -150,30 +428,237 @@ pub async fn get_orderbook_depth(
     info!(
         symbol = %symbol_upper,
         levels,
+        encoding = ?params.encoding,
         "Fetching order book depth"
     );
 
+    // Reuse depth extracted within ORDERBOOK_DEPTH_TTL_MS unless a resnapshot was asked for
+    let variant = format!("{}|{:?}", levels, params.encoding);
+    if !params.force_refresh {
+        if let Some(depth) = manager.cached_depth(&symbol_upper, &variant).await {
+            debug!(symbol = %symbol_upper, "Serving cached order book depth");
+            return Ok(depth);
+        }
+    }
+
+    // Get order book (lazy initialization on first request, or a forced resnapshot)
+    let order_book = fetch_order_book(&manager, &symbol_upper, params.force_refresh).await?;
+
+    let depth = match params.encoding {
+        DepthEncoding::Compact => {
+            let depth = metrics::extract_depth(&order_book, levels);
+            debug!(
+                symbol = %symbol_upper,
+                bid_levels = depth.bids.len(),
+                ask_levels = depth.asks.len(),
+                "Extracted order book depth"
+            );
+            OrderBookDepthResponse::Compact(depth)
+        }
+        DepthEncoding::Decimal => {
+            let depth = metrics::extract_depth_decimal(&order_book, levels);
+            debug!(
+                symbol = %symbol_upper,
+                bid_levels = depth.bids.len(),
+                ask_levels = depth.asks.len(),
+                "Extracted order book depth (decimal)"
+            );
+            OrderBookDepthResponse::Decimal(depth)
+        }
+    };
+
+    manager.cache_depth(&symbol_upper, &variant, &depth);
+    Ok(depth)
+}
+
+/// Get L2 depth aggregated into fixed-width price buckets
+///
+/// Groups the cached book into bands of `price_bucket` (e.g., $10) and sums the
+/// quantity in each band, giving a compact liquidity view instead of raw levels.
+/// The bucket width is validated against the current mid price.
+///
+/// First request: 2-3s (lazy initialization)
+/// Subsequent requests: <300ms (cached data)
+pub async fn get_aggregated_depth(
+    manager: Arc<OrderBookManager>,
+    params: GetAggregatedDepthParams,
+) -> Result<AggregatedDepth, OrderBookToolError> {
+    let symbol_upper = params.symbol.to_uppercase();
+    let max_buckets = params.max_buckets;
+
+    if !(1..=MAX_BUCKETS).contains(&max_buckets) {
+        return Err(OrderBookToolError::InvalidMaxBuckets(max_buckets));
+    }
+
+    info!(
+        symbol = %symbol_upper,
+        price_bucket = params.price_bucket,
+        max_buckets,
+        "Fetching aggregated order book depth"
+    );
+
     // Get order book (lazy initialization on first request)
     let order_book = manager.get_order_book(&symbol_upper).await?;
 
-    // Extract depth with compact encoding
-    let depth = metrics::extract_depth(&order_book, levels);
+    let mid_price = match (order_book.best_bid(), order_book.best_ask()) {
+        (Some(bid), Some(ask)) => (*bid + *ask) / Decimal::TWO,
+        _ => {
+            return Err(OrderBookToolError::MetricsCalculationFailed(format!(
+                "Order book for {} is empty",
+                symbol_upper
+            )))
+        }
+    };
+    let bucket = validate_price_bucket(params.price_bucket, mid_price)?;
+
+    let depth = metrics::aggregate_depth(&order_book, bucket, max_buckets);
 
     debug!(
         symbol = %symbol_upper,
-        bid_levels = depth.bids.len(),
-        ask_levels = depth.asks.len(),
-        "Extracted order book depth"
+        bid_buckets = depth.bids.len(),
+        ask_buckets = depth.asks.len(),
+        "Aggregated order book depth"
     );
 
     Ok(depth)
 }
 
+/// Get support and resistance levels from walls in the cached book
+///
+/// Book-only: the volume profile POC/VAH/VAL overlay needs the
+/// `orderbook_analytics` feature and is added by the tool router.
+///
+/// First request: 2-3s (lazy initialization)
+/// Subsequent requests: <200ms (cached data)
+pub async fn get_key_levels(
+    manager: Arc<OrderBookManager>,
+    params: GetKeyLevelsParams,
+) -> Result<KeyLevels, OrderBookToolError> {
+    let symbol_upper = params.symbol.to_uppercase();
+    let max_levels = params.max_levels;
+
+    if !(1..=MAX_KEY_LEVELS).contains(&max_levels) {
+        return Err(OrderBookToolError::InvalidMaxLevels(max_levels));
+    }
+    let wall_multiple = resolve_wall_multiple(params.wall_multiple)?;
+
+    info!(
+        symbol = %symbol_upper,
+        %wall_multiple,
+        max_levels,
+        "Fetching key levels"
+    );
+
+    // Get order book (lazy initialization on first request)
+    let order_book = manager.get_order_book(&symbol_upper).await?;
+
+    let levels =
+        metrics::find_key_levels(&order_book, KEY_LEVEL_SCAN_DEPTH, wall_multiple, max_levels);
+
+    debug!(
+        symbol = %symbol_upper,
+        support = levels.support.len(),
+        resistance = levels.resistance.len(),
+        "Found key levels"
+    );
+
+    Ok(levels)
+}
+
+/// Get gaps with thin or no resting liquidity in the cached book
+///
+/// Complements the volume-profile vacuums of `get_liquidity_vacuums`: these
+/// gaps are in the live book, so they show where a market order would slip
+/// through right now.
+///
+/// Subsequent requests: <200ms (cached data)
+pub async fn get_book_gaps(
+    manager: Arc<OrderBookManager>,
+    params: GetBookGapsParams,
+) -> Result<BookGaps, OrderBookToolError> {
+    let symbol_upper = params.symbol.to_uppercase();
+    let max_gaps = params.max_gaps;
+
+    if !(1..=MAX_BOOK_GAPS).contains(&max_gaps) {
+        return Err(OrderBookToolError::InvalidMaxGaps(max_gaps));
+    }
+    let gap_multiple = resolve_gap_multiple(params.gap_multiple)?;
+
+    info!(
+        symbol = %symbol_upper,
+        %gap_multiple,
+        max_gaps,
+        "Fetching book gaps"
+    );
+
+    // Get order book (lazy initialization on first request)
+    let order_book = manager.get_order_book(&symbol_upper).await?;
+
+    let gaps = metrics::find_book_gaps(&order_book, BOOK_GAP_SCAN_DEPTH, gap_multiple, max_gaps);
+
+    debug!(
+        symbol = %symbol_upper,
+        bid_gaps = gaps.bid_gaps.len(),
+        ask_gaps = gaps.ask_gaps.len(),
+        "Found book gaps"
+    );
+
+    Ok(gaps)
+}
+
+/// Parameters for get_depth_stats tool
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetDepthStatsParams {
+    /// Trading pair symbol (e.g., "BTCUSDT")
+    #[schemars(description = "Trading pair symbol (e.g., 'BTCUSDT', 'ETHUSDT')")]
+    pub symbol: String,
+
+    /// Number of levels per side to aggregate (1-100, default: 20)
+    #[schemars(description = "Number of price levels per side to aggregate (1-100). Default: 20")]
+    #[serde(default = "default_levels")]
+    pub levels: usize,
+}
+
+/// Get aggregate liquidity over the top `levels` levels of the cached book
+///
+/// Sums volume and notional per side and derives the volume-weighted average
+/// prices and the bid/ask imbalance.
+///
+/// First request: 2-3s (lazy initialization)
+/// Subsequent requests: <200ms (cached data)
+pub async fn get_depth_stats(
+    manager: Arc<OrderBookManager>,
+    params: GetDepthStatsParams,
+) -> Result<DepthStats, OrderBookToolError> {
+    let symbol_upper = params.symbol.to_uppercase();
+    let levels = params.levels;
+
+    if !(1..=100).contains(&levels) {
+        return Err(OrderBookToolError::InvalidLevels(levels));
+    }
+
+    info!(symbol = %symbol_upper, levels, "Fetching depth stats");
+
+    // Get order book (lazy initialization on first request)
+    let order_book = manager.get_order_book(&symbol_upper).await?;
+
+    let stats = metrics::calculate_depth_stats(&order_book, levels);
+
+    debug!(
+        symbol = %symbol_upper,
+        bid_volume = %stats.bid_volume,
+        ask_volume = %stats.ask_volume,
+        "Calculated depth stats"
+    );
+
+    Ok(stats)
+}
+
 /// Get service health status
 ///
 /// Returns operational visibility:
 /// - Overall status (ok/degraded/error)
-/// - Number of active symbol subscriptions (0-20)
+/// - Tracked symbols with last-access ages (capped by `ORDERBOOK_MAX_SYMBOLS`)
 /// - Data freshness (last update age in ms)
 /// - WebSocket connection status
 ///
@@ -204,6 +689,33 @@ mod tests {
         assert_eq!(default_levels(), 20);
     }
 
+    #[test]
+    fn test_tool_error_codes() {
+        use rmcp::model::ErrorCode;
+
+        let invalid: rmcp::ErrorData = OrderBookToolError::InvalidLevels(500).into();
+        assert_eq!(invalid.code, ErrorCode::INVALID_PARAMS);
+
+        let failed: rmcp::ErrorData = OrderBookToolError::InitializationFailed {
+            symbol: "BTCUSDT".to_string(),
+            message: "connection refused".to_string(),
+        }
+        .into();
+        assert_eq!(failed.code, ErrorCode::INTERNAL_ERROR);
+    }
+
+    #[test]
+    fn test_depth_encoding_param() {
+        let params: GetOrderBookDepthParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT"})).unwrap();
+        assert_eq!(params.encoding, DepthEncoding::Compact);
+
+        let params: GetOrderBookDepthParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT", "encoding": "decimal"}))
+                .unwrap();
+        assert_eq!(params.encoding, DepthEncoding::Decimal);
+    }
+
     #[test]
     fn test_invalid_levels_validation() {
         let err = OrderBookToolError::InvalidLevels(0);
@@ -212,4 +724,76 @@ mod tests {
         let err = OrderBookToolError::InvalidLevels(101);
         assert!(err.to_string().contains("between 1 and 100"));
     }
+
+    #[test]
+    fn test_price_bucket_validation() {
+        let mid = Decimal::from(67_650);
+
+        assert_eq!(validate_price_bucket(10.0, mid).unwrap(), Decimal::from(10));
+        assert!(matches!(
+            validate_price_bucket(0.0, mid),
+            Err(OrderBookToolError::InvalidPriceBucket(_))
+        ));
+        assert!(matches!(
+            validate_price_bucket(-5.0, mid),
+            Err(OrderBookToolError::InvalidPriceBucket(_))
+        ));
+        // Too fine (below 0.1 bps) and too coarse (above 10% of mid)
+        assert!(validate_price_bucket(0.01, mid).is_err());
+        assert!(validate_price_bucket(10_000.0, mid).is_err());
+    }
+
+    #[test]
+    fn test_depth_stats_params() {
+        let params: GetDepthStatsParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT"})).unwrap();
+        assert_eq!(params.levels, 20);
+
+        let params: GetDepthStatsParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT", "levels": 5})).unwrap();
+        assert_eq!(params.levels, 5);
+    }
+
+    #[test]
+    fn test_key_levels_params() {
+        let params: GetKeyLevelsParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT"})).unwrap();
+        assert_eq!(params.max_levels, 5);
+        assert_eq!(
+            resolve_wall_multiple(params.wall_multiple).unwrap(),
+            Decimal::from(3)
+        );
+
+        assert_eq!(
+            resolve_wall_multiple(Some(2.5)).unwrap(),
+            Decimal::from_str_exact("2.5").unwrap()
+        );
+        assert!(matches!(
+            resolve_wall_multiple(Some(1.0)),
+            Err(OrderBookToolError::InvalidWallMultiple(_))
+        ));
+        assert!(resolve_wall_multiple(Some(f64::NAN)).is_err());
+        assert!(resolve_wall_multiple(Some(500.0)).is_err());
+    }
+
+    #[test]
+    fn test_book_gaps_params() {
+        let params: GetBookGapsParams =
+            serde_json::from_value(serde_json::json!({"symbol": "BTCUSDT"})).unwrap();
+        assert_eq!(params.max_gaps, 5);
+        assert_eq!(
+            resolve_gap_multiple(params.gap_multiple).unwrap(),
+            Decimal::from(3)
+        );
+
+        assert!(matches!(
+            resolve_gap_multiple(Some(1.0)),
+            Err(OrderBookToolError::InvalidGapMultiple(_))
+        ));
+        assert!(resolve_gap_multiple(Some(f64::INFINITY)).is_err());
+        assert!(resolve_gap_multiple(Some(5_000.0)).is_err());
+
+        let invalid: rmcp::ErrorData = OrderBookToolError::InvalidMaxGaps(50).into();
+        assert_eq!(invalid.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+    }
 }
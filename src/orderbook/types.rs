@@ -7,6 +7,12 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+/// Snapshot age above which a metrics/depth response is flagged `stale`
+///
+/// The depth stream pushes updates every 100ms, so a book that hasn't had a
+/// delta applied for this long is likely missing updates.
+pub const STALE_SNAPSHOT_THRESHOLD_MS: i64 = 2000;
+
 /// Order book state for a single trading symbol
 ///
 /// Maintains sorted bid/ask levels using BTreeMap for efficient range queries.
@@ -27,20 +33,40 @@ pub struct OrderBook {
 
     /// Timestamp of last update (milliseconds since Unix epoch)
     pub timestamp: i64,
+
+    /// Local time the last snapshot or delta was applied (milliseconds since Unix epoch)
+    ///
+    /// Unlike `timestamp`, which carries the exchange event time, this uses the local clock.
+    #[serde(default)]
+    pub last_applied_ms: i64,
+
+    /// True while the book is still the one-shot REST snapshot taken on first request
+    ///
+    /// Cleared once the first WebSocket delta is applied and the book is maintained live.
+    #[serde(default)]
+    pub warming: bool,
 }
 
 impl OrderBook {
     /// Create a new empty order book for the given symbol
     pub fn new(symbol: String) -> Self {
+        let now = chrono::Utc::now().timestamp_millis();
         Self {
             symbol,
             bids: BTreeMap::new(),
             asks: BTreeMap::new(),
             last_update_id: 0,
-            timestamp: chrono::Utc::now().timestamp_millis(),
+            timestamp: now,
+            last_applied_ms: now,
+            warming: false,
         }
     }
 
+    /// Milliseconds since the last snapshot or delta was applied
+    pub fn snapshot_age_ms(&self) -> i64 {
+        (chrono::Utc::now().timestamp_millis() - self.last_applied_ms).max(0)
+    }
+
     /// Get best bid price (highest bid)
     pub fn best_bid(&self) -> Option<&Decimal> {
         self.bids.keys().next_back() // BTreeMap is ascending, so last key is highest
@@ -68,6 +94,47 @@ impl OrderBook {
             self.asks.insert(price, quantity);
         }
     }
+
+    /// Drop the worst levels so each side keeps at most `max_levels`
+    ///
+    /// Removes the lowest bids and the highest asks; the best prices are kept.
+    pub fn trim_to_depth(&mut self, max_levels: usize) {
+        while self.bids.len() > max_levels {
+            self.bids.pop_first();
+        }
+        while self.asks.len() > max_levels {
+            self.asks.pop_last();
+        }
+    }
+}
+
+/// Freshness of the local book a response was computed from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SnapshotFreshness {
+    /// Milliseconds since the last snapshot or delta was applied to the local book
+    pub snapshot_age_ms: i64,
+
+    /// Binance update ID of the last applied snapshot or delta
+    pub last_update_id: i64,
+
+    /// True when `snapshot_age_ms` exceeds [`STALE_SNAPSHOT_THRESHOLD_MS`]
+    pub stale: bool,
+
+    /// True when the data is the REST snapshot served while the WebSocket book warms up
+    pub warming: bool,
+}
+
+impl SnapshotFreshness {
+    /// Freshness of `order_book` as of now
+    pub fn of(order_book: &OrderBook) -> Self {
+        let snapshot_age_ms = order_book.snapshot_age_ms();
+        Self {
+            snapshot_age_ms,
+            last_update_id: order_book.last_update_id,
+            stale: snapshot_age_ms > STALE_SNAPSHOT_THRESHOLD_MS,
+            warming: order_book.warming,
+        }
+    }
 }
 
 /// L1 aggregated metrics for quick spread and liquidity assessment
@@ -82,11 +149,15 @@ pub struct OrderBookMetrics {
     /// Metrics calculation time (milliseconds since Unix epoch)
     pub timestamp: i64,
 
-    /// Spread in basis points: ((best_ask - best_bid) / best_bid) * 10000
-    pub spread_bps: f64,
+    /// Spread in basis points: ((best_ask - best_bid) / mid) * 10000
+    ///
+    /// Exact decimal string, rounded half away from zero to 8 places
+    pub spread_bps: String,
 
     /// Volume-weighted fair price: (best_bid * ask_vol + best_ask * bid_vol) / (bid_vol + ask_vol)
-    pub microprice: f64,
+    ///
+    /// Exact decimal string over the top 20 levels, rounded half away from zero to 8 places
+    pub microprice: String,
 
     /// Sum of top 20 bid level quantities (buy-side liquidity depth)
     pub bid_volume: f64,
@@ -108,6 +179,10 @@ pub struct OrderBookMetrics {
 
     /// VWAP-based slippage estimates for standard target amounts
     pub slippage_estimates: SlippageEstimates,
+
+    /// Age, update ID and staleness of the underlying local book
+    #[serde(flatten)]
+    pub freshness: SnapshotFreshness,
 }
 
 /// Container for bid and ask walls (support/resistance zones)
@@ -209,6 +284,221 @@ pub struct OrderBookDepth {
 
     /// Ask levels as [scaled_price, scaled_qty] tuples (sorted ascending by price)
     pub asks: Vec<[i64; 2]>,
+
+    /// Age, update ID and staleness of the underlying local book
+    #[serde(flatten)]
+    pub freshness: SnapshotFreshness,
+}
+
+/// L2 depth data with decimal string encoding
+///
+/// Human-readable alternative to [`OrderBookDepth`]: prices and quantities are
+/// plain decimal strings, so callers don't need to know the scale factors.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DecimalOrderBookDepth {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Snapshot time (milliseconds since Unix epoch)
+    pub timestamp: i64,
+
+    /// Bid levels as [price, qty] string tuples (sorted descending by price)
+    pub bids: Vec<[String; 2]>,
+
+    /// Ask levels as [price, qty] string tuples (sorted ascending by price)
+    pub asks: Vec<[String; 2]>,
+
+    /// Age, update ID and staleness of the underlying local book
+    #[serde(flatten)]
+    pub freshness: SnapshotFreshness,
+}
+
+/// L2 depth aggregated into fixed-width price buckets
+///
+/// Each level is `[bucket_price, total_qty]` as decimal strings. Bid buckets are
+/// floored to the bucket width and ask buckets are ceiled, so a bucket never
+/// crosses the spread.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AggregatedDepth {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Snapshot time (milliseconds since Unix epoch)
+    pub timestamp: i64,
+
+    /// Bucket width used for aggregation (decimal string)
+    pub price_bucket: String,
+
+    /// Bid buckets as [bucket_price, total_qty] string tuples (sorted descending by price)
+    pub bids: Vec<[String; 2]>,
+
+    /// Ask buckets as [bucket_price, total_qty] string tuples (sorted ascending by price)
+    pub asks: Vec<[String; 2]>,
+}
+
+/// Book level flagged as support or resistance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct KeyLevel {
+    /// Price level (decimal string)
+    pub price: String,
+
+    /// Quantity resting at this level (decimal string)
+    pub qty: String,
+}
+
+/// Support/resistance levels from book walls, optionally overlaid with the volume profile
+///
+/// `poc`, `vah` and `val` are only present when the `orderbook_analytics`
+/// feature is enabled and the volume profile had enough trades.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct KeyLevels {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Bid walls below the spread, largest first
+    pub support: Vec<KeyLevel>,
+
+    /// Ask walls above the spread, largest first
+    pub resistance: Vec<KeyLevel>,
+
+    /// Median level quantity across both scanned sides (decimal string)
+    pub median_qty: String,
+
+    /// Multiple of the median a level must exceed to count as a wall
+    pub wall_multiple: f64,
+
+    /// Volume profile Point of Control (decimal string)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poc: Option<String>,
+
+    /// Volume profile Value Area High (decimal string)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vah: Option<String>,
+
+    /// Volume profile Value Area Low (decimal string)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub val: Option<String>,
+
+    /// Why the volume profile overlay is missing, when it is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_note: Option<String>,
+}
+
+/// A price range inside the book with no resting liquidity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BookGap {
+    /// Price of the level on the spread side of the gap (decimal string)
+    pub from_price: String,
+
+    /// Price of the level beyond the gap (decimal string)
+    pub to_price: String,
+
+    /// Absolute price distance between the two levels (decimal string)
+    pub width: String,
+
+    /// Width as a multiple of the median inter-level spacing
+    pub spacing_multiple: f64,
+
+    /// Quantity resting at `from_price` (decimal string)
+    pub from_qty: String,
+
+    /// Quantity resting at `to_price` (decimal string)
+    pub to_qty: String,
+}
+
+/// Gaps in the current book where a market order would slip through
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BookGaps {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Gaps between bid levels, nearest the spread first
+    pub bid_gaps: Vec<BookGap>,
+
+    /// Gaps between ask levels, nearest the spread first
+    pub ask_gaps: Vec<BookGap>,
+
+    /// Median price distance between adjacent levels across both scanned sides (decimal string)
+    pub median_spacing: String,
+
+    /// Multiple of the median spacing a gap must exceed to be reported
+    pub gap_multiple: f64,
+
+    /// Freshness of the local book the gaps were found in
+    #[serde(flatten)]
+    pub freshness: SnapshotFreshness,
+}
+
+/// Aggregate liquidity over the top levels of the current book
+///
+/// Quantities and notionals are exact decimal strings; averages and the
+/// imbalance are rounded half away from zero to 8 places.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DepthStats {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Levels requested per side
+    pub levels: usize,
+
+    /// Bid levels actually summed (fewer than `levels` on a thin book)
+    pub bid_levels: usize,
+
+    /// Ask levels actually summed (fewer than `levels` on a thin book)
+    pub ask_levels: usize,
+
+    /// Total bid quantity in the base asset (decimal string)
+    pub bid_volume: String,
+
+    /// Total ask quantity in the base asset (decimal string)
+    pub ask_volume: String,
+
+    /// Sum of price × quantity over the bid levels, in the quote asset (decimal string)
+    pub bid_notional: String,
+
+    /// Sum of price × quantity over the ask levels, in the quote asset (decimal string)
+    pub ask_notional: String,
+
+    /// Volume-weighted average bid price: bid_notional / bid_volume (None for an empty side)
+    pub bid_vwap: Option<String>,
+
+    /// Volume-weighted average ask price: ask_notional / ask_volume (None for an empty side)
+    pub ask_vwap: Option<String>,
+
+    /// (bid_volume - ask_volume) / (bid_volume + ask_volume), from -1 (all asks) to 1 (all bids)
+    ///
+    /// None when both sides are empty.
+    pub imbalance: Option<String>,
+
+    /// Freshness of the local book the stats were computed from
+    #[serde(flatten)]
+    pub freshness: SnapshotFreshness,
+}
+
+/// L2 depth in the encoding requested by the caller
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum OrderBookDepthResponse {
+    /// Integer-encoded depth (default)
+    Compact(OrderBookDepth),
+    /// Decimal string depth
+    Decimal(DecimalOrderBookDepth),
+}
+
+/// Per-symbol entry in [`OrderBookHealth`]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrackedSymbol {
+    /// Trading pair symbol (uppercased)
+    pub symbol: String,
+
+    /// Milliseconds since a caller last read this book (eviction is least-recent first)
+    pub last_access_age_ms: i64,
+
+    /// Milliseconds since the last depth update for this symbol
+    pub last_update_age_ms: i64,
+
+    /// Whether this symbol's WebSocket stream is connected
+    pub websocket_connected: bool,
 }
 
 /// Service health status for order book tracking
@@ -219,9 +509,24 @@ pub struct OrderBookHealth {
     /// Overall health status
     pub status: HealthStatus,
 
-    /// Number of symbols with active WebSocket connections (0-20)
+    /// Number of symbols currently tracked (0 to `max_symbols`)
     pub orderbook_symbols_active: usize,
 
+    /// Maximum concurrently tracked symbols (`ORDERBOOK_MAX_SYMBOLS`)
+    pub max_symbols: usize,
+
+    /// How long computed metrics are reused, in ms (`ORDERBOOK_METRICS_TTL_MS`, 0 = always recompute)
+    pub metrics_ttl_ms: u64,
+
+    /// How long extracted depth is reused, in ms (`ORDERBOOK_DEPTH_TTL_MS`, 0 = always recompute)
+    pub depth_ttl_ms: u64,
+
+    /// Price levels retained per side of each local book (`ORDERBOOK_LOCAL_DEPTH`)
+    pub local_depth: usize,
+
+    /// Tracked symbols, most recently accessed first
+    pub tracked_symbols: Vec<TrackedSymbol>,
+
     /// Milliseconds since last successful depth update across all symbols
     /// <5000 is healthy, >5000 indicates staleness
     pub last_update_age_ms: i64,
@@ -0,0 +1,298 @@
+//! Audit trail for order-mutating tool calls
+//!
+//! Every `place_order`, `cancel_order` and `cancel_orders` call emits one structured INFO event
+//! at the `binance::audit` target, whether it succeeds, is blocked or fails.
+//! Operators can route that target to a separate sink with a per-target filter
+//! (e.g., `RUST_LOG=info,binance::audit=info` plus a target-matching layer).
+//!
+//! A record carries the caller (SSE session ID, or `env` for environment
+//! credentials), tool, symbol, side, quantity, the `X-Request-Id` of every
+//! Binance request the call sent and either the resulting order ID, the
+//! canceled/failed counts of a batch, or an error code. Credentials are never
+//! part of a record.
+
+use crate::binance::client::collect_request_ids;
+use rmcp::model::CallToolResult;
+use rmcp::ErrorData;
+use std::future::Future;
+
+/// Tracing target for audit records
+pub const AUDIT_TARGET: &str = "binance::audit";
+
+/// Caller recorded for calls made with environment credentials (stdio mode)
+pub const ENV_CALLER: &str = "env";
+
+/// Request details of an order-mutating tool call
+#[derive(Debug, Clone, Copy)]
+pub struct OrderAudit<'a> {
+    /// SSE session ID, or [`ENV_CALLER`]
+    pub caller: &'a str,
+    /// Tool name (e.g., "place_order")
+    pub tool: &'a str,
+    /// Symbol as passed by the caller
+    pub symbol: &'a str,
+    /// Order side, for tools that take one
+    pub side: Option<&'a str>,
+    /// Order quantity, for tools that take one
+    pub quantity: Option<&'a str>,
+}
+
+/// Result of an audited call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditOutcome {
+    /// The exchange returned this order
+    Order(i64),
+    /// A batch ran with this many canceled and failed orders
+    Batch { canceled: usize, failed: usize },
+    /// The call failed or was refused with this code
+    Error(String),
+}
+
+impl AuditOutcome {
+    /// Classifies a tool result
+    ///
+    /// Structured refusals (`{"error_code": ...}` bodies such as
+    /// `MAINNET_TRADING_DISABLED`) and `ErrorData` failures count as errors;
+    /// a body with an `orderId` counts as an order, and one with `canceled`
+    /// and `failed` lists as a batch.
+    pub fn of(result: &Result<CallToolResult, ErrorData>) -> Self {
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => return Self::Error(e.code.0.to_string()),
+        };
+
+        let body = result
+            .content
+            .first()
+            .and_then(|c| c.as_text())
+            .and_then(|t| serde_json::from_str::<serde_json::Value>(&t.text).ok());
+
+        match body {
+            Some(body) => {
+                if let Some(code) = body.get("error_code").and_then(|c| c.as_str()) {
+                    Self::Error(code.to_string())
+                } else if let Some(id) = body.get("orderId").and_then(|id| id.as_i64()) {
+                    Self::Order(id)
+                } else if let (Some(canceled), Some(failed)) = (
+                    body.get("canceled").and_then(|c| c.as_array()),
+                    body.get("failed").and_then(|f| f.as_array()),
+                ) {
+                    Self::Batch {
+                        canceled: canceled.len(),
+                        failed: failed.len(),
+                    }
+                } else {
+                    Self::Error("UNRECOGNIZED_RESPONSE".to_string())
+                }
+            }
+            None => Self::Error("UNRECOGNIZED_RESPONSE".to_string()),
+        }
+    }
+}
+
+/// Emits the audit record for a finished call
+///
+/// `request_ids` are the `X-Request-Id`s of the Binance requests the call sent,
+/// recorded comma-separated.
+pub fn record(
+    audit: &OrderAudit<'_>,
+    request_ids: &[String],
+    result: &Result<CallToolResult, ErrorData>,
+) {
+    let timestamp_ms = chrono::Utc::now().timestamp_millis();
+    let symbol = audit.symbol.trim().to_uppercase();
+    let side = audit.side.unwrap_or("");
+    let quantity = audit.quantity.unwrap_or("");
+    let request_ids = request_ids.join(",");
+
+    match AuditOutcome::of(result) {
+        AuditOutcome::Order(order_id) => tracing::info!(
+            target: AUDIT_TARGET,
+            timestamp_ms,
+            session_id = %audit.caller,
+            tool = %audit.tool,
+            symbol = %symbol,
+            side = %side,
+            quantity = %quantity,
+            order_id,
+            request_ids = %request_ids,
+            "Order tool call succeeded"
+        ),
+        AuditOutcome::Batch { canceled, failed } => tracing::info!(
+            target: AUDIT_TARGET,
+            timestamp_ms,
+            session_id = %audit.caller,
+            tool = %audit.tool,
+            symbol = %symbol,
+            side = %side,
+            quantity = %quantity,
+            canceled,
+            failed,
+            request_ids = %request_ids,
+            "Order tool call succeeded"
+        ),
+        AuditOutcome::Error(error_code) => tracing::info!(
+            target: AUDIT_TARGET,
+            timestamp_ms,
+            session_id = %audit.caller,
+            tool = %audit.tool,
+            symbol = %symbol,
+            side = %side,
+            quantity = %quantity,
+            error_code = %error_code,
+            request_ids = %request_ids,
+            "Order tool call failed"
+        ),
+    }
+}
+
+/// Runs an order-mutating tool call and records its outcome
+pub async fn audited<F>(audit: OrderAudit<'_>, call: F) -> Result<CallToolResult, ErrorData>
+where
+    F: Future<Output = Result<CallToolResult, ErrorData>>,
+{
+    let (result, request_ids) = collect_request_ids(call).await;
+    record(&audit, &request_ids, &result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+
+    /// Test subscriber for the env-credential audit test
+    #[cfg(not(feature = "sse"))]
+    mod capture {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing_subscriber::layer::Context;
+        use tracing_subscriber::Layer;
+
+        /// Captures every event as "target: field=value ..." lines
+        #[derive(Clone, Default)]
+        pub struct CaptureLayer(pub Arc<Mutex<Vec<String>>>);
+
+        struct LineVisitor(String);
+
+        impl Visit for LineVisitor {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for CaptureLayer {
+            fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+                let mut visitor = LineVisitor(format!("{}:", event.metadata().target()));
+                event.record(&mut visitor);
+                self.0.lock().unwrap().push(visitor.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_outcome_classification() {
+        let order = Ok(CallToolResult::success(vec![Content::text(
+            r#"{"orderId":42,"symbol":"BTCUSDT"}"#,
+        )]));
+        assert_eq!(AuditOutcome::of(&order), AuditOutcome::Order(42));
+
+        let refused = Ok(CallToolResult::success(vec![Content::text(
+            r#"{"error_code":"MAINNET_TRADING_DISABLED","message":"..."}"#,
+        )]));
+        assert_eq!(
+            AuditOutcome::of(&refused),
+            AuditOutcome::Error("MAINNET_TRADING_DISABLED".to_string())
+        );
+
+        let batch = Ok(CallToolResult::success(vec![Content::text(
+            r#"{"symbol":"BTCUSDT","canceled":[{"client_order_id":"a","order_id":1,"status":"CANCELED"}],"failed":[]}"#,
+        )]));
+        assert_eq!(
+            AuditOutcome::of(&batch),
+            AuditOutcome::Batch {
+                canceled: 1,
+                failed: 0
+            }
+        );
+
+        let failed = Err(ErrorData::internal_error("boom", None));
+        assert_eq!(
+            AuditOutcome::of(&failed),
+            AuditOutcome::Error("-32603".to_string())
+        );
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_place_order_emits_audit_record_without_credentials() {
+        use crate::binance::BinanceClient;
+        use crate::server::tool_router::PlaceOrderParam;
+        use crate::server::BinanceServer;
+        use capture::CaptureLayer;
+        use rmcp::handler::server::wrapper::Parameters;
+        use tracing_subscriber::layer::SubscriberExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "symbol": "BTCUSDT", "orderId": 987654, "clientOrderId": "abc",
+                "status": "NEW", "type": "LIMIT", "side": "BUY"
+            })))
+            .mount(&server)
+            .await;
+
+        let mcp = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: server.uri(),
+                api_key: Some("audit-test-api-key".to_string()),
+                api_secret: Some("audit-test-api-secret".to_string()),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+
+        let capture = CaptureLayer::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        mcp.place_order(Parameters(PlaceOrderParam {
+            symbol: "btcusdt".to_string(),
+            side: "BUY".to_string(),
+            order_type: "LIMIT".to_string(),
+            quantity: "0.001".to_string(),
+            price: Some("50000".to_string()),
+            time_in_force: None,
+            client_order_id: None,
+            skip_balance_check: Some(true),
+            auto_round: None,
+        }))
+        .await
+        .unwrap();
+
+        let received = server.received_requests().await.unwrap();
+        let request_id = received[0].headers.get("X-Request-Id").unwrap();
+
+        let events = capture.0.lock().unwrap();
+        let audit: Vec<&String> = events
+            .iter()
+            .filter(|e| e.starts_with("binance::audit:"))
+            .collect();
+        assert_eq!(audit.len(), 1, "exactly one audit record: {:?}", *events);
+
+        let record = audit[0];
+        assert!(record.contains("session_id=env"));
+        assert!(record.contains("tool=place_order"));
+        assert!(record.contains("symbol=BTCUSDT"));
+        assert!(record.contains("side=BUY"));
+        assert!(record.contains("quantity=0.001"));
+        assert!(record.contains("order_id=987654"));
+        // The request ID sent to Binance is echoed into the record
+        assert!(record.contains(&format!("request_ids={}", request_id.to_str().unwrap())));
+        assert!(!record.contains("audit-test-api-key"));
+        assert!(!record.contains("audit-test-api-secret"));
+    }
+}
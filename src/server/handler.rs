@@ -4,26 +4,44 @@
 //! Provides server info, capabilities, and lifecycle management.
 
 #[cfg(feature = "orderbook_analytics")]
-use crate::orderbook::analytics::types::FlowDirection;
-use crate::server::resources::{ResourceCategory, ResourceUri};
+use crate::orderbook::analytics::types::{DataSufficiency, FlowDirection};
+use crate::server::instrumentation::instrument_tool_call;
+use crate::server::market_cache::CachedTicker;
+use crate::server::pagination::{paginate, DEFAULT_PAGE_SIZE};
+use crate::server::resources::{
+    display_amount, display_price, ResourceCategory, ResourceFormat, ResourceUri,
+};
 #[cfg(feature = "orderbook_analytics")]
 use crate::server::types::{AdvancedAnalysisArgs, MarketHealthCheckArgs, OrderFlowSnapshotArgs};
-use crate::server::types::{PortfolioRiskArgs, TradingAnalysisArgs};
+use crate::server::types::{PortfolioRiskArgs, PreTradeCheckArgs, TradingAnalysisArgs};
 use crate::server::BinanceServer;
+use crate::server::{response_limit, response_meta};
+use crate::tools::pre_trade::{check_order_filters, verdict, Verdict};
+use crate::tools::trade_flow::TakerSide;
+use rmcp::handler::server::prompt::PromptContext;
 use rmcp::handler::server::router::prompt::PromptRouter;
+use rmcp::handler::server::tool::ToolCallContext;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::handler::server::ServerHandler;
 use rmcp::model::{
-    AnnotateAble, ErrorData, GetPromptRequestParam, GetPromptResult, Implementation,
-    InitializeResult, ListPromptsResult, ListResourcesResult, PaginatedRequestParam, PromptMessage,
-    PromptMessageRole, PromptsCapability, ProtocolVersion, RawResource, ReadResourceRequestParam,
-    ReadResourceResult, ResourceContents, ResourcesCapability, ServerCapabilities, ToolsCapability,
+    AnnotateAble, CallToolRequestParam, CallToolResult, ErrorData, GetPromptRequestParam,
+    GetPromptResult, Implementation, InitializeRequestParam, InitializeResult, ListPromptsResult,
+    ListResourcesResult, ListToolsResult, PaginatedRequestParam, PromptMessage, PromptMessageRole,
+    PromptsCapability, RawResource, ReadResourceRequestParam, ReadResourceResult, Resource,
+    ResourceContents, ResourcesCapability, ServerCapabilities, ToolsCapability,
 };
 use rmcp::service::RequestContext;
-use rmcp::{prompt, prompt_handler, prompt_router, tool_handler, RoleServer};
+use rmcp::{prompt, prompt_router, RoleServer};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Last-price deviation from `avgPrice` flagged as a possible spike, in basis points
+#[cfg(feature = "orderbook_analytics")]
+const PRICE_SPIKE_THRESHOLD_BPS: f64 = 50.0;
+
+/// Trades returned by a `binance://trades/{symbol}` resource
+const RESOURCE_TRADES_LIMIT: u32 = 20;
 
-#[tool_handler(router = self.tool_router)]
-#[prompt_handler(router = self.prompt_router)]
 impl ServerHandler for BinanceServer {
     /// Returns server information and capabilities
     ///
@@ -31,7 +49,7 @@ impl ServerHandler for BinanceServer {
     /// and supported features to the client.
     fn get_info(&self) -> InitializeResult {
         InitializeResult {
-            protocol_version: ProtocolVersion::V_2024_11_05,
+            protocol_version: self.protocol.default.clone(),
             capabilities: ServerCapabilities {
                 tools: Some(ToolsCapability {
                     list_changed: Some(false),
@@ -46,90 +64,138 @@ impl ServerHandler for BinanceServer {
                 ..Default::default()
             },
             server_info: Implementation {
-                name: "mcp-binance-server".to_string(),
+                name: self.identity.name.clone(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
-                title: Some("Binance MCP Server".to_string()),
+                title: Some(self.identity.title.clone()),
                 website_url: Some("https://github.com/tradeforge/mcp-binance-rs".to_string()),
                 icons: None,
             },
-            instructions: Some(
-                "Binance MCP Server for trading and market data. \
-                Provides tools for market data/account/trading operations, \
-                prompts for AI-guided analysis, and resources for efficient data access."
-                    .to_string(),
-            ),
+            instructions: Some(self.identity.instructions.clone()),
+        }
+    }
+
+    /// Answers `initialize` with the client's protocol version when supported
+    ///
+    /// Otherwise the configured default is answered (see
+    /// [`crate::config::protocol`]).
+    async fn initialize(
+        &self,
+        request: InitializeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<InitializeResult, ErrorData> {
+        let requested = request.protocol_version.to_string();
+        if context.peer.peer_info().is_none() {
+            context.peer.set_peer_info(request);
         }
+        let mut info = self.get_info();
+        info.protocol_version = self.protocol.negotiate(Some(&requested));
+        Ok(info)
+    }
+
+    /// Dispatches a tool call through the tool router
+    ///
+    /// Each invocation runs inside a `tool_call` tracing span that records
+    /// elapsed time and outcome (see [`crate::server::instrumentation`]).
+    /// Responses over `MAX_TOOL_RESPONSE_BYTES` are replaced by a
+    /// `RESPONSE_TOO_LARGE` error (see [`crate::server::response_limit`]).
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let tool = request.name.to_string();
+        self.tool_filter.check(&tool)?;
+        let tcc = ToolCallContext::new(self, request, context);
+        let call = instrument_tool_call(
+            &tool,
+            |result: &Result<CallToolResult, ErrorData>| {
+                result
+                    .as_ref()
+                    .map_or(true, |r| r.is_error.unwrap_or(false))
+            },
+            self.tool_router.call(tcc),
+        );
+        let max_bytes = self.max_tool_response_bytes;
+        let call = async {
+            let mut result = call.await;
+            if let Ok(result) = &mut result {
+                response_limit::guard_result(&tool, result, max_bytes);
+            }
+            result
+        };
+        if !self.response_include_meta {
+            return call.await;
+        }
+
+        let (mut result, meta) = response_meta::measure(&tool, call).await;
+        if let Ok(result) = &mut result {
+            response_meta::wrap_result(result, &meta);
+        }
+        result
+    }
+
+    /// Lists all tools registered on the tool router
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
     }
 
     /// List available resources (T028)
     ///
-    /// Returns a list of all available MCP resources for market data.
+    /// Returns MCP resources for market data ordered by URI, one page at a time.
+    /// Pass the returned `next_cursor` back to fetch the following page.
     async fn list_resources(
         &self,
-        _request: Option<PaginatedRequestParam>,
+        request: Option<PaginatedRequestParam>,
         _context: RequestContext<RoleServer>,
     ) -> Result<ListResourcesResult, ErrorData> {
+        let cursor = request.and_then(|r| r.cursor);
+        let (resources, next_cursor) = paginate(
+            resource_catalog(),
+            |resource| resource.uri.as_str(),
+            cursor.as_deref(),
+            DEFAULT_PAGE_SIZE,
+        );
+
         Ok(ListResourcesResult {
-            resources: vec![
-                // Market data resources
-                RawResource {
-                    uri: "binance://market/btcusdt".to_string(),
-                    name: "BTCUSDT Market Data".to_string(),
-                    title: None,
-                    description: Some(
-                        "Real-time 24-hour ticker statistics for Bitcoin/USDT trading pair"
-                            .to_string(),
-                    ),
-                    mime_type: Some("text/markdown".to_string()),
-                    size: None,
-                    icons: None,
-                }
-                .no_annotation(),
-                RawResource {
-                    uri: "binance://market/ethusdt".to_string(),
-                    name: "ETHUSDT Market Data".to_string(),
-                    title: None,
-                    description: Some(
-                        "Real-time 24-hour ticker statistics for Ethereum/USDT trading pair"
-                            .to_string(),
-                    ),
-                    mime_type: Some("text/markdown".to_string()),
-                    size: None,
-                    icons: None,
-                }
-                .no_annotation(),
-                // Account resources (T035)
-                RawResource {
-                    uri: "binance://account/balances".to_string(),
-                    name: "Account Balances".to_string(),
-                    title: None,
-                    description: Some(
-                        "Current account balances with free and locked amounts for all assets"
-                            .to_string(),
-                    ),
-                    mime_type: Some("text/markdown".to_string()),
-                    size: None,
-                    icons: None,
-                }
-                .no_annotation(),
-                // Orders resources (T035)
-                RawResource {
-                    uri: "binance://orders/open".to_string(),
-                    name: "Open Orders".to_string(),
-                    title: None,
-                    description: Some(
-                        "List of all currently open orders across all trading pairs".to_string(),
-                    ),
-                    mime_type: Some("text/markdown".to_string()),
-                    size: None,
-                    icons: None,
-                }
-                .no_annotation(),
-            ],
-            next_cursor: None,
+            resources,
+            next_cursor,
+        })
+    }
+
+    /// Lists registered prompts ordered by name, one page at a time
+    async fn list_prompts(
+        &self,
+        request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, ErrorData> {
+        let cursor = request.and_then(|r| r.cursor);
+        let (prompts, next_cursor) = paginate(
+            self.prompt_router.list_all(),
+            |prompt| prompt.name.as_str(),
+            cursor.as_deref(),
+            DEFAULT_PAGE_SIZE,
+        );
+
+        Ok(ListPromptsResult {
+            prompts,
+            next_cursor,
         })
     }
 
+    /// Renders a prompt through the prompt router
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let prompt_context = PromptContext::new(self, request.name, request.arguments, context);
+        self.prompt_router.get_prompt(prompt_context).await
+    }
+
     /// Read a specific resource by URI (T029)
     ///
     /// Parses the URI and dispatches to the appropriate resource handler.
@@ -149,32 +215,168 @@ impl ServerHandler for BinanceServer {
                         "binance://market/btcusdt",
                         "binance://market/ethusdt",
                         "binance://account/balances",
-                        "binance://orders/open"
+                        "binance://orders/open",
+                        "binance://trades/btcusdt"
                     ],
                     "recovery_suggestion": "Use format: binance://{category}/{identifier}"
                 })),
             )
         })?;
 
+        if parsed.format == ResourceFormat::Json
+            && !matches!(
+                parsed.category,
+                ResourceCategory::Market | ResourceCategory::Trades
+            )
+        {
+            return Err(ErrorData::new(
+                rmcp::model::ErrorCode(-32404),
+                "JSON format is only available for market and trades resources".to_string(),
+                Some(serde_json::json!({
+                    "provided_uri": request.uri,
+                    "valid_examples": ["binance://market/btcusdt.json", "binance://trades/btcusdt.json"],
+                    "recovery_suggestion": "Drop the .json suffix to read this resource as markdown"
+                })),
+            ));
+        }
+
         // Dispatch to category-specific handlers
         let contents = match parsed.category {
-            ResourceCategory::Market => self.read_market_resource(parsed.identifier).await?,
+            ResourceCategory::Market => {
+                self.read_market_resource(parsed.identifier, parsed.format)
+                    .await?
+            }
             ResourceCategory::Account => self.read_account_resource(parsed.identifier).await?, // T036
             ResourceCategory::Orders => self.read_orders_resource(parsed.identifier).await?, // T037
+            ResourceCategory::Trades => {
+                self.read_trades_resource(parsed.identifier, parsed.format)
+                    .await?
+            }
         };
 
         Ok(ReadResourceResult { contents })
     }
 }
 
+/// All resources exposed by the server (T028)
+fn resource_catalog() -> Vec<Resource> {
+    vec![
+        // Market data resources
+        RawResource {
+            uri: "binance://market/btcusdt".to_string(),
+            name: "BTCUSDT Market Data".to_string(),
+            title: None,
+            description: Some(
+                "Real-time 24-hour ticker statistics for Bitcoin/USDT trading pair".to_string(),
+            ),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        RawResource {
+            uri: "binance://market/btcusdt.json".to_string(),
+            name: "BTCUSDT Market Data (JSON)".to_string(),
+            title: None,
+            description: Some(
+                "Raw 24-hour ticker object for Bitcoin/USDT as compact JSON".to_string(),
+            ),
+            mime_type: Some("application/json".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        RawResource {
+            uri: "binance://market/ethusdt".to_string(),
+            name: "ETHUSDT Market Data".to_string(),
+            title: None,
+            description: Some(
+                "Real-time 24-hour ticker statistics for Ethereum/USDT trading pair".to_string(),
+            ),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        RawResource {
+            uri: "binance://market/ethusdt.json".to_string(),
+            name: "ETHUSDT Market Data (JSON)".to_string(),
+            title: None,
+            description: Some(
+                "Raw 24-hour ticker object for Ethereum/USDT as compact JSON".to_string(),
+            ),
+            mime_type: Some("application/json".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        // Recent trade resources
+        RawResource {
+            uri: "binance://trades/btcusdt".to_string(),
+            name: "BTCUSDT Recent Trades".to_string(),
+            title: None,
+            description: Some(format!(
+                "Last {} trades on the Bitcoin/USDT tape with price, quantity and aggressor side",
+                RESOURCE_TRADES_LIMIT
+            )),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        RawResource {
+            uri: "binance://trades/ethusdt".to_string(),
+            name: "ETHUSDT Recent Trades".to_string(),
+            title: None,
+            description: Some(format!(
+                "Last {} trades on the Ethereum/USDT tape with price, quantity and aggressor side",
+                RESOURCE_TRADES_LIMIT
+            )),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        // Account resources (T035)
+        RawResource {
+            uri: "binance://account/balances".to_string(),
+            name: "Account Balances".to_string(),
+            title: None,
+            description: Some(
+                "Current account balances with free and locked amounts for all assets".to_string(),
+            ),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+        // Orders resources (T035)
+        RawResource {
+            uri: "binance://orders/open".to_string(),
+            name: "Open Orders".to_string(),
+            title: None,
+            description: Some(
+                "List of all currently open orders across all trading pairs".to_string(),
+            ),
+            mime_type: Some("text/markdown".to_string()),
+            size: None,
+            icons: None,
+        }
+        .no_annotation(),
+    ]
+}
+
 /// Resource handler implementation
 impl BinanceServer {
     /// Read market data resource (T030, T031, T034)
     ///
-    /// Fetches 24hr ticker data for the specified symbol and formats it as markdown.
+    /// Serves the symbol's 24hr ticker from the polled [`market_cache`](BinanceServer::market_cache)
+    /// and formats it as markdown, or returns the raw ticker object plus `as_of`
+    /// for [`ResourceFormat::Json`].
     async fn read_market_resource(
         &self,
         identifier: Option<String>,
+        format: ResourceFormat,
     ) -> Result<Vec<ResourceContents>, ErrorData> {
         // Require symbol identifier
         let symbol = identifier.ok_or_else(|| {
@@ -191,15 +393,37 @@ impl BinanceServer {
         // Normalize to uppercase for API (T031)
         let symbol_upper = symbol.to_uppercase();
 
-        // Fetch ticker data
-        let ticker = self
-            .binance_client
-            .get_24hr_ticker(&symbol_upper)
+        // Served from the polled ticker cache
+        let CachedTicker { ticker, as_of } = self
+            .market_cache
+            .ticker(&self.binance_client, &symbol_upper)
             .await
             .map_err(|e| {
                 ErrorData::internal_error(format!("Failed to fetch market data: {}", e), None)
             })?;
 
+        if format == ResourceFormat::Json {
+            let mut json = serde_json::to_value(&ticker).map_err(|e| {
+                ErrorData::internal_error(format!("Serialization error: {}", e), None)
+            })?;
+            json["as_of"] = serde_json::Value::from(as_of.to_rfc3339());
+            let text = json.to_string();
+            return Ok(vec![ResourceContents::TextResourceContents {
+                uri: format!("binance://market/{}.json", symbol),
+                mime_type: Some(format.mime_type().to_string()),
+                text,
+                meta: None,
+            }]);
+        }
+
+        // Trim prices to the symbol's tick size when exchangeInfo is available
+        let tick_size =
+            crate::binance::filters::symbol_increments(&self.binance_client, &symbol_upper)
+                .await
+                .ok()
+                .and_then(|increments| increments.tick_size);
+        let price = |raw: &str| display_price(raw, tick_size);
+
         // Format as markdown
         let content = format!(
             "# {} Market Data\n\n\
@@ -210,19 +434,19 @@ impl BinanceServer {
             **24h Low**: ${}\n\
             **24h Volume**: {} {}\n\
             **Quote Volume**: ${} USDT\n\n\
-            *Last updated: {}*\n\
+            *As of: {}*\n\
             *Data source: Binance API v3*",
             ticker.symbol,
             ticker.symbol,
-            ticker.last_price,
-            ticker.price_change,
+            price(&ticker.last_price),
+            price(&ticker.price_change),
             ticker.price_change_percent,
-            ticker.high_price,
-            ticker.low_price,
-            ticker.volume,
+            price(&ticker.high_price),
+            price(&ticker.low_price),
+            display_amount(&ticker.volume),
             ticker.symbol.trim_end_matches("USDT"),
-            ticker.quote_volume,
-            chrono::Utc::now().to_rfc3339() // T034 timestamp
+            display_amount(&ticker.quote_volume),
+            as_of.to_rfc3339() // T034 timestamp
         );
 
         Ok(vec![ResourceContents::TextResourceContents {
@@ -292,6 +516,10 @@ impl BinanceServer {
 
         // Format as markdown table (T038)
         let mut content = String::from("# Account Balances\n\n");
+        content.push_str(&format!(
+            "**Permissions**: {}\n\n",
+            account.permission_summary()
+        ));
 
         if balances.is_empty() {
             content.push_str("No active balances found in your account.\n\n");
@@ -301,13 +529,16 @@ impl BinanceServer {
             content.push_str("|-------|--------------|----------------|-------|\n");
 
             for balance in &balances {
-                let free = balance.free.parse::<f64>().unwrap_or(0.0);
-                let locked = balance.locked.parse::<f64>().unwrap_or(0.0);
-                let total = free + locked;
+                let free = Decimal::from_str(&balance.free).unwrap_or_default();
+                let locked = Decimal::from_str(&balance.locked).unwrap_or_default();
+                let total = (free + locked).normalize();
 
                 content.push_str(&format!(
-                    "| {} | {} | {} | {:.8} |\n",
-                    balance.asset, balance.free, balance.locked, total
+                    "| {} | {} | {} | {} |\n",
+                    balance.asset,
+                    display_amount(&balance.free),
+                    display_amount(&balance.locked),
+                    total
                 ));
             }
 
@@ -418,6 +649,108 @@ impl BinanceServer {
             meta: None,
         }])
     }
+
+    /// Read recent trades resource
+    ///
+    /// Fetches the last [`RESOURCE_TRADES_LIMIT`] trades for the symbol and formats
+    /// them as a markdown table, or returns the raw trades for [`ResourceFormat::Json`].
+    async fn read_trades_resource(
+        &self,
+        identifier: Option<String>,
+        format: ResourceFormat,
+    ) -> Result<Vec<ResourceContents>, ErrorData> {
+        let invalid_symbol = |message: String, provided: &str| {
+            ErrorData::new(
+                rmcp::model::ErrorCode(-32404),
+                message,
+                Some(serde_json::json!({
+                    "provided_identifier": provided,
+                    "valid_examples": ["binance://trades/btcusdt", "binance://trades/ethusdt"],
+                    "recovery_suggestion": "Specify a listed symbol: binance://trades/{symbol}"
+                })),
+            )
+        };
+
+        // Require symbol identifier
+        let symbol = identifier.ok_or_else(|| {
+            ErrorData::new(
+                rmcp::model::ErrorCode(-32404),
+                "Trades resource requires symbol identifier".to_string(),
+                Some(serde_json::json!({
+                    "valid_examples": ["binance://trades/btcusdt", "binance://trades/ethusdt"],
+                    "recovery_suggestion": "Specify symbol: binance://trades/{symbol}"
+                })),
+            )
+        })?;
+
+        let symbol_upper = crate::binance::normalize_symbol(&symbol)
+            .map_err(|e| invalid_symbol(format!("Invalid symbol '{}': {}", symbol, e), &symbol))?;
+
+        // Binance answers unknown symbols with -1121, surfaced as an invalid request
+        let trades = self
+            .binance_client
+            .get_recent_trades(&symbol_upper, Some(RESOURCE_TRADES_LIMIT))
+            .await
+            .map_err(|e| match e {
+                crate::error::McpError::InvalidRequest(_) => {
+                    invalid_symbol(format!("Unknown symbol '{}': {}", symbol, e), &symbol)
+                }
+                e => {
+                    ErrorData::internal_error(format!("Failed to fetch recent trades: {}", e), None)
+                }
+            })?;
+
+        if format == ResourceFormat::Json {
+            let text = serde_json::to_string(&trades).map_err(|e| {
+                ErrorData::internal_error(format!("Serialization error: {}", e), None)
+            })?;
+            return Ok(vec![ResourceContents::TextResourceContents {
+                uri: format!("binance://trades/{}.json", symbol),
+                mime_type: Some(format.mime_type().to_string()),
+                text,
+                meta: None,
+            }]);
+        }
+
+        // Format as markdown table, newest trade first
+        let mut content = format!("# {} Recent Trades\n\n", symbol_upper);
+
+        if trades.is_empty() {
+            content.push_str("No recent trades found.\n");
+        } else {
+            content.push_str("| Time (UTC) | Price | Quantity | Side |\n");
+            content.push_str("|------------|-------|----------|------|\n");
+
+            for trade in trades.iter().rev() {
+                let time = chrono::DateTime::from_timestamp_millis(trade.time)
+                    .map(|t| t.format("%H:%M:%S%.3f").to_string())
+                    .unwrap_or_else(|| trade.time.to_string());
+                let side = match TakerSide::of(trade) {
+                    TakerSide::Buy => "BUY",
+                    TakerSide::Sell => "SELL",
+                };
+                content.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    time, trade.price, trade.qty, side
+                ));
+            }
+
+            content.push_str(&format!("\n**Trades**: {}\n", trades.len()));
+        }
+
+        content.push_str(&format!(
+            "\n*Last updated: {}*\n\
+            *Data source: Binance API v3*",
+            chrono::Utc::now().to_rfc3339()
+        ));
+
+        Ok(vec![ResourceContents::TextResourceContents {
+            uri: format!("binance://trades/{}", symbol),
+            mime_type: Some("text/markdown".to_string()),
+            text: content,
+            meta: None,
+        }])
+    }
 }
 
 /// Prompt definitions for AI-guided trading analysis and portfolio assessment
@@ -431,19 +764,22 @@ impl BinanceServer {
     /// AI-guided trading analysis prompt
     ///
     /// Analyzes market conditions for a specific cryptocurrency and provides
-    /// trading recommendations based on 24-hour ticker data.
+    /// trading recommendations based on 24-hour ticker data. Without a symbol,
+    /// analyzes `DEFAULT_SYMBOL`.
     #[prompt(
         name = "trading_analysis",
-        description = "Analyze market conditions for a specific cryptocurrency and provide trading recommendations"
+        description = "Analyze market conditions for a specific cryptocurrency and provide trading recommendations. The symbol defaults to the server's DEFAULT_SYMBOL"
     )]
     pub async fn trading_analysis(
         &self,
         Parameters(args): Parameters<TradingAnalysisArgs>,
     ) -> Result<GetPromptResult, ErrorData> {
+        let symbol = self.symbol_or_default(args.symbol.as_deref())?;
+
         // Fetch 24hr ticker data
         let ticker = self
             .binance_client
-            .get_24hr_ticker(&args.symbol)
+            .get_24hr_ticker(&symbol)
             .await
             .map_err(|e| {
                 // Convert McpError to ErrorData
@@ -574,6 +910,138 @@ impl BinanceServer {
         })
     }
 
+    /// Pre-trade checklist prompt
+    ///
+    /// Validates an order against the symbol's exchange filters, estimates its
+    /// slippage from the local order book, adds the market health score, and
+    /// renders a go/no-go verdict. Slippage needs the `orderbook` feature and
+    /// health the `orderbook_analytics` feature; without them those sections
+    /// are skipped and the verdict rests on the filters alone.
+    #[prompt(
+        name = "pre_trade_check",
+        description = "Go/no-go checklist for a prospective order: exchange filters, slippage estimate and market health"
+    )]
+    pub async fn pre_trade_check(
+        &self,
+        Parameters(args): Parameters<PreTradeCheckArgs>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let symbol = crate::binance::normalize_symbol(&args.symbol)?;
+        let side = args.side.to_uppercase();
+        if side != "BUY" && side != "SELL" {
+            return Err(ErrorData::invalid_params(
+                format!("Invalid side '{}'. Use BUY or SELL", args.side),
+                None,
+            ));
+        }
+        let quantity = args
+            .quantity
+            .parse::<f64>()
+            .ok()
+            .filter(|q| q.is_finite() && *q > 0.0)
+            .ok_or_else(|| {
+                ErrorData::invalid_params(
+                    format!(
+                        "Invalid quantity '{}'. Use a positive number",
+                        args.quantity
+                    ),
+                    None,
+                )
+            })?;
+        let limit_price = match &args.price {
+            Some(price) => Some(
+                price
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|p| p.is_finite() && *p > 0.0)
+                    .ok_or_else(|| {
+                        ErrorData::invalid_params(
+                            format!("Invalid price '{}'. Use a positive number", price),
+                            None,
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+
+        let exchange_info = self.binance_client.get_exchange_info().await.map_err(|e| {
+            ErrorData::internal_error(format!("Failed to fetch exchange info: {}", e), None)
+        })?;
+        let info = exchange_info
+            .symbols
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| {
+                ErrorData::invalid_params(format!("Unknown symbol '{}'", symbol), None)
+            })?;
+
+        let price = match limit_price {
+            Some(price) => price,
+            None => self
+                .binance_client
+                .get_ticker_price(&symbol)
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to fetch ticker price: {}", e), None)
+                })?
+                .price
+                .parse::<f64>()
+                .unwrap_or(0.0),
+        };
+
+        let checks = check_order_filters(info, quantity, price, limit_price.is_none());
+        let (slippage_section, slippage_bps) = self
+            .pre_trade_slippage(&symbol, quantity, side == "SELL")
+            .await;
+        let (health_section, health_score) = self.pre_trade_health(&symbol).await;
+        let verdict = verdict(&checks, slippage_bps, health_score);
+
+        let order_line = match &args.price {
+            Some(limit) => format!("{} {} {} @ {} (LIMIT)", side, args.quantity, symbol, limit),
+            None => format!(
+                "{} {} {} @ ~{} (MARKET)",
+                side, args.quantity, symbol, price
+            ),
+        };
+        let mut content = format!(
+            "# Pre-Trade Check: {}\n\n## Exchange Filters\n\n",
+            order_line
+        );
+        for check in &checks {
+            content.push_str(&format!(
+                "- {} {}: {}\n",
+                if check.passed { "✅" } else { "❌" },
+                check.name,
+                check.detail
+            ));
+        }
+        content.push_str(&format!(
+            "\n## Slippage\n\n{}\n\n## Market Health\n\n{}\n\n## Verdict: **{}**\n\n",
+            slippage_section,
+            health_section,
+            verdict.label()
+        ));
+        content.push_str(match verdict {
+            Verdict::NoGo => {
+                "Binance would reject this order. Fix the failed checks above before placing it."
+            }
+            Verdict::Caution => {
+                "The order is valid, but execution conditions are poor. Consider a smaller size or a limit price."
+            }
+            Verdict::Go => {
+                "The order passes every available check."
+            }
+        });
+        content.push_str(&format!(
+            "\n\n*Last updated: {}*\n",
+            chrono::Utc::now().to_rfc3339()
+        ));
+
+        Ok(GetPromptResult {
+            description: Some("Pre-trade go/no-go checklist".to_string()),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, content)],
+        })
+    }
+
     /// Advanced market analysis prompt using orderbook analytics
     ///
     /// Provides comprehensive market analysis combining order flow, volume profile,
@@ -591,11 +1059,9 @@ impl BinanceServer {
             anomaly::detect_anomalies, flow::calculate_order_flow, health::calculate_health_score,
             profile::generate_volume_profile,
         };
-        use rust_decimal::Decimal;
-        use std::str::FromStr;
 
         let symbol = &args.symbol;
-        let storage = &self.snapshot_storage;
+        let storage = self.analytics_storage()?;
 
         // Determine time windows based on analysis depth
         let (flow_window, health_window, profile_hours) = match args.analysis_depth {
@@ -620,7 +1086,7 @@ impl BinanceServer {
             })?;
 
         // 3. Detect anomalies
-        let anomalies = detect_anomalies(storage, symbol, flow_window)
+        let anomaly_report = detect_anomalies(storage, symbol, flow_window)
             .await
             .map_err(|e| {
                 ErrorData::internal_error(format!("Failed to detect anomalies: {}", e), None)
@@ -645,96 +1111,127 @@ impl BinanceServer {
         );
 
         // Section 1: Order Flow Analysis
-        content.push_str(&format!(
-            "## 1. Order Flow Analysis (Last {} seconds)\n\n\
-            **Flow Direction**: **{:?}** {}\n\
-            - Bid Flow Rate: {:.2} orders/sec\n\
-            - Ask Flow Rate: {:.2} orders/sec\n\
-            - Net Flow: {:+.2} orders/sec\n\
-            - Cumulative Delta: {:+.2}\n\n\
-            *Interpretation*: {}\n\n---\n\n",
-            flow_window,
-            order_flow.flow_direction,
-            match order_flow.flow_direction {
-                FlowDirection::StrongBuy => "📈",
-                FlowDirection::ModerateBuy => "↗️",
-                FlowDirection::Neutral => "➡️",
-                FlowDirection::ModerateSell => "↘️",
-                FlowDirection::StrongSell => "📉",
-            },
-            order_flow.bid_flow_rate,
-            order_flow.ask_flow_rate,
-            order_flow.net_flow,
-            order_flow.cumulative_delta,
-            match order_flow.flow_direction {
-                FlowDirection::StrongBuy =>
-                    "Strong buying pressure with bid flow significantly higher than ask flow.",
-                FlowDirection::ModerateBuy =>
-                    "Moderate buying pressure. Bid flow exceeds ask flow.",
-                FlowDirection::Neutral => "Balanced market. Bid and ask flows are roughly equal.",
-                FlowDirection::ModerateSell =>
-                    "Moderate selling pressure. Ask flow exceeds bid flow.",
-                FlowDirection::StrongSell =>
-                    "Strong selling pressure with ask flow significantly higher than bid flow.",
-            }
-        ));
+        if order_flow.data_sufficiency == DataSufficiency::Insufficient {
+            content.push_str(&insufficient_data_section(
+                &format!("1. Order Flow Analysis (Last {} seconds)", flow_window),
+                order_flow.data_note.as_deref(),
+            ));
+        } else {
+            content.push_str(&format!(
+                "## 1. Order Flow Analysis (Last {} seconds)\n\n\
+                **Flow Direction**: **{:?}** {}\n\
+                - Bid Flow Rate: {:.2} orders/sec\n\
+                - Ask Flow Rate: {:.2} orders/sec\n\
+                - Net Flow: {:+.2} orders/sec\n\
+                - Cumulative Delta: {:+.2}\n\n\
+                *Interpretation*: {}\n\n---\n\n",
+                flow_window,
+                order_flow.flow_direction,
+                match order_flow.flow_direction {
+                    FlowDirection::StrongBuy => "📈",
+                    FlowDirection::ModerateBuy => "↗️",
+                    FlowDirection::Neutral => "➡️",
+                    FlowDirection::ModerateSell => "↘️",
+                    FlowDirection::StrongSell => "📉",
+                },
+                order_flow.bid_flow_rate,
+                order_flow.ask_flow_rate,
+                order_flow.net_flow,
+                order_flow.cumulative_delta,
+                match order_flow.flow_direction {
+                    FlowDirection::StrongBuy =>
+                        "Strong buying pressure with bid flow significantly higher than ask flow.",
+                    FlowDirection::ModerateBuy =>
+                        "Moderate buying pressure. Bid flow exceeds ask flow.",
+                    FlowDirection::Neutral =>
+                        "Balanced market. Bid and ask flows are roughly equal.",
+                    FlowDirection::ModerateSell =>
+                        "Moderate selling pressure. Ask flow exceeds bid flow.",
+                    FlowDirection::StrongSell =>
+                        "Strong selling pressure with ask flow significantly higher than bid flow.",
+                }
+            ));
+        }
 
         // Section 2: Volume Profile
-        content.push_str(&format!(
-            "## 2. Volume Profile (Last {} hours)\n\n\
-            **Key Price Levels:**\n\
-            - **POC (Point of Control)**: ${}\n\
-            - **VAH (Value Area High)**: ${}\n\
-            - **VAL (Value Area Low)**: ${}\n\n\
-            **Histogram**: {} price bins, {} bin size\n\n\
-            *Trading Strategy*: Price levels with high volume act as support/resistance. \
-            POC represents fair value.\n\n---\n\n",
-            profile_hours,
-            volume_profile.point_of_control,
-            volume_profile.value_area_high,
-            volume_profile.value_area_low,
-            volume_profile.histogram.len(),
-            volume_profile.bin_size
-        ));
+        if volume_profile.data_sufficiency == DataSufficiency::Insufficient {
+            content.push_str(&insufficient_data_section(
+                &format!("2. Volume Profile (Last {} hours)", profile_hours),
+                volume_profile.data_note.as_deref(),
+            ));
+        } else {
+            content.push_str(&format!(
+                "## 2. Volume Profile (Last {} hours)\n\n\
+                **Key Price Levels:**\n\
+                - **POC (Point of Control)**: ${}\n\
+                - **VAH (Value Area High)**: ${}\n\
+                - **VAL (Value Area Low)**: ${}\n\n\
+                **Histogram**: {} price bins, {} bin size\n\n\
+                *Trading Strategy*: Price levels with high volume act as support/resistance. \
+                POC represents fair value.\n\n---\n\n",
+                profile_hours,
+                volume_profile.point_of_control,
+                volume_profile.value_area_high,
+                volume_profile.value_area_low,
+                volume_profile.histogram.len(),
+                volume_profile.bin_size
+            ));
+        }
 
         // Section 3: Market Health
-        content.push_str(&format!(
-            "## 3. Market Microstructure Health\n\n\
-            **Overall Health Score**: **{:.0}/100** ({}) {}\n\n\
-            **Component Breakdown:**\n\
-            - Spread Stability: {:.0}/100\n\
-            - Liquidity Depth: {:.0}/100\n\
-            - Flow Balance: {:.0}/100\n\
-            - Update Rate: {:.0}/100\n\n\
-            **Trading Recommendation**: *{}*\n\n---\n\n",
-            health.overall_score,
-            health.health_level,
-            match health.health_level.as_str() {
-                "Excellent" => "✅",
-                "Good" => "✅",
-                "Fair" => "⚠️",
-                "Poor" => "⚠️",
-                "Critical" => "🔥",
-                _ => "",
-            },
-            health.spread_stability_score,
-            health.liquidity_depth_score,
-            health.flow_balance_score,
-            health.update_rate_score,
-            health.recommended_action
-        ));
+        if health.data_sufficiency == DataSufficiency::Insufficient {
+            content.push_str(&insufficient_data_section(
+                "3. Market Microstructure Health",
+                health.data_note.as_deref(),
+            ));
+        } else {
+            content.push_str(&format!(
+                "## 3. Market Microstructure Health\n\n\
+                **Overall Health Score**: **{:.0}/100** ({}) {}\n\n\
+                **Component Breakdown:**\n\
+                - Spread Stability: {:.0}/100\n\
+                - Liquidity Depth: {:.0}/100\n\
+                - Flow Balance: {:.0}/100\n\
+                - Update Rate: {:.0}/100\n\n\
+                **Trading Recommendation**: *{}*\n\n---\n\n",
+                health.overall_score,
+                health.health_level,
+                match health.health_level.as_str() {
+                    "Excellent" => "✅",
+                    "Good" => "✅",
+                    "Fair" => "⚠️",
+                    "Poor" => "⚠️",
+                    "Critical" => "🔥",
+                    _ => "",
+                },
+                health.spread_stability_score,
+                health.liquidity_depth_score,
+                health.flow_balance_score,
+                health.update_rate_score,
+                health.recommended_action
+            ));
+        }
 
         // Section 4: Anomaly Detection
+        let anomalies = &anomaly_report.anomalies;
         content.push_str(&format!(
             "## 4. Anomaly Detection\n\n\
             **Detected Anomalies**: {}\n\n",
             anomalies.len()
         ));
 
-        if anomalies.is_empty() {
+        if anomaly_report.data_sufficiency == DataSufficiency::Insufficient {
+            content.push_str(&format!(
+                "⚠️ **{}** No verdict - this is not an all-clear.\n\n",
+                anomaly_report
+                    .data_note
+                    .as_deref()
+                    .unwrap_or("Not enough data.")
+            ));
+        } else if anomalies.is_empty() {
             content.push_str("**No anomalies detected** - Market conditions appear normal.\n\n");
         } else {
-            for anomaly in &anomalies {
+            for anomaly in anomalies {
                 let severity_emoji = match anomaly.severity {
                     crate::orderbook::analytics::types::Severity::Critical => "🔥",
                     crate::orderbook::analytics::types::Severity::High => "⚠️",
@@ -760,16 +1257,26 @@ impl BinanceServer {
         // Section 5: Summary
         content.push_str(&format!(
             "## 5. Summary & Recommendations\n\n\
-            **Market Bias**: {:?}\n\
+            **Market Bias**: {}\n\
             **Risk Level**: {}\n\
-            **Health Score**: {:.0}/100\n\
+            **Health Score**: {}\n\
             **Anomalies**: {}\n\n\
             *Analysis generated using advanced orderbook analytics*\n\n\
             *Last updated: {}*\n",
-            order_flow.flow_direction,
+            if order_flow.data_sufficiency == DataSufficiency::Insufficient {
+                "Unknown (not enough data)".to_string()
+            } else {
+                format!("{:?}", order_flow.flow_direction)
+            },
             health.health_level,
-            health.overall_score,
-            if anomalies.is_empty() {
+            if health.data_sufficiency == DataSufficiency::Insufficient {
+                "n/a (not enough data)".to_string()
+            } else {
+                format!("{:.0}/100", health.overall_score)
+            },
+            if anomaly_report.data_sufficiency == DataSufficiency::Insufficient {
+                "Unknown (not enough data)"
+            } else if anomalies.is_empty() {
                 "None"
             } else {
                 "Detected (see above)"
@@ -799,7 +1306,7 @@ impl BinanceServer {
 
         let symbol = &args.symbol;
         let window_secs = args.window_secs.unwrap_or(60).clamp(10, 300);
-        let storage = &self.snapshot_storage;
+        let storage = self.analytics_storage()?;
 
         let order_flow = calculate_order_flow(storage, symbol, window_secs, None)
             .await
@@ -807,6 +1314,26 @@ impl BinanceServer {
                 ErrorData::internal_error(format!("Failed to calculate order flow: {}", e), None)
             })?;
 
+        if order_flow.data_sufficiency == DataSufficiency::Insufficient {
+            let content = format!(
+                "# Order Flow Snapshot: {}\n\n\
+                **Window**: Last {} seconds\n\
+                **Timestamp**: {}\n\n\
+                {}",
+                symbol,
+                window_secs,
+                chrono::Utc::now().to_rfc3339(),
+                insufficient_data_section(
+                    "Flow Direction: unknown",
+                    order_flow.data_note.as_deref()
+                )
+            );
+            return Ok(GetPromptResult {
+                description: Some("Real-time order flow snapshot (insufficient data)".to_string()),
+                messages: vec![PromptMessage::new_text(PromptMessageRole::User, content)],
+            });
+        }
+
         let content = format!(
             "# Order Flow Snapshot: {}\n\n\
             **Window**: Last {} seconds\n\
@@ -872,6 +1399,8 @@ impl BinanceServer {
     /// Market health check prompt
     ///
     /// Provides instant market health assessment before entering trades.
+    /// Includes a last-price vs `avgPrice` deviation that works even when
+    /// snapshot history is too sparse for a health score.
     #[cfg(feature = "orderbook_analytics")]
     #[prompt(
         name = "market_health_check",
@@ -884,13 +1413,40 @@ impl BinanceServer {
         use crate::orderbook::analytics::health::calculate_health_score;
 
         let symbol = &args.symbol;
-        let storage = &self.snapshot_storage;
-
-        let health = calculate_health_score(storage, symbol, 300)
-            .await
-            .map_err(|e| {
-                ErrorData::internal_error(format!("Failed to calculate health score: {}", e), None)
-            })?;
+        let storage = self.analytics_storage()?;
+
+        let price_signal = self.price_deviation_signal(symbol).await;
+
+        let health = match calculate_health_score(storage, symbol, 300).await {
+            Ok(health) if health.data_sufficiency == DataSufficiency::Sufficient => health,
+            result => {
+                let reason = match result {
+                    Ok(health) => health
+                        .data_note
+                        .unwrap_or_else(|| "Not enough data.".to_string()),
+                    Err(e) => {
+                        tracing::warn!(symbol = %symbol, error = %e, "Health score unavailable");
+                        e.to_string()
+                    }
+                };
+                let content = format!(
+                    "# Market Health: {}\n\n\
+                    **Overall Score**: unavailable ({})\n\n\
+                    **Price Signal:**\n\
+                    {}\n\n\
+                    *Snapshot history is still sparse; re-run once more data has been captured.*\n\n\
+                    *Last updated: {}*\n",
+                    symbol,
+                    reason,
+                    price_signal,
+                    chrono::Utc::now().to_rfc3339()
+                );
+                return Ok(GetPromptResult {
+                    description: Some("Market health assessment (price signal only)".to_string()),
+                    messages: vec![PromptMessage::new_text(PromptMessageRole::User, content)],
+                });
+            }
+        };
 
         let content = format!(
             "# Market Health: {}\n\n\
@@ -900,7 +1456,8 @@ impl BinanceServer {
             - {} Spread Stability: {:.0}/100\n\
             - {} Liquidity: {:.0}/100\n\
             - {} Flow Balance: {:.0}/100\n\
-            - {} Activity: {:.0}/100\n\n\
+            - {} Activity: {:.0}/100\n\
+            {}\n\n\
             **Risk Assessment**: {}\n\n\
             **Recommendation**: {}\n\n\
             *Last updated: {}*\n",
@@ -944,6 +1501,7 @@ impl BinanceServer {
                 "⚠️"
             },
             health.update_rate_score,
+            price_signal,
             match health.overall_score {
                 s if s >= 80.0 => "Low risk. Market conditions are optimal.",
                 s if s >= 60.0 => "Low-medium risk. Normal trading conditions.",
@@ -961,6 +1519,174 @@ impl BinanceServer {
         })
     }
 
+    /// Formats the last-price vs short-window average deviation as a breakdown line
+    ///
+    /// A last price far from `avgPrice` suggests a recent spike. Fetch failures
+    /// degrade to an "unavailable" line instead of failing the prompt.
+    #[cfg(feature = "orderbook_analytics")]
+    async fn price_deviation_signal(&self, symbol: &str) -> String {
+        let (avg_price, last_price) = tokio::join!(
+            self.binance_client.get_avg_price(symbol),
+            self.binance_client.get_ticker_price(symbol)
+        );
+
+        let (avg_price, last_price) = match (avg_price, last_price) {
+            (Ok(avg), Ok(last)) => (avg, last),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::warn!(symbol = %symbol, error = %e, "Price deviation signal unavailable");
+                return format!("- ⚠️ Last vs Avg Price: unavailable ({})", e);
+            }
+        };
+
+        let deviation = last_price
+            .price
+            .parse::<f64>()
+            .ok()
+            .and_then(|last| avg_price.deviation_bps(last));
+
+        match deviation {
+            Some(bps) if bps.abs() >= PRICE_SPIKE_THRESHOLD_BPS => format!(
+                "- ⚠️ Last vs {}m Avg Price: {:+.1} bps (last {}, avg {}) - possible recent spike",
+                avg_price.mins, bps, last_price.price, avg_price.price
+            ),
+            Some(bps) => format!(
+                "- ✅ Last vs {}m Avg Price: {:+.1} bps (last {}, avg {})",
+                avg_price.mins, bps, last_price.price, avg_price.price
+            ),
+            None => "- ⚠️ Last vs Avg Price: unavailable (unparseable prices)".to_string(),
+        }
+    }
+
+    /// Slippage section of `pre_trade_check` and its estimate in basis points
+    ///
+    /// Walks the local order book for a market fill of `quantity`; failures
+    /// degrade to an "unavailable" line instead of failing the prompt.
+    #[cfg(feature = "orderbook")]
+    async fn pre_trade_slippage(
+        &self,
+        symbol: &str,
+        quantity: f64,
+        is_sell: bool,
+    ) -> (String, Option<f64>) {
+        use crate::orderbook::metrics::estimate_slippage_for_quantity;
+        use crate::tools::pre_trade::SLIPPAGE_CAUTION_BPS;
+
+        let order_book = match self.orderbook_manager.get_order_book(symbol).await {
+            Ok(order_book) => order_book,
+            Err(e) => {
+                tracing::warn!(symbol = %symbol, error = %e, "Pre-trade slippage unavailable");
+                return (format!("- ⚠️ Unavailable ({})", e), None);
+            }
+        };
+
+        match estimate_slippage_for_quantity(&order_book, quantity, is_sell) {
+            Some(estimate) if estimate.filled_qty < quantity => (
+                format!(
+                    "- ⚠️ Book holds only {:.8} of {} on the {} side (avg {:.8}, {:.1} bps)",
+                    estimate.filled_qty,
+                    quantity,
+                    if is_sell { "bid" } else { "ask" },
+                    estimate.avg_price,
+                    estimate.slippage_bps
+                ),
+                // The unfilled remainder would walk past the visible book
+                Some(f64::INFINITY),
+            ),
+            Some(estimate) => (
+                format!(
+                    "- {} Market fill avg {:.8} ({:.1} bps vs best {})",
+                    if estimate.slippage_bps >= SLIPPAGE_CAUTION_BPS {
+                        "⚠️"
+                    } else {
+                        "✅"
+                    },
+                    estimate.avg_price,
+                    estimate.slippage_bps,
+                    if is_sell { "bid" } else { "ask" }
+                ),
+                Some(estimate.slippage_bps),
+            ),
+            None => ("- ⚠️ Unavailable (order book is empty)".to_string(), None),
+        }
+    }
+
+    /// Slippage section of `pre_trade_check` when the orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    async fn pre_trade_slippage(
+        &self,
+        _symbol: &str,
+        _quantity: f64,
+        _is_sell: bool,
+    ) -> (String, Option<f64>) {
+        (
+            "- ⏭️ Skipped (requires 'orderbook' feature)".to_string(),
+            None,
+        )
+    }
+
+    /// Health section of `pre_trade_check` and the overall health score
+    ///
+    /// Uses the same score and price signal as `market_health_check`. Sparse
+    /// snapshot history leaves the score out of the verdict.
+    #[cfg(feature = "orderbook_analytics")]
+    async fn pre_trade_health(&self, symbol: &str) -> (String, Option<f64>) {
+        use crate::orderbook::analytics::health::calculate_health_score;
+        use crate::tools::pre_trade::HEALTH_CAUTION_SCORE;
+
+        let price_signal = self.price_deviation_signal(symbol).await;
+        let Some(storage) = &self.snapshot_storage else {
+            return (
+                format!(
+                    "- ⚠️ Overall Score: unavailable (analytics storage unavailable)\n{}",
+                    price_signal
+                ),
+                None,
+            );
+        };
+        match calculate_health_score(storage, symbol, 300).await {
+            Ok(health) if health.data_sufficiency == DataSufficiency::Sufficient => (
+                format!(
+                    "- {} Overall Score: {:.0}/100 ({})\n{}",
+                    if health.overall_score >= HEALTH_CAUTION_SCORE {
+                        "✅"
+                    } else {
+                        "⚠️"
+                    },
+                    health.overall_score,
+                    health.health_level,
+                    price_signal
+                ),
+                Some(health.overall_score),
+            ),
+            Ok(health) => (
+                format!(
+                    "- ⚠️ Overall Score: unavailable ({})\n{}",
+                    health
+                        .data_note
+                        .unwrap_or_else(|| "Not enough data.".to_string()),
+                    price_signal
+                ),
+                None,
+            ),
+            Err(e) => {
+                tracing::warn!(symbol = %symbol, error = %e, "Pre-trade health unavailable");
+                (
+                    format!("- ⚠️ Overall Score: unavailable ({})\n{}", e, price_signal),
+                    None,
+                )
+            }
+        }
+    }
+
+    /// Health section of `pre_trade_check` when the orderbook_analytics feature is disabled
+    #[cfg(not(feature = "orderbook_analytics"))]
+    async fn pre_trade_health(&self, _symbol: &str) -> (String, Option<f64>) {
+        (
+            "- ⏭️ Skipped (requires 'orderbook_analytics' feature)".to_string(),
+            None,
+        )
+    }
+
     /// Stub implementation for advanced_market_analysis when orderbook_analytics feature is disabled
     #[cfg(not(feature = "orderbook_analytics"))]
     #[prompt(
@@ -1009,3 +1735,18 @@ impl BinanceServer {
         ))
     }
 }
+
+/// Markdown for an analytics prompt section whose result was flagged insufficient
+///
+/// Replaces the section's figures so prompts say "not enough data" instead of
+/// rendering zeroed metrics as if they were real.
+#[cfg(feature = "orderbook_analytics")]
+fn insufficient_data_section(title: &str, note: Option<&str>) -> String {
+    format!(
+        "## {}\n\n\
+        ⚠️ **{}**\n\n\
+        *No figures are shown for this section; re-run once more data has been captured.*\n\n---\n\n",
+        title,
+        note.unwrap_or("Not enough data.")
+    )
+}
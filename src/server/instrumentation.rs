@@ -0,0 +1,116 @@
+//! Per-tool-call instrumentation
+//!
+//! Wraps every tool invocation in a `tool_call` tracing span that records the
+//! elapsed time and outcome. Calls that exceed the slow-call threshold are
+//! logged at WARN so slow tools stand out in production logs without a full
+//! metrics stack.
+//!
+//! ## Configuration
+//!
+//! - `TOOL_SLOW_THRESHOLD_MS`: Slow-call threshold in milliseconds (default: 1500)
+
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
+
+/// Default slow-call threshold in milliseconds
+pub const DEFAULT_SLOW_TOOL_THRESHOLD_MS: u64 = 1500;
+
+/// Slow-call threshold, read once from `TOOL_SLOW_THRESHOLD_MS`
+static SLOW_TOOL_THRESHOLD: Lazy<Duration> =
+    Lazy::new(|| parse_slow_threshold(std::env::var("TOOL_SLOW_THRESHOLD_MS").ok().as_deref()));
+
+/// Parses the slow-call threshold, falling back to the default on missing or invalid input
+fn parse_slow_threshold(value: Option<&str>) -> Duration {
+    let millis = value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SLOW_TOOL_THRESHOLD_MS);
+    Duration::from_millis(millis)
+}
+
+/// Returns the configured slow-call threshold
+pub fn slow_tool_threshold() -> Duration {
+    *SLOW_TOOL_THRESHOLD
+}
+
+/// Runs a tool future inside a `tool_call` span and logs its latency on completion
+///
+/// The span carries `tool`, `elapsed_ms` and `success` fields. The duration is
+/// emitted at DEBUG; calls slower than [`slow_tool_threshold`] are emitted at WARN.
+///
+/// # Arguments
+/// * `tool` - Tool name recorded on the span
+/// * `is_error` - Classifies the tool output as a failure
+/// * `fut` - The tool invocation
+///
+/// # Returns
+/// The tool output, unchanged
+pub async fn instrument_tool_call<F, T>(tool: &str, is_error: impl FnOnce(&T) -> bool, fut: F) -> T
+where
+    F: Future<Output = T>,
+{
+    let span = tracing::info_span!(
+        "tool_call",
+        tool = %tool,
+        elapsed_ms = tracing::field::Empty,
+        success = tracing::field::Empty
+    );
+
+    let start = Instant::now();
+    let output = fut.instrument(span.clone()).await;
+    let elapsed = start.elapsed();
+    let success = !is_error(&output);
+
+    span.record("elapsed_ms", elapsed.as_millis() as u64);
+    span.record("success", success);
+
+    let _entered = span.enter();
+    let threshold = slow_tool_threshold();
+    if elapsed >= threshold {
+        tracing::warn!(
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = threshold.as_millis() as u64,
+            success,
+            "Slow tool call"
+        );
+    } else {
+        tracing::debug!(
+            elapsed_ms = elapsed.as_millis() as u64,
+            success,
+            "Tool call completed"
+        );
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_slow_threshold() {
+        assert_eq!(
+            parse_slow_threshold(Some("250")),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            parse_slow_threshold(None),
+            Duration::from_millis(DEFAULT_SLOW_TOOL_THRESHOLD_MS)
+        );
+        assert_eq!(
+            parse_slow_threshold(Some("not-a-number")),
+            Duration::from_millis(DEFAULT_SLOW_TOOL_THRESHOLD_MS)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_instrument_tool_call_passes_output_through() {
+        let output = instrument_tool_call("test_tool", |r: &Result<u32, ()>| r.is_err(), async {
+            Ok::<u32, ()>(42)
+        })
+        .await;
+        assert_eq!(output, Ok(42));
+    }
+}
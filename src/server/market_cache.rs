@@ -0,0 +1,312 @@
+//! Polled ticker cache backing the `binance://market/{symbol}` resource
+//!
+//! Without it every read of the market resource fetches `/ticker/24hr`, so
+//! several clients watching the same symbol multiply the request weight.
+//! Tickers are kept per symbol and refreshed by one background poller that
+//! fetches every tracked symbol in a single batched request. A symbol is
+//! tracked from its first read until it goes unread for [`IDLE_EXPIRY`].
+//!
+//! ## Configuration
+//!
+//! - `MARKET_CACHE_REFRESH_SECS`: Poll interval in seconds (default: 5, `0` disables the cache and fetches on every read)
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::binance::types::Ticker24hr;
+use crate::binance::BinanceClient;
+use crate::error::McpError;
+
+/// Default poll interval for tracked symbols
+pub const DEFAULT_MARKET_CACHE_REFRESH: Duration = Duration::from_secs(5);
+
+/// Symbols unread for this long are no longer polled
+pub const IDLE_EXPIRY: Duration = Duration::from_secs(300);
+
+/// Parses `MARKET_CACHE_REFRESH_SECS`, falling back to the default on missing or invalid input
+///
+/// `0` disables the cache.
+pub fn parse_market_cache_refresh(value: Option<&str>) -> Duration {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_MARKET_CACHE_REFRESH)
+}
+
+/// A cached ticker and when it was fetched
+#[derive(Debug, Clone)]
+pub struct CachedTicker {
+    /// 24-hour statistics as returned by Binance
+    pub ticker: Ticker24hr,
+    /// Time the ticker was fetched from Binance
+    pub as_of: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    cached: CachedTicker,
+    fetched_at: Instant,
+    last_read: Instant,
+}
+
+/// Per-symbol 24hr tickers refreshed by a background poller
+#[derive(Debug, Clone)]
+pub struct MarketCache {
+    refresh: Duration,
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl MarketCache {
+    /// Creates a cache polled every `refresh`; a zero interval disables caching
+    pub fn new(refresh: Duration) -> Self {
+        Self {
+            refresh,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Reads `MARKET_CACHE_REFRESH_SECS`
+    pub fn from_env() -> Self {
+        Self::new(parse_market_cache_refresh(
+            std::env::var("MARKET_CACHE_REFRESH_SECS").ok().as_deref(),
+        ))
+    }
+
+    /// Poll interval (zero when caching is disabled)
+    pub fn refresh_interval(&self) -> Duration {
+        self.refresh
+    }
+
+    /// Symbols currently polled, sorted
+    pub fn tracked_symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self
+            .entries
+            .lock()
+            .expect("market cache lock poisoned")
+            .keys()
+            .cloned()
+            .collect();
+        symbols.sort();
+        symbols
+    }
+
+    /// Returns the ticker for `symbol`, fetching it when not cached
+    ///
+    /// The first read starts tracking the symbol. Polls keep entries younger
+    /// than the interval; one older than two intervals means polls are
+    /// failing or not running, so it is fetched again.
+    pub async fn ticker(
+        &self,
+        client: &BinanceClient,
+        symbol: &str,
+    ) -> Result<CachedTicker, McpError> {
+        if self.refresh.is_zero() {
+            return Ok(CachedTicker {
+                ticker: client.get_24hr_ticker(symbol).await?,
+                as_of: Utc::now(),
+            });
+        }
+
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .expect("market cache lock poisoned")
+            .get_mut(symbol)
+        {
+            entry.last_read = Instant::now();
+            if entry.fetched_at.elapsed() < self.refresh * 2 {
+                return Ok(entry.cached.clone());
+            }
+        }
+
+        let ticker = client.get_24hr_ticker(symbol).await?;
+        Ok(self.store(ticker, true))
+    }
+
+    /// Refetches every tracked symbol in one request, first dropping idle ones
+    ///
+    /// Returns the number of symbols refreshed. On error the previous
+    /// tickers are kept.
+    pub async fn refresh(&self, client: &BinanceClient) -> Result<usize, McpError> {
+        let symbols = {
+            let mut entries = self.entries.lock().expect("market cache lock poisoned");
+            entries.retain(|_, entry| entry.last_read.elapsed() < IDLE_EXPIRY);
+            let mut symbols: Vec<String> = entries.keys().cloned().collect();
+            symbols.sort();
+            symbols
+        };
+        if symbols.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers = client.get_24hr_tickers(Some(&symbols)).await?;
+        let refreshed = tickers.len();
+        for ticker in tickers {
+            self.store(ticker, false);
+        }
+        Ok(refreshed)
+    }
+
+    /// Spawns the poller refreshing tracked symbols every interval
+    ///
+    /// Returns `None` when caching is disabled. Failures are logged and the
+    /// previous tickers kept. Must be called from within a tokio runtime.
+    pub fn spawn_refresh_task(&self, client: BinanceClient) -> Option<tokio::task::JoinHandle<()>> {
+        if self.refresh.is_zero() {
+            return None;
+        }
+        let cache = self.clone();
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(cache.refresh);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match cache.refresh(&client).await {
+                    Ok(0) => {}
+                    Ok(count) => tracing::debug!(symbols = count, "Market cache refreshed"),
+                    Err(e) => tracing::warn!("Failed to refresh market cache: {}", e),
+                }
+            }
+        }))
+    }
+
+    /// Stores a freshly fetched ticker; `read` marks the symbol as just read
+    fn store(&self, ticker: Ticker24hr, read: bool) -> CachedTicker {
+        let now = Instant::now();
+        let cached = CachedTicker {
+            ticker,
+            as_of: Utc::now(),
+        };
+        let mut entries = self.entries.lock().expect("market cache lock poisoned");
+        let last_read = match entries.get(&cached.ticker.symbol) {
+            Some(entry) if !read => entry.last_read,
+            _ => now,
+        };
+        entries.insert(
+            cached.ticker.symbol.clone(),
+            Entry {
+                cached: cached.clone(),
+                fetched_at: now,
+                last_read,
+            },
+        );
+        cached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ticker_json(symbol: &str, last_price: &str) -> serde_json::Value {
+        serde_json::json!({
+            "symbol": symbol, "priceChange": "0", "priceChangePercent": "0",
+            "weightedAvgPrice": last_price, "prevClosePrice": last_price,
+            "lastPrice": last_price, "lastQty": "1", "bidPrice": last_price,
+            "askPrice": last_price, "openPrice": last_price, "highPrice": last_price,
+            "lowPrice": last_price, "volume": "1", "quoteVolume": "1",
+            "openTime": 0, "closeTime": 0, "firstId": 0, "lastId": 0, "count": 0
+        })
+    }
+
+    fn client(server: &MockServer) -> BinanceClient {
+        BinanceClient {
+            base_url: server.uri(),
+            ..BinanceClient::new()
+        }
+    }
+
+    #[test]
+    fn test_parse_market_cache_refresh() {
+        assert_eq!(
+            parse_market_cache_refresh(None),
+            DEFAULT_MARKET_CACHE_REFRESH
+        );
+        assert_eq!(
+            parse_market_cache_refresh(Some("15")),
+            Duration::from_secs(15)
+        );
+        assert_eq!(parse_market_cache_refresh(Some("0")), Duration::ZERO);
+        assert_eq!(
+            parse_market_cache_refresh(Some("soon")),
+            DEFAULT_MARKET_CACHE_REFRESH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reads_within_interval_fetch_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .and(query_param("symbol", "BTCUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ticker_json("BTCUSDT", "65000")))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = client(&server);
+        let cache = MarketCache::new(Duration::from_secs(60));
+
+        let first = cache.ticker(&client, "BTCUSDT").await.unwrap();
+        let second = cache.ticker(&client, "BTCUSDT").await.unwrap();
+
+        assert_eq!(second.ticker.last_price, "65000");
+        assert_eq!(first.as_of, second.as_of);
+        assert_eq!(cache.tracked_symbols(), vec!["BTCUSDT"]);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_fetches_every_read() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ticker_json("BTCUSDT", "65000")))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let client = client(&server);
+        let cache = MarketCache::new(Duration::ZERO);
+
+        cache.ticker(&client, "BTCUSDT").await.unwrap();
+        cache.ticker(&client, "BTCUSDT").await.unwrap();
+        assert!(cache.tracked_symbols().is_empty());
+        assert!(cache.spawn_refresh_task(client).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_polls_tracked_symbols_in_one_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .and(query_param("symbol", "ETHUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ticker_json("ETHUSDT", "3000")))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .and(query_param("symbols", r#"["ETHUSDT"]"#))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([ticker_json("ETHUSDT", "3100")])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = client(&server);
+        let cache = MarketCache::new(Duration::from_secs(60));
+
+        // Nothing tracked yet, so nothing is fetched
+        assert_eq!(cache.refresh(&client).await.unwrap(), 0);
+
+        let before = cache.ticker(&client, "ETHUSDT").await.unwrap();
+        assert_eq!(cache.refresh(&client).await.unwrap(), 1);
+        let after = cache.ticker(&client, "ETHUSDT").await.unwrap();
+
+        assert_eq!(before.ticker.last_price, "3000");
+        assert_eq!(after.ticker.last_price, "3100");
+        assert!(after.as_of >= before.as_of);
+    }
+}
@@ -3,13 +3,28 @@
 //! This module contains the MCP server infrastructure including the ServerHandler
 //! trait implementation and tool routing logic.
 
+pub mod audit;
 pub mod handler;
+pub mod instrumentation;
+pub mod market_cache;
+pub mod pagination;
 pub mod resources;
+pub mod response_limit;
+pub mod response_meta;
 pub mod tool_router;
 pub mod types;
 
+use crate::binance::cache::LastKnownGood;
+use crate::binance::client::parse_time_sync_interval;
+use crate::binance::fetch_many::parse_multi_fetch_concurrency;
+use crate::binance::limits::parse_flag;
 use crate::binance::BinanceClient;
-use crate::config::Credentials;
+use crate::config::default_symbol::default_symbol_from_env;
+use crate::config::{Credentials, ProtocolNegotiation, ServerIdentity, ToolFilter};
+use crate::tools::idempotency::RecentClientOrderIds;
+use crate::tools::trading_guard::SymbolWhitelist;
+use market_cache::MarketCache;
+use response_limit::parse_max_tool_response_bytes;
 use rmcp::handler::server::router::prompt::PromptRouter;
 use rmcp::handler::server::router::tool::ToolRouter;
 
@@ -25,6 +40,9 @@ use crate::orderbook::OrderBookManager;
 #[cfg(feature = "orderbook_analytics")]
 use crate::orderbook::analytics::storage::SnapshotStorage;
 
+#[cfg(feature = "futures")]
+use crate::binance::BinanceFuturesClient;
+
 /// Main Binance MCP Server struct
 ///
 /// This struct holds the server state including Binance API client, credentials,
@@ -42,26 +60,88 @@ pub struct BinanceServer {
     pub tool_router: ToolRouter<Self>,
     /// Prompt router for MCP prompt routing
     pub prompt_router: PromptRouter<Self>,
+    /// Recently submitted client order IDs, for `place_order` duplicate protection
+    pub recent_client_order_ids: RecentClientOrderIds,
+    /// Allow order tools to execute against mainnet (`MAINNET_TRADING_ENABLED`)
+    pub mainnet_trading_enabled: bool,
+    /// Symbols order tools may trade (`TRADING_SYMBOL_WHITELIST`, empty = all)
+    pub trading_symbol_whitelist: SymbolWhitelist,
+    /// Allow `get_open_orders` without a symbol unless the caller opts in (`ALLOW_ALL_SYMBOL_OPEN_ORDERS`)
+    pub allow_all_symbol_open_orders: bool,
+    /// Last-known-good ticker/price responses served during outages (`ALLOW_STALE_ON_OUTAGE`)
+    pub last_known_good: LastKnownGood,
+    /// Polled tickers served by the market resource (`MARKET_CACHE_REFRESH_SECS`)
+    pub market_cache: MarketCache,
+    /// Tools exposed to clients (`TOOLS_ENABLED` / `TOOLS_DISABLED`)
+    pub tool_filter: ToolFilter,
+    /// Wrap tool responses in a `{data, _meta}` envelope (`RESPONSE_INCLUDE_META`)
+    pub response_include_meta: bool,
+    /// Largest tool response passed through, in bytes, `0` = unlimited (`MAX_TOOL_RESPONSE_BYTES`)
+    pub max_tool_response_bytes: usize,
+    /// Requests in flight at once for one multi-symbol tool call (`MULTI_FETCH_CONCURRENCY`)
+    pub multi_fetch_concurrency: usize,
+    /// Directory `export_order_history` writes files to (`ORDER_EXPORT_DIR`)
+    pub order_export_dir: std::path::PathBuf,
+    /// Fallback symbol for prompts and tools whose symbol is optional (`DEFAULT_SYMBOL`)
+    pub default_symbol: Option<String>,
+    /// Name and instructions returned at initialization (`MCP_SERVER_NAME` / `MCP_SERVER_INSTRUCTIONS`)
+    pub identity: ServerIdentity,
+    /// Protocol version answered at initialization (`MCP_PROTOCOL_VERSION` as fallback)
+    pub protocol: ProtocolNegotiation,
     /// Order book manager for depth analysis (feature-gated)
     #[cfg(feature = "orderbook")]
     pub orderbook_manager: Arc<OrderBookManager>,
-    /// Snapshot storage for analytics (feature-gated)
+    /// USD-M Futures market data client (feature-gated); `None` when it couldn't be built from the environment
+    #[cfg(feature = "futures")]
+    pub futures_client: Option<BinanceFuturesClient>,
+    /// Snapshot storage for analytics (feature-gated); `None` when `ORDERBOOK_STORAGE_PATH` couldn't be opened
     #[cfg(feature = "orderbook_analytics")]
-    pub snapshot_storage: Arc<SnapshotStorage>,
+    pub snapshot_storage: Option<Arc<SnapshotStorage>>,
+}
+
+/// Opens the analytics snapshot storage at `path`
+///
+/// A read-only, full or locked path logs an error and returns `None`, which
+/// disables the analytics prompts instead of aborting startup.
+#[cfg(feature = "orderbook_analytics")]
+pub fn open_snapshot_storage(path: &std::path::Path) -> Option<Arc<SnapshotStorage>> {
+    match SnapshotStorage::new(path) {
+        Ok(storage) => Some(Arc::new(storage)),
+        Err(e) => {
+            tracing::error!(
+                path = %path.display(),
+                error = %format!("{:#}", e),
+                "Failed to open snapshot storage; orderbook analytics disabled"
+            );
+            None
+        }
+    }
 }
 
 impl BinanceServer {
     /// Creates a new Binance server instance
     ///
-    /// Loads credentials from environment variables and initializes Binance API client.
+    /// Loads credentials from `BINANCE_CREDENTIALS_FILE` if set, otherwise from
+    /// environment variables, and initializes Binance API client.
     /// Logs credential status at INFO level (masked), or WARN if not configured.
     pub fn new() -> Self {
-        let credentials = match Credentials::from_env() {
+        let loaded = match std::env::var("BINANCE_CREDENTIALS_FILE") {
+            Ok(path) if !path.trim().is_empty() => {
+                Credentials::from_file(path.trim()).or_else(|err| {
+                    tracing::warn!("{}; falling back to environment variables", err);
+                    Credentials::from_env()
+                })
+            }
+            _ => Credentials::from_env(),
+        };
+
+        let credentials = match loaded {
             Ok(creds) => {
                 // T009: Log at INFO level with masked key (NEVER log secret_key)
                 tracing::info!(
-                    "API credentials configured (key: {})",
-                    creds.api_key // Display trait shows masked version
+                    "API credentials configured (key: {}, environment: {})",
+                    creds.api_key, // Display trait shows masked version
+                    creds.environment
                 );
                 Some(creds)
             }
@@ -75,32 +155,255 @@ impl BinanceServer {
             }
         };
 
+        let mainnet_trading_enabled =
+            parse_flag(std::env::var("MAINNET_TRADING_ENABLED").ok().as_deref());
+        if mainnet_trading_enabled {
+            tracing::warn!(
+                "MAINNET TRADING ENABLED: place_order and cancel_order will execute real orders on Binance mainnet"
+            );
+        } else {
+            tracing::info!(
+                "Mainnet trading disabled; order tools only run against testnet (set MAINNET_TRADING_ENABLED=true to allow)"
+            );
+        }
+
+        let trading_symbol_whitelist = SymbolWhitelist::from_env();
+        if trading_symbol_whitelist.is_restricted() {
+            tracing::info!(
+                "Order tools restricted to TRADING_SYMBOL_WHITELIST: {}",
+                trading_symbol_whitelist.symbols().join(", ")
+            );
+        }
+
+        let default_symbol = default_symbol_from_env();
+        if let Some(symbol) = &default_symbol {
+            tracing::info!(
+                "Default symbol for prompts and optional-symbol tools: {}",
+                symbol
+            );
+        }
+
         let binance_client = BinanceClient::new();
 
         #[cfg(feature = "orderbook")]
         let orderbook_manager = Arc::new(OrderBookManager::new(Arc::new(binance_client.clone())));
 
+        #[cfg(feature = "futures")]
+        let futures_client = BinanceFuturesClient::from_env()
+            .map_err(|e| {
+                tracing::error!(
+                    error = %e,
+                    "Failed to create futures HTTP client; futures tools disabled"
+                )
+            })
+            .ok();
+
         #[cfg(feature = "orderbook_analytics")]
         let snapshot_storage = {
             let storage_path = std::env::var("ORDERBOOK_STORAGE_PATH")
                 .unwrap_or_else(|_| "./data/orderbook_snapshots".to_string());
-            Arc::new(
-                SnapshotStorage::new(std::path::Path::new(&storage_path))
-                    .expect("Failed to initialize snapshot storage"),
-            )
+            open_snapshot_storage(std::path::Path::new(&storage_path))
         };
 
-        Self {
+        let mut server = Self {
             binance_client,
             credentials,
             #[cfg(feature = "sse")]
             session_manager: SessionManager::new(),
             tool_router: Self::tool_router(),
             prompt_router: Self::create_prompt_router(),
+            recent_client_order_ids: RecentClientOrderIds::default(),
+            mainnet_trading_enabled,
+            trading_symbol_whitelist,
+            allow_all_symbol_open_orders: parse_flag(
+                std::env::var("ALLOW_ALL_SYMBOL_OPEN_ORDERS")
+                    .ok()
+                    .as_deref(),
+            ),
+            last_known_good: LastKnownGood::new(parse_flag(
+                std::env::var("ALLOW_STALE_ON_OUTAGE").ok().as_deref(),
+            )),
+            market_cache: MarketCache::from_env(),
+            tool_filter: ToolFilter::default(),
+            response_include_meta: parse_flag(
+                std::env::var("RESPONSE_INCLUDE_META").ok().as_deref(),
+            ),
+            max_tool_response_bytes: parse_max_tool_response_bytes(
+                std::env::var("MAX_TOOL_RESPONSE_BYTES").ok().as_deref(),
+            ),
+            multi_fetch_concurrency: parse_multi_fetch_concurrency(
+                std::env::var("MULTI_FETCH_CONCURRENCY").ok().as_deref(),
+            ),
+            order_export_dir: crate::tools::order_export::parse_export_dir(
+                std::env::var("ORDER_EXPORT_DIR").ok().as_deref(),
+            ),
+            default_symbol,
+            identity: ServerIdentity::from_env(),
+            protocol: ProtocolNegotiation::from_env(),
             #[cfg(feature = "orderbook")]
             orderbook_manager,
+            #[cfg(feature = "futures")]
+            futures_client,
             #[cfg(feature = "orderbook_analytics")]
             snapshot_storage,
+        };
+        server.apply_tool_filter(ToolFilter::from_env());
+        server
+    }
+
+    /// Hides the tools `filter` disallows
+    ///
+    /// Removes them from the tool router so they no longer appear in
+    /// `tools/list`; the filter is kept so direct calls can be rejected with
+    /// `TOOL_DISABLED`.
+    pub fn apply_tool_filter(&mut self, filter: ToolFilter) {
+        let hidden: Vec<String> = self
+            .tool_router
+            .list_all()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .filter(|name| !filter.allows(name))
+            .collect();
+        for name in &hidden {
+            self.tool_router.remove_route(name);
+        }
+        if !hidden.is_empty() {
+            tracing::info!("Tools disabled by configuration: {}", hidden.join(", "));
+        }
+        self.tool_filter = filter;
+    }
+
+    /// Starts the background clock offset refresh for signed requests
+    ///
+    /// Interval comes from `TIME_SYNC_INTERVAL_SECS` (default: 300, `0` disables).
+    /// Must be called from within a tokio runtime.
+    pub fn spawn_time_sync(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let interval =
+            parse_time_sync_interval(std::env::var("TIME_SYNC_INTERVAL_SECS").ok().as_deref());
+        if interval.is_zero() {
+            tracing::info!("Background clock offset refresh disabled");
+            return None;
+        }
+        Some(self.binance_client.spawn_time_sync_task(interval))
+    }
+
+    /// Starts the market resource ticker poller
+    ///
+    /// Interval comes from `MARKET_CACHE_REFRESH_SECS` (default: 5, `0` disables).
+    /// Must be called from within a tokio runtime.
+    pub fn spawn_market_cache_refresh(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let handle = self
+            .market_cache
+            .spawn_refresh_task(self.binance_client.clone());
+        if handle.is_none() {
+            tracing::info!("Market resource cache disabled; tickers are fetched on every read");
+        }
+        handle
+    }
+
+    /// Starts warming the order books listed in `ORDERBOOK_PRELOAD_SYMBOLS`
+    ///
+    /// Returns `None` when no symbols are configured. Must be called from
+    /// within a tokio runtime.
+    #[cfg(feature = "orderbook")]
+    pub fn spawn_orderbook_preload(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let symbols = crate::orderbook::manager::parse_preload_symbols(
+            std::env::var("ORDERBOOK_PRELOAD_SYMBOLS").ok().as_deref(),
+        );
+        if symbols.is_empty() {
+            return None;
+        }
+        let manager = self.orderbook_manager.clone();
+        Some(tokio::spawn(async move {
+            manager
+                .preload(&symbols, crate::orderbook::manager::PRELOAD_WARMUP_TIMEOUT)
+                .await;
+        }))
+    }
+
+    /// Starts the periodic snapshot storage flush (orderbook_analytics only)
+    ///
+    /// Interval comes from `ORDERBOOK_FLUSH_INTERVAL_SECS` (default: 30, `0` disables).
+    /// Must be called from within a tokio runtime.
+    #[cfg(feature = "orderbook_analytics")]
+    pub fn spawn_snapshot_flush(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let interval = crate::orderbook::analytics::storage::parse_flush_interval(
+            std::env::var("ORDERBOOK_FLUSH_INTERVAL_SECS")
+                .ok()
+                .as_deref(),
+        );
+        let storage = self.snapshot_storage.as_ref()?;
+        if interval.is_zero() {
+            tracing::info!("Periodic snapshot storage flush disabled");
+            return None;
+        }
+        Some(storage.spawn_flush_task(interval))
+    }
+
+    /// Snapshot storage for the analytics prompts
+    ///
+    /// # Errors
+    /// Returns an internal error with `error_code: ANALYTICS_STORAGE_UNAVAILABLE`
+    /// when the storage failed to open at startup
+    #[cfg(feature = "orderbook_analytics")]
+    pub fn analytics_storage(&self) -> Result<&Arc<SnapshotStorage>, rmcp::ErrorData> {
+        self.snapshot_storage.as_ref().ok_or_else(|| {
+            rmcp::ErrorData::internal_error(
+                "Analytics storage unavailable: ORDERBOOK_STORAGE_PATH could not be opened at startup (see server logs). Market data and order tools still work.",
+                Some(serde_json::json!({
+                    "error_code": "ANALYTICS_STORAGE_UNAVAILABLE",
+                })),
+            )
+        })
+    }
+
+    /// USD-M Futures client for the futures tools
+    ///
+    /// # Errors
+    /// Returns an internal error with `error_code: FUTURES_CLIENT_UNAVAILABLE`
+    /// when the client could not be built at startup
+    #[cfg(feature = "futures")]
+    pub fn futures_client(&self) -> Result<&BinanceFuturesClient, rmcp::ErrorData> {
+        self.futures_client.as_ref().ok_or_else(|| {
+            rmcp::ErrorData::internal_error(
+                "Futures client unavailable: it could not be created from the environment at startup (see server logs). Spot tools still work.",
+                Some(serde_json::json!({
+                    "error_code": "FUTURES_CLIENT_UNAVAILABLE",
+                })),
+            )
+        })
+    }
+
+    /// Flushes buffered state before the process exits
+    ///
+    /// Called from the graceful-shutdown path; with `orderbook_analytics` this
+    /// forces recent snapshots to disk.
+    pub async fn shutdown(&self) {
+        #[cfg(feature = "orderbook_analytics")]
+        if let Some(storage) = &self.snapshot_storage {
+            match storage.flush().await {
+                Ok(()) => tracing::info!("Snapshot storage flushed"),
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to flush snapshot storage on shutdown")
+                }
+            }
+        }
+    }
+
+    /// Resolves an optional symbol argument, falling back to `DEFAULT_SYMBOL`
+    ///
+    /// # Errors
+    /// Returns invalid params if the symbol is malformed, or omitted without a
+    /// configured default
+    pub fn symbol_or_default(&self, symbol: Option<&str>) -> Result<String, rmcp::ErrorData> {
+        match symbol {
+            Some(symbol) => Ok(crate::binance::normalize_symbol(symbol)?),
+            None => self.default_symbol.clone().ok_or_else(|| {
+                rmcp::ErrorData::invalid_params(
+                    "symbol is required (no DEFAULT_SYMBOL configured)",
+                    None,
+                )
+            }),
         }
     }
 
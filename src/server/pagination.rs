@@ -0,0 +1,103 @@
+//! Cursor-based pagination for MCP list methods
+//!
+//! Items are ordered by a stable string key (resource URI, prompt name) and the
+//! cursor is the key of the last item on the previous page. Resuming from a
+//! cursor returns every item whose key sorts after it, so paging stays
+//! deterministic with no duplicates or gaps even if the list is rebuilt
+//! between calls.
+
+/// Number of items returned per page by list methods
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Returns one page of `items` and the cursor for the next page
+///
+/// # Arguments
+/// * `items` - Full, unordered item list
+/// * `key` - Stable ordering key for an item (must be unique)
+/// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+/// * `page_size` - Maximum items per page (values below 1 are treated as 1)
+///
+/// # Returns
+/// The page of items and `Some(next_cursor)` when more items remain
+pub fn paginate<T>(
+    mut items: Vec<T>,
+    key: impl Fn(&T) -> &str,
+    cursor: Option<&str>,
+    page_size: usize,
+) -> (Vec<T>, Option<String>) {
+    items.sort_by(|a, b| key(a).cmp(key(b)));
+
+    let start = match cursor {
+        Some(cursor) => items.partition_point(|item| key(item) <= cursor),
+        None => 0,
+    };
+
+    let mut page: Vec<T> = items.into_iter().skip(start).collect();
+    let has_more = page.len() > page_size.max(1);
+    page.truncate(page_size.max(1));
+
+    let next_cursor = if has_more {
+        page.last().map(|item| key(item).to_string())
+    } else {
+        None
+    };
+
+    (page, next_cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate_walks_all_pages() {
+        let items: Vec<String> = (0..23).rev().map(|i| format!("item-{:02}", i)).collect();
+
+        let mut seen = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut pages = 0;
+        loop {
+            let (page, next) = paginate(items.clone(), |s| s.as_str(), cursor.as_deref(), 5);
+            assert!(page.len() <= 5);
+            seen.extend(page);
+            pages += 1;
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        let mut expected = items.clone();
+        expected.sort();
+        assert_eq!(pages, 5);
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn test_paginate_single_page() {
+        let items = vec!["b", "a", "c"];
+        let (page, next) = paginate(items, |s| s, None, 10);
+        assert_eq!(page, ["a", "b", "c"]);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn test_paginate_exact_page_has_no_cursor() {
+        let items = vec!["a", "b", "c", "d"];
+        let (page, next) = paginate(items.clone(), |s| s, None, 2);
+        assert_eq!(page, ["a", "b"]);
+        assert_eq!(next.as_deref(), Some("b"));
+
+        let (page, next) = paginate(items, |s| s, next.as_deref(), 2);
+        assert_eq!(page, ["c", "d"]);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn test_paginate_cursor_past_end() {
+        let items = vec!["a", "b"];
+        let (page, next) = paginate(items, |s| s, Some("z"), 2);
+        assert!(page.is_empty());
+        assert_eq!(next, None);
+    }
+}
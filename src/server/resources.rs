@@ -1,7 +1,14 @@
 //! Resource URI Handling
 //!
 //! This module defines the resource URI parser and category types for MCP resources
-//! including market data, account balances, and order information.
+//! including market data, account balances, and order information, plus the
+//! number formatting used by the markdown representations.
+
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Significant figures shown for prices when the symbol's tick size is unknown
+pub const DISPLAY_PRICE_SIG_FIGS: u32 = 8;
 
 /// Resource category types for URI parsing (T025)
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -12,6 +19,28 @@ pub enum ResourceCategory {
     Account,
     /// Order information resources (e.g., binance://orders/open)
     Orders,
+    /// Recent trade resources (e.g., binance://trades/btcusdt)
+    Trades,
+}
+
+/// Representation requested for a resource
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ResourceFormat {
+    /// Human-readable markdown (default)
+    #[default]
+    Markdown,
+    /// Raw JSON, requested with a `.json` suffix (e.g., binance://market/btcusdt.json)
+    Json,
+}
+
+impl ResourceFormat {
+    /// MIME type of contents in this format
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ResourceFormat::Markdown => "text/markdown",
+            ResourceFormat::Json => "application/json",
+        }
+    }
 }
 
 /// Parsed resource URI structure (T026)
@@ -19,32 +48,44 @@ pub enum ResourceCategory {
 pub struct ResourceUri {
     /// URI scheme (always "binance")
     pub scheme: String,
-    /// Resource category (market/account/orders)
+    /// Resource category (market/account/orders/trades)
     pub category: ResourceCategory,
     /// Optional resource identifier (e.g., "btcusdt", "balances", "open")
     pub identifier: Option<String>,
+    /// Requested representation (`.json` suffix selects JSON)
+    pub format: ResourceFormat,
 }
 
 impl ResourceUri {
     /// Parse a resource URI string (T027)
     ///
-    /// Expected format: `binance://{category}/{identifier}`
+    /// Expected format: `binance://{category}/{identifier}`, optionally with a
+    /// `.json` suffix on the identifier to request JSON instead of markdown.
     ///
     /// # Examples
     ///
     /// ```
-    /// use mcp_binance_server::server::resources::{ResourceUri, ResourceCategory};
+    /// use mcp_binance_server::server::resources::{ResourceCategory, ResourceFormat, ResourceUri};
     ///
     /// let uri = ResourceUri::parse("binance://market/btcusdt").unwrap();
     /// assert_eq!(uri.category, ResourceCategory::Market);
     /// assert_eq!(uri.identifier, Some("btcusdt".to_string()));
+    /// assert_eq!(uri.format, ResourceFormat::Markdown);
+    ///
+    /// let uri = ResourceUri::parse("binance://market/btcusdt.json").unwrap();
+    /// assert_eq!(uri.identifier, Some("btcusdt".to_string()));
+    /// assert_eq!(uri.format, ResourceFormat::Json);
+    ///
+    /// let uri = ResourceUri::parse("binance://trades/ethusdt").unwrap();
+    /// assert_eq!(uri.category, ResourceCategory::Trades);
+    /// assert_eq!(uri.identifier, Some("ethusdt".to_string()));
     /// ```
     ///
     /// # Errors
     ///
     /// Returns an error string if:
     /// - URI doesn't start with "binance://"
-    /// - Category is not one of: market, account, orders
+    /// - Category is not one of: market, account, orders, trades
     /// - URI format is invalid
     pub fn parse(uri: &str) -> Result<Self, String> {
         // Split by "://"
@@ -75,17 +116,26 @@ impl ResourceUri {
             "market" => ResourceCategory::Market,
             "account" => ResourceCategory::Account,
             "orders" => ResourceCategory::Orders,
+            "trades" => ResourceCategory::Trades,
             other => {
                 return Err(format!(
-                    "Unknown category: '{}'. Valid categories: market, account, orders",
+                    "Unknown category: '{}'. Valid categories: market, account, orders, trades",
                     other
                 ));
             }
         };
 
-        // Parse optional identifier
+        // Parse optional identifier and format suffix
+        let mut format = ResourceFormat::Markdown;
         let identifier = if path_parts.len() > 1 {
-            Some(path_parts[1].to_string())
+            let id = match path_parts[1].strip_suffix(".json") {
+                Some(stripped) => {
+                    format = ResourceFormat::Json;
+                    stripped
+                }
+                None => path_parts[1],
+            };
+            Some(id.to_string())
         } else {
             None
         };
@@ -94,6 +144,91 @@ impl ResourceUri {
             scheme: "binance".to_string(),
             category,
             identifier,
+            format,
         })
     }
 }
+
+/// Formats a Binance price string for markdown display
+///
+/// With the symbol's tick size, trailing zeros beyond the tick's decimals are
+/// stripped (`67650.12000000` at tick `0.01` → `67650.12`, `67650.10000000`
+/// → `67650.10`). Without one, the price is rounded to
+/// [`DISPLAY_PRICE_SIG_FIGS`] significant figures and trailing zeros dropped.
+/// Values that don't parse are returned unchanged.
+pub fn display_price(raw: &str, tick_size: Option<Decimal>) -> String {
+    let Ok(price) = Decimal::from_str(raw.trim()) else {
+        return raw.to_string();
+    };
+
+    match tick_size {
+        Some(tick) => {
+            let tick_dp = tick.normalize().scale();
+            let mut price = price.normalize();
+            if price.scale() < tick_dp {
+                price.rescale(tick_dp);
+            }
+            price.to_string()
+        }
+        None => price
+            .round_sf(DISPLAY_PRICE_SIG_FIGS)
+            .unwrap_or(price)
+            .normalize()
+            .to_string(),
+    }
+}
+
+/// Formats a Binance quantity or balance string for markdown display
+///
+/// Drops trailing zeros without rounding (`0.50000000` → `0.5`), so no amount
+/// is ever misrepresented. Values that don't parse are returned unchanged.
+pub fn display_amount(raw: &str) -> String {
+    Decimal::from_str(raw.trim())
+        .map(|amount| amount.normalize().to_string())
+        .unwrap_or_else(|_| raw.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_display_price_with_tick_size() {
+        // High-priced asset with a 0.01 tick
+        let tick = Some(dec("0.01000000"));
+        assert_eq!(display_price("67650.12000000", tick), "67650.12");
+        assert_eq!(display_price("67650.10000000", tick), "67650.10");
+        assert_eq!(display_price("67650.00000000", tick), "67650.00");
+
+        // Low-priced asset with a 0.00000001 tick keeps every significant digit
+        let tick = Some(dec("0.00000001"));
+        assert_eq!(display_price("0.00001234", tick), "0.00001234");
+        assert_eq!(display_price("0.00001200", tick), "0.00001200");
+
+        // Whole-number ticks drop the fraction entirely
+        assert_eq!(display_price("95000.00000000", Some(dec("1.00"))), "95000");
+    }
+
+    #[test]
+    fn test_display_price_without_tick_size() {
+        assert_eq!(display_price("67650.12000000", None), "67650.12");
+        assert_eq!(display_price("0.00001234", None), "0.00001234");
+        assert_eq!(display_price("67650.123456789", None), "67650.123");
+        assert_eq!(display_price("0.000012345678912", None), "0.000012345679");
+        assert_eq!(display_price("-1234.50000000", None), "-1234.5");
+        assert_eq!(display_price("n/a", None), "n/a");
+    }
+
+    #[test]
+    fn test_display_amount() {
+        assert_eq!(display_amount("0.50000000"), "0.5");
+        assert_eq!(display_amount("10000.00"), "10000");
+        assert_eq!(display_amount("0.00000001"), "0.00000001");
+        assert_eq!(display_amount("12345.67891234"), "12345.67891234");
+        assert_eq!(display_amount(""), "");
+    }
+}
@@ -0,0 +1,176 @@
+//! Size guard for tool responses
+//!
+//! Broad calls (`get_all_orders` over a long history, `get_open_orders`
+//! across all symbols, a 5000-level `get_order_book`) can produce megabytes
+//! of JSON, far beyond what fits in an LLM context. After a tool has run,
+//! the transport dispatchers measure the text it returned; a response larger
+//! than `MAX_TOOL_RESPONSE_BYTES` is replaced by a `RESPONSE_TOO_LARGE` error
+//! telling the caller to narrow the query, and the event is logged.
+//!
+//! ## Configuration
+//!
+//! - `MAX_TOOL_RESPONSE_BYTES`: Largest tool response passed through, in bytes (default: 1048576, `0` disables the guard)
+
+use serde_json::{json, Value};
+
+use rmcp::model::{CallToolResult, Content, RawContent};
+
+/// Default maximum tool response size (1 MiB)
+pub const DEFAULT_MAX_TOOL_RESPONSE_BYTES: usize = 1024 * 1024;
+
+/// Error code of an oversized response
+pub const RESPONSE_TOO_LARGE: &str = "RESPONSE_TOO_LARGE";
+
+/// Parses `MAX_TOOL_RESPONSE_BYTES`, falling back to the default on missing or invalid input
+///
+/// `0` disables the guard.
+pub fn parse_max_tool_response_bytes(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .unwrap_or(DEFAULT_MAX_TOOL_RESPONSE_BYTES)
+}
+
+/// Error body replacing a `tool` response of `size` bytes
+pub fn too_large_body(tool: &str, size: usize, max: usize) -> Value {
+    json!({
+        "error_code": RESPONSE_TOO_LARGE,
+        "message": format!(
+            "{} returned {} bytes, more than the {} byte limit. Narrow the query: pass a symbol, lower the limit or shorten the time range.",
+            tool, size, max
+        ),
+        "details": {
+            "tool": tool,
+            "size_bytes": size,
+            "max_bytes": max,
+        },
+    })
+}
+
+/// Logs an oversized response and returns the body replacing it, if `size` exceeds `max`
+fn check(tool: &str, size: usize, max: usize) -> Option<Value> {
+    if max == 0 || size <= max {
+        return None;
+    }
+    tracing::warn!(
+        tool = %tool,
+        size_bytes = size,
+        max_bytes = max,
+        "Tool response exceeds MAX_TOOL_RESPONSE_BYTES; returning RESPONSE_TOO_LARGE"
+    );
+    Some(too_large_body(tool, size, max))
+}
+
+/// Replaces a tool result whose text content is larger than `max` bytes
+pub fn guard_result(tool: &str, result: &mut CallToolResult, max: usize) {
+    let size = result
+        .content
+        .iter()
+        .filter_map(|content| match &content.raw {
+            RawContent::Text(text) => Some(text.text.len()),
+            _ => None,
+        })
+        .sum();
+    if let Some(body) = check(tool, size, max) {
+        *result = CallToolResult::error(vec![Content::text(body.to_string())]);
+    }
+}
+
+/// Replaces a serialized tool result whose text content is larger than `max` bytes
+/// (Streamable HTTP transport)
+pub fn guard_result_value(tool: &str, result: &mut Value, max: usize) {
+    let size = result
+        .get("content")
+        .and_then(Value::as_array)
+        .map(|content| {
+            content
+                .iter()
+                .filter_map(|item| item.get("text").and_then(Value::as_str))
+                .map(str::len)
+                .sum()
+        })
+        .unwrap_or(0);
+    if let Some(body) = check(tool, size, max) {
+        *result = json!({
+            "content": [{"type": "text", "text": body.to_string()}],
+            "isError": true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A synthetic `get_open_orders` response of `count` orders
+    fn orders(count: usize) -> String {
+        let orders: Vec<Value> = (0..count)
+            .map(|i| json!({"symbol": format!("SYM{i}USDT"), "orderId": i, "price": "1.00000000"}))
+            .collect();
+        Value::Array(orders).to_string()
+    }
+
+    fn error_body(text: &str) -> Value {
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_max_tool_response_bytes() {
+        assert_eq!(
+            parse_max_tool_response_bytes(None),
+            DEFAULT_MAX_TOOL_RESPONSE_BYTES
+        );
+        assert_eq!(parse_max_tool_response_bytes(Some("65536")), 65536);
+        assert_eq!(parse_max_tool_response_bytes(Some("0")), 0);
+        assert_eq!(
+            parse_max_tool_response_bytes(Some("1MB")),
+            DEFAULT_MAX_TOOL_RESPONSE_BYTES
+        );
+    }
+
+    #[test]
+    fn test_oversized_result_is_replaced() {
+        let text = orders(2000);
+        let size = text.len();
+        let mut result = CallToolResult::success(vec![Content::text(text)]);
+
+        guard_result("get_open_orders", &mut result, 64 * 1024);
+
+        assert_eq!(result.is_error, Some(true));
+        let body = error_body(&result.content[0].as_text().unwrap().text);
+        assert_eq!(body["error_code"], RESPONSE_TOO_LARGE);
+        assert_eq!(body["details"]["size_bytes"], size);
+        assert_eq!(body["details"]["max_bytes"], 64 * 1024);
+        assert!(body["message"]
+            .as_str()
+            .unwrap()
+            .contains("Narrow the query"));
+    }
+
+    #[test]
+    fn test_small_or_unguarded_results_pass_through() {
+        let text = orders(10);
+        let mut result = CallToolResult::success(vec![Content::text(text.clone())]);
+        guard_result("get_open_orders", &mut result, 64 * 1024);
+        assert_eq!(result.content[0].as_text().unwrap().text, text);
+
+        // 0 disables the guard
+        let mut result = CallToolResult::success(vec![Content::text(orders(2000))]);
+        guard_result("get_open_orders", &mut result, 0);
+        assert_ne!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_guard_result_value() {
+        let mut value = json!({"content": [{"type": "text", "text": orders(2000)}]});
+        guard_result_value("get_all_orders", &mut value, 1024);
+
+        assert_eq!(value["isError"], true);
+        let body = error_body(value["content"][0]["text"].as_str().unwrap());
+        assert_eq!(body["error_code"], RESPONSE_TOO_LARGE);
+        assert_eq!(body["details"]["tool"], "get_all_orders");
+
+        let mut small = json!({"content": [{"type": "text", "text": "[]"}]});
+        guard_result_value("get_all_orders", &mut small, 1024);
+        assert_eq!(small["content"][0]["text"], "[]");
+    }
+}
@@ -0,0 +1,217 @@
+//! Opt-in provenance envelope for tool responses
+//!
+//! With `RESPONSE_INCLUDE_META` set, the JSON text of every successful tool
+//! response is wrapped as `{data: <original>, _meta: {source, latency_ms,
+//! fetched_at}}` by the transport dispatchers, so agents can tell live
+//! Binance data from cached or locally computed values. Off by default: the
+//! envelope changes the shape consumers parse.
+//!
+//! The source is derived from the REST requests the call actually sent (see
+//! [`collect_request_ids`]): none means the response was served from a cache.
+//! Tools computed from the local order book always report `local-book`.
+//!
+//! ## Configuration
+//!
+//! - `RESPONSE_INCLUDE_META`: Wrap tool responses in the envelope (default: false)
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::future::Future;
+use std::time::Instant;
+
+use crate::binance::client::collect_request_ids;
+use rmcp::model::{CallToolResult, RawContent};
+
+/// Tools answered from the local order book maintained over WebSocket
+const LOCAL_BOOK_TOOLS: &[&str] = &[
+    "get_orderbook_metrics",
+    "get_orderbook_depth",
+    "get_aggregated_depth",
+    "get_key_levels",
+    "get_depth_stats",
+    "get_orderbook_health",
+    "get_order_flow",
+    "detect_market_anomalies",
+    "get_liquidity_vacuums",
+    "get_microstructure_health",
+    "get_imbalance_series",
+];
+
+/// Where a tool response came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ResponseSource {
+    /// Live Spot REST API (`/api/v3`, `/sapi`)
+    #[serde(rename = "binance-api-v3")]
+    BinanceApi,
+    /// Live USD-M futures REST API (`/fapi/v1`)
+    #[serde(rename = "binance-fapi-v1")]
+    BinanceFuturesApi,
+    /// Served without contacting Binance (response caches, last-known-good data)
+    #[serde(rename = "cache")]
+    Cache,
+    /// Computed from the local order book
+    #[serde(rename = "local-book")]
+    LocalBook,
+}
+
+impl ResponseSource {
+    /// Source of a `tool` response that sent `live_requests` REST requests
+    pub fn classify(tool: &str, live_requests: usize) -> Self {
+        if LOCAL_BOOK_TOOLS.contains(&tool) {
+            ResponseSource::LocalBook
+        } else if live_requests == 0 {
+            ResponseSource::Cache
+        } else if tool.starts_with("get_futures_") {
+            ResponseSource::BinanceFuturesApi
+        } else {
+            ResponseSource::BinanceApi
+        }
+    }
+}
+
+/// The `_meta` object of the envelope
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResponseMeta {
+    /// Where the data came from
+    pub source: ResponseSource,
+    /// Time spent handling the call, in milliseconds
+    pub latency_ms: u64,
+    /// When the response was produced (milliseconds since Unix epoch)
+    pub fetched_at: i64,
+}
+
+/// Runs a tool call and records the metadata for its envelope
+pub async fn measure<F: Future>(tool: &str, call: F) -> (F::Output, ResponseMeta) {
+    let started = Instant::now();
+    let (output, request_ids) = collect_request_ids(call).await;
+    let meta = ResponseMeta {
+        source: ResponseSource::classify(tool, request_ids.len()),
+        latency_ms: started.elapsed().as_millis() as u64,
+        fetched_at: chrono::Utc::now().timestamp_millis(),
+    };
+    (output, meta)
+}
+
+/// Wraps a JSON response text as `{data, _meta}`
+///
+/// Returns `None` for non-JSON text and structured refusals (bodies with an
+/// `error_code`), which are passed through unchanged.
+pub fn wrap_text(text: &str, meta: &ResponseMeta) -> Option<String> {
+    let data: Value = serde_json::from_str(text).ok()?;
+    if data.get("error_code").is_some_and(Value::is_string) {
+        return None;
+    }
+    Some(json!({ "data": data, "_meta": meta }).to_string())
+}
+
+/// Wraps the text content of a successful tool result
+pub fn wrap_result(result: &mut CallToolResult, meta: &ResponseMeta) {
+    if result.is_error == Some(true) {
+        return;
+    }
+    for content in &mut result.content {
+        if let RawContent::Text(text) = &mut content.raw {
+            if let Some(wrapped) = wrap_text(&text.text, meta) {
+                text.text = wrapped;
+            }
+        }
+    }
+}
+
+/// Wraps the text content of a serialized tool result (Streamable HTTP transport)
+pub fn wrap_result_value(result: &mut Value, meta: &ResponseMeta) {
+    if result.get("isError").and_then(Value::as_bool) == Some(true) {
+        return;
+    }
+    let Some(content) = result.get_mut("content").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for item in content {
+        let wrapped = item
+            .get("text")
+            .and_then(Value::as_str)
+            .and_then(|text| wrap_text(text, meta));
+        if let Some(wrapped) = wrapped {
+            item["text"] = Value::String(wrapped);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+
+    fn meta(source: ResponseSource) -> ResponseMeta {
+        ResponseMeta {
+            source,
+            latency_ms: 12,
+            fetched_at: 1_700_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_classify_source() {
+        assert_eq!(
+            ResponseSource::classify("get_ticker", 1),
+            ResponseSource::BinanceApi
+        );
+        assert_eq!(
+            ResponseSource::classify("get_ticker", 0),
+            ResponseSource::Cache
+        );
+        assert_eq!(
+            ResponseSource::classify("get_futures_ticker", 1),
+            ResponseSource::BinanceFuturesApi
+        );
+        // The first call may fetch a REST snapshot, but the data is the local book
+        assert_eq!(
+            ResponseSource::classify("get_orderbook_metrics", 1),
+            ResponseSource::LocalBook
+        );
+    }
+
+    #[test]
+    fn test_wrap_result() {
+        let mut result = CallToolResult::success(vec![Content::text(
+            json!({"symbol": "BTCUSDT", "price": "65000.00"}).to_string(),
+        )]);
+
+        wrap_result(&mut result, &meta(ResponseSource::Cache));
+
+        let text = &result.content[0].as_text().unwrap().text;
+        let envelope: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(envelope["data"]["price"], "65000.00");
+        assert_eq!(envelope["_meta"]["source"], "cache");
+        assert_eq!(envelope["_meta"]["latency_ms"], 12);
+        assert_eq!(envelope["_meta"]["fetched_at"], 1_700_000_000_000i64);
+    }
+
+    #[test]
+    fn test_refusals_and_errors_are_not_wrapped() {
+        let refusal = json!({"error_code": "SYMBOL_NOT_ALLOWED", "message": "no"}).to_string();
+        assert_eq!(wrap_text(&refusal, &meta(ResponseSource::BinanceApi)), None);
+        assert_eq!(
+            wrap_text("plain text", &meta(ResponseSource::BinanceApi)),
+            None
+        );
+
+        let mut error = json!({"isError": true, "content": [{"type": "text", "text": "{}"}]});
+        wrap_result_value(&mut error, &meta(ResponseSource::BinanceApi));
+        assert_eq!(error["content"][0]["text"], "{}");
+
+        let mut value = json!({"content": [{"type": "text", "text": "[1,2]"}]});
+        wrap_result_value(&mut value, &meta(ResponseSource::BinanceApi));
+        let envelope: Value =
+            serde_json::from_str(value["content"][0]["text"].as_str().unwrap()).unwrap();
+        assert_eq!(envelope["data"], json!([1, 2]));
+        assert_eq!(envelope["_meta"]["source"], "binance-api-v3");
+    }
+
+    #[tokio::test]
+    async fn test_measure_counts_no_requests_as_cache() {
+        let (output, meta) = measure("get_ticker", async { 7 }).await;
+        assert_eq!(output, 7);
+        assert_eq!(meta.source, ResponseSource::Cache);
+    }
+}
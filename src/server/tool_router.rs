@@ -4,17 +4,56 @@
 //! Automatically generates JSON Schema for tool parameters and provides
 //! structured routing for all Binance API tools.
 
+use crate::binance::fetch_many::fetch_many;
+use crate::binance::filters::symbol_increments;
+use crate::binance::limits::{
+    check_open_orders_scope, validate_depth_limit, HistoryQuery, TransferHistoryQuery,
+};
+use crate::binance::normalize_symbol;
+use crate::binance::types::{AccountInfo, AccountPermissions, Order, WalletTransfer};
+use crate::error::{explain_binance_error, known_binance_error_codes, map_binance_err, McpError};
+use crate::server::audit::{self, OrderAudit};
 use crate::server::BinanceServer;
+use crate::tools::balance_check::check_balance;
+use crate::tools::batch_cancel::{cancel_each, dedupe_client_order_ids, MAX_CANCEL_BATCH};
+use crate::tools::book_summary::{summarize_book, SUMMARY_DEPTH};
+use crate::tools::capabilities::Capabilities;
+use crate::tools::idempotency::validate_client_order_id;
+use crate::tools::momentum::{resolve_windows, Momentum};
+use crate::tools::open_orders::{OpenOrderSort, OpenOrdersView};
+use crate::tools::order_export::{
+    collect_order_history, export_file_name, render_orders, write_export, ExportFormat,
+};
+use crate::tools::order_wait::{wait_until_terminal, WaitPlan};
+use crate::tools::ping::{resolve_ping_count, PingStats};
+use crate::tools::quote_quantity::quote_to_quantity;
+use crate::tools::resample::{
+    resample_klines, resampled_interval, resolve_resample_limits, ResampledKlines,
+};
+use crate::tools::symbol_rules::explain_symbol_rules;
+use crate::tools::tickers::{rank_tickers, TickerSortBy, DEFAULT_TICKER_LIMIT};
+use crate::tools::time_in_force::resolve_time_in_force;
+use crate::tools::trade_flow::{summarize_trades, RecentTrade};
+use crate::tools::trading_guard::check_mainnet_trading;
+use crate::tools::trailing_stop::{trailing_delta_bps, trailing_stop_type};
+use crate::tools::watchlist::{overview_each, prepare_watchlist, MAX_WATCHLIST_SYMBOLS};
 use rmcp::handler::server::wrapper::Parameters;
-use rmcp::model::{CallToolResult, Content};
+use rmcp::model::{CallToolResult, Content, Meta};
 use rmcp::{tool, tool_router, ErrorData};
+use rust_decimal::Decimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::future::Future;
+use std::str::FromStr;
 
+#[cfg(feature = "sse")]
+use crate::error::CredentialError;
 #[cfg(feature = "sse")]
 use crate::tools::credentials::{validate_api_key, validate_api_secret};
 #[cfg(feature = "sse")]
+use crate::tools::order_export::session_export_dir;
+#[cfg(feature = "sse")]
 use crate::transport::sse::session::Credentials;
 #[cfg(feature = "sse")]
 use crate::types::Environment;
@@ -33,6 +72,12 @@ pub struct OrderBookParam {
     /// Depth limit: 5, 10, 20, 50, 100, 500, 1000, 5000 (default: 100)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Snap an unsupported limit to the nearest valid value instead of rejecting it (default: false)
+    #[serde(default)]
+    pub snap: bool,
+    /// Return only best bid/ask, mid price and spread from a depth-5 fetch; limit is ignored (default: false)
+    #[serde(default)]
+    pub summary_only: bool,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -44,6 +89,15 @@ pub struct RecentTradesParam {
     pub limit: Option<u32>,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TradeFlowParam {
+    /// Trading pair symbol (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Number of recent trades to summarize (default: 500, max: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct KlinesParam {
     /// Trading pair symbol (e.g., BTCUSDT)
@@ -55,6 +109,60 @@ pub struct KlinesParam {
     pub limit: Option<u32>,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ResampledKlinesParam {
+    /// Trading pair symbol (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Interval of the Binance klines to aggregate (e.g., 1h, 4h)
+    pub base_interval: String,
+    /// Base klines per synthetic kline (at least 2, e.g., 2 for 2h from 1h)
+    pub target_multiple: u32,
+    /// Number of synthetic klines (default: 100; limit × target_multiple at most 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TickersParam {
+    /// Symbols to include (e.g., ["BTCUSDT", "ETHUSDT"]); omit for all symbols
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<Vec<String>>,
+    /// Sort order: quote_volume (default), gainers, losers, movers (absolute change)
+    #[serde(default)]
+    pub sort_by: TickerSortBy,
+    /// Maximum tickers to return (default: 20, max: 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct WatchlistParam {
+    /// Symbols to include (e.g., ["BTCUSDT", "ETHUSDT"]; max 20)
+    pub symbols: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ExplainErrorParam {
+    /// Binance error code (e.g., -2010; the sign is optional)
+    pub code: i32,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct PingParam {
+    /// Number of pings to send (default: 3, max: 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct MomentumParam {
+    /// Trading pair symbol (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Rolling windows to compare (1m-59m, 1h-23h, 1d-7d; default: ["1h", "4h", "1d"], max 8)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub windows: Option<Vec<String>>,
+}
+
 // SSE version with session_id
 #[cfg(feature = "sse")]
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -77,6 +185,15 @@ pub struct AccountTradesParam {
     /// Number of trades (default: 500, max: 1000)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Only trades at or after this time (ms since epoch); cannot be combined with from_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only trades at or before this time (ms since epoch); window at most 24h
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Return trades with ID >= from_id (use next_from_id from the previous page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<i64>,
     /// Session ID from Mcp-Session-Id header
     pub session_id: String,
 }
@@ -90,11 +207,93 @@ pub struct AccountTradesParam {
     /// Number of trades (default: 500, max: 1000)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Only trades at or after this time (ms since epoch); cannot be combined with from_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only trades at or before this time (ms since epoch); window at most 24h
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Return trades with ID >= from_id (use next_from_id from the previous page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<i64>,
+}
+
+impl AccountTradesParam {
+    /// Paging and time filters for `myTrades`
+    fn history(&self) -> HistoryQuery {
+        HistoryQuery {
+            limit: self.limit,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            from_id: self.from_id,
+        }
+    }
 }
 
 // SSE version with session_id
 #[cfg(feature = "sse")]
 #[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TransferHistoryParam {
+    /// Only records for this coin (e.g., USDT); omit for all coins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    /// Only records at or after this time (ms since epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only records at or before this time (ms since epoch); window at most 90 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Maximum records (default: 1000, max: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (no session_id)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TransferHistoryParam {
+    /// Only records for this coin (e.g., USDT); omit for all coins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    /// Only records at or after this time (ms since epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only records at or before this time (ms since epoch); window at most 90 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Maximum records (default: 1000, max: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl TransferHistoryParam {
+    /// Coin and time filters for the wallet history endpoints
+    fn history(&self) -> TransferHistoryQuery {
+        TransferHistoryQuery {
+            coin: self.coin.clone(),
+            start_time: self.start_time,
+            end_time: self.end_time,
+            limit: self.limit,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct QuoteToQuantityParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Amount of the quote asset to spend (e.g., "50" for $50 on a USDT pair)
+    pub quote_amount: String,
+    /// Limit price to convert at (default: current price)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+}
+
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PlaceOrderParam {
     /// Trading pair (e.g., BTCUSDT)
     pub symbol: String,
@@ -108,13 +307,25 @@ pub struct PlaceOrderParam {
     /// Price for LIMIT orders (required for LIMIT)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<String>,
+    /// Time in force for LIMIT orders: GTC, IOC or FOK (default: GTC). Rejected for MARKET orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<String>,
+    /// Optional client order ID (1-36 chars: A-Z a-z 0-9 . : / _ -). Reusing it on a retry returns the existing order instead of placing a duplicate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    /// Skip the pre-flight free balance check (default: false). Balances can change between the check and the submit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_balance_check: Option<bool>,
+    /// Round price to the nearest tickSize and quantity down to the stepSize before submitting (default: false). The submitted values are returned in _meta.auto_round
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_round: Option<bool>,
     /// Session ID from Mcp-Session-Id header
     pub session_id: String,
 }
 
 // Non-SSE version (no session_id)
 #[cfg(not(feature = "sse"))]
-#[derive(Serialize, Deserialize, JsonSchema)]
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PlaceOrderParam {
     /// Trading pair (e.g., BTCUSDT)
     pub symbol: String,
@@ -128,6 +339,54 @@ pub struct PlaceOrderParam {
     /// Price for LIMIT orders (required for LIMIT)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<String>,
+    /// Time in force for LIMIT orders: GTC, IOC or FOK (default: GTC). Rejected for MARKET orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<String>,
+    /// Optional client order ID (1-36 chars: A-Z a-z 0-9 . : / _ -). Reusing it on a retry returns the existing order instead of placing a duplicate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    /// Skip the pre-flight free balance check (default: false). Balances can change between the check and the submit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_balance_check: Option<bool>,
+    /// Round price to the nearest tickSize and quantity down to the stepSize before submitting (default: false). The submitted values are returned in _meta.auto_round
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_round: Option<bool>,
+}
+
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TrailingStopParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Order side: SELL to protect a long, BUY to protect a short
+    pub side: String,
+    /// Quantity to trade (e.g., 0.001)
+    pub quantity: String,
+    /// Optional price at which trailing starts; omit to trail from the current price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<String>,
+    /// Trailing distance in percent (0.1 to 20, e.g., 2 = 2%)
+    pub trailing_percent: f64,
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (no session_id)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TrailingStopParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Order side: SELL to protect a long, BUY to protect a short
+    pub side: String,
+    /// Quantity to trade (e.g., 0.001)
+    pub quantity: String,
+    /// Optional price at which trailing starts; omit to trail from the current price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<String>,
+    /// Trailing distance in percent (0.1 to 20, e.g., 2 = 2%)
+    pub trailing_percent: f64,
 }
 
 // SSE version with session_id
@@ -152,13 +411,77 @@ pub struct OrderParam {
     pub order_id: i64,
 }
 
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct WaitForOrderParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Order ID
+    pub order_id: i64,
+    /// Seconds to wait for a terminal status (default 30, max 60)
+    pub timeout_secs: Option<f64>,
+    /// Seconds between status checks (default 1, min 0.5)
+    pub poll_interval_secs: Option<f64>,
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (no session_id)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct WaitForOrderParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Order ID
+    pub order_id: i64,
+    /// Seconds to wait for a terminal status (default 30, max 60)
+    pub timeout_secs: Option<f64>,
+    /// Seconds between status checks (default 1, min 0.5)
+    pub poll_interval_secs: Option<f64>,
+}
+
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct CancelOrdersParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Client order IDs to cancel (max 50; blanks and repeats are ignored)
+    pub client_order_ids: Vec<String>,
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (no session_id)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct CancelOrdersParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Client order IDs to cancel (max 50; blanks and repeats are ignored)
+    pub client_order_ids: Vec<String>,
+}
+
 // SSE version with session_id
 #[cfg(feature = "sse")]
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct OpenOrdersParam {
-    /// Trading pair (optional, returns all if omitted)
+    /// Trading pair (optional; defaults to DEFAULT_SYMBOL, otherwise omitting it scans every pair and needs all_symbols=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
+    /// Confirm an all-symbol scan when symbol is omitted (weight 80 instead of 6)
+    #[serde(default)]
+    pub all_symbols: bool,
+    /// Sort the orders: price (lowest first), time (oldest first) or symbol
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<OpenOrderSort>,
+    /// Only return orders on this side (BUY or SELL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    /// Only return orders with this status (e.g., NEW, PARTIALLY_FILLED)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
     /// Session ID from Mcp-Session-Id header
     pub session_id: String,
 }
@@ -167,9 +490,32 @@ pub struct OpenOrdersParam {
 #[cfg(not(feature = "sse"))]
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct OpenOrdersParam {
-    /// Trading pair (optional, returns all if omitted)
+    /// Trading pair (optional; defaults to DEFAULT_SYMBOL, otherwise omitting it scans every pair and needs all_symbols=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
+    /// Confirm an all-symbol scan when symbol is omitted (weight 80 instead of 6)
+    #[serde(default)]
+    pub all_symbols: bool,
+    /// Sort the orders: price (lowest first), time (oldest first) or symbol
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<OpenOrderSort>,
+    /// Only return orders on this side (BUY or SELL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    /// Only return orders with this status (e.g., NEW, PARTIALLY_FILLED)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl OpenOrdersParam {
+    /// Filters and ordering requested for the result
+    fn view(&self) -> OpenOrdersView {
+        OpenOrdersView {
+            sort_by: self.sort_by,
+            side: self.side.clone(),
+            status: self.status.clone(),
+        }
+    }
 }
 
 // SSE version with session_id
@@ -181,6 +527,15 @@ pub struct AllOrdersParam {
     /// Number of orders (default: 500, max: 1000)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Only orders at or after this time (ms since epoch); cannot be combined with order_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only orders at or before this time (ms since epoch); window at most 24h
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Return orders with ID >= order_id (use next_from_id from the previous page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
     /// Session ID from Mcp-Session-Id header
     pub session_id: String,
 }
@@ -194,6 +549,59 @@ pub struct AllOrdersParam {
     /// Number of orders (default: 500, max: 1000)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Only orders at or after this time (ms since epoch); cannot be combined with order_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// Only orders at or before this time (ms since epoch); window at most 24h
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Return orders with ID >= order_id (use next_from_id from the previous page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+}
+
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ExportOrderHistoryParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Export orders created at or after this time (ms since epoch)
+    pub start_time: i64,
+    /// Export orders created at or before this time (ms since epoch); any span, paged internally
+    pub end_time: i64,
+    /// File format: "csv" (default) or "json"
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (no session_id)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ExportOrderHistoryParam {
+    /// Trading pair (e.g., BTCUSDT)
+    pub symbol: String,
+    /// Export orders created at or after this time (ms since epoch)
+    pub start_time: i64,
+    /// Export orders created at or before this time (ms since epoch); any span, paged internally
+    pub end_time: i64,
+    /// File format: "csv" (default) or "json"
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+impl AllOrdersParam {
+    /// Paging and time filters for `allOrders`
+    fn history(&self) -> HistoryQuery {
+        HistoryQuery {
+            limit: self.limit,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            from_id: self.order_id,
+        }
+    }
 }
 
 #[cfg(feature = "sse")]
@@ -227,6 +635,19 @@ pub struct CredentialsStatusParam {
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct CredentialsStatusParam {}
 
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct CapabilitiesParam {
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE version (environment credentials only)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct CapabilitiesParam {}
+
 // SSE version with session_id
 #[cfg(feature = "sse")]
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -240,6 +661,19 @@ pub struct RevokeCredentialsParam {
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct RevokeCredentialsParam {}
 
+// SSE version with session_id
+#[cfg(feature = "sse")]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserStreamHealthParam {
+    /// Session ID from Mcp-Session-Id header
+    pub session_id: String,
+}
+
+// Non-SSE stub version (user data streams need a credentialed session)
+#[cfg(not(feature = "sse"))]
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserStreamHealthParam {}
+
 #[tool_router(vis = "pub")]
 impl BinanceServer {
     /// Get current Binance server time
@@ -266,7 +700,7 @@ impl BinanceServer {
             .binance_client
             .get_server_time()
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
         // Calculate offset
         let local_time_after = std::time::SystemTime::now()
@@ -303,6 +737,86 @@ impl BinanceServer {
         )]))
     }
 
+    /// Measure round-trip latency to Binance
+    ///
+    /// Calls `/api/v3/ping` `count` times in sequence and reports min/avg/max
+    /// round-trip time. An unreachable API is reported in the result
+    /// (`reachable: false`) rather than as a tool error.
+    #[tool(
+        description = "Measure round-trip latency to Binance by calling /api/v3/ping `count` times (default 3, max 10, weight 1 each). Returns {ping_ms: {min, avg, max}, reachable: true}, or {reachable: false, error} if Binance can't be reached. Use to tell a slow or unreachable Binance apart from a tool problem; get_server_time reports clock offset instead."
+    )]
+    pub async fn ping_binance(
+        &self,
+        params: Parameters<PingParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let count = resolve_ping_count(params.0.count)
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let mut samples_ms = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let started = std::time::Instant::now();
+            if let Err(e) = self.binance_client.ping().await {
+                tracing::warn!("Binance ping failed: {}", e);
+                let response_json = json!({
+                    "reachable": false,
+                    "error": e.to_string()
+                });
+                return Ok(CallToolResult::success(vec![Content::text(
+                    response_json.to_string(),
+                )]));
+            }
+            samples_ms.push(started.elapsed().as_secs_f64() * 1000.0);
+        }
+
+        let response_json = json!({
+            "ping_ms": PingStats::from_samples(&samples_ms),
+            "reachable": true
+        });
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Report what this deployment supports
+    ///
+    /// Compiled features come from `cfg!`, so agents can skip tools that would
+    /// only return a "feature not enabled" stub.
+    #[tool(
+        description = "Get this deployment's capabilities: {features: {orderbook, orderbook_analytics, sse, http_api, websocket, futures}, authenticated, mainnet_trading_enabled, default_symbol, version}. Call first to avoid tools whose feature isn't compiled in (they only return a 'not enabled' error) and to know whether account/order tools have credentials."
+    )]
+    pub async fn get_capabilities(
+        &self,
+        params: Parameters<CapabilitiesParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let env_credentials =
+            self.binance_client.api_key.is_some() && self.binance_client.api_secret.is_some();
+        #[cfg(feature = "sse")]
+        let authenticated = env_credentials
+            || self
+                .session_manager
+                .get_credentials(&params.0.session_id)
+                .await
+                .is_some();
+        #[cfg(not(feature = "sse"))]
+        let authenticated = {
+            let _ = &params;
+            env_credentials
+        };
+
+        let capabilities = Capabilities::new(
+            authenticated,
+            self.mainnet_trading_enabled,
+            self.default_symbol.clone(),
+        );
+        let response_json = serde_json::to_value(&capabilities)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
     /// Configure API credentials for session (SSE feature only)
     ///
     /// Stores Binance API credentials (testnet or mainnet) for this session.
@@ -318,11 +832,12 @@ impl BinanceServer {
     ///
     /// # Returns
     ///
-    /// Success response with configuration details or structured error.
+    /// Success response with configuration details.
     ///
     /// # Errors
     ///
-    /// Returns error if:
+    /// Returns a tool error whose data is the structured `CredentialError`
+    /// payload (`error_code`, `message`) if:
     /// - API key format invalid (not 64 alphanumeric chars)
     /// - API secret format invalid (not 64 alphanumeric chars)
     /// - Environment invalid (not "testnet" or "mainnet")
@@ -337,41 +852,13 @@ impl BinanceServer {
     ) -> Result<CallToolResult, ErrorData> {
         let p = params.0;
 
-        // Validate API key format (synchronous, <10ms)
-        if let Err(e) = validate_api_key(&p.api_key) {
-            let error_json = json!({
-                "error_code": "INVALID_API_KEY_FORMAT",
-                "message": e.to_string(),
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
-
-        // Validate API secret format (synchronous, <10ms)
-        if let Err(e) = validate_api_secret(&p.api_secret) {
-            let error_json = json!({
-                "error_code": "INVALID_API_SECRET_FORMAT",
-                "message": e.to_string(),
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
-
-        // Parse environment
-        let environment = match p.environment.parse::<Environment>() {
-            Ok(env) => env,
-            Err(msg) => {
-                let error_json = json!({
-                    "error_code": "INVALID_ENVIRONMENT",
-                    "message": msg,
-                });
-                return Ok(CallToolResult::success(vec![Content::text(
-                    error_json.to_string(),
-                )]));
-            }
-        };
+        // Validate key, secret and environment (synchronous, <10ms)
+        validate_api_key(&p.api_key)?;
+        validate_api_secret(&p.api_secret)?;
+        let environment = p
+            .environment
+            .parse::<Environment>()
+            .map_err(CredentialError::InvalidEnvironment)?;
 
         // Create credentials struct
         let credentials = Credentials::new(
@@ -382,16 +869,13 @@ impl BinanceServer {
         );
 
         // Store credentials in session manager
-        let stored = self.session_manager.store_credentials(credentials).await;
-
+        let stored = self
+            .session_manager
+            .store_credentials(credentials.clone())
+            .await;
+
         if !stored {
-            let error_json = json!({
-                "error_code": "SESSION_NOT_FOUND",
-                "message": format!("Session {} not found. Ensure SSE connection is active.", p.session_id),
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
+            return Err(CredentialError::SessionNotFound(p.session_id).into());
         }
 
         // Log successful configuration (mask API key, never log secret)
@@ -403,11 +887,21 @@ impl BinanceServer {
             "API credentials configured for session"
         );
 
+        // Push order updates for these credentials to the session's event stream
+        #[cfg(feature = "websocket")]
+        crate::transport::sse::order_updates::spawn_order_update_stream(
+            self.session_manager.clone(),
+            self.binance_client.clone(),
+            credentials,
+        )
+        .await;
+
         // Return success response
         let response_json = json!({
             "configured": true,
             "environment": environment.to_string(),
             "key_prefix": key_prefix,
+            "order_updates": cfg!(feature = "websocket"),
             "message": format!("Credentials successfully configured for {} environment", environment),
         });
 
@@ -500,6 +994,71 @@ impl BinanceServer {
         ))
     }
 
+    /// Get liveness of the session's user data stream
+    ///
+    /// Reports whether the order update stream opened by `configure_credentials`
+    /// is connected and how long ago its last event arrived, so agents relying
+    /// on fill notifications can detect a dead stream and fall back to polling.
+    ///
+    /// # Response Format
+    ///
+    /// ```json
+    /// {
+    ///   "user_stream": { "connected": true, "last_event_age_ms": 12500 }
+    /// }
+    /// ```
+    ///
+    /// `user_stream` is `null` when no stream is running for the session.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Get liveness of this session's user data (order update) stream: connected flag and last_event_age_ms (null until the first event; quiet accounts receive no events). user_stream is null when no stream is running (no credentials configured, or built without 'websocket')."
+    )]
+    pub async fn get_user_stream_health(
+        &self,
+        params: Parameters<UserStreamHealthParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        #[cfg(feature = "websocket")]
+        let health = self
+            .session_manager
+            .user_stream_health(&params.0.session_id)
+            .await;
+        #[cfg(not(feature = "websocket"))]
+        let health: Option<serde_json::Value> = {
+            let _ = &params;
+            None
+        };
+
+        let response_json = match health {
+            Some(health) => json!({ "user_stream": health }),
+            None => json!({
+                "user_stream": null,
+                "message": if cfg!(feature = "websocket") {
+                    "No user data stream for this session. Call configure_credentials to start one"
+                } else {
+                    "Order update streams require the 'websocket' feature"
+                },
+            }),
+        };
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Stub implementation for get_user_stream_health when SSE feature is disabled
+    #[cfg(not(feature = "sse"))]
+    #[tool(description = "User stream health not available (requires 'sse' feature)")]
+    pub async fn get_user_stream_health(
+        &self,
+        _params: Parameters<UserStreamHealthParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "User data streams are not enabled in this deployment. Rebuild with --features sse,websocket"
+                .to_string(),
+            None,
+        ))
+    }
+
     /// Revoke API credentials from current session
     ///
     /// Clears credentials from the session without closing the connection.
@@ -559,43 +1118,68 @@ impl BinanceServer {
     ///
     /// Returns price change statistics for the last 24 hours for a trading pair.
     #[tool(
-        description = "Get 24-hour ticker price change statistics for a symbol. Returns price, volume, high, low, and change percentage."
+        description = "Get 24-hour ticker price change statistics for a symbol. Returns price, volume, high, low, and change percentage. If the server sets ALLOW_STALE_ON_OUTAGE, a Binance outage returns the last known ticker marked stale=true with as_of (ms) instead of an error."
     )]
     pub async fn get_ticker(
         &self,
         params: Parameters<SymbolParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let ticker = self
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let fetched = self
             .binance_client
-            .get_24hr_ticker(&params.0.symbol)
+            .get_24hr_ticker(&symbol)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .and_then(|ticker| Ok(serde_json::to_value(&ticker)?));
 
-        let response_json = serde_json::to_value(&ticker)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let response_json = self
+            .last_known_good
+            .resolve(&format!("ticker|{}", symbol), fetched)
+            .map_err(map_binance_err)?;
 
         Ok(CallToolResult::success(vec![Content::text(
             response_json.to_string(),
         )]))
     }
 
-    /// Get order book depth
+    /// Get multi-timeframe price changes for a symbol
     ///
-    /// Returns current order book with bids and asks for a trading pair.
+    /// Fetches one rolling-window ticker per window (bounded by
+    /// `MULTI_FETCH_CONCURRENCY`) alongside the 24hr ticker.
     #[tool(
-        description = "Get current order book depth (bids and asks) for a symbol. Returns price levels and quantities."
+        description = "Get multi-timeframe momentum for a symbol: price change percent over several rolling windows (default 1h, 4h, 1d; 1m-59m, 1h-23h, 1d-7d, max 8) in one call. Returns {symbol, changes: {\"1h\": 1.25, ...}, last_price}."
     )]
-    pub async fn get_order_book(
+    pub async fn get_momentum(
         &self,
-        params: Parameters<OrderBookParam>,
+        params: Parameters<MomentumParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let order_book = self
-            .binance_client
-            .get_order_book(&params.0.symbol, params.0.limit)
+        let symbol = normalize_symbol(&params.0.symbol)?;
+        let windows = resolve_windows(params.0.windows.as_deref())
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let collect_windows = async {
+            fetch_many(windows, self.multi_fetch_concurrency, |window| {
+                let symbol = &symbol;
+                async move {
+                    self.binance_client
+                        .get_rolling_window_ticker(symbol, &window)
+                        .await
+                }
+            })
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .into_iter()
+            .map(|(window, ticker)| Ok((window, ticker.map_err(map_binance_err)?)))
+            .collect::<Result<Vec<_>, ErrorData>>()
+        };
 
-        let response_json = serde_json::to_value(&order_book)
+        let (changes, ticker) = tokio::join!(
+            collect_windows,
+            self.binance_client.get_24hr_ticker(&symbol)
+        );
+        let ticker = ticker.map_err(map_binance_err)?;
+        let momentum = Momentum::new(symbol, &changes?, ticker.last_price);
+
+        let response_json = serde_json::to_value(&momentum)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -603,23 +1187,42 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get recent trades
+    /// Get 24-hour ticker statistics for many symbols at once
     ///
-    /// Returns list of recent trades for a trading pair.
+    /// Fetches all symbols (or the given list) in one request, sorts server-side
+    /// and returns a compact, token-bounded list for market overviews.
     #[tool(
-        description = "Get recent public trades for a symbol. Returns trade history with prices, quantities, and timestamps."
+        description = "Get 24h tickers for all symbols or a symbols list in one call, sorted by quote_volume (default), gainers, losers or movers (absolute % change). Returns top N (default 20, max 100) as {symbol, lastPrice, priceChangePercent, quoteVolume}."
     )]
-    pub async fn get_recent_trades(
+    pub async fn get_all_tickers(
         &self,
-        params: Parameters<RecentTradesParam>,
+        params: Parameters<TickersParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let trades = self
+        let symbols = params
+            .0
+            .symbols
+            .as_ref()
+            .map(|symbols| {
+                symbols
+                    .iter()
+                    .map(|s| normalize_symbol(s))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        let tickers = self
             .binance_client
-            .get_recent_trades(&params.0.symbol, params.0.limit)
+            .get_24hr_tickers(symbols.as_deref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&trades)
+        let ranked = rank_tickers(
+            &tickers,
+            params.0.sort_by,
+            params.0.limit.unwrap_or(DEFAULT_TICKER_LIMIT),
+        );
+
+        let response_json = serde_json::to_value(&ranked)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -627,23 +1230,50 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get candlestick/kline data
+    /// Get a compact overview of a watchlist
     ///
-    /// Returns OHLCV (Open, High, Low, Close, Volume) candlestick data.
+    /// Fetches the 24hr change and best bid/ask of every symbol concurrently;
+    /// symbols that fail are reported in `failed` instead of failing the call.
     #[tool(
-        description = "Get candlestick/kline data (OHLCV) for technical analysis. Supports multiple timeframes from 1m to 1M."
+        description = "Get a compact overview of a watchlist (max 20 symbols): 24h change and best bid/ask for each, fetched concurrently. Returns rows [{symbol, price, change_pct, spread_bps}] sorted by change_pct (biggest gainer first) and failed [{symbol, error_code, error}] for skipped symbols."
     )]
-    pub async fn get_klines(
+    pub async fn get_watchlist_overview(
         &self,
-        params: Parameters<KlinesParam>,
+        params: Parameters<WatchlistParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let klines = self
-            .binance_client
-            .get_klines(&params.0.symbol, &params.0.interval, params.0.limit)
-            .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        if params.0.symbols.len() > MAX_WATCHLIST_SYMBOLS {
+            return Err(ErrorData::invalid_params(
+                format!(
+                    "At most {} symbols per call, got {}",
+                    MAX_WATCHLIST_SYMBOLS,
+                    params.0.symbols.len()
+                ),
+                None,
+            ));
+        }
 
-        let response_json = serde_json::to_value(&klines)
+        let (symbols, failed) = prepare_watchlist(&params.0.symbols);
+        if symbols.is_empty() && failed.is_empty() {
+            return Err(ErrorData::invalid_params(
+                "symbols must contain at least one symbol",
+                None,
+            ));
+        }
+
+        let overview = overview_each(
+            symbols,
+            failed,
+            self.multi_fetch_concurrency,
+            |symbol| async move {
+                tokio::try_join!(
+                    self.binance_client.get_24hr_ticker(&symbol),
+                    self.binance_client.get_book_ticker(&symbol),
+                )
+            },
+        )
+        .await;
+
+        let response_json = serde_json::to_value(&overview)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -651,23 +1281,45 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get current average price
+    /// Get order book depth
     ///
-    /// Returns current average price for a symbol.
+    /// Returns current order book with bids and asks for a trading pair.
     #[tool(
-        description = "Get current average price for a symbol. Simpler alternative to 24hr ticker."
+        description = "Get current order book depth (bids and asks) for a symbol. Returns price levels and quantities. limit must be one of 5, 10, 20, 50, 100, 500, 1000, 5000 unless snap=true. With summary_only=true returns just {symbol, best_bid, best_ask, mid_price, spread, spread_bps} without the depth arrays."
     )]
-    pub async fn get_average_price(
+    pub async fn get_order_book(
         &self,
-        params: Parameters<SymbolParam>,
+        params: Parameters<OrderBookParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let price = self
+        let symbol = normalize_symbol(&params.0.symbol)?;
+        if params.0.summary_only {
+            let order_book = self
+                .binance_client
+                .get_order_book(&symbol, Some(SUMMARY_DEPTH))
+                .await
+                .map_err(map_binance_err)?;
+            let response_json = serde_json::to_value(summarize_book(&symbol, &order_book))
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Serialization error: {}", e), None)
+                })?;
+            return Ok(CallToolResult::success(vec![Content::text(
+                response_json.to_string(),
+            )]));
+        }
+        let limit = params
+            .0
+            .limit
+            .map(|limit| validate_depth_limit(limit, params.0.snap))
+            .transpose()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let order_book = self
             .binance_client
-            .get_ticker_price(&params.0.symbol)
+            .get_order_book(&symbol, limit)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&price)
+        let response_json = serde_json::to_value(&order_book)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -675,40 +1327,28 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get account information (SSE version with session credentials)
+    /// Get recent trades
     ///
-    /// Returns account balances and trading permissions. Requires API credentials.
-    #[cfg(feature = "sse")]
+    /// Returns list of recent trades for a trading pair.
     #[tool(
-        description = "Get account information including balances and permissions. Requires API credentials configured via configure_credentials."
+        description = "Get recent public trades for a symbol. Returns trade history with prices, quantities, and timestamps. Each trade has side \"buy\" (taker bought) or \"sell\" (taker sold); use it rather than isBuyerMaker, which is true for taker sells."
     )]
-    pub async fn get_account_info(
+    pub async fn get_recent_trades(
         &self,
-        params: Parameters<AccountInfoParam>,
+        params: Parameters<RecentTradesParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        // Retrieve credentials from session
-        let credentials = self
-            .session_manager
-            .get_credentials(&params.0.session_id)
-            .await;
-
-        if credentials.is_none() {
-            let error_json = json!({
-                "error_code": "CREDENTIALS_NOT_CONFIGURED",
-                "message": "API credentials not configured for this session. Call configure_credentials first."
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
+        let symbol = normalize_symbol(&params.0.symbol)?;
 
-        let account = self
+        let trades: Vec<RecentTrade> = self
             .binance_client
-            .get_account(credentials.as_ref())
+            .get_recent_trades(&symbol, params.0.limit)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?
+            .into_iter()
+            .map(RecentTrade::from)
+            .collect();
 
-        let response_json = serde_json::to_value(&account)
+        let response_json = serde_json::to_value(&trades)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -716,21 +1356,26 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get account information (non-SSE version with environment credentials)
+    /// Summarize taker flow of recent trades
     ///
-    /// Returns account balances and trading permissions. Requires API credentials.
-    #[cfg(not(feature = "sse"))]
+    /// Aggregates the recent trade tape into buy vs sell volume using `isBuyerMaker`.
     #[tool(
-        description = "Get account information including balances and permissions. Requires API credentials."
+        description = "Summarize the last N public trades for a symbol: taker buy vs sell volume (base and quote), net taker flow (buy - sell), trade count, average trade size, largest trade, and the time window covered (first/last trade time). REST-only; needs no order book subscription."
     )]
-    pub async fn get_account_info(&self) -> Result<CallToolResult, ErrorData> {
-        let account = self
+    pub async fn get_trade_flow_summary(
+        &self,
+        params: Parameters<TradeFlowParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let trades = self
             .binance_client
-            .get_account()
+            .get_recent_trades(&symbol, params.0.limit)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&account)
+        let summary = summarize_trades(&symbol, &trades);
+        let response_json = serde_json::to_value(&summary)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -738,40 +1383,25 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get account trade history (SSE version with session credentials)
+    /// Get candlestick/kline data
     ///
-    /// Returns trade history for your account on a specific symbol. Requires API credentials.
-    #[cfg(feature = "sse")]
+    /// Returns OHLCV (Open, High, Low, Close, Volume) candlestick data.
     #[tool(
-        description = "Get your account trade history for a symbol. Returns executed trades with fees and commissions. Requires API credentials configured via configure_credentials."
+        description = "Get candlestick/kline data (OHLCV) for technical analysis. Supports multiple timeframes from 1m to 1M. Returns objects with named fields (openTime, open, high, low, close, volume, closeTime, quoteVolume, trades, takerBuyBase, takerBuyQuote), oldest first."
     )]
-    pub async fn get_account_trades(
+    pub async fn get_klines(
         &self,
-        params: Parameters<AccountTradesParam>,
+        params: Parameters<KlinesParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        // Retrieve credentials from session
-        let credentials = self
-            .session_manager
-            .get_credentials(&params.0.session_id)
-            .await;
-
-        if credentials.is_none() {
-            let error_json = json!({
-                "error_code": "CREDENTIALS_NOT_CONFIGURED",
-                "message": "API credentials not configured for this session. Call configure_credentials first."
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
+        let symbol = normalize_symbol(&params.0.symbol)?;
 
-        let trades = self
+        let klines = self
             .binance_client
-            .get_my_trades(&params.0.symbol, params.0.limit, credentials.as_ref())
+            .get_klines(&symbol, &params.0.interval, params.0.limit)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&trades)
+        let response_json = serde_json::to_value(&klines)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -779,24 +1409,38 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get account trade history (non-SSE version with environment credentials)
+    /// Get klines resampled to a multiple of a Binance interval
     ///
-    /// Returns trade history for your account on a specific symbol. Requires API credentials.
-    #[cfg(not(feature = "sse"))]
+    /// Fetches `limit × target_multiple` base klines and aggregates each group
+    /// of `target_multiple` into one synthetic kline.
     #[tool(
-        description = "Get your account trade history for a symbol. Returns executed trades with fees and commissions. Requires API credentials."
+        description = "Get klines for a timeframe Binance doesn't offer (e.g., 2h from 1h, 8h from 4h) by aggregating every target_multiple (>= 2) base_interval candles: open=first open, close=last close, high=max, low=min, volumes and trades summed. Groups end at the newest candle. Returns {symbol, interval, base_interval, target_multiple, klines} with named fields, oldest first; limit (default 100) × target_multiple must be at most 1000."
     )]
-    pub async fn get_account_trades(
+    pub async fn get_resampled_klines(
         &self,
-        params: Parameters<AccountTradesParam>,
+        params: Parameters<ResampledKlinesParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let trades = self
+        let params = params.0;
+        let symbol = normalize_symbol(&params.symbol)?;
+        let base_limit = resolve_resample_limits(params.target_multiple, params.limit)
+            .map_err(map_binance_err)?;
+
+        let base_klines = self
             .binance_client
-            .get_my_trades(&params.0.symbol, params.0.limit)
+            .get_klines(&symbol, &params.base_interval, Some(base_limit))
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
-
-        let response_json = serde_json::to_value(&trades)
+            .map_err(map_binance_err)?;
+        let klines = resample_klines(&base_klines, params.target_multiple as usize)
+            .map_err(map_binance_err)?;
+
+        let resampled = ResampledKlines {
+            symbol,
+            interval: resampled_interval(&params.base_interval, params.target_multiple),
+            base_interval: params.base_interval,
+            target_multiple: params.target_multiple,
+            klines,
+        };
+        let response_json = serde_json::to_value(&resampled)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -804,80 +1448,54 @@ impl BinanceServer {
         )]))
     }
 
-    /// Place a new order (SSE version with session credentials)
+    /// Get current average price
     ///
-    /// Creates a new trading order. Requires API credentials.
-    /// ⚠️ TESTNET ONLY - Use testnet credentials to avoid real trades.
-    #[cfg(feature = "sse")]
+    /// Returns current average price for a symbol.
     #[tool(
-        description = "Place a new order (BUY/SELL, LIMIT/MARKET). ⚠️ Use TESTNET credentials only! Requires API credentials configured via configure_credentials."
+        description = "Get current average price for a symbol. Simpler alternative to 24hr ticker. If the server sets ALLOW_STALE_ON_OUTAGE, a Binance outage returns the last known price marked stale=true with as_of (ms) instead of an error."
     )]
-    pub async fn place_order(
+    pub async fn get_average_price(
         &self,
-        params: Parameters<PlaceOrderParam>,
+        params: Parameters<SymbolParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        // Retrieve credentials from session
-        let credentials = self
-            .session_manager
-            .get_credentials(&params.0.session_id)
-            .await;
-
-        if credentials.is_none() {
-            let error_json = json!({
-                "error_code": "CREDENTIALS_NOT_CONFIGURED",
-                "message": "API credentials not configured for this session. Call configure_credentials first."
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
+        let symbol = normalize_symbol(&params.0.symbol)?;
 
-        let order = self
+        let fetched = self
             .binance_client
-            .create_order(
-                &params.0.symbol,
-                &params.0.side,
-                &params.0.order_type,
-                &params.0.quantity,
-                params.0.price.as_deref(),
-                credentials.as_ref(),
-            )
+            .get_ticker_price(&symbol)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .and_then(|price| Ok(serde_json::to_value(&price)?));
 
-        let response_json = serde_json::to_value(&order)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let response_json = self
+            .last_known_good
+            .resolve(&format!("price|{}", symbol), fetched)
+            .map_err(map_binance_err)?;
 
         Ok(CallToolResult::success(vec![Content::text(
             response_json.to_string(),
         )]))
     }
 
-    /// Place a new order (non-SSE version with environment credentials)
+    /// Get 24-hour ticker statistics for a USD-M perpetual contract
     ///
-    /// Creates a new trading order. Requires API credentials.
-    /// ⚠️ TESTNET ONLY - Use testnet credentials to avoid real trades.
-    #[cfg(not(feature = "sse"))]
+    /// Reads from fapi.binance.com, not the spot API.
+    #[cfg(feature = "futures")]
     #[tool(
-        description = "Place a new order (BUY/SELL, LIMIT/MARKET). ⚠️ Use TESTNET credentials only! Requires API credentials."
+        description = "Get 24-hour ticker statistics for a USD-M perpetual futures contract (fapi.binance.com). Returns last price, change percentage, high, low and volume."
     )]
-    pub async fn place_order(
+    pub async fn get_futures_ticker(
         &self,
-        params: Parameters<PlaceOrderParam>,
+        params: Parameters<SymbolParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let order = self
-            .binance_client
-            .create_order(
-                &params.0.symbol,
-                &params.0.side,
-                &params.0.order_type,
-                &params.0.quantity,
-                params.0.price.as_deref(),
-            )
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let data = self
+            .futures_client()?
+            .get_futures_ticker(&symbol)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&order)
+        let response_json = serde_json::to_value(&data)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -885,40 +1503,27 @@ impl BinanceServer {
         )]))
     }
 
-    /// Query order status (SSE version with session credentials)
+    /// Get the current funding rate for a USD-M perpetual contract
     ///
-    /// Get details of a specific order by orderId. Requires API credentials.
-    #[cfg(feature = "sse")]
+    /// Returns the current period's funding rate with mark/index price and the
+    /// next funding time.
+    #[cfg(feature = "futures")]
     #[tool(
-        description = "Query the status of a specific order by orderId. Returns current order state. Requires API credentials configured via configure_credentials."
+        description = "Get the current funding rate for a USD-M perpetual futures contract. Returns lastFundingRate (e.g., 0.0001 = 0.01% per 8h), mark price, index price and nextFundingTime."
     )]
-    pub async fn get_order(
+    pub async fn get_futures_funding_rate(
         &self,
-        params: Parameters<OrderParam>,
+        params: Parameters<SymbolParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        // Retrieve credentials from session
-        let credentials = self
-            .session_manager
-            .get_credentials(&params.0.session_id)
-            .await;
-
-        if credentials.is_none() {
-            let error_json = json!({
-                "error_code": "CREDENTIALS_NOT_CONFIGURED",
-                "message": "API credentials not configured for this session. Call configure_credentials first."
-            });
-            return Ok(CallToolResult::success(vec![Content::text(
-                error_json.to_string(),
-            )]));
-        }
+        let symbol = normalize_symbol(&params.0.symbol)?;
 
-        let order = self
-            .binance_client
-            .query_order(&params.0.symbol, params.0.order_id, credentials.as_ref())
+        let data = self
+            .futures_client()?
+            .get_futures_funding_rate(&symbol)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&order)
+        let response_json = serde_json::to_value(&data)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -926,24 +1531,24 @@ impl BinanceServer {
         )]))
     }
 
-    /// Query order status (non-SSE version with environment credentials)
-    ///
-    /// Get details of a specific order by orderId. Requires API credentials.
-    #[cfg(not(feature = "sse"))]
+    /// Get current open interest for a USD-M perpetual contract
+    #[cfg(feature = "futures")]
     #[tool(
-        description = "Query the status of a specific order by orderId. Returns current order state. Requires API credentials."
+        description = "Get current open interest (outstanding contracts, in base asset) for a USD-M perpetual futures contract."
     )]
-    pub async fn get_order(
+    pub async fn get_futures_open_interest(
         &self,
-        params: Parameters<OrderParam>,
+        params: Parameters<SymbolParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let order = self
-            .binance_client
-            .query_order(&params.0.symbol, params.0.order_id)
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let data = self
+            .futures_client()?
+            .get_futures_open_interest(&symbol)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&order)
+        let response_json = serde_json::to_value(&data)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -951,16 +1556,55 @@ impl BinanceServer {
         )]))
     }
 
-    /// Cancel an order (SSE version with session credentials)
+    /// Stub implementation for get_futures_ticker when futures feature is disabled
+    #[cfg(not(feature = "futures"))]
+    #[tool(description = "Futures ticker not available (requires 'futures' feature)")]
+    pub async fn get_futures_ticker(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Futures market data is not enabled in this deployment. Rebuild with --features futures".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_futures_funding_rate when futures feature is disabled
+    #[cfg(not(feature = "futures"))]
+    #[tool(description = "Futures funding rate not available (requires 'futures' feature)")]
+    pub async fn get_futures_funding_rate(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Futures market data is not enabled in this deployment. Rebuild with --features futures".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_futures_open_interest when futures feature is disabled
+    #[cfg(not(feature = "futures"))]
+    #[tool(description = "Futures open interest not available (requires 'futures' feature)")]
+    pub async fn get_futures_open_interest(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Futures market data is not enabled in this deployment. Rebuild with --features futures".to_string(),
+            None,
+        ))
+    }
+
+    /// Get account information (SSE version with session credentials)
     ///
-    /// Cancel an active order. Requires API credentials.
+    /// Returns account balances and trading permissions. Requires API credentials.
     #[cfg(feature = "sse")]
     #[tool(
-        description = "Cancel an active order by orderId. Returns canceled order details. Requires API credentials configured via configure_credentials."
+        description = "Get account information including balances and permissions. Requires API credentials configured via configure_credentials."
     )]
-    pub async fn cancel_order(
+    pub async fn get_account_info(
         &self,
-        params: Parameters<OrderParam>,
+        params: Parameters<AccountInfoParam>,
     ) -> Result<CallToolResult, ErrorData> {
         // Retrieve credentials from session
         let credentials = self
@@ -978,55 +1622,188 @@ impl BinanceServer {
             )]));
         }
 
-        let order = self
+        let account = self
             .binance_client
-            .cancel_order(&params.0.symbol, params.0.order_id, credentials.as_ref())
+            .get_account(credentials.as_ref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&order)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        Ok(self.signed_text(account_info_text(&account)?))
+    }
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+    /// Get account information (non-SSE version with environment credentials)
+    ///
+    /// Returns account balances and trading permissions. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Get account information including balances and permissions. Requires API credentials."
+    )]
+    pub async fn get_account_info(&self) -> Result<CallToolResult, ErrorData> {
+        let account = self
+            .binance_client
+            .get_account()
+            .await
+            .map_err(map_binance_err)?;
+
+        Ok(self.signed_text(account_info_text(&account)?))
     }
 
-    /// Cancel an order (non-SSE version with environment credentials)
+    /// Get account and API trading status (SSE version with session credentials)
     ///
-    /// Cancel an active order. Requires API credentials.
+    /// Reports whether the account is restricted and whether API trading is locked.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Get account status and API trading status. Returns {account_status, api_trading_status}: account_status is \"Normal\" or the restriction reason; api_trading_status has isLocked, plannedRecoverTime (ms) and the triggerCondition thresholds that lock API trading after too many rejected or cancelled orders. Check this when orders fail repeatedly. Requires mainnet API credentials configured via configure_credentials."
+    )]
+    pub async fn get_account_status(
+        &self,
+        params: Parameters<AccountInfoParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let (account_status, api_trading_status) = tokio::try_join!(
+            self.binance_client.get_account_status(credentials.as_ref()),
+            self.binance_client
+                .get_api_trading_status(credentials.as_ref())
+        )
+        .map_err(map_binance_err)?;
+
+        Ok(self.signed_result(json!({
+            "account_status": account_status.data,
+            "api_trading_status": api_trading_status.data,
+        })))
+    }
+
+    /// Get account and API trading status (non-SSE version with environment credentials)
+    ///
+    /// Reports whether the account is restricted and whether API trading is locked.
     #[cfg(not(feature = "sse"))]
     #[tool(
-        description = "Cancel an active order by orderId. Returns canceled order details. Requires API credentials."
+        description = "Get account status and API trading status. Returns {account_status, api_trading_status}: account_status is \"Normal\" or the restriction reason; api_trading_status has isLocked, plannedRecoverTime (ms) and the triggerCondition thresholds that lock API trading after too many rejected or cancelled orders. Check this when orders fail repeatedly. Requires API credentials."
     )]
-    pub async fn cancel_order(
+    pub async fn get_account_status(&self) -> Result<CallToolResult, ErrorData> {
+        if self.binance_client.api_key.is_none() || self.binance_client.api_secret.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured. Set BINANCE_API_KEY and BINANCE_API_SECRET."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let (account_status, api_trading_status) = tokio::try_join!(
+            self.binance_client.get_account_status(),
+            self.binance_client.get_api_trading_status()
+        )
+        .map_err(map_binance_err)?;
+
+        Ok(self.signed_result(json!({
+            "account_status": account_status.data,
+            "api_trading_status": api_trading_status.data,
+        })))
+    }
+
+    /// Get account trade history (SSE version with session credentials)
+    ///
+    /// Returns trade history for your account on a specific symbol. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Get your account trade history for a symbol. Returns {trades, next_from_id} with fees and commissions. Page with from_id (or a start_time/end_time window of at most 24h); a full page includes next_from_id for the next call. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn get_account_trades(
         &self,
-        params: Parameters<OrderParam>,
+        params: Parameters<AccountTradesParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let order = self
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let trades = self
             .binance_client
-            .cancel_order(&params.0.symbol, params.0.order_id)
+            .get_my_trades(&symbol, &history, credentials.as_ref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&order)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let next_from_id = history.next_from_id(trades.len(), trades.iter().map(|t| t.id).max());
+        let response_json = history_page("trades", &trades, next_from_id)?;
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+        Ok(self.signed_result(response_json))
     }
 
-    /// Get all open orders (SSE version with session credentials)
+    /// Get account trade history (non-SSE version with environment credentials)
     ///
-    /// Returns all currently active orders. Requires API credentials.
+    /// Returns trade history for your account on a specific symbol. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Get your account trade history for a symbol. Returns {trades, next_from_id} with fees and commissions. Page with from_id (or a start_time/end_time window of at most 24h); a full page includes next_from_id for the next call. Requires API credentials."
+    )]
+    pub async fn get_account_trades(
+        &self,
+        params: Parameters<AccountTradesParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let trades = self
+            .binance_client
+            .get_my_trades(&symbol, &history)
+            .await
+            .map_err(map_binance_err)?;
+
+        let next_from_id = history.next_from_id(trades.len(), trades.iter().map(|t| t.id).max());
+        let response_json = history_page("trades", &trades, next_from_id)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Get deposit history (SSE version with session credentials)
+    ///
+    /// Returns normalized deposit records from the wallet (`/sapi`) API. Requires API credentials.
     #[cfg(feature = "sse")]
     #[tool(
-        description = "Get all open orders. Optionally filter by symbol or get all open orders across all pairs. Requires API credentials configured via configure_credentials."
+        description = "Get your wallet deposit history. Returns {deposits} with coin, amount, network, status (pending/success/rejected/credited/...), statusCode, txId and time (ms). Filter by coin and a start_time/end_time window of at most 90 days (default: last 90 days). Requires mainnet API credentials configured via configure_credentials."
     )]
-    pub async fn get_open_orders(
+    pub async fn get_deposit_history(
         &self,
-        params: Parameters<OpenOrdersParam>,
+        params: Parameters<TransferHistoryParam>,
     ) -> Result<CallToolResult, ErrorData> {
         // Retrieve credentials from session
         let credentials = self
@@ -1044,55 +1821,63 @@ impl BinanceServer {
             )]));
         }
 
-        let orders = self
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let records = self
             .binance_client
-            .get_open_orders(params.0.symbol.as_deref(), credentials.as_ref())
+            .get_deposit_history(&history, credentials.as_ref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&orders)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let transfers: Vec<WalletTransfer> =
+            records.into_iter().map(WalletTransfer::from).collect();
+        let response_json = history_page("deposits", &transfers, None)?;
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+        Ok(self.signed_result(response_json))
     }
 
-    /// Get all open orders (non-SSE version with environment credentials)
+    /// Get deposit history (non-SSE version with environment credentials)
     ///
-    /// Returns all currently active orders. Requires API credentials.
+    /// Returns normalized deposit records from the wallet (`/sapi`) API. Requires API credentials.
     #[cfg(not(feature = "sse"))]
     #[tool(
-        description = "Get all open orders. Optionally filter by symbol or get all open orders across all pairs. Requires API credentials."
+        description = "Get your wallet deposit history. Returns {deposits} with coin, amount, network, status (pending/success/rejected/credited/...), statusCode, txId and time (ms). Filter by coin and a start_time/end_time window of at most 90 days (default: last 90 days). Requires API credentials."
     )]
-    pub async fn get_open_orders(
+    pub async fn get_deposit_history(
         &self,
-        params: Parameters<OpenOrdersParam>,
+        params: Parameters<TransferHistoryParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let orders = self
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let records = self
             .binance_client
-            .get_open_orders(params.0.symbol.as_deref())
+            .get_deposit_history(&history)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&orders)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let transfers: Vec<WalletTransfer> =
+            records.into_iter().map(WalletTransfer::from).collect();
+        let response_json = history_page("deposits", &transfers, None)?;
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+        Ok(self.signed_result(response_json))
     }
 
-    /// Get all orders (history) (SSE version with session credentials)
+    /// Get withdrawal history (SSE version with session credentials)
     ///
-    /// Returns all orders (active, canceled, filled) for a symbol. Requires API credentials.
+    /// Returns normalized withdrawal records from the wallet (`/sapi`) API. Requires API credentials.
     #[cfg(feature = "sse")]
     #[tool(
-        description = "Get complete order history for a symbol (active, canceled, filled). Requires API credentials configured via configure_credentials."
+        description = "Get your wallet withdrawal history. Returns {withdrawals} with coin, amount, network, status (email_sent/awaiting_approval/processing/completed/...), statusCode, txId and time (ms). Filter by coin and a start_time/end_time window of at most 90 days (default: last 90 days). Requires mainnet API credentials configured via configure_credentials."
     )]
-    pub async fn get_all_orders(
+    pub async fn get_withdraw_history(
         &self,
-        params: Parameters<AllOrdersParam>,
+        params: Parameters<TransferHistoryParam>,
     ) -> Result<CallToolResult, ErrorData> {
         // Retrieve credentials from session
         let credentials = self
@@ -1110,38 +1895,79 @@ impl BinanceServer {
             )]));
         }
 
-        let orders = self
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let records = self
             .binance_client
-            .get_all_orders(&params.0.symbol, params.0.limit, credentials.as_ref())
+            .get_withdraw_history(&history, credentials.as_ref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&orders)
-            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+        let transfers: Vec<WalletTransfer> =
+            records.into_iter().map(WalletTransfer::from).collect();
+        let response_json = history_page("withdrawals", &transfers, None)?;
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+        Ok(self.signed_result(response_json))
     }
 
-    /// Get all orders (history) (non-SSE version with environment credentials)
+    /// Get withdrawal history (non-SSE version with environment credentials)
     ///
-    /// Returns all orders (active, canceled, filled) for a symbol. Requires API credentials.
+    /// Returns normalized withdrawal records from the wallet (`/sapi`) API. Requires API credentials.
     #[cfg(not(feature = "sse"))]
     #[tool(
-        description = "Get complete order history for a symbol (active, canceled, filled). Requires API credentials."
+        description = "Get your wallet withdrawal history. Returns {withdrawals} with coin, amount, network, status (email_sent/awaiting_approval/processing/completed/...), statusCode, txId and time (ms). Filter by coin and a start_time/end_time window of at most 90 days (default: last 90 days). Requires API credentials."
     )]
-    pub async fn get_all_orders(
+    pub async fn get_withdraw_history(
         &self,
-        params: Parameters<AllOrdersParam>,
+        params: Parameters<TransferHistoryParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let orders = self
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let records = self
             .binance_client
-            .get_all_orders(&params.0.symbol, params.0.limit)
+            .get_withdraw_history(&history)
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&orders)
+        let transfers: Vec<WalletTransfer> =
+            records.into_iter().map(WalletTransfer::from).collect();
+        let response_json = history_page("withdrawals", &transfers, None)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Explain a symbol's trading rules
+    ///
+    /// Translates the symbol's `exchangeInfo` filters into normalized values
+    /// and plain-language guidance.
+    #[tool(
+        description = "Explain one symbol's trading rules in plain language, from the cached exchangeInfo: min/max order quantity and quantity step, limit price step, minimum notional, max open orders and whether MARKET orders are allowed. Returns the normalized values plus guidance sentences to relay to the user (e.g., \"Orders must be for at least 0.00001 BTC ...\")."
+    )]
+    pub async fn explain_symbol_rules(
+        &self,
+        params: Parameters<SymbolParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+        let exchange_info = self
+            .binance_client
+            .get_exchange_info()
+            .await
+            .map_err(map_binance_err)?;
+        let info = exchange_info
+            .symbols
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| {
+                ErrorData::invalid_params(format!("Unknown symbol '{}'", symbol), None)
+            })?;
+
+        let response_json = serde_json::to_value(explain_symbol_rules(info))
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -1149,32 +1975,33 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get L1 aggregated metrics for quick spread assessment
-    ///
-    /// Provides lightweight order book analysis (15% token cost vs L2-full):
-    /// - Spread in basis points
-    /// - Microprice (volume-weighted fair price)
-    /// - Bid/ask volume imbalance
-    /// - Wall detection (large levels)
-    /// - VWAP-based slippage estimates
+    /// Explain a Binance error code
     ///
-    /// First request: 2-3s (lazy initialization). Subsequent: <200ms (cached).
-    #[cfg(feature = "orderbook")]
+    /// Looks the code up in the static table of common spot trading errors.
     #[tool(
-        description = "Get L1 aggregated order book metrics for quick spread assessment. Includes spread, microprice, imbalance, walls, and slippage estimates. Lightweight (15% token cost vs full depth)."
+        description = "Explain a Binance error code (e.g., -2010, -1013, -1021) from a failed request: returns {code, name, meaning, common_causes, recovery}. Covers the common spot trading codes -418, -1003, -1013, -1015, -1021, -1100, -1111, -1121, -2010, -2011 and -2013. Tool errors quoting one of these codes already carry the explanation in their data."
     )]
-    pub async fn get_orderbook_metrics(
+    pub async fn explain_error(
         &self,
-        params: Parameters<crate::orderbook::tools::GetOrderBookMetricsParams>,
+        params: Parameters<ExplainErrorParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let metrics = crate::orderbook::tools::get_orderbook_metrics(
-            self.orderbook_manager.clone(),
-            params.0,
-        )
-        .await
-        .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        let code = params.0.code;
+        let explanation = explain_binance_error(code).ok_or_else(|| {
+            let known: Vec<String> = known_binance_error_codes()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            ErrorData::invalid_params(
+                format!(
+                    "No explanation for Binance error code {}. Known codes: {}",
+                    code,
+                    known.join(", ")
+                ),
+                Some(json!({"error_code": "UNKNOWN_ERROR_CODE"})),
+            )
+        })?;
 
-        let response_json = serde_json::to_value(&metrics)
+        let response_json = serde_json::to_value(explanation)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -1182,29 +2009,52 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get L2 depth with compact integer encoding
-    ///
-    /// Token cost: 50% (L2-lite with 20 levels) or 100% (L2-full with 100 levels).
-    ///
-    /// Compact encoding reduces JSON size by ~40%:
-    /// - price_scale = 100 (e.g., 67650.00 → 6765000)
-    /// - qty_scale = 100000 (e.g., 1.234 → 123400)
+    /// Convert a quote amount into an order quantity
     ///
-    /// First request: 2-3s (lazy initialization). Subsequent: <300ms (cached).
-    #[cfg(feature = "orderbook")]
+    /// Divides the amount by the price, rounds down to the step size and
+    /// checks the result against the symbol's minimum notional.
     #[tool(
-        description = "Get L2 order book depth with compact integer encoding. Returns price levels and quantities. Use levels=20 for L2-lite (50% cost) or levels=100 for L2-full (100% cost)."
+        description = "Convert a quote-asset amount (e.g., $50) into a base quantity for place_order: amount / price (current price, or the given limit price), rounded down to LOT_SIZE stepSize. Returns {symbol, price, quantity, estimated_cost, min_notional}. Errors when the rounded order is below minNotional, naming the shortfall and the smallest amount that passes. Use for LIMIT orders, which can't take quoteOrderQty."
     )]
-    pub async fn get_orderbook_depth(
+    pub async fn quote_to_quantity(
         &self,
-        params: Parameters<crate::orderbook::tools::GetOrderBookDepthParams>,
+        params: Parameters<QuoteToQuantityParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        let depth =
-            crate::orderbook::tools::get_orderbook_depth(self.orderbook_manager.clone(), params.0)
-                .await
-                .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        let symbol = normalize_symbol(&params.0.symbol)?;
+        let parse = |name: &str, value: &str| {
+            Decimal::from_str(value.trim()).map_err(|_| {
+                ErrorData::invalid_params(format!("Invalid {} '{}'", name, value), None)
+            })
+        };
+        let quote_amount = parse("quote_amount", &params.0.quote_amount)?;
+        let price = match params.0.price.as_deref() {
+            Some(price) => parse("price", price)?,
+            None => {
+                let ticker = self
+                    .binance_client
+                    .get_ticker_price(&symbol)
+                    .await
+                    .map_err(map_binance_err)?;
+                parse("ticker price", &ticker.price)?
+            }
+        };
 
-        let response_json = serde_json::to_value(&depth)
+        let exchange_info = self
+            .binance_client
+            .get_exchange_info()
+            .await
+            .map_err(map_binance_err)?;
+        let info = exchange_info
+            .symbols
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| {
+                ErrorData::invalid_params(format!("Unknown symbol '{}'", symbol), None)
+            })?;
+
+        let converted = quote_to_quantity(info, quote_amount, price).map_err(map_binance_err)?;
+
+        let response_json = serde_json::to_value(&converted)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
         Ok(CallToolResult::success(vec![Content::text(
@@ -1212,65 +2062,2690 @@ impl BinanceServer {
         )]))
     }
 
-    /// Get service health status for order book tracking
-    ///
-    /// Returns operational visibility:
-    /// - Overall status (ok/degraded/error)
-    /// - Number of active symbol subscriptions (0-20)
-    /// - Data freshness (last update age in ms)
-    /// - WebSocket connection status
+    /// Place a new order (SSE version with session credentials)
     ///
-    /// Latency: <50ms (no external API calls).
-    #[cfg(feature = "orderbook")]
-    #[tool(
-        description = "Get order book service health status. Returns connection status, active symbols (0-20), and data freshness. Fast (<50ms, no API calls)."
-    )]
-    pub async fn get_orderbook_health(&self) -> Result<CallToolResult, ErrorData> {
-        let health = crate::orderbook::tools::get_orderbook_health(self.orderbook_manager.clone())
+    /// Creates a new trading order. Requires API credentials.
+    /// ⚠️ TESTNET ONLY - Use testnet credentials to avoid real trades.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Place a new order (BUY/SELL, LIMIT/MARKET). ⚠️ Use TESTNET credentials only! Mainnet orders return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials configured via configure_credentials. Pass a client_order_id to make retries safe: if an order with that ID already exists it is returned (with _meta.idempotent_replay=true) instead of placing a new one, and a resubmit seconds after the first is rejected. Before submitting, free balance is checked against the cached account (BUY: quote >= price * quantity + 0.1% fee buffer, using the last price for MARKET; SELL: base >= quantity); a shortfall returns INSUFFICIENT_BALANCE with required/available amounts. Set skip_balance_check=true to submit without it. LIMIT orders take time_in_force GTC, IOC or FOK (default GTC); MARKET orders reject it."
+    )]
+    pub async fn place_order(
+        &self,
+        params: Parameters<PlaceOrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let time_in_force =
+            resolve_time_in_force(&params.0.order_type, params.0.time_in_force.as_deref())
+                .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+        let audit = OrderAudit {
+            caller: &params.0.session_id,
+            tool: "place_order",
+            symbol: &params.0.symbol,
+            side: Some(&params.0.side),
+            quantity: Some(&params.0.quantity),
+        };
+
+        let mut auto_round = None;
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("place_order", &symbol) {
+                return Ok(blocked);
+            }
+
+            // Retrieve credentials from session
+            let credentials = self
+                .session_manager
+                .get_credentials(&params.0.session_id)
+                .await;
+
+            if credentials.is_none() {
+                let error_json = json!({
+                    "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                    "message": "API credentials not configured for this session. Call configure_credentials first."
+                });
+                return Ok(CallToolResult::success(vec![Content::text(
+                    error_json.to_string(),
+                )]));
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("place_order", credentials.as_ref()) {
+                return Ok(blocked);
+            }
+
+            // Rounded after the guards, so the audit keeps the requested values
+            let mut order = params.0.clone();
+            auto_round = self.auto_round_order(&mut order).await?;
+
+            let client_order_id = order.client_order_id.as_deref();
+            let lookup = client_order_id.map(|id| {
+                self.binance_client
+                    .query_order_by_client_id(&symbol, id, credentials.as_ref())
+            });
+            let place = self.binance_client.create_order(
+                &symbol,
+                &order.side,
+                &order.order_type,
+                &order.quantity,
+                order.price.as_deref(),
+                time_in_force,
+                client_order_id,
+                credentials.as_ref(),
+            );
+            let preflight = self.check_order_balance(&symbol, &order, credentials.as_ref());
+
+            self.place_order_once(&symbol, client_order_id, lookup, preflight, place)
+                .await
+        })
+        .await
+        .map(|result| with_auto_round_meta(result, auto_round))
+    }
+
+    /// Place a new order (non-SSE version with environment credentials)
+    ///
+    /// Creates a new trading order. Requires API credentials.
+    /// ⚠️ TESTNET ONLY - Use testnet credentials to avoid real trades.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Place a new order (BUY/SELL, LIMIT/MARKET). ⚠️ Use TESTNET credentials only! Mainnet orders return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials. Pass a client_order_id to make retries safe: if an order with that ID already exists it is returned (with _meta.idempotent_replay=true) instead of placing a new one, and a resubmit seconds after the first is rejected. Before submitting, free balance is checked against the cached account (BUY: quote >= price * quantity + 0.1% fee buffer, using the last price for MARKET; SELL: base >= quantity); a shortfall returns INSUFFICIENT_BALANCE with required/available amounts. Set skip_balance_check=true to submit without it. LIMIT orders take time_in_force GTC, IOC or FOK (default GTC); MARKET orders reject it."
+    )]
+    pub async fn place_order(
+        &self,
+        params: Parameters<PlaceOrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let time_in_force =
+            resolve_time_in_force(&params.0.order_type, params.0.time_in_force.as_deref())
+                .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+        let audit = OrderAudit {
+            caller: audit::ENV_CALLER,
+            tool: "place_order",
+            symbol: &params.0.symbol,
+            side: Some(&params.0.side),
+            quantity: Some(&params.0.quantity),
+        };
+
+        let mut auto_round = None;
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("place_order", &symbol) {
+                return Ok(blocked);
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("place_order") {
+                return Ok(blocked);
+            }
+
+            // Rounded after the guards, so the audit keeps the requested values
+            let mut order = params.0.clone();
+            auto_round = self.auto_round_order(&mut order).await?;
+
+            let client_order_id = order.client_order_id.as_deref();
+            let lookup =
+                client_order_id.map(|id| self.binance_client.query_order_by_client_id(&symbol, id));
+            let place = self.binance_client.create_order(
+                &symbol,
+                &order.side,
+                &order.order_type,
+                &order.quantity,
+                order.price.as_deref(),
+                time_in_force,
+                client_order_id,
+            );
+            let preflight = self.check_order_balance(&symbol, &order);
+
+            self.place_order_once(&symbol, client_order_id, lookup, preflight, place)
+                .await
+        })
+        .await
+        .map(|result| with_auto_round_meta(result, auto_round))
+    }
+
+    /// Place a trailing stop order (SSE version with session credentials)
+    ///
+    /// Converts `trailing_percent` into Binance's `trailingDelta` and picks
+    /// STOP_LOSS or TAKE_PROFIT. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Place a trailing stop order. trailing_percent (0.1-20) is converted to Binance trailingDelta in basis points (percent * 100). Optional activation_price delays trailing until the market reaches it. Returns {trailing_delta_bps, order_type, order} so the conversion can be checked. ⚠️ Use TESTNET credentials only! Mainnet orders return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn place_trailing_stop(
+        &self,
+        params: Parameters<TrailingStopParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: &params.0.session_id,
+            tool: "place_trailing_stop",
+            symbol: &params.0.symbol,
+            side: Some(&params.0.side),
+            quantity: Some(&params.0.quantity),
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("place_trailing_stop", &symbol) {
+                return Ok(blocked);
+            }
+
+            let credentials = self
+                .session_manager
+                .get_credentials(&params.0.session_id)
+                .await;
+
+            if credentials.is_none() {
+                let error_json = json!({
+                    "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                    "message": "API credentials not configured for this session. Call configure_credentials first."
+                });
+                return Ok(CallToolResult::success(vec![Content::text(
+                    error_json.to_string(),
+                )]));
+            }
+
+            if let Some(blocked) =
+                self.mainnet_trading_blocked("place_trailing_stop", credentials.as_ref())
+            {
+                return Ok(blocked);
+            }
+
+            let (trailing_delta, order_type) = self.trailing_stop_plan(&symbol, &params.0).await?;
+            let order = self
+                .binance_client
+                .create_trailing_stop_order(
+                    &symbol,
+                    &params.0.side,
+                    order_type,
+                    &params.0.quantity,
+                    params.0.activation_price.as_deref(),
+                    trailing_delta,
+                    credentials.as_ref(),
+                )
+                .await
+                .map_err(map_binance_err)?;
+
+            Ok(self.signed_result(trailing_stop_json(trailing_delta, order_type, &order)?))
+        })
+        .await
+    }
+
+    /// Place a trailing stop order (non-SSE version with environment credentials)
+    ///
+    /// Converts `trailing_percent` into Binance's `trailingDelta` and picks
+    /// STOP_LOSS or TAKE_PROFIT. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Place a trailing stop order. trailing_percent (0.1-20) is converted to Binance trailingDelta in basis points (percent * 100). Optional activation_price delays trailing until the market reaches it. Returns {trailing_delta_bps, order_type, order} so the conversion can be checked. ⚠️ Use TESTNET credentials only! Mainnet orders return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials."
+    )]
+    pub async fn place_trailing_stop(
+        &self,
+        params: Parameters<TrailingStopParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: audit::ENV_CALLER,
+            tool: "place_trailing_stop",
+            symbol: &params.0.symbol,
+            side: Some(&params.0.side),
+            quantity: Some(&params.0.quantity),
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("place_trailing_stop", &symbol) {
+                return Ok(blocked);
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("place_trailing_stop") {
+                return Ok(blocked);
+            }
+
+            let (trailing_delta, order_type) = self.trailing_stop_plan(&symbol, &params.0).await?;
+            let order = self
+                .binance_client
+                .create_trailing_stop_order(
+                    &symbol,
+                    &params.0.side,
+                    order_type,
+                    &params.0.quantity,
+                    params.0.activation_price.as_deref(),
+                    trailing_delta,
+                )
+                .await
+                .map_err(map_binance_err)?;
+
+            Ok(self.signed_result(trailing_stop_json(trailing_delta, order_type, &order)?))
+        })
+        .await
+    }
+
+    /// Query order status (SSE version with session credentials)
+    ///
+    /// Get details of a specific order by orderId. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Query the status of a specific order by orderId. Returns current order state. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn get_order(
+        &self,
+        params: Parameters<OrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let order = self
+            .binance_client
+            .query_order(&symbol, params.0.order_id, credentials.as_ref())
             .await
-            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            .map_err(map_binance_err)?;
 
-        let response_json = serde_json::to_value(&health)
+        let response_json = serde_json::to_value(&order)
             .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
 
-        Ok(CallToolResult::success(vec![Content::text(
-            response_json.to_string(),
-        )]))
+        Ok(self.signed_result(response_json))
     }
 
-    /// Stub implementation for get_orderbook_metrics when orderbook feature is disabled
-    #[cfg(not(feature = "orderbook"))]
-    #[tool(description = "Order book metrics not available (requires 'orderbook' feature)")]
-    pub async fn get_orderbook_metrics(
+    /// Query order status (non-SSE version with environment credentials)
+    ///
+    /// Get details of a specific order by orderId. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Query the status of a specific order by orderId. Returns current order state. Requires API credentials."
+    )]
+    pub async fn get_order(
         &self,
-        _params: Parameters<serde_json::Value>,
+        params: Parameters<OrderParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        Err(ErrorData::internal_error(
-            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
-            None,
-        ))
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let order = self
+            .binance_client
+            .query_order(&symbol, params.0.order_id)
+            .await
+            .map_err(map_binance_err)?;
+
+        let response_json = serde_json::to_value(&order)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(self.signed_result(response_json))
     }
 
-    /// Stub implementation for get_orderbook_depth when orderbook feature is disabled
-    #[cfg(not(feature = "orderbook"))]
-    #[tool(description = "Order book depth not available (requires 'orderbook' feature)")]
-    pub async fn get_orderbook_depth(
+    /// Wait for an order to reach a terminal status (SSE version with session credentials)
+    ///
+    /// Polls `query_order` until the order is terminal or the timeout elapses.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Wait until an order is FILLED, CANCELED, REJECTED or EXPIRED, polling its status every poll_interval_secs (default 1, min 0.5) for up to timeout_secs (default 30, max 60). Returns {order, timed_out, polls, elapsed_ms}; on timeout, order is the last state seen. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn wait_for_order(
         &self,
-        _params: Parameters<serde_json::Value>,
+        params: Parameters<WaitForOrderParam>,
     ) -> Result<CallToolResult, ErrorData> {
-        Err(ErrorData::internal_error(
-            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
-            None,
-        ))
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let plan = WaitPlan::new(params.0.timeout_secs, params.0.poll_interval_secs);
+        let result = wait_until_terminal(plan, || {
+            self.binance_client
+                .query_order(&symbol, params.0.order_id, credentials.as_ref())
+        })
+        .await
+        .map_err(map_binance_err)?;
+
+        let response_json = serde_json::to_value(&result)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(self.signed_result(response_json))
     }
 
-    /// Stub implementation for get_orderbook_health when orderbook feature is disabled
-    #[cfg(not(feature = "orderbook"))]
-    #[tool(description = "Order book health not available (requires 'orderbook' feature)")]
-    pub async fn get_orderbook_health(&self) -> Result<CallToolResult, ErrorData> {
-        Err(ErrorData::internal_error(
-            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
-            None,
-        ))
+    /// Wait for an order to reach a terminal status (non-SSE version with environment credentials)
+    ///
+    /// Polls `query_order` until the order is terminal or the timeout elapses.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Wait until an order is FILLED, CANCELED, REJECTED or EXPIRED, polling its status every poll_interval_secs (default 1, min 0.5) for up to timeout_secs (default 30, max 60). Returns {order, timed_out, polls, elapsed_ms}; on timeout, order is the last state seen. Requires API credentials."
+    )]
+    pub async fn wait_for_order(
+        &self,
+        params: Parameters<WaitForOrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let plan = WaitPlan::new(params.0.timeout_secs, params.0.poll_interval_secs);
+        let result = wait_until_terminal(plan, || {
+            self.binance_client.query_order(&symbol, params.0.order_id)
+        })
+        .await
+        .map_err(map_binance_err)?;
+
+        let response_json = serde_json::to_value(&result)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Cancel an order (SSE version with session credentials)
+    ///
+    /// Cancel an active order. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Cancel an active order by orderId. Returns canceled order details. Mainnet cancels return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn cancel_order(
+        &self,
+        params: Parameters<OrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: &params.0.session_id,
+            tool: "cancel_order",
+            symbol: &params.0.symbol,
+            side: None,
+            quantity: None,
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("cancel_order", &symbol) {
+                return Ok(blocked);
+            }
+
+            // Retrieve credentials from session
+            let credentials = self
+                .session_manager
+                .get_credentials(&params.0.session_id)
+                .await;
+
+            if credentials.is_none() {
+                let error_json = json!({
+                    "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                    "message": "API credentials not configured for this session. Call configure_credentials first."
+                });
+                return Ok(CallToolResult::success(vec![Content::text(
+                    error_json.to_string(),
+                )]));
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("cancel_order", credentials.as_ref()) {
+                return Ok(blocked);
+            }
+
+            let order = self
+                .binance_client
+                .cancel_order(&symbol, params.0.order_id, credentials.as_ref())
+                .await
+                .map_err(map_binance_err)?;
+
+            let response_json = serde_json::to_value(&order)
+                .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+            Ok(self.signed_result(response_json))
+        })
+        .await
+    }
+
+    /// Cancel an order (non-SSE version with environment credentials)
+    ///
+    /// Cancel an active order. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Cancel an active order by orderId. Returns canceled order details. Mainnet cancels return MAINNET_TRADING_DISABLED unless the server sets MAINNET_TRADING_ENABLED. Requires API credentials."
+    )]
+    pub async fn cancel_order(
+        &self,
+        params: Parameters<OrderParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: audit::ENV_CALLER,
+            tool: "cancel_order",
+            symbol: &params.0.symbol,
+            side: None,
+            quantity: None,
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("cancel_order", &symbol) {
+                return Ok(blocked);
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("cancel_order") {
+                return Ok(blocked);
+            }
+
+            let order = self
+                .binance_client
+                .cancel_order(&symbol, params.0.order_id)
+                .await
+                .map_err(map_binance_err)?;
+
+            let response_json = serde_json::to_value(&order).map_err(|e| {
+                ErrorData::internal_error(format!("Serialization error: {}", e), None)
+            })?;
+
+            Ok(self.signed_result(response_json))
+        })
+        .await
+    }
+
+    /// Cancel several orders by client order ID (SSE version with session credentials)
+    ///
+    /// Each ID is cancelled independently; failures are reported per ID.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Cancel up to 50 orders of one symbol by client order ID (origClientOrderId). Cancels run concurrently and independently: returns {symbol, canceled: [{client_order_id, order_id, status}], failed: [{id, error_code, error}]} so one unknown or filled order doesn't abort the rest. Same mainnet and symbol whitelist guards as cancel_order. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn cancel_orders(
+        &self,
+        params: Parameters<CancelOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: &params.0.session_id,
+            tool: "cancel_orders",
+            symbol: &params.0.symbol,
+            side: None,
+            quantity: None,
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+            let ids = batch_cancel_ids(&params.0.client_order_ids)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("cancel_orders", &symbol) {
+                return Ok(blocked);
+            }
+
+            // Retrieve credentials from session
+            let credentials = self
+                .session_manager
+                .get_credentials(&params.0.session_id)
+                .await;
+
+            if credentials.is_none() {
+                let error_json = json!({
+                    "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                    "message": "API credentials not configured for this session. Call configure_credentials first."
+                });
+                return Ok(CallToolResult::success(vec![Content::text(
+                    error_json.to_string(),
+                )]));
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("cancel_orders", credentials.as_ref()) {
+                return Ok(blocked);
+            }
+
+            let summary = cancel_each(&symbol, ids, self.multi_fetch_concurrency, |id| {
+                let symbol = &symbol;
+                let credentials = credentials.as_ref();
+                async move {
+                    self.binance_client
+                        .cancel_order_by_client_id(symbol, &id, credentials)
+                        .await
+                }
+            })
+            .await;
+
+            let response_json = serde_json::to_value(&summary)
+                .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+            Ok(self.signed_result(response_json))
+        })
+        .await
+    }
+
+    /// Cancel several orders by client order ID (non-SSE version with environment credentials)
+    ///
+    /// Each ID is cancelled independently; failures are reported per ID.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Cancel up to 50 orders of one symbol by client order ID (origClientOrderId). Cancels run concurrently and independently: returns {symbol, canceled: [{client_order_id, order_id, status}], failed: [{id, error_code, error}]} so one unknown or filled order doesn't abort the rest. Same mainnet and symbol whitelist guards as cancel_order. Requires API credentials."
+    )]
+    pub async fn cancel_orders(
+        &self,
+        params: Parameters<CancelOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let audit = OrderAudit {
+            caller: audit::ENV_CALLER,
+            tool: "cancel_orders",
+            symbol: &params.0.symbol,
+            side: None,
+            quantity: None,
+        };
+
+        audit::audited(audit, async {
+            let symbol = normalize_symbol(&params.0.symbol)?;
+            let ids = batch_cancel_ids(&params.0.client_order_ids)?;
+
+            if let Some(blocked) = self.symbol_not_allowed("cancel_orders", &symbol) {
+                return Ok(blocked);
+            }
+
+            if let Some(blocked) = self.mainnet_trading_blocked("cancel_orders") {
+                return Ok(blocked);
+            }
+
+            let summary = cancel_each(&symbol, ids, self.multi_fetch_concurrency, |id| {
+                let symbol = &symbol;
+                async move {
+                    self.binance_client
+                        .cancel_order_by_client_id(symbol, &id)
+                        .await
+                }
+            })
+            .await;
+
+            let response_json = serde_json::to_value(&summary).map_err(|e| {
+                ErrorData::internal_error(format!("Serialization error: {}", e), None)
+            })?;
+
+            Ok(self.signed_result(response_json))
+        })
+        .await
+    }
+
+    /// Get all open orders (SSE version with session credentials)
+    ///
+    /// Returns all currently active orders. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Get open orders for a symbol. Optional sort_by (price, time, symbol) and side/status filters return {total, count, orders} instead of the plain list. Omitting symbol uses the server's DEFAULT_SYMBOL if set (unless all_symbols=true); otherwise it scans every pair at request weight 80 instead of 6, so it also needs all_symbols=true (unless the server sets ALLOW_ALL_SYMBOL_OPEN_ORDERS). Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn get_open_orders(
+        &self,
+        params: Parameters<OpenOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = match params.0.symbol.as_deref() {
+            Some(symbol) => Some(normalize_symbol(symbol)?),
+            // An explicit all-symbol scan wins over DEFAULT_SYMBOL
+            None if params.0.all_symbols => None,
+            None => self.default_symbol.clone(),
+        };
+
+        check_open_orders_scope(
+            symbol.as_deref(),
+            params.0.all_symbols,
+            self.allow_all_symbol_open_orders,
+        )
+        .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let orders = self
+            .binance_client
+            .get_open_orders(symbol.as_deref(), credentials.as_ref())
+            .await
+            .map_err(map_binance_err)?;
+
+        let response_json = open_orders_response(&params.0.view(), orders)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Get all open orders (non-SSE version with environment credentials)
+    ///
+    /// Returns all currently active orders. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Get open orders for a symbol. Optional sort_by (price, time, symbol) and side/status filters return {total, count, orders} instead of the plain list. Omitting symbol uses the server's DEFAULT_SYMBOL if set (unless all_symbols=true); otherwise it scans every pair at request weight 80 instead of 6, so it also needs all_symbols=true (unless the server sets ALLOW_ALL_SYMBOL_OPEN_ORDERS). Requires API credentials."
+    )]
+    pub async fn get_open_orders(
+        &self,
+        params: Parameters<OpenOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = match params.0.symbol.as_deref() {
+            Some(symbol) => Some(normalize_symbol(symbol)?),
+            // An explicit all-symbol scan wins over DEFAULT_SYMBOL
+            None if params.0.all_symbols => None,
+            None => self.default_symbol.clone(),
+        };
+
+        check_open_orders_scope(
+            symbol.as_deref(),
+            params.0.all_symbols,
+            self.allow_all_symbol_open_orders,
+        )
+        .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let orders = self
+            .binance_client
+            .get_open_orders(symbol.as_deref())
+            .await
+            .map_err(map_binance_err)?;
+
+        let response_json = open_orders_response(&params.0.view(), orders)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Get all orders (history) (SSE version with session credentials)
+    ///
+    /// Returns all orders (active, canceled, filled) for a symbol. Requires API credentials.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Get complete order history for a symbol (active, canceled, filled). Returns {orders, next_from_id}. Page with order_id (or a start_time/end_time window of at most 24h); a full page includes next_from_id to pass as order_id. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn get_all_orders(
+        &self,
+        params: Parameters<AllOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let orders = self
+            .binance_client
+            .get_all_orders(&symbol, &history, credentials.as_ref())
+            .await
+            .map_err(map_binance_err)?;
+
+        let next_from_id =
+            history.next_from_id(orders.len(), orders.iter().map(|o| o.order_id).max());
+        let response_json = history_page("orders", &orders, next_from_id)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Get all orders (history) (non-SSE version with environment credentials)
+    ///
+    /// Returns all orders (active, canceled, filled) for a symbol. Requires API credentials.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Get complete order history for a symbol (active, canceled, filled). Returns {orders, next_from_id}. Page with order_id (or a start_time/end_time window of at most 24h); a full page includes next_from_id to pass as order_id. Requires API credentials."
+    )]
+    pub async fn get_all_orders(
+        &self,
+        params: Parameters<AllOrdersParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let history = params.0.history();
+        history
+            .validate()
+            .map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        let orders = self
+            .binance_client
+            .get_all_orders(&symbol, &history)
+            .await
+            .map_err(map_binance_err)?;
+
+        let next_from_id =
+            history.next_from_id(orders.len(), orders.iter().map(|o| o.order_id).max());
+        let response_json = history_page("orders", &orders, next_from_id)?;
+
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Export order history for a range to a file (SSE version with session credentials)
+    ///
+    /// Pages `allOrders` across the whole range and writes CSV or JSON to the export directory.
+    #[cfg(feature = "sse")]
+    #[tool(
+        description = "Export every order of a symbol created from start_time to end_time (ms) to a CSV or JSON file for record-keeping. The range may span any length: allOrders is paged by orderId behind the scenes. Files go to a per-session directory under the server's ORDER_EXPORT_DIR (default ./exports) as {symbol}_orders_{start_time}_{end_time}.{csv|json}, replacing this session's earlier export of the same range. Returns {path, rows, format}. Requires API credentials configured via configure_credentials."
+    )]
+    pub async fn export_order_history(
+        &self,
+        params: Parameters<ExportOrderHistoryParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        // Retrieve credentials from session
+        let credentials = self
+            .session_manager
+            .get_credentials(&params.0.session_id)
+            .await;
+
+        if credentials.is_none() {
+            let error_json = json!({
+                "error_code": "CREDENTIALS_NOT_CONFIGURED",
+                "message": "API credentials not configured for this session. Call configure_credentials first."
+            });
+            return Ok(CallToolResult::success(vec![Content::text(
+                error_json.to_string(),
+            )]));
+        }
+
+        let orders = collect_order_history(params.0.start_time, params.0.end_time, |query| {
+            let symbol = &symbol;
+            let credentials = credentials.as_ref();
+            async move {
+                self.binance_client
+                    .get_all_orders(symbol, &query, credentials)
+                    .await
+            }
+        })
+        .await
+        .map_err(map_binance_err)?;
+
+        let response_json = self.write_order_export(&symbol, &params.0, &orders).await?;
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Export order history for a range to a file (non-SSE version with environment credentials)
+    ///
+    /// Pages `allOrders` across the whole range and writes CSV or JSON to the export directory.
+    #[cfg(not(feature = "sse"))]
+    #[tool(
+        description = "Export every order of a symbol created from start_time to end_time (ms) to a CSV or JSON file for record-keeping. The range may span any length: allOrders is paged by orderId behind the scenes. Files go to the server's ORDER_EXPORT_DIR (default ./exports) as {symbol}_orders_{start_time}_{end_time}.{csv|json}, replacing an earlier export of the same range. Returns {path, rows, format}. Requires API credentials."
+    )]
+    pub async fn export_order_history(
+        &self,
+        params: Parameters<ExportOrderHistoryParam>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let symbol = normalize_symbol(&params.0.symbol)?;
+
+        let orders = collect_order_history(params.0.start_time, params.0.end_time, |query| {
+            let symbol = &symbol;
+            async move { self.binance_client.get_all_orders(symbol, &query).await }
+        })
+        .await
+        .map_err(map_binance_err)?;
+
+        let response_json = self.write_order_export(&symbol, &params.0, &orders).await?;
+        Ok(self.signed_result(response_json))
+    }
+
+    /// Get L1 aggregated metrics for quick spread assessment
+    ///
+    /// Provides lightweight order book analysis (15% token cost vs L2-full):
+    /// - Spread in basis points
+    /// - Microprice (volume-weighted fair price)
+    /// - Bid/ask volume imbalance
+    /// - Wall detection (large levels)
+    /// - VWAP-based slippage estimates
+    ///
+    /// First request: REST snapshot flagged `warming` until the stream catches up.
+    /// Subsequent: <200ms (cached).
+    /// `force_refresh` resnapshots via REST: 2-3s.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Get L1 aggregated order book metrics for quick spread assessment. Includes spread, microprice, imbalance, walls, and slippage estimates. Lightweight (15% token cost vs full depth). snapshot_age_ms and last_update_id describe the local book; stale=true means no update was applied for over 2s; warming=true means the data is the REST snapshot served while the live WebSocket book starts up. Set force_refresh=true to rebuild the book from a fresh REST snapshot (adds 2-3s; max once per second per symbol)."
+    )]
+    pub async fn get_orderbook_metrics(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetOrderBookMetricsParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+
+        let metrics =
+            crate::orderbook::tools::get_orderbook_metrics(self.orderbook_manager.clone(), params)
+                .await
+                .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&metrics)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get L2 depth with compact integer encoding
+    ///
+    /// Token cost: 50% (L2-lite with 20 levels) or 100% (L2-full with 100 levels).
+    ///
+    /// Compact encoding reduces JSON size by ~40%:
+    /// - price_scale = 100 (e.g., 67650.00 → 6765000)
+    /// - qty_scale = 100000 (e.g., 1.234 → 123400)
+    ///
+    /// Pass `encoding: "decimal"` for human-readable price/qty strings instead.
+    ///
+    /// First request: REST snapshot flagged `warming` until the stream catches up.
+    /// Subsequent: <300ms (cached).
+    /// `force_refresh` resnapshots via REST: 2-3s.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Get L2 order book depth from the cached book. Returns price levels and quantities. Use levels=20 for L2-lite (50% cost) or levels=100 for L2-full (100% cost). encoding='compact' (default) returns scaled integers; encoding='decimal' returns price/qty strings. snapshot_age_ms and last_update_id describe the local book; stale=true means no update was applied for over 2s; warming=true means the data is the REST snapshot served while the live WebSocket book starts up. Set force_refresh=true to rebuild the book from a fresh REST snapshot (adds 2-3s; max once per second per symbol)."
+    )]
+    pub async fn get_orderbook_depth(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetOrderBookDepthParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+
+        let depth =
+            crate::orderbook::tools::get_orderbook_depth(self.orderbook_manager.clone(), params)
+                .await
+                .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&depth)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get L2 depth aggregated into price buckets
+    ///
+    /// Groups the cached book into bands of `price_bucket` width, summing the
+    /// quantity per band. Returns `[bucket_price, total_qty]` string tuples.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Get order book depth aggregated into price buckets (e.g., price_bucket=10 groups all levels within $10). Returns {bids: [[bucket_price, total_qty]], asks: [...]} from the cached book. price_bucket must be between 0.001% and 10% of the mid price; max_buckets (1-100, default 20) caps buckets per side."
+    )]
+    pub async fn get_aggregated_depth(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetAggregatedDepthParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+
+        let depth =
+            crate::orderbook::tools::get_aggregated_depth(self.orderbook_manager.clone(), params)
+                .await
+                .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&depth)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get support/resistance levels from order book walls
+    ///
+    /// Levels whose quantity exceeds `wall_multiple` × the median level size
+    /// become support (bids) or resistance (asks). With the
+    /// `orderbook_analytics` feature the volume profile POC/VAH/VAL is
+    /// overlaid; otherwise only book levels are returned.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Get key support/resistance levels: bid and ask walls from the cached book (levels whose qty exceeds wall_multiple x the median level size, default 3, range 1.5-100), largest first, up to max_levels per side (1-20, default 5). Returns {support: [{price, qty}], resistance: [{price, qty}], median_qty, wall_multiple, poc, vah, val}. poc/vah/val come from the volume profile (adds ~5s, binned by tick_size or the symbol's tickSize) and are omitted with a profile_note when analytics is unavailable."
+    )]
+    pub async fn get_key_levels(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetKeyLevelsParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+        #[cfg(feature = "orderbook_analytics")]
+        let tick_size = params.tick_size.clone();
+
+        let mut levels =
+            crate::orderbook::tools::get_key_levels(self.orderbook_manager.clone(), params)
+                .await
+                .map_err(ErrorData::from)?;
+
+        #[cfg(feature = "orderbook_analytics")]
+        self.overlay_volume_profile(&mut levels, tick_size).await;
+        #[cfg(not(feature = "orderbook_analytics"))]
+        {
+            levels.profile_note = Some(
+                "Volume profile overlay requires the 'orderbook_analytics' feature".to_string(),
+            );
+        }
+
+        let response_json = serde_json::to_value(&levels)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get gaps in the current order book
+    ///
+    /// Adjacent levels whose price distance exceeds `gap_multiple` × the median
+    /// level spacing are where a market order would slip through right now,
+    /// unlike the historical volume-profile vacuums.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Find liquidity gaps in the current cached book: adjacent price levels further apart than gap_multiple x the median spacing between levels (default 3, range 1.5-1000), nearest the spread first, up to max_gaps per side (1-20, default 5). Returns {bid_gaps: [{from_price, to_price, width, spacing_multiple, from_qty, to_qty}], ask_gaps: [...], median_spacing, gap_multiple, snapshot_age_ms, last_update_id, stale, warming}. Shows where a market order would slip through now; get_liquidity_vacuums covers historical volume."
+    )]
+    pub async fn get_book_gaps(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetBookGapsParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+
+        let gaps = crate::orderbook::tools::get_book_gaps(self.orderbook_manager.clone(), params)
+            .await
+            .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&gaps)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get aggregate liquidity over the top levels of the current order book
+    ///
+    /// Volume and notional per side, the volume-weighted average prices and
+    /// the bid/ask imbalance, computed exactly from the local book.
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Aggregate liquidity over the top N levels per side of the current cached book (levels 1-100, default 20). Returns {levels, bid_levels, ask_levels, bid_volume, ask_volume, bid_notional, ask_notional, bid_vwap, ask_vwap, imbalance, snapshot_age_ms, last_update_id, stale, warming} as exact decimal strings; vwap is the volume-weighted average price per side and imbalance is (bid_volume - ask_volume) / (bid_volume + ask_volume), from -1 to 1."
+    )]
+    pub async fn get_depth_stats(
+        &self,
+        params: Parameters<crate::orderbook::tools::GetDepthStatsParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut params = params.0;
+        params.symbol = normalize_symbol(&params.symbol)?;
+
+        let stats =
+            crate::orderbook::tools::get_depth_stats(self.orderbook_manager.clone(), params)
+                .await
+                .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&stats)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Get service health status for order book tracking
+    ///
+    /// Returns operational visibility:
+    /// - Overall status (ok/degraded/error)
+    /// - Tracked symbols and their last-access ages (capped by `ORDERBOOK_MAX_SYMBOLS`)
+    /// - Data freshness (last update age in ms)
+    /// - WebSocket connection status
+    ///
+    /// Latency: <50ms (no external API calls).
+    #[cfg(feature = "orderbook")]
+    #[tool(
+        description = "Get order book service health status. Returns connection status, tracked symbols with last-access ages (LRU-evicted beyond ORDERBOOK_MAX_SYMBOLS, default 20), and data freshness. Fast (<50ms, no API calls)."
+    )]
+    pub async fn get_orderbook_health(&self) -> Result<CallToolResult, ErrorData> {
+        let health = crate::orderbook::tools::get_orderbook_health(self.orderbook_manager.clone())
+            .await
+            .map_err(ErrorData::from)?;
+
+        let response_json = serde_json::to_value(&health)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            response_json.to_string(),
+        )]))
+    }
+
+    /// Stub implementation for get_orderbook_metrics when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(description = "Order book metrics not available (requires 'orderbook' feature)")]
+    pub async fn get_orderbook_metrics(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_orderbook_depth when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(description = "Order book depth not available (requires 'orderbook' feature)")]
+    pub async fn get_orderbook_depth(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_aggregated_depth when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(
+        description = "Aggregated order book depth not available (requires 'orderbook' feature)"
+    )]
+    pub async fn get_aggregated_depth(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_key_levels when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(
+        description = "Key support/resistance levels not available (requires 'orderbook' feature)"
+    )]
+    pub async fn get_key_levels(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_book_gaps when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(description = "Order book gaps not available (requires 'orderbook' feature)")]
+    pub async fn get_book_gaps(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_depth_stats when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(description = "Order book depth stats not available (requires 'orderbook' feature)")]
+    pub async fn get_depth_stats(
+        &self,
+        _params: Parameters<serde_json::Value>,
+    ) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+
+    /// Stub implementation for get_orderbook_health when orderbook feature is disabled
+    #[cfg(not(feature = "orderbook"))]
+    #[tool(description = "Order book health not available (requires 'orderbook' feature)")]
+    pub async fn get_orderbook_health(&self) -> Result<CallToolResult, ErrorData> {
+        Err(ErrorData::internal_error(
+            "Order book features are not enabled in this deployment. Rebuild with --features orderbook".to_string(),
+            None,
+        ))
+    }
+}
+
+/// Builds a history page: `{"<key>": [...], "next_from_id": N}` (hint only when the page is full)
+fn history_page<T: Serialize>(
+    key: &str,
+    items: &[T],
+    next_from_id: Option<i64>,
+) -> Result<serde_json::Value, ErrorData> {
+    let items = serde_json::to_value(items)
+        .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+
+    let mut page = serde_json::Map::new();
+    page.insert(key.to_string(), items);
+    if let Some(next) = next_from_id {
+        page.insert("next_from_id".to_string(), json!(next));
+    }
+    Ok(serde_json::Value::Object(page))
+}
+
+/// Builds the `place_trailing_stop` result: the computed delta and type next to the order
+fn trailing_stop_json(
+    trailing_delta: u32,
+    order_type: &str,
+    order: &Order,
+) -> Result<serde_json::Value, ErrorData> {
+    let order = serde_json::to_value(order)
+        .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))?;
+    Ok(json!({
+        "trailing_delta_bps": trailing_delta,
+        "order_type": order_type,
+        "order": order,
+    }))
+}
+
+impl BinanceServer {
+    /// Wraps the JSON of a signed call in a tool result carrying `_meta.clock_offset_ms`
+    ///
+    /// The offset is the server-minus-local clock difference applied to the
+    /// request timestamp, so agents can surface clock-skew problems.
+    fn signed_result(&self, response_json: serde_json::Value) -> CallToolResult {
+        self.signed_text(response_json.to_string())
+    }
+
+    /// [`Self::signed_result`] for an already serialized response
+    fn signed_text(&self, text: String) -> CallToolResult {
+        let mut meta = Meta::new();
+        meta.insert(
+            "clock_offset_ms".to_string(),
+            json!(self.binance_client.time_offset_ms()),
+        );
+
+        let mut result = CallToolResult::success(vec![Content::text(text)]);
+        result.meta = Some(meta);
+        result
+    }
+
+    /// Writes an `export_order_history` file and describes it
+    async fn write_order_export(
+        &self,
+        symbol: &str,
+        params: &ExportOrderHistoryParam,
+        orders: &[Order],
+    ) -> Result<serde_json::Value, ErrorData> {
+        let contents = render_orders(orders, params.format)
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+        let file_name = export_file_name(symbol, params.start_time, params.end_time, params.format);
+        #[cfg(feature = "sse")]
+        let dir = session_export_dir(&self.order_export_dir, &params.session_id);
+        #[cfg(not(feature = "sse"))]
+        let dir = self.order_export_dir.clone();
+        let path = write_export(&dir, &file_name, &contents)
+            .await
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+
+        Ok(json!({
+            "path": path.display().to_string(),
+            "rows": orders.len(),
+            "format": params.format,
+        }))
+    }
+
+    /// Returns the `MAINNET_TRADING_DISABLED` result if `tool` would trade on mainnet
+    ///
+    /// Session credentials decide the environment; without them the client's
+    /// base URL is used.
+    #[cfg(feature = "sse")]
+    fn mainnet_trading_blocked(
+        &self,
+        tool: &str,
+        credentials: Option<&Credentials>,
+    ) -> Option<CallToolResult> {
+        let base_url = credentials
+            .map(|creds| creds.environment.base_url())
+            .unwrap_or_else(|| self.binance_client.base_url());
+        check_mainnet_trading(tool, base_url, self.mainnet_trading_enabled)
+            .map(|error_json| CallToolResult::success(vec![Content::text(error_json.to_string())]))
+    }
+
+    /// Returns the `MAINNET_TRADING_DISABLED` result if `tool` would trade on mainnet
+    #[cfg(not(feature = "sse"))]
+    fn mainnet_trading_blocked(&self, tool: &str) -> Option<CallToolResult> {
+        check_mainnet_trading(
+            tool,
+            self.binance_client.base_url(),
+            self.mainnet_trading_enabled,
+        )
+        .map(|error_json| CallToolResult::success(vec![Content::text(error_json.to_string())]))
+    }
+
+    /// Returns the `SYMBOL_NOT_ALLOWED` result if `symbol` is outside `TRADING_SYMBOL_WHITELIST`
+    fn symbol_not_allowed(&self, tool: &str, symbol: &str) -> Option<CallToolResult> {
+        self.trading_symbol_whitelist
+            .check(tool, symbol)
+            .map(|error_json| CallToolResult::success(vec![Content::text(error_json.to_string())]))
+    }
+
+    /// Pre-flight balance check for `place_order`, unless `skip_balance_check` is set
+    ///
+    /// Uses the cached account snapshot (`ACCOUNT_CACHE_TTL_MS`) and, for MARKET
+    /// orders, the last price. A shortfall is [`BinanceError::InsufficientBalance`]
+    /// (`INSUFFICIENT_BALANCE`), returned before the order reaches the exchange.
+    ///
+    /// [`BinanceError::InsufficientBalance`]: crate::error::BinanceError::InsufficientBalance
+    async fn check_order_balance(
+        &self,
+        symbol: &str,
+        params: &PlaceOrderParam,
+        #[cfg(feature = "sse")] credentials: Option<&Credentials>,
+    ) -> Result<(), ErrorData> {
+        if params.skip_balance_check.unwrap_or(false) {
+            return Ok(());
+        }
+
+        let parse = |name: &str, value: &str| {
+            Decimal::from_str(value.trim())
+                .ok()
+                .filter(|v| *v > Decimal::ZERO)
+                .ok_or_else(|| {
+                    ErrorData::invalid_params(format!("Invalid {} '{}'", name, value), None)
+                })
+        };
+        let quantity = parse("quantity", &params.quantity)?;
+        let price = match params.price.as_deref() {
+            Some(price) => parse("price", price)?,
+            None => {
+                let ticker = self
+                    .binance_client
+                    .get_ticker_price(symbol)
+                    .await
+                    .map_err(map_binance_err)?;
+                parse("ticker price", &ticker.price)?
+            }
+        };
+
+        let exchange_info = self
+            .binance_client
+            .get_exchange_info()
+            .await
+            .map_err(map_binance_err)?;
+        let info = exchange_info
+            .symbols
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| {
+                ErrorData::invalid_params(format!("Unknown symbol '{}'", symbol), None)
+            })?;
+
+        #[cfg(feature = "sse")]
+        let account = self.binance_client.get_account(credentials).await;
+        #[cfg(not(feature = "sse"))]
+        let account = self.binance_client.get_account().await;
+        let account = account.map_err(map_binance_err)?;
+
+        check_balance(info, &params.side, quantity, price, &account.balances)
+            .map_err(ErrorData::from)
+    }
+
+    /// Applies `auto_round` to an order before submission
+    ///
+    /// Rounds the price to the nearest tick and the quantity down to the
+    /// step size, updating `params` in place. Returns the requested and
+    /// submitted values for `_meta.auto_round`, or `None` when not requested.
+    async fn auto_round_order(
+        &self,
+        params: &mut PlaceOrderParam,
+    ) -> Result<Option<serde_json::Value>, ErrorData> {
+        if !params.auto_round.unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let symbol = normalize_symbol(&params.symbol)?;
+        let increments = symbol_increments(&self.binance_client, &symbol)
+            .await
+            .map_err(map_binance_err)?;
+        let parse = |name: &str, value: &str| {
+            Decimal::from_str(value.trim())
+                .ok()
+                .filter(|v| *v > Decimal::ZERO)
+                .ok_or_else(|| {
+                    ErrorData::invalid_params(format!("Invalid {} '{}'", name, value), None)
+                })
+        };
+
+        let quantity = increments.floor_quantity(parse("quantity", &params.quantity)?);
+        if quantity.is_zero() {
+            return Err(ErrorData::invalid_params(
+                format!(
+                    "quantity {} rounds down to 0 at stepSize {}",
+                    params.quantity,
+                    increments.step_size.unwrap_or_default()
+                ),
+                None,
+            ));
+        }
+        let mut adjusted = json!({
+            "quantity": {"requested": params.quantity, "submitted": quantity.to_string()},
+        });
+        params.quantity = quantity.to_string();
+
+        if let Some(requested) = params.price.take() {
+            let price = increments.round_price(parse("price", &requested)?);
+            adjusted["price"] = json!({"requested": requested, "submitted": price.to_string()});
+            params.price = Some(price.to_string());
+        }
+
+        Ok(Some(adjusted))
+    }
+
+    /// Fills the volume profile POC/VAH/VAL into key levels
+    ///
+    /// The profile is an optional overlay, so failures and thin trade samples
+    /// leave the book levels untouched and only set `profile_note`.
+    #[cfg(feature = "orderbook_analytics")]
+    async fn overlay_volume_profile(
+        &self,
+        levels: &mut crate::orderbook::types::KeyLevels,
+        tick_size: Option<String>,
+    ) {
+        use crate::binance::types::SymbolFilter;
+        use crate::orderbook::analytics::profile::generate_volume_profile;
+        use crate::orderbook::analytics::types::DataSufficiency;
+
+        let tick_size = match tick_size {
+            Some(tick) => Some(tick),
+            None => self
+                .binance_client
+                .get_exchange_info()
+                .await
+                .ok()
+                .and_then(|info| {
+                    info.symbols
+                        .iter()
+                        .find(|s| s.symbol == levels.symbol)
+                        .and_then(|s| {
+                            s.filters.iter().find_map(|f| match f {
+                                SymbolFilter::PriceFilter { tick_size, .. } => {
+                                    Some(tick_size.clone())
+                                }
+                                _ => None,
+                            })
+                        })
+                }),
+        };
+        let Some(tick) = tick_size.and_then(|t| Decimal::from_str_exact(&t).ok()) else {
+            levels.profile_note = Some("No valid tick_size for the volume profile".to_string());
+            return;
+        };
+
+        match generate_volume_profile(&levels.symbol, 24, tick).await {
+            Ok(profile) if profile.data_sufficiency == DataSufficiency::Insufficient => {
+                levels.profile_note = profile.data_note;
+            }
+            Ok(profile) => {
+                levels.poc = Some(profile.point_of_control.normalize().to_string());
+                levels.vah = Some(profile.value_area_high.normalize().to_string());
+                levels.val = Some(profile.value_area_low.normalize().to_string());
+            }
+            Err(e) => {
+                levels.profile_note = Some(format!("Volume profile unavailable: {}", e));
+            }
+        }
+    }
+
+    /// Validates a trailing stop and returns its `(trailingDelta, order type)`
+    ///
+    /// The current price is only fetched when an activation price is given,
+    /// since that decides between STOP_LOSS and TAKE_PROFIT.
+    async fn trailing_stop_plan(
+        &self,
+        symbol: &str,
+        params: &TrailingStopParam,
+    ) -> Result<(u32, &'static str), ErrorData> {
+        let invalid = |e: McpError| ErrorData::invalid_params(e.to_string(), None);
+        let trailing_delta = trailing_delta_bps(params.trailing_percent).map_err(invalid)?;
+
+        let activation = match params.activation_price.as_deref() {
+            Some(price) => Some(
+                price
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|p| p.is_finite() && *p > 0.0)
+                    .ok_or_else(|| {
+                        ErrorData::invalid_params(
+                            format!("Invalid activation_price '{}'", price),
+                            None,
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+        let market_price = match activation {
+            Some(_) => self
+                .binance_client
+                .get_ticker_price(symbol)
+                .await
+                .map_err(map_binance_err)?
+                .price
+                .parse::<f64>()
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Invalid ticker price: {}", e), None)
+                })?,
+            None => 0.0,
+        };
+
+        let order_type =
+            trailing_stop_type(&params.side, activation, market_price).map_err(invalid)?;
+        Ok((trailing_delta, order_type))
+    }
+
+    /// Places an order at most once per `client_order_id`
+    ///
+    /// Without an ID this just awaits `place`. With one, `lookup` runs first
+    /// and an existing order is returned with `_meta.idempotent_replay: true`.
+    /// Otherwise the ID is claimed in the recent-submission set, so a second
+    /// call racing the first is rejected before it reaches the exchange.
+    ///
+    /// `preflight` (the balance check) runs just before placing, after the
+    /// lookup, so a replay isn't refused for funds its own order has locked.
+    async fn place_order_once(
+        &self,
+        symbol: &str,
+        client_order_id: Option<&str>,
+        lookup: Option<impl Future<Output = Result<Option<Order>, McpError>>>,
+        preflight: impl Future<Output = Result<(), ErrorData>>,
+        place: impl Future<Output = Result<Order, McpError>>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let to_json = |order: &Order| {
+            serde_json::to_value(order)
+                .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))
+        };
+
+        let (Some(id), Some(lookup)) = (client_order_id, lookup) else {
+            preflight.await?;
+            let order = place.await.map_err(map_binance_err)?;
+            return Ok(self.signed_result(to_json(&order)?));
+        };
+        validate_client_order_id(id).map_err(|e| ErrorData::invalid_params(e.to_string(), None))?;
+
+        if let Some(existing) = lookup.await.map_err(map_binance_err)? {
+            tracing::info!(
+                symbol = %symbol,
+                client_order_id = %id,
+                order_id = existing.order_id,
+                "Order already exists for client order ID; not placing a duplicate"
+            );
+            let mut result = self.signed_result(to_json(&existing)?);
+            if let Some(meta) = result.meta.as_mut() {
+                meta.insert("idempotent_replay".to_string(), json!(true));
+            }
+            return Ok(result);
+        }
+        preflight.await?;
+
+        let key = format!("{}:{}", symbol, id);
+        if !self.recent_client_order_ids.try_claim(&key) {
+            return Err(ErrorData::invalid_request(
+                format!(
+                    "An order with client_order_id '{}' was submitted moments ago and is not visible on the exchange yet. Check get_open_orders before resubmitting.",
+                    id
+                ),
+                None,
+            ));
+        }
+
+        match place.await {
+            Ok(order) => Ok(self.signed_result(to_json(&order)?)),
+            Err(e) => {
+                // A connection error may hide a placed order, so keep the claim
+                if !matches!(e, McpError::ConnectionError(_)) {
+                    self.recent_client_order_ids.release(&key);
+                }
+                Err(map_binance_err(e))
+            }
+        }
+    }
+}
+
+/// `get_account_info` response: the account with its permission summary first
+#[derive(Serialize)]
+struct AccountInfoResponse<'a> {
+    permission_summary: AccountPermissions,
+    #[serde(flatten)]
+    account: &'a AccountInfo,
+}
+
+/// Serializes `get_account_info` results
+///
+/// Serialized straight to text, not through `Value`, so `permission_summary`
+/// stays the first key.
+fn account_info_text(account: &AccountInfo) -> Result<String, ErrorData> {
+    serde_json::to_string(&AccountInfoResponse {
+        permission_summary: account.permission_summary(),
+        account,
+    })
+    .map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))
+}
+
+/// Serializes `get_open_orders` results
+///
+/// Without sorting or filters this is the plain order list, as before;
+/// otherwise `{total, count, orders}`.
+fn open_orders_response(
+    view: &OpenOrdersView,
+    orders: Vec<Order>,
+) -> Result<serde_json::Value, ErrorData> {
+    let response = if view.is_requested() {
+        serde_json::to_value(view.apply(orders))
+    } else {
+        serde_json::to_value(&orders)
+    };
+    response.map_err(|e| ErrorData::internal_error(format!("Serialization error: {}", e), None))
+}
+
+/// Validates the `client_order_ids` of `cancel_orders`
+///
+/// Returns the trimmed, de-duplicated IDs, or `invalid_params` if none remain
+/// or more than [`MAX_CANCEL_BATCH`] were given.
+fn batch_cancel_ids(client_order_ids: &[String]) -> Result<Vec<String>, ErrorData> {
+    let ids = dedupe_client_order_ids(client_order_ids);
+    if ids.is_empty() {
+        return Err(ErrorData::invalid_params(
+            "client_order_ids must contain at least one client order ID",
+            None,
+        ));
+    }
+    if ids.len() > MAX_CANCEL_BATCH {
+        return Err(ErrorData::invalid_params(
+            format!(
+                "At most {} client order IDs per call, got {}",
+                MAX_CANCEL_BATCH,
+                ids.len()
+            ),
+            None,
+        ));
+    }
+    Ok(ids)
+}
+
+/// Adds the `auto_round` adjustments to a submitted order's `_meta`
+fn with_auto_round_meta(
+    mut result: CallToolResult,
+    auto_round: Option<serde_json::Value>,
+) -> CallToolResult {
+    if let (Some(adjusted), Some(meta)) = (auto_round, result.meta.as_mut()) {
+        meta.insert("auto_round".to_string(), adjusted);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binance::BinanceClient;
+    use rmcp::model::ErrorCode;
+
+    #[cfg(feature = "sse")]
+    fn error_code(err: &ErrorData) -> &str {
+        err.data
+            .as_ref()
+            .and_then(|data| data["error_code"].as_str())
+            .unwrap_or_default()
+    }
+
+    fn server_at(base_url: &str) -> BinanceServer {
+        BinanceServer {
+            binance_client: BinanceClient {
+                base_url: base_url.to_string(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        }
+    }
+
+    /// Server whose client targets `mock` with test API credentials
+    #[cfg(not(feature = "sse"))]
+    fn test_server(mock: &wiremock::MockServer) -> BinanceServer {
+        BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                api_key: Some("test-api-key".to_string()),
+                api_secret: Some("test-api-secret".to_string()),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        }
+    }
+
+    async fn recent_trades(server: &BinanceServer) -> ErrorData {
+        server
+            .get_recent_trades(Parameters(RecentTradesParam {
+                symbol: "BTCUSDT".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_rejected_symbol_is_invalid_params() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/trades"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(json!({"code": -1121, "msg": "Invalid symbol."})),
+            )
+            .mount(&mock)
+            .await;
+
+        let err = recent_trades(&server_at(&mock.uri())).await;
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS);
+
+        // Malformed symbols are rejected before reaching Binance
+        let err = server_at(&mock.uri())
+            .get_recent_trades(Parameters(RecentTradesParam {
+                symbol: "B".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(crate::error::tool_error_code(&err), "INVALID_SYMBOL");
+    }
+
+    #[tokio::test]
+    async fn test_network_error_is_internal_error() {
+        let err = recent_trades(&server_at("http://127.0.0.1:1")).await;
+
+        assert_eq!(err.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(err.data.unwrap()["error_type"], "connection_error");
+    }
+
+    #[cfg(feature = "sse")]
+    fn params(api_key: &str, environment: &str, session_id: &str) -> ConfigureCredentialsParam {
+        ConfigureCredentialsParam {
+            api_key: api_key.to_string(),
+            api_secret: "s".repeat(64),
+            environment: environment.to_string(),
+            session_id: session_id.to_string(),
+        }
+    }
+
+    #[cfg(feature = "sse")]
+    #[tokio::test]
+    async fn test_configure_credentials_invalid_key_is_error() {
+        let server = BinanceServer::new();
+
+        let err = server
+            .configure_credentials(Parameters(params("too-short", "testnet", "s1")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(error_code(&err), "INVALID_API_KEY_FORMAT");
+    }
+
+    #[cfg(feature = "sse")]
+    #[tokio::test]
+    async fn test_configure_credentials_invalid_environment_is_error() {
+        let server = BinanceServer::new();
+
+        let err = server
+            .configure_credentials(Parameters(params(&"k".repeat(64), "staging", "s1")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS);
+        assert_eq!(error_code(&err), "INVALID_ENVIRONMENT");
+    }
+
+    #[cfg(feature = "sse")]
+    #[tokio::test]
+    async fn test_configure_credentials_unknown_session_is_error() {
+        let server = BinanceServer::new();
+
+        let err = server
+            .configure_credentials(Parameters(params(&"k".repeat(64), "testnet", "missing")))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::INVALID_REQUEST);
+        assert_eq!(error_code(&err), "SESSION_NOT_FOUND");
+    }
+
+    #[cfg(not(feature = "sse"))]
+    async fn mock_account_with_usdt(server: &wiremock::MockServer, free_usdt: &str) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "timezone": "UTC", "serverTime": 0,
+                "symbols": [{"symbol": "BTCUSDT", "status": "TRADING",
+                             "baseAsset": "BTC", "quoteAsset": "USDT", "filters": []}]
+            })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/account"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "makerCommission": 10, "takerCommission": 10,
+                "buyerCommission": 0, "sellerCommission": 0,
+                "canTrade": true, "canWithdraw": true, "canDeposit": true,
+                "updateTime": 0, "accountType": "SPOT",
+                "balances": [{"asset": "USDT", "free": free_usdt, "locked": "0.00000000"}],
+                "permissions": ["SPOT"]
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[cfg(not(feature = "sse"))]
+    fn buy_order(skip_balance_check: Option<bool>) -> PlaceOrderParam {
+        PlaceOrderParam {
+            symbol: "BTCUSDT".to_string(),
+            side: "BUY".to_string(),
+            order_type: "LIMIT".to_string(),
+            quantity: "0.002".to_string(),
+            price: Some("50000".to_string()),
+            time_in_force: None,
+            client_order_id: None,
+            skip_balance_check,
+            auto_round: None,
+        }
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_place_order_insufficient_balance_is_rejected_locally() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        mock_account_with_usdt(&mock, "50.00000000").await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        let err = server
+            .place_order(Parameters(buy_order(None)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code, ErrorCode(-32004));
+        let data = err.data.unwrap();
+        assert_eq!(data["asset"], "USDT");
+        assert_eq!(data["required_amount"], "100.10000000");
+        assert_eq!(data["available_amount"], "50.00000000");
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_place_order_skip_balance_check_submits() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        mock_account_with_usdt(&mock, "50.00000000").await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "BTCUSDT", "orderId": 42, "clientOrderId": "abc",
+                "status": "NEW", "type": "LIMIT", "side": "BUY"
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        server
+            .place_order(Parameters(buy_order(Some(true))))
+            .await
+            .unwrap();
+        let account_requests = mock
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/api/v3/account")
+            .count();
+        assert_eq!(account_requests, 0);
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_place_order_auto_round_submits_rounded_values() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "timezone": "UTC", "serverTime": 0,
+                "symbols": [{"symbol": "BTCUSDT", "status": "TRADING",
+                             "baseAsset": "BTC", "quoteAsset": "USDT", "filters": [
+                    {"filterType": "PRICE_FILTER", "minPrice": "0.01000000",
+                     "maxPrice": "1000000.00000000", "tickSize": "0.01000000"},
+                    {"filterType": "LOT_SIZE", "minQty": "0.00001000",
+                     "maxQty": "9000.00000000", "stepSize": "0.00001000"}
+                ]}]
+            })))
+            .mount(&mock)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .and(query_param("quantity", "0.00123"))
+            .and(query_param("price", "65123.46"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "BTCUSDT", "orderId": 42, "clientOrderId": "abc",
+                "status": "NEW", "type": "LIMIT", "side": "BUY"
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        let result = server
+            .place_order(Parameters(PlaceOrderParam {
+                quantity: "0.0012345".to_string(),
+                price: Some("65123.456".to_string()),
+                auto_round: Some(true),
+                ..buy_order(Some(true))
+            }))
+            .await
+            .unwrap();
+
+        let adjusted = &result.meta.unwrap()["auto_round"];
+        assert_eq!(adjusted["quantity"]["requested"], "0.0012345");
+        assert_eq!(adjusted["quantity"]["submitted"], "0.00123");
+        assert_eq!(adjusted["price"]["requested"], "65123.456");
+        assert_eq!(adjusted["price"]["submitted"], "65123.46");
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_symbol_whitelist_blocks_orders_before_submission() {
+        use crate::tools::trading_guard::{SymbolWhitelist, SYMBOL_NOT_ALLOWED};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .and(query_param("symbol", "BTCUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "BTCUSDT", "orderId": 42, "clientOrderId": "abc",
+                "status": "NEW", "type": "LIMIT", "side": "BUY"
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .and(query_param("symbol", "DOGEUSDT"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock)
+            .await;
+        // auto_round runs after the whitelist, so a blocked symbol fetches nothing
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            trading_symbol_whitelist: SymbolWhitelist::parse(Some("BTCUSDT,ETHUSDT")),
+            ..test_server(&mock)
+        };
+
+        let body = |result: CallToolResult| -> serde_json::Value {
+            let text = &result.content[0].as_text().unwrap().text;
+            serde_json::from_str(text).unwrap()
+        };
+
+        let allowed = server
+            .place_order(Parameters(buy_order(Some(true))))
+            .await
+            .unwrap();
+        assert_eq!(body(allowed)["orderId"], 42);
+
+        let blocked = server
+            .place_order(Parameters(PlaceOrderParam {
+                symbol: "dogeusdt".to_string(),
+                auto_round: Some(true),
+                ..buy_order(Some(true))
+            }))
+            .await
+            .unwrap();
+        let blocked = body(blocked);
+        assert_eq!(blocked["error_code"], SYMBOL_NOT_ALLOWED);
+        assert_eq!(blocked["symbol"], "DOGEUSDT");
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_cancel_orders_reports_per_id_results() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        for (client_id, order_id) in [("grid-1", 101), ("grid-3", 103)] {
+            Mock::given(method("DELETE"))
+                .and(path("/api/v3/order"))
+                .and(query_param("origClientOrderId", client_id))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "symbol": "BTCUSDT", "orderId": order_id, "clientOrderId": client_id,
+                    "status": "CANCELED", "type": "LIMIT", "side": "BUY"
+                })))
+                .expect(1)
+                .mount(&mock)
+                .await;
+        }
+        Mock::given(method("DELETE"))
+            .and(path("/api/v3/order"))
+            .and(query_param("origClientOrderId", "grid-2"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(json!({"code": -2011, "msg": "Unknown order sent."})),
+            )
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        let result = server
+            .cancel_orders(Parameters(CancelOrdersParam {
+                symbol: "btcusdt".to_string(),
+                client_order_ids: ["grid-1", "grid-2", "grid-3", "grid-1"]
+                    .map(String::from)
+                    .to_vec(),
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let summary: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        assert_eq!(summary["symbol"], "BTCUSDT");
+        assert_eq!(summary["canceled"][0]["order_id"], 101);
+        assert_eq!(summary["canceled"][1]["client_order_id"], "grid-3");
+        assert_eq!(summary["canceled"].as_array().unwrap().len(), 2);
+        assert_eq!(summary["failed"][0]["id"], "grid-2");
+        assert_eq!(summary["failed"][0]["error_code"], "INVALID_PARAMS");
+
+        let empty = server
+            .cancel_orders(Parameters(CancelOrdersParam {
+                symbol: "BTCUSDT".to_string(),
+                client_order_ids: vec![" ".to_string()],
+            }))
+            .await;
+        assert!(empty.is_err());
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_wait_for_order_returns_once_filled() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let order = |status: &str| {
+            json!({
+                "symbol": "BTCUSDT", "orderId": 555, "clientOrderId": "wait-1",
+                "price": "65000.00", "origQty": "0.001", "executedQty": "0.001",
+                "status": status, "type": "LIMIT", "side": "BUY"
+            })
+        };
+        let mock = MockServer::start().await;
+        // The first query sees the order resting, later ones see it filled
+        Mock::given(method("GET"))
+            .and(path("/api/v3/order"))
+            .and(query_param("orderId", "555"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(order("NEW")))
+            .up_to_n_times(1)
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/order"))
+            .and(query_param("orderId", "555"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(order("FILLED")))
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        let result = server
+            .wait_for_order(Parameters(WaitForOrderParam {
+                symbol: "btcusdt".to_string(),
+                order_id: 555,
+                timeout_secs: Some(10.0),
+                // Clamped up to the 500ms minimum
+                poll_interval_secs: Some(0.01),
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let waited: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        assert_eq!(waited["timed_out"], false);
+        assert_eq!(waited["order"]["status"], "FILLED");
+        assert_eq!(waited["polls"], 2);
+        assert!(waited["elapsed_ms"].as_u64().unwrap() >= 500);
+        assert_eq!(mock.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_watchlist_overview_skips_invalid_symbols() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        for (symbol, last, change, bid, ask) in [
+            ("BTCUSDT", "65000.00", "2.50", "64999.99", "65000.00"),
+            ("ETHUSDT", "3000.00", "-1.20", "2999.00", "3001.00"),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/api/v3/ticker/24hr"))
+                .and(query_param("symbol", symbol))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "symbol": symbol, "priceChange": "0", "priceChangePercent": change,
+                    "weightedAvgPrice": last, "prevClosePrice": last, "lastPrice": last,
+                    "lastQty": "1", "bidPrice": bid, "askPrice": ask, "openPrice": last,
+                    "highPrice": last, "lowPrice": last, "volume": "1", "quoteVolume": "1",
+                    "openTime": 0, "closeTime": 0, "firstId": 0, "lastId": 0, "count": 0
+                })))
+                .mount(&mock)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/api/v3/ticker/bookTicker"))
+                .and(query_param("symbol", symbol))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "symbol": symbol, "bidPrice": bid, "bidQty": "1",
+                    "askPrice": ask, "askQty": "1"
+                })))
+                .mount(&mock)
+                .await;
+        }
+        // Anything else is an unknown pair
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(json!({"code": -1121, "msg": "Invalid symbol."})),
+            )
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+
+        let result = server
+            .get_watchlist_overview(Parameters(WatchlistParam {
+                symbols: ["eth/usdt", "FAKEUSDT", "btcusdt", "ETHUSDT", "bad!"]
+                    .map(String::from)
+                    .to_vec(),
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let overview: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        let rows = overview["rows"].as_array().unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["symbol"], "BTCUSDT");
+        assert_eq!(rows[0]["price"], "65000.00");
+        assert_eq!(rows[0]["change_pct"], "2.50");
+        assert_eq!(rows[1]["symbol"], "ETHUSDT");
+        assert_eq!(rows[1]["spread_bps"], "6.67");
+
+        let failed = overview["failed"].as_array().unwrap();
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0]["symbol"], "bad!");
+        assert_eq!(failed[1]["symbol"], "FAKEUSDT");
+        assert_eq!(failed[1]["error_code"], "INVALID_PARAMS");
+
+        let too_many = server
+            .get_watchlist_overview(Parameters(WatchlistParam {
+                symbols: (0..=MAX_WATCHLIST_SYMBOLS)
+                    .map(|i| format!("SYM{i}USDT"))
+                    .collect(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(too_many.code, ErrorCode::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_quote_to_quantity_checks_min_notional() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "timezone": "UTC", "serverTime": 0,
+                "symbols": [{"symbol": "ETHUSDT", "status": "TRADING",
+                             "baseAsset": "ETH", "quoteAsset": "USDT", "filters": [
+                    {"filterType": "LOT_SIZE", "minQty": "0.0001", "maxQty": "9000", "stepSize": "0.00010000"},
+                    {"filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true,
+                     "maxNotional": "9000000", "applyMaxToMarket": false}
+                ]}]
+            })))
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/price"))
+            .and(query_param("symbol", "ETHUSDT"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"symbol": "ETHUSDT", "price": "3012.34000000"})),
+            )
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+        let convert = |quote_amount: &str, price: Option<&str>| QuoteToQuantityParam {
+            symbol: "eth/usdt".to_string(),
+            quote_amount: quote_amount.to_string(),
+            price: price.map(str::to_string),
+        };
+
+        let result = server
+            .quote_to_quantity(Parameters(convert("50", None)))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let converted: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(converted["price"], "3012.34");
+        assert_eq!(converted["quantity"], "0.0165");
+        assert_eq!(converted["estimated_cost"], "49.70361");
+        assert_eq!(converted["min_notional"], "5");
+
+        // A limit price replaces the current price
+        let result = server
+            .quote_to_quantity(Parameters(convert("50", Some("2500"))))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let converted: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(converted["quantity"], "0.02");
+        assert_eq!(converted["estimated_cost"], "50");
+
+        let err = server
+            .quote_to_quantity(Parameters(convert("4", None)))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::INVALID_PARAMS);
+        assert!(
+            err.message.contains("below minNotional 5"),
+            "{}",
+            err.message
+        );
+        assert!(err.message.contains("short by"), "{}", err.message);
+    }
+
+    #[tokio::test]
+    async fn test_explain_symbol_rules_per_symbol() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/exchangeInfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "timezone": "UTC", "serverTime": 0,
+                "symbols": [
+                    {"symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT",
+                     "orderTypes": ["LIMIT", "MARKET"], "filters": [
+                        {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000", "tickSize": "0.01000000"},
+                        {"filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000", "stepSize": "0.00001000"},
+                        {"filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true,
+                         "maxNotional": "9000000", "applyMaxToMarket": false}
+                    ]},
+                    {"symbol": "ETHBTC", "status": "TRADING", "baseAsset": "ETH", "quoteAsset": "BTC",
+                     "orderTypes": ["LIMIT"], "filters": [
+                        {"filterType": "LOT_SIZE", "minQty": "0.0001", "maxQty": "100000", "stepSize": "0.0001"},
+                        {"filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200}
+                    ]}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+        let explain = |symbol: &str| {
+            server.explain_symbol_rules(Parameters(SymbolParam {
+                symbol: symbol.to_string(),
+            }))
+        };
+
+        let result = explain("btc/usdt").await.unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let rules: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(rules["symbol"], "BTCUSDT");
+        assert_eq!(rules["quantity_step"], "0.00001");
+        assert_eq!(rules["min_notional"], "5");
+        assert_eq!(rules["market_orders_allowed"], true);
+        assert_eq!(
+            rules["guidance"][1],
+            "Orders must be for at least 0.00001 BTC and at most 9000 BTC, in increments of 0.00001 BTC."
+        );
+
+        // Served from the cached exchangeInfo
+        let result = explain("ETHBTC").await.unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let rules: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(rules["min_notional"], serde_json::Value::Null);
+        assert_eq!(rules["max_open_orders"], 200);
+        assert_eq!(rules["market_orders_allowed"], false);
+
+        let err = explain("DOGEUSDT").await.unwrap_err();
+        assert!(err.message.contains("Unknown symbol 'DOGEUSDT'"));
+    }
+
+    #[tokio::test]
+    async fn test_order_book_summary_only_omits_depth() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/depth"))
+            .and(query_param("symbol", "ETHUSDT"))
+            .and(query_param("limit", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "lastUpdateId": 42,
+                "bids": [["2999.00000000", "1.5"], ["2998.00000000", "2"]],
+                "asks": [["3001.00000000", "2"], ["3002.00000000", "4"]]
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+
+        let result = server
+            .get_order_book(Parameters(OrderBookParam {
+                symbol: "ethusdt".to_string(),
+                limit: Some(1000),
+                snap: false,
+                summary_only: true,
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let summary: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        assert_eq!(
+            summary,
+            json!({
+                "symbol": "ETHUSDT",
+                "best_bid": "2999",
+                "best_ask": "3001",
+                "mid_price": "3000",
+                "spread": "2",
+                "spread_bps": "6.67"
+            })
+        );
+        assert!(summary.get("bids").is_none() && summary.get("asks").is_none());
+    }
+
+    #[test]
+    fn test_account_info_leads_with_permission_summary() {
+        let account: AccountInfo = serde_json::from_value(json!({
+            "makerCommission": 10, "takerCommission": 10, "buyerCommission": 0,
+            "sellerCommission": 0, "canTrade": false, "canWithdraw": false,
+            "canDeposit": true, "updateTime": 0, "accountType": "SPOT",
+            "balances": [{"asset": "BTC", "free": "0.5", "locked": "0"}],
+            "permissions": ["SPOT"]
+        }))
+        .unwrap();
+
+        let text = account_info_text(&account).unwrap();
+        assert!(
+            text.starts_with(r#"{"permission_summary":{"account_type":"SPOT","can_trade":false"#),
+            "{}",
+            text
+        );
+        let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["permission_summary"]["can_deposit"], true);
+        assert_eq!(value["canTrade"], false);
+        assert_eq!(value["permissions"], json!(["SPOT"]));
+        assert_eq!(value["balances"][0]["asset"], "BTC");
+    }
+
+    #[tokio::test]
+    async fn test_recent_trades_carry_taker_side() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/trades"))
+            .and(query_param("symbol", "BTCUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"id": 1, "price": "65000.00", "qty": "0.1", "quoteQty": "6500.00",
+                 "time": 1700000000000i64, "isBuyerMaker": true, "isBestMatch": true},
+                {"id": 2, "price": "65001.00", "qty": "0.2", "quoteQty": "13000.20",
+                 "time": 1700000001000i64, "isBuyerMaker": false, "isBestMatch": true}
+            ])))
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+
+        let result = server
+            .get_recent_trades(Parameters(RecentTradesParam {
+                symbol: "BTCUSDT".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let trades: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        assert_eq!(trades[0]["side"], "sell");
+        assert_eq!(trades[1]["side"], "buy");
+        assert_eq!(trades[1]["price"], "65001.00");
+        assert_eq!(trades[1]["isBuyerMaker"], false);
+    }
+
+    #[cfg(feature = "orderbook_analytics")]
+    #[tokio::test]
+    async fn test_unwritable_analytics_storage_keeps_public_tools() {
+        use crate::error::tool_error_code;
+        use crate::server::types::MarketHealthCheckArgs;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock)
+            .await;
+        // A directory can't be created below a regular file
+        let blocker = tempfile::NamedTempFile::new().unwrap();
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            snapshot_storage: crate::server::open_snapshot_storage(
+                &blocker.path().join("snapshots"),
+            ),
+            ..BinanceServer::new()
+        };
+        assert!(server.snapshot_storage.is_none());
+        assert!(server.spawn_snapshot_flush().is_none());
+
+        let result = server
+            .get_recent_trades(Parameters(RecentTradesParam {
+                symbol: "BTCUSDT".to_string(),
+                limit: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.content[0].as_text().unwrap().text, "[]");
+
+        let err = server
+            .market_health_check(Parameters(MarketHealthCheckArgs {
+                symbol: "BTCUSDT".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(tool_error_code(&err), "ANALYTICS_STORAGE_UNAVAILABLE");
+        assert!(err.message.contains("Analytics storage unavailable"));
+        server.shutdown().await;
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn test_missing_futures_client_returns_configuration_error() {
+        use crate::error::tool_error_code;
+
+        let server = BinanceServer {
+            futures_client: None,
+            ..BinanceServer::new()
+        };
+
+        let err = server
+            .get_futures_funding_rate(Parameters(SymbolParam {
+                symbol: "BTCUSDT".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(tool_error_code(&err), "FUTURES_CLIENT_UNAVAILABLE");
+    }
+
+    #[tokio::test]
+    async fn test_explain_error_and_explained_failures() {
+        use crate::error::tool_error_code;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = BinanceServer::new();
+        let result = server
+            .explain_error(Parameters(ExplainErrorParam { code: -2011 }))
+            .await
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&result.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(body["name"], "CANCEL_REJECTED");
+        assert!(body["recovery"]
+            .as_str()
+            .unwrap()
+            .contains("Query the order"));
+
+        let err = server
+            .explain_error(Parameters(ExplainErrorParam { code: -7777 }))
+            .await
+            .unwrap_err();
+        assert_eq!(tool_error_code(&err), "UNKNOWN_ERROR_CODE");
+        assert!(err.message.contains("-2010"));
+
+        // A rejected request carries the explanation of Binance's code
+        let mock = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "code": -1121,
+                "msg": "Invalid symbol."
+            })))
+            .mount(&mock)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock.uri(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+        let err = server
+            .get_ticker(Parameters(SymbolParam {
+                symbol: "NOPEUSDT".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        let data = err.data.unwrap();
+        assert_eq!(data["binance_code"], -1121);
+        assert_eq!(data["explanation"]["name"], "BAD_SYMBOL");
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_place_order_time_in_force() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/order"))
+            .and(query_param("type", "LIMIT"))
+            .and(query_param("timeInForce", "GTC"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "BTCUSDT", "orderId": 42, "clientOrderId": "abc",
+                "status": "NEW", "type": "LIMIT", "side": "BUY"
+            })))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        let server = test_server(&mock);
+
+        // Omitted time_in_force defaults to GTC
+        let placed = server
+            .place_order(Parameters(buy_order(Some(true))))
+            .await
+            .unwrap();
+        assert_ne!(placed.is_error, Some(true));
+
+        // MARKET orders take no time in force; nothing reaches Binance
+        let err = server
+            .place_order(Parameters(PlaceOrderParam {
+                order_type: "MARKET".to_string(),
+                price: None,
+                time_in_force: Some("IOC".to_string()),
+                ..buy_order(Some(true))
+            }))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("not valid for MARKET orders"));
+
+        let err = server
+            .place_order(Parameters(PlaceOrderParam {
+                time_in_force: Some("DAY".to_string()),
+                ..buy_order(Some(true))
+            }))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("GTC, IOC, FOK"));
+    }
+
+    #[cfg(not(feature = "sse"))]
+    #[tokio::test]
+    async fn test_export_order_history_pages_full_range() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        const START: i64 = 1_700_000_000_000;
+        const HOUR: i64 = 60 * 60 * 1000;
+        let end = START + 48 * HOUR;
+        let order = |id: i64, time: i64| {
+            json!({
+                "symbol": "BTCUSDT", "orderId": id, "clientOrderId": format!("cid-{}", id),
+                "time": time, "price": "50000.00", "origQty": "0.001",
+                "status": "FILLED", "type": "LIMIT", "side": "BUY"
+            })
+        };
+
+        let mock = MockServer::start().await;
+        // First 24h window locates the first order
+        Mock::given(method("GET"))
+            .and(path("/api/v3/allOrders"))
+            .and(query_param("startTime", START.to_string()))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!([order(100, START + HOUR)])),
+            )
+            .expect(1)
+            .mount(&mock)
+            .await;
+        // A full page, then a last one that runs past the end of the range
+        let full_page: Vec<_> = (0..1000)
+            .map(|i| order(100 + i, START + HOUR + i * 60_000))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/api/v3/allOrders"))
+            .and(query_param("orderId", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!(full_page)))
+            .expect(1)
+            .mount(&mock)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/allOrders"))
+            .and(query_param("orderId", "1100"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([order(1100, end - HOUR), order(1101, end + HOUR)])),
+            )
+            .expect(1)
+            .mount(&mock)
+            .await;
+
+        let export_dir = tempfile::TempDir::new().unwrap();
+        let server = BinanceServer {
+            order_export_dir: export_dir.path().to_path_buf(),
+            ..test_server(&mock)
+        };
+
+        let result = server
+            .export_order_history(Parameters(ExportOrderHistoryParam {
+                symbol: "btcusdt".to_string(),
+                start_time: START,
+                end_time: end,
+                format: ExportFormat::Csv,
+            }))
+            .await
+            .unwrap();
+        let text = &result.content[0].as_text().unwrap().text;
+        let body: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(body["rows"], 1001);
+        assert_eq!(body["format"], "csv");
+
+        let path = export_dir
+            .path()
+            .join(format!("BTCUSDT_orders_{}_{}.csv", START, end));
+        assert_eq!(body["path"], path.display().to_string());
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 1002);
+        assert!(lines[1].starts_with("100,cid-100,BTCUSDT,BUY,LIMIT,FILLED"));
+        assert!(lines[1001].starts_with("1100,"));
     }
 }
@@ -10,9 +10,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TradingAnalysisArgs {
-    /// Trading pair symbol (e.g., BTCUSDT, ETHUSDT)
-    #[schemars(description = "Trading pair symbol (e.g., BTCUSDT, ETHUSDT)")]
-    pub symbol: String,
+    /// Trading pair symbol (e.g., BTCUSDT, ETHUSDT); defaults to `DEFAULT_SYMBOL`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(
+        description = "Trading pair symbol (e.g., BTCUSDT, ETHUSDT). Optional when the server sets DEFAULT_SYMBOL"
+    )]
+    pub symbol: Option<String>,
 
     /// Optional trading strategy preference
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,6 +59,28 @@ pub struct PortfolioRiskArgs {
     // Account info is derived from API credentials
 }
 
+/// Arguments for pre_trade_check prompt
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PreTradeCheckArgs {
+    /// Trading pair symbol (e.g., BTCUSDT)
+    #[schemars(description = "Trading pair symbol (e.g., BTCUSDT)")]
+    pub symbol: String,
+
+    /// Order side: BUY or SELL
+    #[schemars(description = "Order side: BUY or SELL")]
+    pub side: String,
+
+    /// Order quantity in the base asset
+    #[schemars(description = "Order quantity in the base asset (e.g., 0.001)")]
+    pub quantity: String,
+
+    /// Limit price; omit to check a MARKET order at the last price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(description = "Limit price (omit for a MARKET order)")]
+    pub price: Option<String>,
+}
+
 /// Arguments for advanced_market_analysis prompt
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
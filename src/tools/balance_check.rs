@@ -0,0 +1,130 @@
+//! Pre-flight balance check for order placement
+//!
+//! Without a check the only balance feedback is Binance's -2010 rejection.
+//! `place_order` compares the order against the (cached) account balances
+//! first and returns [`BinanceError::InsufficientBalance`] with the required
+//! and available amounts. Balances can change between the check and the
+//! submit, so callers can skip it.
+
+use crate::binance::types::{Balance, SymbolInfo};
+use crate::error::BinanceError;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Extra quote balance required on a BUY to cover fees (0.1%, the default spot taker fee)
+pub const BUY_FEE_BUFFER: Decimal = Decimal::from_parts(1, 0, 0, false, 3);
+
+/// Checks that the free balance covers the order
+///
+/// A BUY needs `price * quantity` of the quote asset plus [`BUY_FEE_BUFFER`];
+/// a SELL needs `quantity` of the base asset. `price` is the limit price, or
+/// the last price for a MARKET order. Assets missing from `balances` count
+/// as zero.
+pub fn check_balance(
+    info: &SymbolInfo,
+    side: &str,
+    quantity: Decimal,
+    price: Decimal,
+    balances: &[Balance],
+) -> Result<(), BinanceError> {
+    let (asset, required) = if side.eq_ignore_ascii_case("BUY") {
+        (
+            &info.quote_asset,
+            quantity * price * (Decimal::ONE + BUY_FEE_BUFFER),
+        )
+    } else {
+        (&info.base_asset, quantity)
+    };
+
+    let available = balances
+        .iter()
+        .find(|b| &b.asset == asset)
+        .map(|b| b.free.as_str())
+        .unwrap_or("0");
+
+    if Decimal::from_str(available).unwrap_or(Decimal::ZERO) >= required {
+        return Ok(());
+    }
+
+    Err(BinanceError::InsufficientBalance {
+        asset: asset.clone(),
+        required: format!("{:.8}", required),
+        available: available.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn btcusdt() -> SymbolInfo {
+        SymbolInfo {
+            symbol: "BTCUSDT".to_string(),
+            status: "TRADING".to_string(),
+            base_asset: "BTC".to_string(),
+            quote_asset: "USDT".to_string(),
+            order_types: Vec::new(),
+            filters: vec![],
+        }
+    }
+
+    fn balances() -> Vec<Balance> {
+        vec![
+            Balance {
+                asset: "BTC".to_string(),
+                free: "0.00500000".to_string(),
+                locked: "1.00000000".to_string(),
+            },
+            Balance {
+                asset: "USDT".to_string(),
+                free: "100.00000000".to_string(),
+                locked: "0.00000000".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_buy_checks_quote_with_fee_buffer() {
+        assert!(check_balance(&btcusdt(), "BUY", dec!(0.001), dec!(50000.0), &balances()).is_ok());
+
+        // 100 USDT of notional plus the fee buffer exceeds 100 free
+        let err =
+            check_balance(&btcusdt(), "BUY", dec!(0.002), dec!(50000.0), &balances()).unwrap_err();
+        match err {
+            BinanceError::InsufficientBalance {
+                asset,
+                required,
+                available,
+            } => {
+                assert_eq!(asset, "USDT");
+                assert_eq!(required, "100.10000000");
+                assert_eq!(available, "100.00000000");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sell_checks_free_base_only() {
+        assert!(check_balance(&btcusdt(), "SELL", dec!(0.005), dec!(50000.0), &balances()).is_ok());
+
+        // Locked BTC doesn't count
+        let err =
+            check_balance(&btcusdt(), "sell", dec!(0.01), dec!(50000.0), &balances()).unwrap_err();
+        assert!(matches!(
+            err,
+            BinanceError::InsufficientBalance { ref asset, ref available, .. }
+                if asset == "BTC" && available == "0.00500000"
+        ));
+    }
+
+    #[test]
+    fn test_missing_asset_is_zero() {
+        let err = check_balance(&btcusdt(), "BUY", dec!(0.001), dec!(50000.0), &[]).unwrap_err();
+        assert!(matches!(
+            err,
+            BinanceError::InsufficientBalance { ref available, .. } if available == "0"
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+//! Batch cancellation by client order ID for `cancel_orders`
+//!
+//! Agents that track orders by their own client IDs can cancel several orders
+//! of one symbol at once without looking up exchange order IDs. Each ID is
+//! cancelled independently (`origClientOrderId`), so one unknown or already
+//! filled order becomes an entry in `failed` instead of aborting the batch.
+//! Cancels go out through [`fetch_many`], `MULTI_FETCH_CONCURRENCY` at a time.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::future::Future;
+
+use crate::binance::fetch_many::fetch_many;
+use crate::binance::types::Order;
+use crate::error::{map_binance_err, tool_error_code, McpError};
+
+/// Maximum client order IDs accepted per `cancel_orders` call
+pub const MAX_CANCEL_BATCH: usize = 50;
+
+/// An order cancelled by the batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanceledOrder {
+    /// Client order ID as requested
+    pub client_order_id: String,
+    /// Exchange order ID
+    pub order_id: i64,
+    /// Order status after the cancel (normally CANCELED)
+    pub status: String,
+}
+
+/// A client order ID the batch could not cancel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedCancel {
+    /// Client order ID as requested
+    pub id: String,
+    /// Machine-readable code (e.g., INVALID_PARAMS for unknown orders, RATE_LIMITED)
+    pub error_code: String,
+    /// Error message from Binance
+    pub error: String,
+}
+
+/// Response of `cancel_orders`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchCancelSummary {
+    /// Trading pair
+    pub symbol: String,
+    /// Orders cancelled, in request order
+    pub canceled: Vec<CanceledOrder>,
+    /// IDs that failed, in request order
+    pub failed: Vec<FailedCancel>,
+}
+
+/// Trims the requested IDs, dropping blanks and repeats while keeping order
+pub fn dedupe_client_order_ids(ids: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    ids.iter()
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty() && seen.insert(*id))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Cancels every client order ID with `cancel`, at most `concurrency` at a time
+///
+/// Cancels run on the calling task, so audit scopes see their request IDs.
+pub async fn cancel_each<F, Fut>(
+    symbol: &str,
+    ids: Vec<String>,
+    concurrency: usize,
+    cancel: F,
+) -> BatchCancelSummary
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Order, McpError>>,
+{
+    let results = fetch_many(ids, concurrency, cancel).await;
+
+    let mut summary = BatchCancelSummary {
+        symbol: symbol.to_string(),
+        canceled: Vec::new(),
+        failed: Vec::new(),
+    };
+    for (id, result) in results {
+        match result {
+            Ok(order) => summary.canceled.push(CanceledOrder {
+                client_order_id: id,
+                order_id: order.order_id,
+                status: order.status,
+            }),
+            Err(err) => {
+                let error = err.to_string();
+                summary.failed.push(FailedCancel {
+                    id,
+                    error_code: tool_error_code(&map_binance_err(err)),
+                    error,
+                });
+            }
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn canceled(client_order_id: &str, order_id: i64) -> Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": order_id,
+            "clientOrderId": client_order_id,
+            "status": "CANCELED",
+            "type": "LIMIT",
+            "side": "BUY"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dedupe_client_order_ids() {
+        let ids = ["a", " b ", "", "a", "c", "b"].map(String::from);
+        assert_eq!(dedupe_client_order_ids(&ids), vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_partial_failure_does_not_abort_batch() {
+        let ids = vec!["ok-1".to_string(), "gone".to_string(), "ok-2".to_string()];
+
+        let summary = cancel_each("BTCUSDT", ids, 2, |id| async move {
+            match id.as_str() {
+                "ok-1" => Ok(canceled(&id, 1)),
+                "ok-2" => Ok(canceled(&id, 2)),
+                _ => Err(McpError::InvalidRequest(
+                    "Unknown order sent. (-2011)".to_string(),
+                )),
+            }
+        })
+        .await;
+
+        assert_eq!(summary.symbol, "BTCUSDT");
+        let ids: Vec<_> = summary.canceled.iter().map(|c| c.order_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].id, "gone");
+        assert_eq!(summary.failed[0].error_code, "INVALID_PARAMS");
+        assert!(summary.failed[0].error.contains("-2011"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let ids = (0..12).map(|i| format!("id-{i}")).collect();
+
+        let summary = cancel_each("BTCUSDT", ids, 3, |id| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(canceled(&id, 7))
+            }
+        })
+        .await;
+
+        assert_eq!(summary.canceled.len(), 12);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+}
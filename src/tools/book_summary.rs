@@ -0,0 +1,106 @@
+//! Top-of-book summary for `get_order_book` with `summary_only`
+//!
+//! A caller that only needs the best prices shouldn't have to page through
+//! depth arrays: the summary is derived from a depth-5 fetch and reduces it
+//! to the best bid/ask, their mid price and the spread.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::binance::types::OrderBook;
+use crate::tools::watchlist::bid_ask_spread_bps;
+
+/// Depth fetched for a summary
+pub const SUMMARY_DEPTH: u32 = 5;
+
+/// Response of `get_order_book` with `summary_only`
+///
+/// Price fields are `null` when the corresponding side of the book is empty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSummary {
+    /// Trading pair
+    pub symbol: String,
+    /// Highest bid price
+    pub best_bid: Option<String>,
+    /// Lowest ask price
+    pub best_ask: Option<String>,
+    /// `(best_bid + best_ask) / 2`
+    pub mid_price: Option<String>,
+    /// `best_ask - best_bid` in the quote asset
+    pub spread: Option<String>,
+    /// Spread in basis points of the mid price
+    pub spread_bps: Option<String>,
+}
+
+/// Best price of one side (levels arrive best first)
+fn best_price(levels: &[(String, String)]) -> Option<Decimal> {
+    levels
+        .first()
+        .and_then(|(price, _)| Decimal::from_str(price).ok())
+        .filter(|price| *price > Decimal::ZERO)
+}
+
+/// Summarizes the top of `book`
+pub fn summarize_book(symbol: &str, book: &OrderBook) -> BookSummary {
+    let bid = best_price(&book.bids);
+    let ask = best_price(&book.asks);
+    let both = bid.zip(ask);
+
+    BookSummary {
+        symbol: symbol.to_string(),
+        best_bid: bid.map(|p| p.normalize().to_string()),
+        best_ask: ask.map(|p| p.normalize().to_string()),
+        mid_price: both.map(|(bid, ask)| ((bid + ask) / Decimal::TWO).normalize().to_string()),
+        spread: both.map(|(bid, ask)| (ask - bid).normalize().to_string()),
+        spread_bps: both
+            .and_then(|(bid, ask)| bid_ask_spread_bps(bid, ask))
+            .map(|bps| bps.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(bids: &[(&str, &str)], asks: &[(&str, &str)]) -> OrderBook {
+        let levels = |side: &[(&str, &str)]| {
+            side.iter()
+                .map(|(p, q)| (p.to_string(), q.to_string()))
+                .collect()
+        };
+        OrderBook {
+            last_update_id: 1,
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    #[test]
+    fn test_summarize_book() {
+        let summary = summarize_book(
+            "ETHUSDT",
+            &book(
+                &[("2999.00000000", "1.5"), ("2998.50000000", "3")],
+                &[("3001.00000000", "2"), ("3002.00000000", "4")],
+            ),
+        );
+
+        assert_eq!(summary.best_bid.as_deref(), Some("2999"));
+        assert_eq!(summary.best_ask.as_deref(), Some("3001"));
+        assert_eq!(summary.mid_price.as_deref(), Some("3000"));
+        assert_eq!(summary.spread.as_deref(), Some("2"));
+        assert_eq!(summary.spread_bps.as_deref(), Some("6.67"));
+    }
+
+    #[test]
+    fn test_one_sided_book() {
+        let summary = summarize_book("NEWUSDT", &book(&[("0.5", "100")], &[]));
+
+        assert_eq!(summary.best_bid.as_deref(), Some("0.5"));
+        assert_eq!(summary.best_ask, None);
+        assert_eq!(summary.mid_price, None);
+        assert_eq!(summary.spread, None);
+        assert_eq!(summary.spread_bps, None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Deployment capabilities for `get_capabilities`
+//!
+//! Optional tools are compiled behind cargo features and answer with a
+//! "feature not enabled" stub otherwise. Reporting the compiled features,
+//! whether credentials are available and whether mainnet orders are allowed
+//! lets an agent plan around the deployment instead of probing tool by tool.
+
+use serde::{Deserialize, Serialize};
+
+/// Cargo features compiled into this build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledFeatures {
+    /// Local order book tools (`get_orderbook_metrics`, `get_orderbook_depth`, ...)
+    pub orderbook: bool,
+    /// Volume profile, order flow and anomaly tools
+    pub orderbook_analytics: bool,
+    /// Remote MCP over Streamable HTTP with per-session credentials
+    pub sse: bool,
+    /// REST API for non-MCP clients
+    pub http_api: bool,
+    /// WebSocket streams and user data stream tools
+    pub websocket: bool,
+    /// USD-M futures market data tools
+    pub futures: bool,
+}
+
+impl CompiledFeatures {
+    /// Features of the running binary
+    pub fn current() -> Self {
+        Self {
+            orderbook: cfg!(feature = "orderbook"),
+            orderbook_analytics: cfg!(feature = "orderbook_analytics"),
+            sse: cfg!(feature = "sse"),
+            http_api: cfg!(feature = "http-api"),
+            websocket: cfg!(feature = "websocket"),
+            futures: cfg!(feature = "futures"),
+        }
+    }
+}
+
+/// Response of `get_capabilities`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Compiled cargo features
+    pub features: CompiledFeatures,
+    /// Whether API credentials are available to the caller (authenticated tools will work)
+    pub authenticated: bool,
+    /// Whether order tools may execute against mainnet (`MAINNET_TRADING_ENABLED`)
+    pub mainnet_trading_enabled: bool,
+    /// Symbol used when an optional symbol is omitted (`DEFAULT_SYMBOL`)
+    pub default_symbol: Option<String>,
+    /// Server version
+    pub version: String,
+}
+
+impl Capabilities {
+    /// Capabilities of this build for a caller with or without credentials
+    pub fn new(
+        authenticated: bool,
+        mainnet_trading_enabled: bool,
+        default_symbol: Option<String>,
+    ) -> Self {
+        Self {
+            features: CompiledFeatures::current(),
+            authenticated,
+            mainnet_trading_enabled,
+            default_symbol,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features_match_build() {
+        let features = CompiledFeatures::current();
+
+        assert_eq!(features.orderbook, cfg!(feature = "orderbook"));
+        assert_eq!(features.sse, cfg!(feature = "sse"));
+        assert_eq!(features.http_api, cfg!(feature = "http-api"));
+        assert_eq!(features.futures, cfg!(feature = "futures"));
+    }
+
+    #[test]
+    fn test_capabilities_shape() {
+        let value =
+            serde_json::to_value(Capabilities::new(true, false, Some("BTCUSDT".to_string())))
+                .unwrap();
+
+        assert_eq!(value["authenticated"], true);
+        assert_eq!(value["mainnet_trading_enabled"], false);
+        assert_eq!(value["default_symbol"], "BTCUSDT");
+        assert_eq!(value["version"], env!("CARGO_PKG_VERSION"));
+        let features = value["features"].as_object().unwrap();
+        for key in [
+            "orderbook",
+            "orderbook_analytics",
+            "sse",
+            "http_api",
+            "websocket",
+            "futures",
+        ] {
+            assert!(features[key].is_boolean(), "missing feature flag {key}");
+        }
+    }
+}
@@ -6,7 +6,9 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
 
+use crate::binance::types::{SymbolInfo, Ticker24hr};
 use crate::binance::BinanceClient;
 use crate::error::McpError;
 
@@ -39,88 +41,251 @@ pub struct FetchResult {
     pub metadata: Option<serde_json::Value>,
 }
 
-/// Common trading symbols for search
-const POPULAR_SYMBOLS: &[&str] = &[
-    "BTCUSDT",
-    "ETHUSDT",
-    "BNBUSDT",
-    "ADAUSDT",
-    "SOLUSDT",
-    "XRPUSDT",
-    "DOGEUSDT",
-    "DOTUSDT",
-    "MATICUSDT",
-    "LINKUSDT",
-    "LTCUSDT",
-    "AVAXUSDT",
-    "UNIUSDT",
-    "ATOMUSDT",
-    "XLMUSDT",
+/// Pairs suggested when a query matches nothing
+const POPULAR_SYMBOLS: &[&str] = &["BTCUSDT", "ETHUSDT", "BNBUSDT", "SOLUSDT", "XRPUSDT"];
+
+/// Maximum number of search results
+const MAX_SEARCH_RESULTS: usize = 10;
+
+/// Maximum matches priced with a single 24hr ticker request
+const MAX_PRICED_CANDIDATES: usize = 100;
+
+/// Common asset names mapped to their base asset
+const ASSET_ALIASES: &[(&str, &str)] = &[
+    ("bitcoin", "BTC"),
+    ("ethereum", "ETH"),
+    ("ether", "ETH"),
+    ("binance", "BNB"),
+    ("solana", "SOL"),
+    ("ripple", "XRP"),
+    ("cardano", "ADA"),
+    ("dogecoin", "DOGE"),
+    ("doge", "DOGE"),
+    ("polkadot", "DOT"),
+    ("polygon", "POL"),
+    ("chainlink", "LINK"),
+    ("litecoin", "LTC"),
+    ("avalanche", "AVAX"),
+    ("uniswap", "UNI"),
+    ("cosmos", "ATOM"),
+    ("stellar", "XLM"),
+    ("tron", "TRX"),
+    ("toncoin", "TON"),
+    ("shiba", "SHIB"),
+    ("tether", "USDT"),
 ];
 
+/// Query words that describe the search rather than name an asset
+const STOP_WORDS: &[&str] = &[
+    "PAIR", "PAIRS", "MARKET", "MARKETS", "COIN", "COINS", "TOKEN", "TOKENS", "PRICE", "PRICES",
+    "ALL", "THE", "FOR", "AND", "VS",
+];
+
+/// Words that turn a quote asset in the query into a quote filter ("usdt pairs")
+const PAIR_WORDS: &[&str] = &["PAIR", "PAIRS", "MARKET", "MARKETS"];
+
+/// Parsed search query
+#[derive(Debug, Default, PartialEq)]
+struct SearchQuery {
+    /// Uppercased terms, each of which must match the symbol or its assets
+    terms: Vec<String>,
+    /// Restrict results to this quote asset
+    quote_asset: Option<String>,
+}
+
+/// Splits a free-text query into asset terms and an optional quote filter
+///
+/// Common names are mapped to base assets ("ethereum" → ETH, tolerating one typo),
+/// and a quote asset next to "pairs"/"markets" becomes a filter ("usdt pairs").
+fn parse_query(query: &str, quote_assets: &HashSet<&str>) -> SearchQuery {
+    let tokens: Vec<String> = query
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_uppercase())
+        .collect();
+    let lists_pairs = tokens.iter().any(|t| PAIR_WORDS.contains(&t.as_str()));
+
+    let mut parsed = SearchQuery::default();
+    for token in tokens {
+        if STOP_WORDS.contains(&token.as_str()) {
+            continue;
+        }
+        let term = resolve_alias(&token).unwrap_or(token);
+        if lists_pairs && parsed.quote_asset.is_none() && quote_assets.contains(term.as_str()) {
+            parsed.quote_asset = Some(term);
+        } else {
+            parsed.terms.push(term);
+        }
+    }
+    parsed
+}
+
+/// Maps a common asset name to its ticker, allowing one typo in longer names
+fn resolve_alias(token: &str) -> Option<String> {
+    let lower = token.to_lowercase();
+    ASSET_ALIASES
+        .iter()
+        .find(|(name, _)| *name == lower)
+        .or_else(|| {
+            ASSET_ALIASES
+                .iter()
+                .find(|(name, _)| name.len() >= 5 && edit_distance(name, &lower) <= 1)
+        })
+        .map(|(_, asset)| asset.to_string())
+}
+
+/// Scores how well a single term matches a symbol (0 = no match)
+fn score_term(info: &SymbolInfo, term: &str) -> u32 {
+    if info.symbol == term {
+        100
+    } else if info.base_asset == term {
+        80
+    } else if info.quote_asset == term {
+        40
+    } else if info.symbol.starts_with(term) {
+        30
+    } else if term.len() >= 2 && info.base_asset.starts_with(term) {
+        25
+    } else if term.len() >= 3 && edit_distance(&info.base_asset, term) <= 1 {
+        20
+    } else if term.len() >= 3 && info.symbol.contains(term) {
+        10
+    } else {
+        0
+    }
+}
+
+/// Matches a query against the tradable symbol universe
+///
+/// Every term must match; results are ordered by match score, then symbol.
+fn match_symbols<'a>(universe: &'a [SymbolInfo], query: &str) -> Vec<(&'a SymbolInfo, u32)> {
+    let quote_assets: HashSet<&str> = universe.iter().map(|s| s.quote_asset.as_str()).collect();
+    let parsed = parse_query(query, &quote_assets);
+    if parsed.terms.is_empty() && parsed.quote_asset.is_none() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(&SymbolInfo, u32)> = universe
+        .iter()
+        .filter(|info| info.is_trading())
+        .filter(|info| {
+            parsed
+                .quote_asset
+                .as_ref()
+                .map_or(true, |quote| &info.quote_asset == quote)
+        })
+        .filter_map(|info| {
+            let mut total = 1;
+            for term in &parsed.terms {
+                match score_term(info, term) {
+                    0 => return None,
+                    score => total += score,
+                }
+            }
+            Some((info, total))
+        })
+        .collect();
+
+    matches.sort_by(|(a, a_score), (b, b_score)| {
+        b_score.cmp(a_score).then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    matches
+}
+
+/// Levenshtein distance between two ASCII strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            row[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
 /// Search for trading symbols by keyword
 ///
-/// Returns top 10 matching symbols with current prices.
-/// Searches against common trading pairs.
+/// Matches the query against every tradable pair from `exchangeInfo` (cached,
+/// refreshed every few minutes) by symbol, base and quote asset, then ranks
+/// matches by score and 24h quote volume. Returns the top 10 with current prices.
+///
+/// Understands quote filters ("usdt pairs") and common asset names
+/// ("ethereum" → ETH). Falls back to a few popular pairs when nothing matches.
 pub async fn search_symbols(
     client: &BinanceClient,
     query: &str,
 ) -> Result<Vec<SearchResult>, McpError> {
-    let query_upper = query.to_uppercase();
-    let mut results = Vec::new();
+    let exchange_info = client.get_exchange_info().await?;
 
-    // Filter popular symbols by query match
-    let matched_symbols: Vec<&str> = POPULAR_SYMBOLS
-        .iter()
-        .filter(|symbol| symbol.contains(&query_upper))
-        .take(10)
-        .copied()
+    let mut candidates: Vec<(&SymbolInfo, u32)> = match_symbols(&exchange_info.symbols, query)
+        .into_iter()
+        .take(MAX_PRICED_CANDIDATES)
         .collect();
 
-    // Get current prices for matched symbols
-    for symbol in matched_symbols {
-        // Get ticker price
-        let ticker = match client.get_ticker_price(symbol).await {
-            Ok(t) => t,
-            Err(_) => continue, // Skip if price unavailable
-        };
-
-        // Parse symbol into base/quote (e.g., BTCUSDT -> BTC/USDT)
-        let (base, quote) = parse_symbol(symbol);
-        let title = format!("{}/{}", base, quote);
-        let text = format!("Current price: {} {}", ticker.price, quote);
-        let url = format!("https://www.binance.com/en/trade/{}_{}", base, quote);
-
-        results.push(SearchResult {
-            id: symbol.to_string(),
-            title,
-            text,
-            url,
-        });
+    if candidates.is_empty() {
+        candidates = exchange_info
+            .symbols
+            .iter()
+            .filter(|info| info.is_trading() && POPULAR_SYMBOLS.contains(&info.symbol.as_str()))
+            .map(|info| (info, 0))
+            .collect();
     }
-
-    // If no results, return top 5 popular pairs
-    if results.is_empty() {
-        for symbol in POPULAR_SYMBOLS.iter().take(5) {
-            let ticker = match client.get_ticker_price(symbol).await {
-                Ok(t) => t,
-                Err(_) => continue,
-            };
-
-            let (base, quote) = parse_symbol(symbol);
-            let title = format!("{}/{}", base, quote);
-            let text = format!("Current price: {} {}", ticker.price, quote);
-            let url = format!("https://www.binance.com/en/trade/{}_{}", base, quote);
-
-            results.push(SearchResult {
-                id: symbol.to_string(),
-                title,
-                text,
-                url,
-            });
-        }
+    if candidates.is_empty() {
+        return Ok(Vec::new());
     }
 
+    // One request prices every candidate and provides the volume ranking
+    let symbols: Vec<String> = candidates
+        .iter()
+        .map(|(info, _)| info.symbol.clone())
+        .collect();
+    let tickers: HashMap<String, Ticker24hr> = client
+        .get_24hr_tickers(Some(&symbols))
+        .await?
+        .into_iter()
+        .map(|t| (t.symbol.clone(), t))
+        .collect();
+
+    let quote_volume = |symbol: &str| {
+        tickers
+            .get(symbol)
+            .and_then(|t| t.quote_volume.parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+    candidates.sort_by(|(a, a_score), (b, b_score)| {
+        b_score.cmp(a_score).then_with(|| {
+            quote_volume(&b.symbol)
+                .partial_cmp(&quote_volume(&a.symbol))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    });
+
+    let results = candidates
+        .into_iter()
+        .filter_map(|(info, _)| {
+            let ticker = tickers.get(&info.symbol)?;
+            let (base, quote) = (&info.base_asset, &info.quote_asset);
+            Some(SearchResult {
+                id: info.symbol.clone(),
+                title: format!("{}/{}", base, quote),
+                text: format!(
+                    "Current price: {} {} | 24h change: {}% | 24h volume: {} {}",
+                    ticker.last_price,
+                    quote,
+                    ticker.price_change_percent,
+                    ticker.quote_volume,
+                    quote
+                ),
+                url: format!("https://www.binance.com/en/trade/{}_{}", base, quote),
+            })
+        })
+        .take(MAX_SEARCH_RESULTS)
+        .collect();
+
     Ok(results)
 }
 
@@ -290,4 +455,67 @@ mod tests {
             ("ADA".to_string(), "ETH".to_string())
         );
     }
+
+    fn symbol(symbol: &str, base: &str, quote: &str, status: &str) -> SymbolInfo {
+        SymbolInfo {
+            symbol: symbol.to_string(),
+            status: status.to_string(),
+            base_asset: base.to_string(),
+            quote_asset: quote.to_string(),
+            order_types: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    fn universe() -> Vec<SymbolInfo> {
+        vec![
+            symbol("BTCUSDT", "BTC", "USDT", "TRADING"),
+            symbol("ETHUSDT", "ETH", "USDT", "TRADING"),
+            symbol("ETHBTC", "ETH", "BTC", "TRADING"),
+            symbol("ETHFIUSDT", "ETHFI", "USDT", "TRADING"),
+            symbol("SOLBTC", "SOL", "BTC", "TRADING"),
+            symbol("LUNAUSDT", "LUNA", "USDT", "BREAK"),
+        ]
+    }
+
+    fn matched(query: &str) -> Vec<String> {
+        let universe = universe();
+        match_symbols(&universe, query)
+            .into_iter()
+            .map(|(info, _)| info.symbol.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_quote_filter_query() {
+        assert_eq!(
+            matched("usdt pairs"),
+            vec!["BTCUSDT", "ETHFIUSDT", "ETHUSDT"]
+        );
+        assert_eq!(matched("BTC markets"), vec!["ETHBTC", "SOLBTC"]);
+    }
+
+    #[test]
+    fn test_common_names_map_to_base_asset() {
+        assert_eq!(matched("ethereum")[..2], ["ETHBTC", "ETHUSDT"]);
+        // One typo in the name still resolves
+        assert_eq!(matched("etherium")[..2], ["ETHBTC", "ETHUSDT"]);
+        // Exact base asset outranks prefix matches like ETHFI
+        assert_eq!(matched("ethereum usdt"), vec!["ETHUSDT", "ETHFIUSDT"]);
+    }
+
+    #[test]
+    fn test_exact_symbol_ranks_first_and_halted_pairs_excluded() {
+        assert_eq!(matched("ETHUSDT")[0], "ETHUSDT");
+        assert!(matched("luna").is_empty());
+        assert!(matched("").is_empty());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("ETH", "ETH"), 0);
+        assert_eq!(edit_distance("ETH", "ETC"), 1);
+        assert_eq!(edit_distance("DOGE", "DOG"), 1);
+        assert_eq!(edit_distance("SOL", "BTC"), 3);
+    }
 }
@@ -0,0 +1,118 @@
+//! Duplicate order protection for `place_order`
+//!
+//! Agents sometimes retry `place_order` after a timeout even though the first
+//! call went through. When a `client_order_id` is supplied, the tool first
+//! asks Binance for an order with that ID and returns it instead of placing a
+//! second one. [`RecentClientOrderIds`] covers the window before the exchange
+//! knows about the first order, rejecting rapid double-submits outright.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::McpError;
+
+/// How long a submitted client order ID is remembered
+pub const CLIENT_ORDER_ID_TTL: Duration = Duration::from_secs(60);
+
+/// Maximum client order ID length accepted by Binance
+pub const MAX_CLIENT_ORDER_ID_LEN: usize = 36;
+
+/// Validates a client order ID against Binance's `^[.A-Z:/a-z0-9_-]{1,36}$`
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` describing the accepted format
+pub fn validate_client_order_id(id: &str) -> Result<(), McpError> {
+    let valid_chars = id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | ':' | '/' | '_' | '-'));
+
+    if id.is_empty() || id.len() > MAX_CLIENT_ORDER_ID_LEN || !valid_chars {
+        return Err(McpError::InvalidRequest(format!(
+            "Invalid client_order_id '{}': use 1-{} characters from A-Z, a-z, 0-9 and . : / _ -",
+            id, MAX_CLIENT_ORDER_ID_LEN
+        )));
+    }
+    Ok(())
+}
+
+/// Recently submitted client order IDs (shared across clones)
+///
+/// Keys are `SYMBOL:client_order_id`. Entries expire after the TTL, by which
+/// time a placed order is visible to `origClientOrderId` lookups.
+#[derive(Debug, Clone)]
+pub struct RecentClientOrderIds {
+    ttl: Duration,
+    seen: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl RecentClientOrderIds {
+    /// Creates an empty set with the given TTL
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            seen: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Records a submission, returning false if the key was already seen within the TTL
+    pub fn try_claim(&self, key: &str) -> bool {
+        let mut seen = self.seen.lock().expect("Client order ID lock poisoned");
+        let ttl = self.ttl;
+        seen.retain(|_, at| at.elapsed() < ttl);
+
+        if seen.contains_key(key) {
+            return false;
+        }
+        seen.insert(key.to_string(), Instant::now());
+        true
+    }
+
+    /// Forgets a key so the order can be resubmitted (used when placement was rejected)
+    pub fn release(&self, key: &str) {
+        self.seen
+            .lock()
+            .expect("Client order ID lock poisoned")
+            .remove(key);
+    }
+}
+
+impl Default for RecentClientOrderIds {
+    fn default() -> Self {
+        Self::new(CLIENT_ORDER_ID_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_client_order_id() {
+        assert!(validate_client_order_id("agent-42_retry.1:a/b").is_ok());
+        assert!(validate_client_order_id("").is_err());
+        assert!(validate_client_order_id("has space").is_err());
+        assert!(validate_client_order_id(&"x".repeat(37)).is_err());
+    }
+
+    #[test]
+    fn test_double_submit_is_caught() {
+        let ids = RecentClientOrderIds::default();
+
+        assert!(ids.try_claim("BTCUSDT:abc"));
+        assert!(!ids.try_claim("BTCUSDT:abc"));
+        // Same ID on another symbol is a different order
+        assert!(ids.try_claim("ETHUSDT:abc"));
+
+        ids.release("BTCUSDT:abc");
+        assert!(ids.try_claim("BTCUSDT:abc"));
+    }
+
+    #[test]
+    fn test_claims_expire() {
+        let ids = RecentClientOrderIds::new(Duration::ZERO);
+
+        assert!(ids.try_claim("BTCUSDT:abc"));
+        assert!(ids.try_claim("BTCUSDT:abc"));
+    }
+}
@@ -2,6 +2,26 @@
 //!
 //! This module contains implementations of MCP tools for Binance API interaction.
 
+pub mod balance_check;
+pub mod batch_cancel;
+pub mod book_summary;
+pub mod capabilities;
 pub mod chatgpt;
 pub mod credentials;
 pub mod get_server_time;
+pub mod idempotency;
+pub mod momentum;
+pub mod open_orders;
+pub mod order_export;
+pub mod order_wait;
+pub mod ping;
+pub mod pre_trade;
+pub mod quote_quantity;
+pub mod resample;
+pub mod symbol_rules;
+pub mod tickers;
+pub mod time_in_force;
+pub mod trade_flow;
+pub mod trading_guard;
+pub mod trailing_stop;
+pub mod watchlist;
@@ -0,0 +1,162 @@
+//! Multi-timeframe momentum from rolling-window tickers
+//!
+//! Combines several `/api/v3/ticker?windowSize=...` responses and the 24hr
+//! ticker into one `{symbol, changes, last_price}` summary, so "is this coin
+//! pumping" questions need a single tool call instead of one per timeframe.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::binance::types::RollingWindowTicker;
+use crate::error::McpError;
+
+/// Windows used when the caller doesn't pass any
+pub const DEFAULT_MOMENTUM_WINDOWS: &[&str] = &["1h", "4h", "1d"];
+
+/// Maximum windows per call (one REST request each)
+pub const MAX_MOMENTUM_WINDOWS: usize = 8;
+
+/// Price change per window for one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Momentum {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Price change percent keyed by window (e.g., "1h"); `None` if unparseable
+    pub changes: BTreeMap<String, Option<f64>>,
+    /// Last price from the 24hr ticker
+    pub last_price: String,
+}
+
+impl Momentum {
+    /// Builds the summary from `(window, ticker)` pairs and the last price
+    pub fn new(
+        symbol: String,
+        windows: &[(String, RollingWindowTicker)],
+        last_price: String,
+    ) -> Self {
+        let changes = windows
+            .iter()
+            .map(|(window, ticker)| (window.clone(), ticker.price_change_percent.parse().ok()))
+            .collect();
+        Self {
+            symbol,
+            changes,
+            last_price,
+        }
+    }
+}
+
+/// Normalizes one Binance `windowSize`
+///
+/// Accepted: `1m`-`59m`, `1h`-`23h`, `1d`-`7d` (case-insensitive).
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` for any other value
+pub fn validate_window_size(window: &str) -> Result<String, McpError> {
+    let window = window.trim().to_lowercase();
+    let max = match window.chars().last() {
+        Some('m') => 59,
+        Some('h') => 23,
+        Some('d') => 7,
+        _ => 0,
+    };
+    let amount = window[..window.len().saturating_sub(1)].parse::<u32>().ok();
+
+    match amount {
+        Some(n) if (1..=max).contains(&n) => Ok(window),
+        _ => Err(McpError::InvalidRequest(format!(
+            "Invalid window '{}'. Use 1m-59m, 1h-23h or 1d-7d",
+            window
+        ))),
+    }
+}
+
+/// Validates the requested windows, dropping duplicates
+///
+/// `None` or an empty list selects [`DEFAULT_MOMENTUM_WINDOWS`].
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` for an invalid window or more than
+/// [`MAX_MOMENTUM_WINDOWS`] distinct windows
+pub fn resolve_windows(windows: Option<&[String]>) -> Result<Vec<String>, McpError> {
+    let requested: Vec<String> = match windows {
+        Some(windows) if !windows.is_empty() => windows.to_vec(),
+        _ => DEFAULT_MOMENTUM_WINDOWS
+            .iter()
+            .map(|w| w.to_string())
+            .collect(),
+    };
+
+    let mut resolved: Vec<String> = Vec::new();
+    for window in &requested {
+        let window = validate_window_size(window)?;
+        if !resolved.contains(&window) {
+            resolved.push(window);
+        }
+    }
+
+    if resolved.len() > MAX_MOMENTUM_WINDOWS {
+        return Err(McpError::InvalidRequest(format!(
+            "Too many windows ({}). At most {} per call",
+            resolved.len(),
+            MAX_MOMENTUM_WINDOWS
+        )));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_windows() {
+        assert_eq!(resolve_windows(None).unwrap(), vec!["1h", "4h", "1d"]);
+        assert_eq!(
+            resolve_windows(Some(&[
+                "15M".to_string(),
+                "15m".to_string(),
+                "7d".to_string()
+            ]))
+            .unwrap(),
+            vec!["15m", "7d"]
+        );
+
+        for bad in ["0h", "24h", "60m", "8d", "1w", "h", ""] {
+            assert!(
+                resolve_windows(Some(&[bad.to_string()])).is_err(),
+                "{} should be rejected",
+                bad
+            );
+        }
+
+        let too_many: Vec<String> = (1..=9).map(|n| format!("{}h", n)).collect();
+        assert!(resolve_windows(Some(&too_many)).is_err());
+    }
+
+    #[test]
+    fn test_momentum_collects_changes() {
+        let ticker = |pct: &str| -> RollingWindowTicker {
+            serde_json::from_value(serde_json::json!({
+                "symbol": "BTCUSDT", "priceChange": "0", "priceChangePercent": pct,
+                "weightedAvgPrice": "0", "openPrice": "0", "highPrice": "0",
+                "lowPrice": "0", "lastPrice": "0", "volume": "0", "quoteVolume": "0",
+                "openTime": 0, "closeTime": 0, "firstId": 0, "lastId": 0, "count": 0
+            }))
+            .unwrap()
+        };
+
+        let momentum = Momentum::new(
+            "BTCUSDT".to_string(),
+            &[
+                ("1h".to_string(), ticker("1.250")),
+                ("4h".to_string(), ticker("-0.400")),
+            ],
+            "67650.10".to_string(),
+        );
+
+        assert_eq!(momentum.changes["1h"], Some(1.25));
+        assert_eq!(momentum.changes["4h"], Some(-0.4));
+        assert_eq!(momentum.last_price, "67650.10");
+    }
+}
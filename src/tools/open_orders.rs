@@ -0,0 +1,172 @@
+//! Server-side filtering and ordering for `get_open_orders`
+//!
+//! Binance returns open orders in no particular order, which makes large
+//! order sets hard to read and wasteful to send back in full. Filters and
+//! sorting are applied to the deserialized orders; the API call is unchanged.
+
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::binance::types::Order;
+
+/// Sort order for open orders (all ascending; ties keep Binance's order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenOrderSort {
+    /// Lowest limit price first
+    Price,
+    /// Oldest order first
+    Time,
+    /// Alphabetical by symbol, oldest first within a symbol
+    Symbol,
+}
+
+/// Filters and ordering requested for `get_open_orders`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenOrdersView {
+    /// Sort order (`None` keeps Binance's order)
+    pub sort_by: Option<OpenOrderSort>,
+    /// Keep only this side (BUY, SELL; case-insensitive)
+    pub side: Option<String>,
+    /// Keep only this status (NEW, PARTIALLY_FILLED; case-insensitive)
+    pub status: Option<String>,
+}
+
+/// Open orders after filtering, with counts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectedOrders {
+    /// Open orders before filtering
+    pub total: usize,
+    /// Orders returned
+    pub count: usize,
+    /// Matching orders, in the requested order
+    pub orders: Vec<Order>,
+}
+
+impl OpenOrdersView {
+    /// Whether any option was given (otherwise the plain order list is returned)
+    pub fn is_requested(&self) -> bool {
+        self.sort_by.is_some() || self.side.is_some() || self.status.is_some()
+    }
+
+    /// Applies the filters, then the sort order
+    pub fn apply(&self, orders: Vec<Order>) -> SelectedOrders {
+        let total = orders.len();
+        let matches = |wanted: &Option<String>, value: &str| {
+            wanted
+                .as_deref()
+                .map_or(true, |wanted| wanted.trim().eq_ignore_ascii_case(value))
+        };
+
+        let mut orders: Vec<Order> = orders
+            .into_iter()
+            .filter(|order| {
+                matches(&self.side, &order.side) && matches(&self.status, &order.status)
+            })
+            .collect();
+
+        match self.sort_by {
+            // Unparseable prices (never sent by Binance) sort first
+            Some(OpenOrderSort::Price) => {
+                orders.sort_by_key(|order| Decimal::from_str(&order.price).unwrap_or_default())
+            }
+            Some(OpenOrderSort::Time) => orders.sort_by_key(order_time),
+            Some(OpenOrderSort::Symbol) => orders.sort_by(|a, b| {
+                a.symbol
+                    .cmp(&b.symbol)
+                    .then(order_time(a).cmp(&order_time(b)))
+            }),
+            None => {}
+        }
+
+        SelectedOrders {
+            total,
+            count: orders.len(),
+            orders,
+        }
+    }
+}
+
+/// Creation time of an open order, falling back to the transaction time
+fn order_time(order: &Order) -> i64 {
+    order.time.unwrap_or(order.transact_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(symbol: &str, order_id: i64, side: &str, price: &str, time: i64) -> Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "orderId": order_id,
+            "clientOrderId": format!("c{order_id}"),
+            "price": price,
+            "status": if order_id % 2 == 0 { "PARTIALLY_FILLED" } else { "NEW" },
+            "type": "LIMIT",
+            "side": side,
+            "time": time
+        }))
+        .unwrap()
+    }
+
+    fn orders() -> Vec<Order> {
+        vec![
+            order("ETHUSDT", 1, "BUY", "3000.5", 300),
+            order("BTCUSDT", 2, "SELL", "70000", 100),
+            order("BTCUSDT", 3, "BUY", "65000", 200),
+            order("ETHUSDT", 4, "SELL", "900.25", 50),
+        ]
+    }
+
+    fn ids(selected: &SelectedOrders) -> Vec<i64> {
+        selected.orders.iter().map(|o| o.order_id).collect()
+    }
+
+    #[test]
+    fn test_sort_orders() {
+        let by = |sort_by| {
+            OpenOrdersView {
+                sort_by: Some(sort_by),
+                ..Default::default()
+            }
+            .apply(orders())
+        };
+
+        // Numeric, not lexicographic: 900.25 < 3000.5 < 65000 < 70000
+        assert_eq!(ids(&by(OpenOrderSort::Price)), vec![4, 1, 3, 2]);
+        assert_eq!(ids(&by(OpenOrderSort::Time)), vec![4, 2, 3, 1]);
+        assert_eq!(ids(&by(OpenOrderSort::Symbol)), vec![2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn test_filter_orders() {
+        let buys = OpenOrdersView {
+            side: Some("buy".to_string()),
+            sort_by: Some(OpenOrderSort::Price),
+            ..Default::default()
+        }
+        .apply(orders());
+        assert_eq!(ids(&buys), vec![1, 3]);
+        assert_eq!(buys.total, 4);
+        assert_eq!(buys.count, 2);
+
+        let partial_sells = OpenOrdersView {
+            side: Some("SELL".to_string()),
+            status: Some("partially_filled".to_string()),
+            ..Default::default()
+        }
+        .apply(orders());
+        // Binance's order is kept without sort_by
+        assert_eq!(ids(&partial_sells), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_no_options_is_not_requested() {
+        assert!(!OpenOrdersView::default().is_requested());
+        let unchanged = OpenOrdersView::default().apply(orders());
+        assert_eq!(ids(&unchanged), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,371 @@
+//! Order history export for `export_order_history`
+//!
+//! `allOrders` returns at most 1000 orders per call and a time window of at
+//! most 24 hours, so a longer range can't be fetched in one request.
+//! [`collect_order_history`] walks 24h windows until it finds the first order
+//! in the range, then pages on `orderId` until it passes the end. The orders
+//! are written as CSV or JSON to the export directory (`ORDER_EXPORT_DIR`,
+//! default `./exports`) under a name built from the sanitized symbol and range.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use crate::binance::limits::{HistoryQuery, MAX_HISTORY_LIMIT, MAX_HISTORY_WINDOW_MS};
+use crate::binance::types::Order;
+use crate::error::McpError;
+
+/// Export directory used when `ORDER_EXPORT_DIR` is unset
+pub const DEFAULT_ORDER_EXPORT_DIR: &str = "./exports";
+
+/// Columns of a CSV export, in order
+pub const CSV_COLUMNS: &[&str] = &[
+    "order_id",
+    "client_order_id",
+    "symbol",
+    "side",
+    "type",
+    "status",
+    "price",
+    "orig_qty",
+    "executed_qty",
+    "cummulative_quote_qty",
+    "time_in_force",
+    "time",
+];
+
+/// File format of an export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// One header row, then one row per order
+    #[default]
+    Csv,
+    /// Array of order objects as returned by Binance
+    Json,
+}
+
+impl ExportFormat {
+    /// File extension for the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Parses `ORDER_EXPORT_DIR`, falling back to [`DEFAULT_ORDER_EXPORT_DIR`] when unset or blank
+pub fn parse_export_dir(value: Option<&str>) -> PathBuf {
+    let dir = value.map(str::trim).filter(|v| !v.is_empty());
+    PathBuf::from(dir.unwrap_or(DEFAULT_ORDER_EXPORT_DIR))
+}
+
+/// Time an order was created (`time`, or `transactTime` when absent)
+fn order_time(order: &Order) -> i64 {
+    order.time.unwrap_or(order.transact_time)
+}
+
+/// Fetches every order created from `start_time` to `end_time` (ms, inclusive)
+///
+/// `fetch` makes one `allOrders` request. Time windows are searched oldest
+/// first, 24 hours at a time, for the first order in the range; from there
+/// pages of [`MAX_HISTORY_LIMIT`] are requested by `orderId` until a page
+/// reaches past `end_time` or isn't full. Returns the orders by `orderId`.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` if `end_time` is before `start_time`,
+/// or the first error from `fetch`
+pub async fn collect_order_history<F, Fut>(
+    start_time: i64,
+    end_time: i64,
+    mut fetch: F,
+) -> Result<Vec<Order>, McpError>
+where
+    F: FnMut(HistoryQuery) -> Fut,
+    Fut: Future<Output = Result<Vec<Order>, McpError>>,
+{
+    if end_time < start_time {
+        return Err(McpError::InvalidRequest(format!(
+            "end_time ({}) is before start_time ({})",
+            end_time, start_time
+        )));
+    }
+
+    // Find the first order: time windows are limited to 24 hours
+    let mut window_start = start_time;
+    let first_id = loop {
+        let window_end = end_time.min(window_start.saturating_add(MAX_HISTORY_WINDOW_MS));
+        let page = fetch(HistoryQuery {
+            limit: Some(MAX_HISTORY_LIMIT),
+            start_time: Some(window_start),
+            end_time: Some(window_end),
+            from_id: None,
+        })
+        .await?;
+        if let Some(id) = page.iter().map(|o| o.order_id).min() {
+            break id;
+        }
+        if window_end >= end_time {
+            return Ok(Vec::new());
+        }
+        window_start = window_end + 1;
+    };
+
+    // Order IDs grow with creation time, so page on them until past the range
+    let mut orders = Vec::new();
+    let mut from_id = Some(first_id);
+    while let Some(id) = from_id {
+        let query = HistoryQuery {
+            limit: Some(MAX_HISTORY_LIMIT),
+            from_id: Some(id),
+            ..HistoryQuery::default()
+        };
+        let page = fetch(query).await?;
+        from_id = query.next_from_id(page.len(), page.iter().map(|o| o.order_id).max());
+
+        for order in page {
+            let time = order_time(&order);
+            if time > end_time {
+                from_id = None;
+            } else if time >= start_time {
+                orders.push(order);
+            }
+        }
+    }
+
+    orders.sort_by_key(|o| o.order_id);
+    Ok(orders)
+}
+
+/// Replaces every character outside `A-Z a-z 0-9 . _ -` with `_`
+///
+/// Leading dots are dropped so the name can't be hidden or climb out of the
+/// export directory.
+pub fn sanitize_file_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let sanitized = sanitized.trim_start_matches('.');
+    if sanitized.is_empty() {
+        "export".to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// File name of an export: `{symbol}_orders_{start_time}_{end_time}.{ext}`
+pub fn export_file_name(
+    symbol: &str,
+    start_time: i64,
+    end_time: i64,
+    format: ExportFormat,
+) -> String {
+    format!(
+        "{}.{}",
+        sanitize_file_name(&format!("{}_orders_{}_{}", symbol, start_time, end_time)),
+        format.extension()
+    )
+}
+
+/// Export directory of one SSE session: `{root}/{session_id}` (sanitized)
+///
+/// Sessions may trade different accounts, so each gets its own directory and
+/// can't overwrite, or be handed the path of, another session's export.
+pub fn session_export_dir(root: &Path, session_id: &str) -> PathBuf {
+    root.join(sanitize_file_name(session_id))
+}
+
+/// Quotes a CSV field when it contains a comma, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders orders in `format`
+///
+/// # Errors
+/// Returns `McpError::ParseError` if JSON serialization fails
+pub fn render_orders(orders: &[Order], format: ExportFormat) -> Result<String, McpError> {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(orders)
+            .map_err(|e| McpError::ParseError(format!("Serialization error: {}", e))),
+        ExportFormat::Csv => {
+            let mut csv = CSV_COLUMNS.join(",");
+            csv.push('\n');
+            for order in orders {
+                let row = [
+                    order.order_id.to_string(),
+                    csv_field(&order.client_order_id),
+                    csv_field(&order.symbol),
+                    csv_field(&order.side),
+                    csv_field(&order.order_type),
+                    csv_field(&order.status),
+                    csv_field(&order.price),
+                    csv_field(&order.orig_qty),
+                    csv_field(&order.executed_qty),
+                    csv_field(&order.cummulative_quote_qty),
+                    csv_field(&order.time_in_force),
+                    order_time(order).to_string(),
+                ];
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+    }
+}
+
+/// Writes `contents` to `file_name` in `dir`, creating the directory if needed
+///
+/// # Errors
+/// Returns `McpError::InternalError` if the directory or file can't be written
+pub async fn write_export(
+    dir: &Path,
+    file_name: &str,
+    contents: &str,
+) -> Result<PathBuf, McpError> {
+    tokio::fs::create_dir_all(dir).await.map_err(|e| {
+        McpError::InternalError(format!(
+            "Failed to create export directory {}: {}",
+            dir.display(),
+            e
+        ))
+    })?;
+    let path = dir.join(file_name);
+    tokio::fs::write(&path, contents).await.map_err(|e| {
+        McpError::InternalError(format!("Failed to write {}: {}", path.display(), e))
+    })?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn order(order_id: i64, time: i64) -> Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": order_id,
+            "clientOrderId": format!("cid-{}", order_id),
+            "time": time,
+            "price": "50000.00",
+            "origQty": "0.001",
+            "status": "FILLED",
+            "type": "LIMIT",
+            "side": "BUY"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_collect_skips_empty_windows_and_stops_past_end() {
+        let day = MAX_HISTORY_WINDOW_MS;
+        let requests = Mutex::new(Vec::new());
+
+        let orders = collect_order_history(0, 3 * day, |query| {
+            requests.lock().unwrap().push(query);
+            async move {
+                Ok(match (query.start_time, query.from_id) {
+                    // First day is empty, the second holds order 7
+                    (Some(0), None) => Vec::new(),
+                    (Some(_), None) => vec![order(7, day + 5)],
+                    (None, Some(7)) => {
+                        vec![order(7, day + 5), order(8, 2 * day), order(9, 4 * day)]
+                    }
+                    other => panic!("unexpected query {:?}", other),
+                })
+            }
+        })
+        .await
+        .unwrap();
+
+        let ids: Vec<i64> = orders.iter().map(|o| o.order_id).collect();
+        assert_eq!(ids, vec![7, 8]);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_collect_rejects_inverted_range() {
+        let err = collect_order_history(10, 5, |_| async { Ok(Vec::new()) })
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("before start_time"));
+    }
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(
+            sanitize_file_name("BTCUSDT_orders_1_2"),
+            "BTCUSDT_orders_1_2"
+        );
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "_.._etc_passwd");
+        assert_eq!(sanitize_file_name("..."), "export");
+        assert_eq!(
+            export_file_name("btc/usdt", 1, 2, ExportFormat::Json),
+            "btc_usdt_orders_1_2.json"
+        );
+    }
+
+    #[test]
+    fn test_sessions_export_to_separate_paths() {
+        let root = Path::new("/exports");
+        let file_name = export_file_name("BTCUSDT", 1, 2, ExportFormat::Csv);
+
+        let alice = session_export_dir(root, "session-a").join(&file_name);
+        let bob = session_export_dir(root, "session-b").join(&file_name);
+        assert_ne!(alice, bob);
+        assert_eq!(
+            alice,
+            Path::new("/exports/session-a/BTCUSDT_orders_1_2.csv")
+        );
+
+        // A crafted session ID can't reach a sibling's directory
+        assert_eq!(
+            session_export_dir(root, "../session-a"),
+            Path::new("/exports/_session-a")
+        );
+    }
+
+    #[test]
+    fn test_render_csv_quotes_fields() {
+        let mut quoted = order(1, 1000);
+        quoted.client_order_id = "a,\"b\"".to_string();
+
+        let csv = render_orders(&[quoted], ExportFormat::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "1,\"a,\"\"b\"\"\",BTCUSDT,BUY,LIMIT,FILLED,50000.00,0.001,,,,1000"
+        );
+    }
+
+    #[test]
+    fn test_parse_export_dir() {
+        assert_eq!(
+            parse_export_dir(Some("/tmp/exports")),
+            PathBuf::from("/tmp/exports")
+        );
+        assert_eq!(
+            parse_export_dir(Some(" ")),
+            PathBuf::from(DEFAULT_ORDER_EXPORT_DIR)
+        );
+        assert_eq!(
+            parse_export_dir(None),
+            PathBuf::from(DEFAULT_ORDER_EXPORT_DIR)
+        );
+    }
+}
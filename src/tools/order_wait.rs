@@ -0,0 +1,224 @@
+//! Order-status polling for `wait_for_order`
+//!
+//! Gives agents "wait until filled or N seconds" semantics without the
+//! user-data stream: the order is queried until its status is terminal or the
+//! timeout elapses. The poll interval and total duration are clamped so one
+//! call can't burn through the request weight budget (`query_order` costs 4).
+
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::binance::types::Order;
+use crate::error::McpError;
+
+/// Shortest allowed poll interval
+pub const MIN_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Poll interval when none is given
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Timeout when none is given
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest allowed wait per call
+pub const MAX_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Statuses after which an order can no longer change
+pub const TERMINAL_STATUSES: [&str; 5] = [
+    "FILLED",
+    "CANCELED",
+    "REJECTED",
+    "EXPIRED",
+    "EXPIRED_IN_MATCH",
+];
+
+/// Whether an order in `status` will never change again
+pub fn is_terminal_status(status: &str) -> bool {
+    TERMINAL_STATUSES.contains(&status)
+}
+
+/// Clamped timing of one wait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitPlan {
+    /// Total time to wait for a terminal status
+    pub timeout: Duration,
+    /// Delay between polls
+    pub poll_interval: Duration,
+}
+
+impl WaitPlan {
+    /// Builds a plan from the requested seconds, clamping both to their bounds
+    ///
+    /// Non-finite or negative values fall back to the defaults. A timeout of
+    /// 0 polls exactly once.
+    pub fn new(timeout_secs: Option<f64>, poll_interval_secs: Option<f64>) -> Self {
+        let secs = |value: Option<f64>| {
+            value
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .map(Duration::from_secs_f64)
+        };
+
+        Self {
+            timeout: secs(timeout_secs)
+                .unwrap_or(DEFAULT_WAIT_TIMEOUT)
+                .min(MAX_WAIT_TIMEOUT),
+            poll_interval: secs(poll_interval_secs)
+                .unwrap_or(DEFAULT_POLL_INTERVAL)
+                .clamp(MIN_POLL_INTERVAL, MAX_WAIT_TIMEOUT),
+        }
+    }
+}
+
+/// Response of `wait_for_order`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderWaitResult {
+    /// Final order state, or the last one seen when the wait timed out
+    pub order: Order,
+    /// True when the timeout elapsed before a terminal status
+    pub timed_out: bool,
+    /// Number of `query_order` calls made
+    pub polls: u32,
+    /// Time spent waiting, in milliseconds
+    pub elapsed_ms: u64,
+}
+
+/// Polls with `query` until the order is terminal or `plan.timeout` elapses
+///
+/// The first poll happens immediately and a last poll is made at the
+/// deadline, so a fill just before the timeout is still reported.
+///
+/// # Errors
+/// The first error returned by `query`
+pub async fn wait_until_terminal<F, Fut>(
+    plan: WaitPlan,
+    mut query: F,
+) -> Result<OrderWaitResult, McpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Order, McpError>>,
+{
+    let start = Instant::now();
+    let deadline = start + plan.timeout;
+    let mut polls = 0;
+
+    loop {
+        let order = query().await?;
+        polls += 1;
+
+        let now = Instant::now();
+        let terminal = is_terminal_status(&order.status);
+        if terminal || now >= deadline {
+            return Ok(OrderWaitResult {
+                order,
+                timed_out: !terminal,
+                polls,
+                elapsed_ms: now.duration_since(start).as_millis() as u64,
+            });
+        }
+
+        tokio::time::sleep(plan.poll_interval.min(deadline - now)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn order(status: &str) -> Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": 42,
+            "clientOrderId": "wait-test",
+            "status": status,
+            "type": "LIMIT",
+            "side": "BUY"
+        }))
+        .unwrap()
+    }
+
+    fn quick_plan(timeout_ms: u64) -> WaitPlan {
+        WaitPlan {
+            timeout: Duration::from_millis(timeout_ms),
+            poll_interval: Duration::from_millis(5),
+        }
+    }
+
+    #[test]
+    fn test_wait_plan_bounds() {
+        let plan = WaitPlan::new(None, None);
+        assert_eq!(plan.timeout, DEFAULT_WAIT_TIMEOUT);
+        assert_eq!(plan.poll_interval, DEFAULT_POLL_INTERVAL);
+
+        let plan = WaitPlan::new(Some(3600.0), Some(0.1));
+        assert_eq!(plan.timeout, MAX_WAIT_TIMEOUT);
+        assert_eq!(plan.poll_interval, MIN_POLL_INTERVAL);
+
+        let plan = WaitPlan::new(Some(-1.0), Some(f64::NAN));
+        assert_eq!(plan.timeout, DEFAULT_WAIT_TIMEOUT);
+        assert_eq!(plan.poll_interval, DEFAULT_POLL_INTERVAL);
+
+        assert_eq!(WaitPlan::new(Some(0.0), Some(2.5)).timeout, Duration::ZERO);
+        assert_eq!(
+            WaitPlan::new(Some(10.0), Some(2.5)).poll_interval,
+            Duration::from_millis(2500)
+        );
+    }
+
+    #[test]
+    fn test_terminal_statuses() {
+        for status in TERMINAL_STATUSES {
+            assert!(is_terminal_status(status));
+        }
+        assert!(!is_terminal_status("NEW"));
+        assert!(!is_terminal_status("PARTIALLY_FILLED"));
+        assert!(!is_terminal_status("PENDING_CANCEL"));
+    }
+
+    #[tokio::test]
+    async fn test_waits_until_filled() {
+        let calls = AtomicU32::new(0);
+        let result = wait_until_terminal(quick_plan(5_000), || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(order(match call {
+                    0 => "NEW",
+                    1 => "PARTIALLY_FILLED",
+                    _ => "FILLED",
+                }))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(!result.timed_out);
+        assert_eq!(result.order.status, "FILLED");
+        assert_eq!(result.polls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_times_out_with_last_seen_state() {
+        let result = wait_until_terminal(quick_plan(30), || async { Ok(order("NEW")) })
+            .await
+            .unwrap();
+
+        assert!(result.timed_out);
+        assert_eq!(result.order.status, "NEW");
+        assert!(result.polls >= 2);
+        assert!(result.elapsed_ms >= 30);
+    }
+
+    #[tokio::test]
+    async fn test_query_error_ends_wait() {
+        let err = wait_until_terminal(quick_plan(1_000), || async {
+            Err(McpError::InvalidRequest(
+                "Order does not exist. (-2013)".to_string(),
+            ))
+        })
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("-2013"));
+    }
+}
@@ -0,0 +1,84 @@
+//! Round-trip latency probe
+//!
+//! Summarizes repeated `/api/v3/ping` timings for the `ping_binance` tool, so
+//! "Binance is slow" can be told apart from clock offset (`get_server_time`)
+//! or a broken tool.
+
+use serde::Serialize;
+
+use crate::error::McpError;
+
+/// Pings sent when the caller doesn't pass a count
+pub const DEFAULT_PING_COUNT: u32 = 3;
+
+/// Maximum pings per call (weight 1 each)
+pub const MAX_PING_COUNT: u32 = 10;
+
+/// Min/avg/max round-trip time in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PingStats {
+    pub min: f64,
+    pub avg: f64,
+    pub max: f64,
+}
+
+impl PingStats {
+    /// Summarizes round-trip samples in milliseconds, rounded to 0.01ms
+    ///
+    /// Returns `None` for an empty sample set.
+    pub fn from_samples(samples_ms: &[f64]) -> Option<Self> {
+        if samples_ms.is_empty() {
+            return None;
+        }
+        let round = |v: f64| (v * 100.0).round() / 100.0;
+        let min = samples_ms.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = samples_ms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let avg = samples_ms.iter().sum::<f64>() / samples_ms.len() as f64;
+        Some(Self {
+            min: round(min),
+            avg: round(avg),
+            max: round(max),
+        })
+    }
+}
+
+/// Resolves the requested ping count (default 3, 1..=10)
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` for a count outside 1..=10
+pub fn resolve_ping_count(count: Option<u32>) -> Result<u32, McpError> {
+    match count.unwrap_or(DEFAULT_PING_COUNT) {
+        count @ 1..=MAX_PING_COUNT => Ok(count),
+        count => Err(McpError::InvalidRequest(format!(
+            "count must be between 1 and {}, got {}",
+            MAX_PING_COUNT, count
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ping_stats_from_samples() {
+        let stats = PingStats::from_samples(&[42.123, 38.0, 51.5]).unwrap();
+        assert_eq!(
+            stats,
+            PingStats {
+                min: 38.0,
+                avg: 43.87,
+                max: 51.5
+            }
+        );
+        assert_eq!(PingStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_resolve_ping_count() {
+        assert_eq!(resolve_ping_count(None).unwrap(), DEFAULT_PING_COUNT);
+        assert_eq!(resolve_ping_count(Some(10)).unwrap(), 10);
+        assert!(resolve_ping_count(Some(0)).is_err());
+        assert!(resolve_ping_count(Some(11)).is_err());
+    }
+}
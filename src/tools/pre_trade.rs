@@ -0,0 +1,330 @@
+//! Pre-trade checklist helpers
+//!
+//! Checks a prospective order against the symbol's `exchangeInfo` filters so
+//! the `pre_trade_check` prompt can name the filter an order would trip
+//! instead of waiting for Binance's -1013 rejection. The verdict folds in the
+//! optional slippage and health figures when those features are compiled in.
+
+use crate::binance::types::{SymbolFilter, SymbolInfo};
+
+/// Estimated slippage at or above this many basis points downgrades the verdict to CAUTION
+pub const SLIPPAGE_CAUTION_BPS: f64 = 25.0;
+
+/// Market health score below this downgrades the verdict to CAUTION
+pub const HEALTH_CAUTION_SCORE: f64 = 60.0;
+
+/// One line of the pre-trade checklist
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckItem {
+    /// Check name (filter type, or `STATUS` for the trading status)
+    pub name: &'static str,
+    /// Whether the order passes this check
+    pub passed: bool,
+    /// Human-readable explanation
+    pub detail: String,
+}
+
+impl CheckItem {
+    fn new(name: &'static str, passed: bool, detail: String) -> Self {
+        Self {
+            name,
+            passed,
+            detail,
+        }
+    }
+}
+
+/// Overall go/no-go verdict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Every check passed
+    Go,
+    /// Filters pass, but slippage or market health is a concern
+    Caution,
+    /// At least one filter or the trading status would reject the order
+    NoGo,
+}
+
+impl Verdict {
+    /// Label rendered in the checklist (e.g., "✅ GO")
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Go => "✅ GO",
+            Verdict::Caution => "⚠️ CAUTION",
+            Verdict::NoGo => "❌ NO-GO",
+        }
+    }
+}
+
+/// Checks an order against the symbol's trading status and filters
+///
+/// `price` is the limit price, or the reference (last) price for a MARKET
+/// order, where it is only used for the notional checks. Filters the server
+/// doesn't model are not checked.
+pub fn check_order_filters(
+    info: &SymbolInfo,
+    quantity: f64,
+    price: f64,
+    is_market: bool,
+) -> Vec<CheckItem> {
+    let mut checks = vec![CheckItem::new(
+        "STATUS",
+        info.is_trading(),
+        format!("{} is {}", info.symbol, info.status),
+    )];
+    let notional = quantity * price;
+
+    for filter in &info.filters {
+        match filter {
+            SymbolFilter::PriceFilter {
+                min_price,
+                max_price,
+                tick_size,
+            } if !is_market => {
+                checks.push(check_bounded_step(
+                    "PRICE_FILTER",
+                    "price",
+                    price,
+                    ("minPrice", min_price),
+                    ("maxPrice", max_price),
+                    ("tickSize", tick_size),
+                ));
+            }
+            SymbolFilter::LotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => {
+                checks.push(check_bounded_step(
+                    "LOT_SIZE",
+                    "quantity",
+                    quantity,
+                    ("minQty", min_qty),
+                    ("maxQty", max_qty),
+                    ("stepSize", step_size),
+                ));
+            }
+            SymbolFilter::MarketLotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } if is_market => {
+                checks.push(check_bounded_step(
+                    "MARKET_LOT_SIZE",
+                    "quantity",
+                    quantity,
+                    ("minQty", min_qty),
+                    ("maxQty", max_qty),
+                    ("stepSize", step_size),
+                ));
+            }
+            SymbolFilter::MinNotional {
+                min_notional,
+                apply_to_market,
+            } if !is_market || *apply_to_market => {
+                let min = parse_bound(min_notional);
+                checks.push(CheckItem::new(
+                    "MIN_NOTIONAL",
+                    notional >= min,
+                    format!("notional {:.8} vs minNotional {}", notional, min_notional),
+                ));
+            }
+            SymbolFilter::Notional {
+                min_notional,
+                apply_min_to_market,
+                max_notional,
+                apply_max_to_market,
+            } => {
+                let min = if !is_market || *apply_min_to_market {
+                    parse_bound(min_notional)
+                } else {
+                    0.0
+                };
+                let max = if !is_market || *apply_max_to_market {
+                    parse_bound(max_notional)
+                } else {
+                    0.0
+                };
+                let passed = notional >= min && (max <= 0.0 || notional <= max);
+                checks.push(CheckItem::new(
+                    "NOTIONAL",
+                    passed,
+                    format!(
+                        "notional {:.8} vs minNotional {} / maxNotional {}",
+                        notional, min_notional, max_notional
+                    ),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    checks
+}
+
+/// Combines the filter checks with the optional slippage and health figures
+///
+/// Any failed check is NO-GO. Slippage at or above [`SLIPPAGE_CAUTION_BPS`] or
+/// a health score below [`HEALTH_CAUTION_SCORE`] is CAUTION. Missing figures
+/// (feature disabled, not enough data) don't affect the verdict.
+pub fn verdict(
+    checks: &[CheckItem],
+    slippage_bps: Option<f64>,
+    health_score: Option<f64>,
+) -> Verdict {
+    if checks.iter().any(|c| !c.passed) {
+        Verdict::NoGo
+    } else if slippage_bps.is_some_and(|bps| bps >= SLIPPAGE_CAUTION_BPS)
+        || health_score.is_some_and(|score| score < HEALTH_CAUTION_SCORE)
+    {
+        Verdict::Caution
+    } else {
+        Verdict::Go
+    }
+}
+
+/// Parses a filter bound, treating unparseable values as disabled (`0`)
+fn parse_bound(value: &str) -> f64 {
+    value.parse().unwrap_or(0.0)
+}
+
+/// Checks `min <= value <= max` and that `value - min` is a multiple of `step`
+///
+/// Zero bounds and a zero step are disabled, as in Binance's filter rules.
+fn check_bounded_step(
+    name: &'static str,
+    label: &str,
+    value: f64,
+    (min_name, min): (&str, &str),
+    (max_name, max): (&str, &str),
+    (step_name, step): (&str, &str),
+) -> CheckItem {
+    let (min_v, max_v, step_v) = (parse_bound(min), parse_bound(max), parse_bound(step));
+
+    if min_v > 0.0 && value < min_v {
+        return CheckItem::new(
+            name,
+            false,
+            format!("{} {} is below {} {}", label, value, min_name, min),
+        );
+    }
+    if max_v > 0.0 && value > max_v {
+        return CheckItem::new(
+            name,
+            false,
+            format!("{} {} is above {} {}", label, value, max_name, max),
+        );
+    }
+    if step_v > 0.0 {
+        let steps = (value - min_v) / step_v;
+        if (steps - steps.round()).abs() > 1e-6 {
+            return CheckItem::new(
+                name,
+                false,
+                format!(
+                    "{} {} is not a multiple of {} {}",
+                    label, value, step_name, step
+                ),
+            );
+        }
+    }
+
+    CheckItem::new(
+        name,
+        true,
+        format!(
+            "{} {} within {}..{} ({} {})",
+            label, value, min, max, step_name, step
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btcusdt() -> SymbolInfo {
+        SymbolInfo {
+            symbol: "BTCUSDT".to_string(),
+            status: "TRADING".to_string(),
+            base_asset: "BTC".to_string(),
+            quote_asset: "USDT".to_string(),
+            order_types: Vec::new(),
+            filters: vec![
+                SymbolFilter::PriceFilter {
+                    min_price: "0.01".to_string(),
+                    max_price: "1000000.00".to_string(),
+                    tick_size: "0.01".to_string(),
+                },
+                SymbolFilter::LotSize {
+                    min_qty: "0.00001".to_string(),
+                    max_qty: "9000.00".to_string(),
+                    step_size: "0.00001".to_string(),
+                },
+                SymbolFilter::Notional {
+                    min_notional: "5.00".to_string(),
+                    apply_min_to_market: true,
+                    max_notional: "9000000.00".to_string(),
+                    apply_max_to_market: false,
+                },
+                SymbolFilter::Other,
+            ],
+        }
+    }
+
+    fn failed(checks: &[CheckItem]) -> Vec<&'static str> {
+        checks
+            .iter()
+            .filter(|c| !c.passed)
+            .map(|c| c.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_limit_order_passes() {
+        let checks = check_order_filters(&btcusdt(), 0.001, 65000.12, false);
+        assert_eq!(
+            checks.iter().map(|c| c.name).collect::<Vec<_>>(),
+            ["STATUS", "PRICE_FILTER", "LOT_SIZE", "NOTIONAL"]
+        );
+        assert!(failed(&checks).is_empty());
+    }
+
+    #[test]
+    fn test_filter_violations() {
+        // Price off the tick, quantity below minQty and notional below 5 USDT
+        let checks = check_order_filters(&btcusdt(), 0.000001, 65000.123, false);
+        assert_eq!(failed(&checks), ["PRICE_FILTER", "LOT_SIZE", "NOTIONAL"]);
+
+        let checks = check_order_filters(&btcusdt(), 0.0012345, 65000.0, false);
+        assert_eq!(failed(&checks), ["LOT_SIZE"]);
+        assert!(checks[2].detail.contains("stepSize"));
+    }
+
+    #[test]
+    fn test_market_order_skips_price_filter() {
+        let checks = check_order_filters(&btcusdt(), 0.001, 65000.123, true);
+        assert!(!checks.iter().any(|c| c.name == "PRICE_FILTER"));
+        assert!(failed(&checks).is_empty());
+    }
+
+    #[test]
+    fn test_halted_symbol_fails_status() {
+        let mut info = btcusdt();
+        info.status = "BREAK".to_string();
+        let checks = check_order_filters(&info, 0.001, 65000.0, false);
+        assert_eq!(failed(&checks), ["STATUS"]);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let passing = check_order_filters(&btcusdt(), 0.001, 65000.0, false);
+        let failing = check_order_filters(&btcusdt(), 0.000001, 65000.0, false);
+
+        assert_eq!(verdict(&passing, None, None), Verdict::Go);
+        assert_eq!(verdict(&passing, Some(3.0), Some(85.0)), Verdict::Go);
+        assert_eq!(verdict(&passing, Some(40.0), None), Verdict::Caution);
+        assert_eq!(verdict(&passing, None, Some(45.0)), Verdict::Caution);
+        assert_eq!(verdict(&failing, Some(1.0), Some(90.0)), Verdict::NoGo);
+    }
+}
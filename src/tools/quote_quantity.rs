@@ -0,0 +1,190 @@
+//! Quote-amount to base-quantity conversion for `quote_to_quantity`
+//!
+//! "Buy $50 of ETH" needs a base quantity: the amount divided by the price,
+//! rounded down to `LOT_SIZE.stepSize` and still worth at least the symbol's
+//! minimum notional. MARKET orders can send `quoteOrderQty` instead, but
+//! LIMIT orders can't, so dollar-denominated limit orders go through this.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::filters::SymbolIncrements;
+use crate::binance::types::{SymbolFilter, SymbolInfo};
+use crate::error::McpError;
+
+/// Response of `quote_to_quantity`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuoteQuantity {
+    /// Trading pair
+    pub symbol: String,
+    /// Price the amount was converted at
+    pub price: String,
+    /// Base quantity, rounded down to the step size
+    pub quantity: String,
+    /// `quantity * price` in the quote asset (at most the requested amount)
+    pub estimated_cost: String,
+    /// Minimum order value in the quote asset, if the symbol has one
+    pub min_notional: Option<String>,
+}
+
+/// Minimum order value from the `NOTIONAL` or legacy `MIN_NOTIONAL` filter
+///
+/// `None` when neither filter is present or its minimum is `0`.
+pub fn min_notional(info: &SymbolInfo) -> Option<Decimal> {
+    info.filters
+        .iter()
+        .filter_map(|filter| match filter {
+            SymbolFilter::Notional { min_notional, .. }
+            | SymbolFilter::MinNotional { min_notional, .. } => min_notional.parse().ok(),
+            _ => None,
+        })
+        .filter(|min: &Decimal| *min > Decimal::ZERO)
+        .max()
+}
+
+/// Converts `quote_amount` into a step-aligned base quantity at `price`
+///
+/// # Errors
+/// `InvalidRequest` if the amount or price isn't positive, the quantity
+/// rounds down to 0, or the rounded order is worth less than the minimum
+/// notional (the message names the shortfall and the smallest amount that
+/// would pass)
+pub fn quote_to_quantity(
+    info: &SymbolInfo,
+    quote_amount: Decimal,
+    price: Decimal,
+) -> Result<QuoteQuantity, McpError> {
+    if quote_amount <= Decimal::ZERO || price <= Decimal::ZERO {
+        return Err(McpError::InvalidRequest(format!(
+            "quote_amount and price must be positive, got {} at {}",
+            quote_amount, price
+        )));
+    }
+
+    let increments = SymbolIncrements::from_symbol_info(info);
+    let quantity = increments.floor_quantity(quote_amount / price);
+    let estimated_cost = (quantity * price).normalize();
+    let min_notional = min_notional(info);
+
+    if let Some(min) = min_notional.filter(|min| estimated_cost < *min) {
+        return Err(McpError::InvalidRequest(format!(
+            "{} {} at {} buys {} {} worth {}, below minNotional {}: short by {}. Use at least {} {}",
+            quote_amount,
+            info.quote_asset,
+            price,
+            quantity,
+            info.base_asset,
+            estimated_cost,
+            min,
+            (min - estimated_cost).normalize(),
+            min_quote_amount(min, price, increments.step_size),
+            info.quote_asset
+        )));
+    }
+    if quantity.is_zero() {
+        return Err(McpError::InvalidRequest(format!(
+            "{} {} at {} rounds down to 0 {} at stepSize {}",
+            quote_amount,
+            info.quote_asset,
+            price,
+            info.base_asset,
+            increments.step_size.unwrap_or_default()
+        )));
+    }
+
+    Ok(QuoteQuantity {
+        symbol: info.symbol.clone(),
+        price: price.normalize().to_string(),
+        quantity: quantity.to_string(),
+        estimated_cost: estimated_cost.to_string(),
+        min_notional: min_notional.map(|min| min.normalize().to_string()),
+    })
+}
+
+/// Cost of the smallest step-aligned quantity worth at least `min`
+fn min_quote_amount(min: Decimal, price: Decimal, step_size: Option<Decimal>) -> Decimal {
+    let quantity = min / price;
+    let quantity = match step_size {
+        Some(step) => (quantity / step).ceil() * step,
+        None => quantity,
+    };
+    (quantity * price).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn ethusdt() -> SymbolInfo {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "ETHUSDT",
+            "status": "TRADING",
+            "baseAsset": "ETH",
+            "quoteAsset": "USDT",
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000", "tickSize": "0.01"},
+                {"filterType": "LOT_SIZE", "minQty": "0.0001", "maxQty": "9000", "stepSize": "0.00010000"},
+                {"filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true,
+                 "maxNotional": "9000000", "applyMaxToMarket": false}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_min_notional() {
+        assert_eq!(min_notional(&ethusdt()), Some(dec("5")));
+
+        let mut legacy = ethusdt();
+        legacy.filters = serde_json::from_value(serde_json::json!([
+            {"filterType": "MIN_NOTIONAL", "minNotional": "10.0", "applyToMarket": true}
+        ]))
+        .unwrap();
+        assert_eq!(min_notional(&legacy), Some(dec("10")));
+
+        legacy.filters.clear();
+        assert_eq!(min_notional(&legacy), None);
+    }
+
+    #[test]
+    fn test_normal_amount_rounds_down_to_step() {
+        // 50 / 3012.34 = 0.016598... -> 0.0165 ETH
+        let converted = quote_to_quantity(&ethusdt(), dec("50"), dec("3012.34")).unwrap();
+        assert_eq!(converted.quantity, "0.0165");
+        assert_eq!(converted.estimated_cost, "49.70361");
+        assert_eq!(converted.price, "3012.34");
+        assert_eq!(converted.min_notional.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn test_small_amount_below_min_notional() {
+        // 4 USDT buys 0.0013 ETH = 3.9 USDT; the smallest passing order is 0.0017 ETH = 5.1 USDT
+        let err = quote_to_quantity(&ethusdt(), dec("4"), dec("3000")).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("below minNotional 5"), "{}", message);
+        assert!(message.contains("short by 1.1"), "{}", message);
+        assert!(message.contains("Use at least 5.1 USDT"), "{}", message);
+
+        // Enough for the minimum before rounding, not after: 5.05 / 3000 floors to 0.0016 = 4.8
+        let err = quote_to_quantity(&ethusdt(), dec("5.05"), dec("3000")).unwrap_err();
+        assert!(err.to_string().contains("short by 0.2"));
+    }
+
+    #[test]
+    fn test_zero_quantity_and_invalid_inputs() {
+        let mut no_minimum = ethusdt();
+        no_minimum
+            .filters
+            .retain(|f| !matches!(f, SymbolFilter::Notional { .. }));
+        let err = quote_to_quantity(&no_minimum, dec("0.1"), dec("3000")).unwrap_err();
+        assert!(err.to_string().contains("rounds down to 0"));
+
+        assert!(quote_to_quantity(&ethusdt(), dec("0"), dec("3000")).is_err());
+        assert!(quote_to_quantity(&ethusdt(), dec("50"), dec("-1")).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! Kline resampling to timeframes Binance doesn't offer
+//!
+//! Aggregates every `target_multiple` consecutive base-interval candles into a
+//! synthetic candle, so a 2h chart can be built from 1h klines or an 8h chart
+//! from 4h klines without the client doing the arithmetic.
+
+use serde::Serialize;
+
+use crate::binance::limits::MAX_KLINES_LIMIT;
+use crate::binance::types::Kline;
+use crate::error::McpError;
+
+/// Default number of synthetic candles returned
+pub const DEFAULT_RESAMPLED_LIMIT: u32 = 100;
+
+/// Resampled klines returned by `get_resampled_klines`
+#[derive(Debug, Clone, Serialize)]
+pub struct ResampledKlines {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Synthetic interval label (e.g., "2h" for 2 × 1h)
+    pub interval: String,
+    /// Interval of the aggregated Binance klines
+    pub base_interval: String,
+    /// Base candles per synthetic candle
+    pub target_multiple: u32,
+    /// Synthetic candles, oldest first
+    pub klines: Vec<Kline>,
+}
+
+/// Validates the multiple and resolves how many base candles to fetch
+///
+/// `limit` is the number of synthetic candles (default: 100, reduced so the
+/// base fetch stays within Binance's 1000-kline cap).
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` if `target_multiple` is below 2 or the
+/// base fetch would exceed 1000 klines
+pub fn resolve_resample_limits(target_multiple: u32, limit: Option<u32>) -> Result<u32, McpError> {
+    if target_multiple < 2 {
+        return Err(McpError::InvalidRequest(format!(
+            "target_multiple must be at least 2, got {}",
+            target_multiple
+        )));
+    }
+    let max_limit = MAX_KLINES_LIMIT / target_multiple;
+    if max_limit == 0 {
+        return Err(McpError::InvalidRequest(format!(
+            "target_multiple must be at most {}, got {}",
+            MAX_KLINES_LIMIT, target_multiple
+        )));
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_RESAMPLED_LIMIT.min(max_limit));
+    if limit == 0 || limit > max_limit {
+        return Err(McpError::InvalidRequest(format!(
+            "limit must be between 1 and {} for target_multiple {} (at most {} base klines)",
+            max_limit, target_multiple, MAX_KLINES_LIMIT
+        )));
+    }
+    Ok(limit * target_multiple)
+}
+
+/// Labels the synthetic interval, e.g. "1h" × 2 → "2h"
+///
+/// Falls back to `"{multiple}x{interval}"` for intervals without a leading count.
+pub fn resampled_interval(base_interval: &str, target_multiple: u32) -> String {
+    let digits = base_interval
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    match base_interval[..digits].parse::<u32>() {
+        Ok(count) if digits < base_interval.len() => {
+            format!("{}{}", count * target_multiple, &base_interval[digits..])
+        }
+        _ => format!("{}x{}", target_multiple, base_interval),
+    }
+}
+
+/// Aggregates every `target_multiple` consecutive klines into one
+///
+/// Groups end at the newest kline, so a partial group is dropped from the
+/// oldest end. Open is the first open, close the last close, high/low the
+/// extremes, and volumes and trade counts are summed.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` if fewer than `target_multiple` klines
+/// were returned, or `McpError::ParseError` for a non-numeric kline field
+pub fn resample_klines(klines: &[Kline], target_multiple: usize) -> Result<Vec<Kline>, McpError> {
+    if target_multiple < 2 {
+        return Err(McpError::InvalidRequest(format!(
+            "target_multiple must be at least 2, got {}",
+            target_multiple
+        )));
+    }
+    if klines.len() < target_multiple {
+        return Err(McpError::InvalidRequest(format!(
+            "Not enough klines to resample: got {}, need at least {}",
+            klines.len(),
+            target_multiple
+        )));
+    }
+
+    let skip = klines.len() % target_multiple;
+    klines[skip..]
+        .chunks(target_multiple)
+        .map(aggregate)
+        .collect()
+}
+
+/// Folds one group of consecutive klines into a synthetic kline
+fn aggregate(group: &[Kline]) -> Result<Kline, McpError> {
+    let (first, last) = (&group[0], &group[group.len() - 1]);
+    let num = |value: &str| {
+        value
+            .parse::<f64>()
+            .map_err(|_| McpError::ParseError(format!("Invalid kline value '{}'", value)))
+    };
+    let sum = |field: fn(&Kline) -> &str| -> Result<String, McpError> {
+        let total = group
+            .iter()
+            .map(|k| num(field(k)))
+            .sum::<Result<f64, _>>()?;
+        Ok(format!("{:.8}", total))
+    };
+
+    // Keep the original high/low strings so no precision is lost
+    let mut high = first;
+    let mut low = first;
+    for kline in group {
+        if num(&kline.high)? > num(&high.high)? {
+            high = kline;
+        }
+        if num(&kline.low)? < num(&low.low)? {
+            low = kline;
+        }
+    }
+
+    Ok(Kline {
+        open_time: first.open_time,
+        open: first.open.clone(),
+        high: high.high.clone(),
+        low: low.low.clone(),
+        close: last.close.clone(),
+        volume: sum(|k| &k.volume)?,
+        close_time: last.close_time,
+        quote_volume: sum(|k| &k.quote_volume)?,
+        trades: group.iter().map(|k| k.trades).sum(),
+        taker_buy_base: sum(|k| &k.taker_buy_base)?,
+        taker_buy_quote: sum(|k| &k.taker_buy_quote)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_MS: i64 = 3_600_000;
+
+    fn kline(hour: i64, open: &str, high: &str, low: &str, close: &str, volume: &str) -> Kline {
+        Kline {
+            open_time: hour * HOUR_MS,
+            open: open.to_string(),
+            high: high.to_string(),
+            low: low.to_string(),
+            close: close.to_string(),
+            volume: volume.to_string(),
+            close_time: (hour + 1) * HOUR_MS - 1,
+            quote_volume: "100.00000000".to_string(),
+            trades: 10,
+            taker_buy_base: "0.50000000".to_string(),
+            taker_buy_quote: "50.00000000".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resample_two_hour_candles() {
+        let hourly = vec![
+            kline(0, "100.0", "105.0", "99.0", "104.0", "1.5"),
+            kline(1, "104.0", "110.0", "103.0", "108.0", "2.25"),
+            kline(2, "108.0", "109.0", "95.5", "96.0", "3.0"),
+            kline(3, "96.0", "101.0", "96.0", "100.0", "0.25"),
+        ];
+
+        let resampled = resample_klines(&hourly, 2).unwrap();
+
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(
+            resampled[0],
+            Kline {
+                open_time: 0,
+                open: "100.0".to_string(),
+                high: "110.0".to_string(),
+                low: "99.0".to_string(),
+                close: "108.0".to_string(),
+                volume: "3.75000000".to_string(),
+                close_time: 2 * HOUR_MS - 1,
+                quote_volume: "200.00000000".to_string(),
+                trades: 20,
+                taker_buy_base: "1.00000000".to_string(),
+                taker_buy_quote: "100.00000000".to_string(),
+            }
+        );
+        assert_eq!(resampled[1].low, "95.5");
+        assert_eq!(resampled[1].close, "100.0");
+    }
+
+    #[test]
+    fn test_partial_group_dropped_from_oldest_end() {
+        let hourly: Vec<Kline> = (0..5)
+            .map(|h| kline(h, "1", "2", "0.5", "1.5", "1"))
+            .collect();
+
+        let resampled = resample_klines(&hourly, 2).unwrap();
+
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled[0].open_time, HOUR_MS);
+        assert_eq!(resampled[1].close_time, 5 * HOUR_MS - 1);
+    }
+
+    #[test]
+    fn test_not_enough_klines() {
+        let hourly = vec![kline(0, "1", "2", "0.5", "1.5", "1")];
+        assert!(resample_klines(&hourly, 2).is_err());
+        assert!(resample_klines(&hourly, 1).is_err());
+    }
+
+    #[test]
+    fn test_resolve_resample_limits() {
+        assert_eq!(resolve_resample_limits(2, None).unwrap(), 200);
+        assert_eq!(resolve_resample_limits(20, None).unwrap(), 1000);
+        assert_eq!(resolve_resample_limits(4, Some(250)).unwrap(), 1000);
+        assert!(resolve_resample_limits(1, None).is_err());
+        assert!(resolve_resample_limits(4, Some(251)).is_err());
+        assert!(resolve_resample_limits(2000, None).is_err());
+    }
+
+    #[test]
+    fn test_resampled_interval() {
+        assert_eq!(resampled_interval("1h", 2), "2h");
+        assert_eq!(resampled_interval("4h", 2), "8h");
+        assert_eq!(resampled_interval("15m", 3), "45m");
+        assert_eq!(resampled_interval("M", 2), "2xM");
+    }
+}
@@ -0,0 +1,306 @@
+//! Plain-language trading rules for `explain_symbol_rules`
+//!
+//! `exchangeInfo` describes a symbol's limits as filters (`LOT_SIZE`,
+//! `PRICE_FILTER`, `NOTIONAL`, ...) with padded decimal strings and `"0"`
+//! meaning "no bound". This turns them into normalized values plus one
+//! sentence per rule ("Orders must be at least 0.00001 BTC ...") that an
+//! agent can relay to a user as-is.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::binance::types::{SymbolFilter, SymbolInfo};
+
+/// Trading rules of one symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolRules {
+    /// Trading pair
+    pub symbol: String,
+    /// Trading status (e.g., TRADING, BREAK)
+    pub status: String,
+    /// Base asset (quantities are in this asset)
+    pub base_asset: String,
+    /// Quote asset (prices and notional are in this asset)
+    pub quote_asset: String,
+    /// Smallest order quantity
+    pub min_quantity: Option<String>,
+    /// Largest order quantity
+    pub max_quantity: Option<String>,
+    /// Quantity increment
+    pub quantity_step: Option<String>,
+    /// Limit price increment
+    pub price_step: Option<String>,
+    /// Smallest order value (price * quantity) in the quote asset
+    pub min_notional: Option<String>,
+    /// Most open orders allowed on the symbol
+    pub max_open_orders: Option<u32>,
+    /// Whether MARKET orders are accepted (`None` when exchangeInfo lists no order types)
+    pub market_orders_allowed: Option<bool>,
+    /// One plain-language sentence per rule
+    pub guidance: Vec<String>,
+}
+
+/// Normalized value of a filter field, `None` for `"0"` (disabled) or unparseable values
+fn bound(value: &str) -> Option<String> {
+    Decimal::from_str(value)
+        .ok()
+        .filter(|d| *d > Decimal::ZERO)
+        .map(|d| d.normalize().to_string())
+}
+
+/// Explains the symbol's filters, status and order types
+pub fn explain_symbol_rules(info: &SymbolInfo) -> SymbolRules {
+    let base = &info.base_asset;
+    let quote = &info.quote_asset;
+    let mut rules = SymbolRules {
+        symbol: info.symbol.clone(),
+        status: info.status.clone(),
+        base_asset: base.clone(),
+        quote_asset: quote.clone(),
+        min_quantity: None,
+        max_quantity: None,
+        quantity_step: None,
+        price_step: None,
+        min_notional: None,
+        max_open_orders: None,
+        market_orders_allowed: if info.order_types.is_empty() {
+            None
+        } else {
+            Some(info.order_types.iter().any(|t| t == "MARKET"))
+        },
+        guidance: Vec::new(),
+    };
+
+    rules.guidance.push(if info.is_trading() {
+        format!("{} is open for trading.", info.symbol)
+    } else {
+        format!(
+            "{} is not trading right now (status {}); new orders will be rejected.",
+            info.symbol, info.status
+        )
+    });
+
+    let mut price_range = String::new();
+    let mut notional_on_market = None;
+    let mut market_lot = None;
+    for filter in &info.filters {
+        match filter {
+            SymbolFilter::LotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => {
+                rules.min_quantity = bound(min_qty);
+                rules.max_quantity = bound(max_qty);
+                rules.quantity_step = bound(step_size);
+            }
+            SymbolFilter::PriceFilter {
+                min_price,
+                max_price,
+                tick_size,
+            } => {
+                rules.price_step = bound(tick_size);
+                price_range = match (bound(min_price), bound(max_price)) {
+                    (Some(min), Some(max)) => format!(" between {} and {} {}", min, max, quote),
+                    (Some(min), None) => format!(" of at least {} {}", min, quote),
+                    (None, Some(max)) => format!(" of at most {} {}", max, quote),
+                    (None, None) => String::new(),
+                };
+            }
+            SymbolFilter::Notional {
+                min_notional,
+                apply_min_to_market,
+                ..
+            } => {
+                rules.min_notional = bound(min_notional);
+                notional_on_market = Some(*apply_min_to_market);
+            }
+            SymbolFilter::MinNotional {
+                min_notional,
+                apply_to_market,
+            } => {
+                rules.min_notional = bound(min_notional);
+                notional_on_market = Some(*apply_to_market);
+            }
+            SymbolFilter::MarketLotSize {
+                min_qty, max_qty, ..
+            } => market_lot = Some((bound(min_qty), bound(max_qty))),
+            SymbolFilter::MaxNumOrders { max_num_orders } => {
+                rules.max_open_orders = Some(*max_num_orders)
+            }
+            SymbolFilter::Other => {}
+        }
+    }
+
+    let quantity = match (&rules.min_quantity, &rules.max_quantity) {
+        (Some(min), Some(max)) => Some(format!(
+            "at least {} {} and at most {} {}",
+            min, base, max, base
+        )),
+        (Some(min), None) => Some(format!("at least {} {}", min, base)),
+        (None, Some(max)) => Some(format!("at most {} {}", max, base)),
+        (None, None) => None,
+    };
+    match (quantity, &rules.quantity_step) {
+        (Some(quantity), Some(step)) => rules.guidance.push(format!(
+            "Orders must be for {}, in increments of {} {}.",
+            quantity, step, base
+        )),
+        (Some(quantity), None) => rules
+            .guidance
+            .push(format!("Orders must be for {}.", quantity)),
+        (None, Some(step)) => rules.guidance.push(format!(
+            "Quantities must be in increments of {} {}.",
+            step, base
+        )),
+        (None, None) => {}
+    }
+
+    if let Some(step) = &rules.price_step {
+        rules.guidance.push(format!(
+            "Limit prices must be in increments of {} {}{}.",
+            step, quote, price_range
+        ));
+    } else if !price_range.is_empty() {
+        rules
+            .guidance
+            .push(format!("Limit prices must be{}.", price_range));
+    }
+
+    if let Some(min) = &rules.min_notional {
+        let market = match notional_on_market {
+            Some(false) => " (not enforced on MARKET orders)",
+            _ => "",
+        };
+        rules.guidance.push(format!(
+            "Each order must be worth at least {} {} (price × quantity){}.",
+            min, quote, market
+        ));
+    }
+
+    if let Some(max) = rules.max_open_orders {
+        rules.guidance.push(format!(
+            "At most {} open orders are allowed on {} at a time.",
+            max, info.symbol
+        ));
+    }
+
+    match rules.market_orders_allowed {
+        Some(true) => {
+            let limits = match market_lot {
+                Some((Some(min), Some(max))) => {
+                    format!(" for {} to {} {}", min, max, base)
+                }
+                Some((None, Some(max))) => format!(" for up to {} {}", max, base),
+                _ => String::new(),
+            };
+            rules
+                .guidance
+                .push(format!("MARKET orders are allowed{}.", limits));
+        }
+        Some(false) => rules.guidance.push(format!(
+            "MARKET orders are not allowed; use one of {}.",
+            info.order_types.join(", ")
+        )),
+        None => {}
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(value: serde_json::Value) -> SymbolInfo {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn btcusdt() -> SymbolInfo {
+        info(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "status": "TRADING",
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "orderTypes": ["LIMIT", "LIMIT_MAKER", "MARKET", "STOP_LOSS_LIMIT", "TAKE_PROFIT_LIMIT"],
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01000000", "maxPrice": "1000000.00000000", "tickSize": "0.01000000"},
+                {"filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000.00000000", "stepSize": "0.00001000"},
+                {"filterType": "ICEBERG_PARTS", "limit": 10},
+                {"filterType": "MARKET_LOT_SIZE", "minQty": "0.00000000", "maxQty": "120.50000000", "stepSize": "0.00000000"},
+                {"filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200},
+                {"filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true,
+                 "maxNotional": "9000000.00000000", "applyMaxToMarket": false}
+            ]
+        }))
+    }
+
+    #[test]
+    fn test_explain_btcusdt() {
+        let rules = explain_symbol_rules(&btcusdt());
+
+        assert_eq!(rules.min_quantity.as_deref(), Some("0.00001"));
+        assert_eq!(rules.max_quantity.as_deref(), Some("9000"));
+        assert_eq!(rules.quantity_step.as_deref(), Some("0.00001"));
+        assert_eq!(rules.price_step.as_deref(), Some("0.01"));
+        assert_eq!(rules.min_notional.as_deref(), Some("5"));
+        assert_eq!(rules.max_open_orders, Some(200));
+        assert_eq!(rules.market_orders_allowed, Some(true));
+        assert_eq!(
+            rules.guidance,
+            vec![
+                "BTCUSDT is open for trading.",
+                "Orders must be for at least 0.00001 BTC and at most 9000 BTC, in increments of 0.00001 BTC.",
+                "Limit prices must be in increments of 0.01 USDT between 0.01 and 1000000 USDT.",
+                "Each order must be worth at least 5 USDT (price × quantity).",
+                "At most 200 open orders are allowed on BTCUSDT at a time.",
+                "MARKET orders are allowed for up to 120.5 BTC.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explain_halted_limit_only_symbol() {
+        // Legacy MIN_NOTIONAL, no price bounds, no MARKET orders
+        let rules = explain_symbol_rules(&info(serde_json::json!({
+            "symbol": "SHIBEUR",
+            "status": "BREAK",
+            "baseAsset": "SHIB",
+            "quoteAsset": "EUR",
+            "orderTypes": ["LIMIT", "LIMIT_MAKER"],
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0.00000000", "maxPrice": "0.00000000", "tickSize": "0.00000001"},
+                {"filterType": "LOT_SIZE", "minQty": "1.00", "maxQty": "0", "stepSize": "1.00"},
+                {"filterType": "MIN_NOTIONAL", "minNotional": "10.0", "applyToMarket": false}
+            ]
+        })));
+
+        assert_eq!(rules.max_quantity, None);
+        assert_eq!(rules.max_open_orders, None);
+        assert_eq!(rules.market_orders_allowed, Some(false));
+        assert_eq!(
+            rules.guidance,
+            vec![
+                "SHIBEUR is not trading right now (status BREAK); new orders will be rejected.",
+                "Orders must be for at least 1 SHIB, in increments of 1 SHIB.",
+                "Limit prices must be in increments of 0.00000001 EUR.",
+                "Each order must be worth at least 10 EUR (price × quantity) (not enforced on MARKET orders).",
+                "MARKET orders are not allowed; use one of LIMIT, LIMIT_MAKER.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_filters_and_order_types() {
+        let rules = explain_symbol_rules(&info(serde_json::json!({
+            "symbol": "ODDUSDT",
+            "status": "TRADING",
+            "baseAsset": "ODD",
+            "quoteAsset": "USDT"
+        })));
+
+        assert_eq!(rules.market_orders_allowed, None);
+        assert_eq!(rules.guidance, vec!["ODDUSDT is open for trading."]);
+    }
+}
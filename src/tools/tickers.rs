@@ -0,0 +1,165 @@
+//! Batch ticker ranking for market overviews
+//!
+//! Turns a full `/api/v3/ticker/24hr` response into a short, sorted list so
+//! "biggest movers" style questions can be answered in a single tool call
+//! without sending thousands of tickers back to the client.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::binance::types::Ticker24hr;
+
+/// Default number of tickers returned
+pub const DEFAULT_TICKER_LIMIT: usize = 20;
+
+/// Maximum number of tickers returned
+pub const MAX_TICKER_LIMIT: usize = 100;
+
+/// Sort order for batch ticker results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TickerSortBy {
+    /// Highest 24h quote volume first (default)
+    #[default]
+    QuoteVolume,
+    /// Largest positive 24h price change first
+    Gainers,
+    /// Largest negative 24h price change first
+    Losers,
+    /// Largest absolute 24h price change first
+    Movers,
+}
+
+/// Compact ticker entry returned by `get_all_tickers`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerSummary {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Last price
+    pub last_price: String,
+    /// 24h price change percent
+    pub price_change_percent: String,
+    /// 24h quote asset volume
+    pub quote_volume: String,
+}
+
+impl From<&Ticker24hr> for TickerSummary {
+    fn from(ticker: &Ticker24hr) -> Self {
+        Self {
+            symbol: ticker.symbol.clone(),
+            last_price: ticker.last_price.clone(),
+            price_change_percent: ticker.price_change_percent.clone(),
+            quote_volume: ticker.quote_volume.clone(),
+        }
+    }
+}
+
+/// Sorts tickers and trims the result to `limit` entries
+///
+/// Non-numeric fields sort as zero. `limit` is clamped to 1..=[`MAX_TICKER_LIMIT`].
+pub fn rank_tickers(
+    tickers: &[Ticker24hr],
+    sort_by: TickerSortBy,
+    limit: usize,
+) -> Vec<TickerSummary> {
+    let parse = |value: &str| value.parse::<f64>().unwrap_or(0.0);
+    let key = |ticker: &Ticker24hr| match sort_by {
+        TickerSortBy::QuoteVolume => parse(&ticker.quote_volume),
+        TickerSortBy::Gainers => parse(&ticker.price_change_percent),
+        TickerSortBy::Losers => -parse(&ticker.price_change_percent),
+        TickerSortBy::Movers => parse(&ticker.price_change_percent).abs(),
+    };
+
+    let mut ranked: Vec<&Ticker24hr> = tickers.iter().collect();
+    ranked.sort_by(|a, b| key(b).total_cmp(&key(a)));
+
+    ranked
+        .into_iter()
+        .take(limit.clamp(1, MAX_TICKER_LIMIT))
+        .map(TickerSummary::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(symbol: &str, change_pct: &str, quote_volume: &str) -> Ticker24hr {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "priceChange": "0",
+            "priceChangePercent": change_pct,
+            "weightedAvgPrice": "0",
+            "prevClosePrice": "0",
+            "lastPrice": "1.0",
+            "lastQty": "0",
+            "bidPrice": "0",
+            "askPrice": "0",
+            "openPrice": "0",
+            "highPrice": "0",
+            "lowPrice": "0",
+            "volume": "0",
+            "quoteVolume": quote_volume,
+            "openTime": 0,
+            "closeTime": 0,
+            "firstId": 0,
+            "lastId": 0,
+            "count": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rank_tickers() {
+        let tickers = vec![
+            ticker("AAAUSDT", "5.0", "100"),
+            ticker("BBBUSDT", "-12.5", "900"),
+            ticker("CCCUSDT", "8.1", "500"),
+        ];
+
+        let symbols = |sort_by| -> Vec<String> {
+            rank_tickers(&tickers, sort_by, 10)
+                .into_iter()
+                .map(|t| t.symbol)
+                .collect()
+        };
+
+        assert_eq!(
+            symbols(TickerSortBy::QuoteVolume),
+            ["BBBUSDT", "CCCUSDT", "AAAUSDT"]
+        );
+        assert_eq!(
+            symbols(TickerSortBy::Gainers),
+            ["CCCUSDT", "AAAUSDT", "BBBUSDT"]
+        );
+        assert_eq!(
+            symbols(TickerSortBy::Losers),
+            ["BBBUSDT", "AAAUSDT", "CCCUSDT"]
+        );
+        assert_eq!(
+            symbols(TickerSortBy::Movers),
+            ["BBBUSDT", "CCCUSDT", "AAAUSDT"]
+        );
+    }
+
+    #[test]
+    fn test_rank_tickers_limit() {
+        let tickers: Vec<Ticker24hr> = (0..150)
+            .map(|i| ticker(&format!("T{:03}USDT", i), "0", &i.to_string()))
+            .collect();
+
+        assert_eq!(
+            rank_tickers(&tickers, TickerSortBy::QuoteVolume, 5).len(),
+            5
+        );
+        assert_eq!(
+            rank_tickers(&tickers, TickerSortBy::QuoteVolume, 1000).len(),
+            MAX_TICKER_LIMIT
+        );
+        assert_eq!(
+            rank_tickers(&tickers, TickerSortBy::QuoteVolume, 0).len(),
+            1
+        );
+    }
+}
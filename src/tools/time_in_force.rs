@@ -0,0 +1,94 @@
+//! Time-in-force rules for placed orders
+//!
+//! Binance rejects a LIMIT order without a `timeInForce` (-1102) and a MARKET
+//! order with one (-1106), and an unknown value fails with -1115. `place_order`
+//! resolves the parameter here before anything is submitted: LIMIT-style
+//! orders default to `GTC` when it is omitted, and order types that take no
+//! time in force refuse it.
+
+use crate::error::McpError;
+
+/// Time-in-force values accepted by Binance spot orders
+pub const TIME_IN_FORCE_VALUES: &[&str] = &["GTC", "IOC", "FOK"];
+
+/// Time in force applied to LIMIT orders that don't give one
+pub const DEFAULT_TIME_IN_FORCE: &str = "GTC";
+
+/// Order types that rest on the book at a price and need a time in force
+const TIME_IN_FORCE_ORDER_TYPES: &[&str] = &["LIMIT", "STOP_LOSS_LIMIT", "TAKE_PROFIT_LIMIT"];
+
+/// Resolves the `timeInForce` to submit for an order of `order_type`
+///
+/// Returns `Some` value for LIMIT, STOP_LOSS_LIMIT and TAKE_PROFIT_LIMIT
+/// orders ([`DEFAULT_TIME_IN_FORCE`] when `time_in_force` is omitted) and
+/// `None` for every other type. Values are matched case-insensitively.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` if `time_in_force` is not one of
+/// [`TIME_IN_FORCE_VALUES`], or is given for an order type that takes none
+/// (MARKET, LIMIT_MAKER, STOP_LOSS, TAKE_PROFIT)
+pub fn resolve_time_in_force(
+    order_type: &str,
+    time_in_force: Option<&str>,
+) -> Result<Option<&'static str>, McpError> {
+    let requested = match time_in_force.map(str::trim) {
+        None => None,
+        Some(value) => Some(
+            TIME_IN_FORCE_VALUES
+                .iter()
+                .copied()
+                .find(|allowed| allowed.eq_ignore_ascii_case(value))
+                .ok_or_else(|| {
+                    McpError::InvalidRequest(format!(
+                        "Invalid time_in_force '{}'. Use one of: {}",
+                        value,
+                        TIME_IN_FORCE_VALUES.join(", ")
+                    ))
+                })?,
+        ),
+    };
+
+    let takes_time_in_force = TIME_IN_FORCE_ORDER_TYPES
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(order_type.trim()));
+    match (takes_time_in_force, requested) {
+        (true, requested) => Ok(Some(requested.unwrap_or(DEFAULT_TIME_IN_FORCE))),
+        (false, None) => Ok(None),
+        (false, Some(value)) => Err(McpError::InvalidRequest(format!(
+            "time_in_force {} is not valid for {} orders. Omit it, or use a LIMIT order",
+            value,
+            order_type.trim().to_ascii_uppercase()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_orders_default_to_gtc() {
+        assert_eq!(resolve_time_in_force("LIMIT", None).unwrap(), Some("GTC"));
+        assert_eq!(
+            resolve_time_in_force("LIMIT", Some("ioc")).unwrap(),
+            Some("IOC")
+        );
+        assert_eq!(
+            resolve_time_in_force("STOP_LOSS_LIMIT", Some("FOK")).unwrap(),
+            Some("FOK")
+        );
+        assert_eq!(resolve_time_in_force("MARKET", None).unwrap(), None);
+        assert_eq!(resolve_time_in_force("LIMIT_MAKER", None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_time_in_force_is_rejected() {
+        let err = resolve_time_in_force("MARKET", Some("GTC")).unwrap_err();
+        assert!(err.to_string().contains("not valid for MARKET orders"));
+
+        let err = resolve_time_in_force("LIMIT", Some("GTD")).unwrap_err();
+        assert!(err.to_string().contains("Invalid time_in_force 'GTD'"));
+
+        assert!(resolve_time_in_force("LIMIT_MAKER", Some("IOC")).is_err());
+    }
+}
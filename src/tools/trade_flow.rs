@@ -0,0 +1,232 @@
+//! Taker flow summary of the recent trade tape
+//!
+//! Aggregates `/api/v3/trades` into buy vs sell volume, trade size and net
+//! taker flow, so agents get the gist of the tape without reading hundreds of
+//! raw trades. REST-only, unlike the orderbook analytics order flow.
+//!
+//! Binance flags each trade with `isBuyerMaker`: `true` means the buyer was
+//! resting on the book, so the aggressive (taker) side sold.
+
+use serde::{Deserialize, Serialize};
+
+use crate::binance::types::Trade;
+
+/// Taker side of a trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TakerSide {
+    /// Taker bought (lifted the offer)
+    Buy,
+    /// Taker sold (hit the bid)
+    Sell,
+}
+
+impl TakerSide {
+    /// Taker side of a trade from its `isBuyerMaker` flag
+    pub fn of(trade: &Trade) -> Self {
+        if trade.is_buyer_maker {
+            TakerSide::Sell
+        } else {
+            TakerSide::Buy
+        }
+    }
+}
+
+/// A `get_recent_trades` entry: the Binance trade plus its taker side
+///
+/// `isBuyerMaker` is kept for compatibility; `side` states the same fact
+/// without the inversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentTrade {
+    /// Trade as returned by Binance
+    #[serde(flatten)]
+    pub trade: Trade,
+    /// Taker side: `buy` when the taker lifted the offer, `sell` when it hit the bid
+    pub side: TakerSide,
+}
+
+impl From<Trade> for RecentTrade {
+    fn from(trade: Trade) -> Self {
+        let side = TakerSide::of(&trade);
+        RecentTrade { trade, side }
+    }
+}
+
+/// Largest trade in the summarized window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LargestTrade {
+    /// Trade ID
+    pub id: i64,
+    /// Price
+    pub price: String,
+    /// Base quantity
+    pub qty: String,
+    /// Taker side
+    pub side: TakerSide,
+    /// Trade time (ms)
+    pub time: i64,
+}
+
+/// Buy/sell breakdown of the last N trades
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeFlowSummary {
+    /// Trading pair symbol
+    pub symbol: String,
+    /// Number of trades summarized
+    pub trade_count: usize,
+    /// Time of the oldest trade (ms), `None` without trades
+    pub first_trade_time: Option<i64>,
+    /// Time of the newest trade (ms), `None` without trades
+    pub last_trade_time: Option<i64>,
+    /// Base volume bought by takers
+    pub buy_volume: f64,
+    /// Base volume sold by takers
+    pub sell_volume: f64,
+    /// Quote volume bought by takers
+    pub buy_quote_volume: f64,
+    /// Quote volume sold by takers
+    pub sell_quote_volume: f64,
+    /// `buy_volume - sell_volume`; positive means net buying pressure
+    pub net_taker_flow: f64,
+    /// Share of base volume bought by takers (0-1), `None` without volume
+    pub buy_ratio: Option<f64>,
+    /// Mean base quantity per trade
+    pub avg_trade_size: f64,
+    /// Trade with the largest base quantity
+    pub largest_trade: Option<LargestTrade>,
+}
+
+/// Summarizes a list of trades
+///
+/// Quantities that don't parse count as zero. Trades may be in any order;
+/// the window bounds come from the earliest and latest trade times.
+pub fn summarize_trades(symbol: &str, trades: &[Trade]) -> TradeFlowSummary {
+    let parse = |value: &str| value.parse::<f64>().unwrap_or(0.0);
+
+    let mut buy_volume = 0.0;
+    let mut sell_volume = 0.0;
+    let mut buy_quote_volume = 0.0;
+    let mut sell_quote_volume = 0.0;
+    let mut largest: Option<(&Trade, f64)> = None;
+
+    for trade in trades {
+        let qty = parse(&trade.qty);
+        let quote_qty = parse(&trade.quote_qty);
+        match TakerSide::of(trade) {
+            TakerSide::Buy => {
+                buy_volume += qty;
+                buy_quote_volume += quote_qty;
+            }
+            TakerSide::Sell => {
+                sell_volume += qty;
+                sell_quote_volume += quote_qty;
+            }
+        }
+        if largest.map_or(true, |(_, max)| qty > max) {
+            largest = Some((trade, qty));
+        }
+    }
+
+    let total_volume = buy_volume + sell_volume;
+    TradeFlowSummary {
+        symbol: symbol.to_string(),
+        trade_count: trades.len(),
+        first_trade_time: trades.iter().map(|t| t.time).min(),
+        last_trade_time: trades.iter().map(|t| t.time).max(),
+        buy_volume,
+        sell_volume,
+        buy_quote_volume,
+        sell_quote_volume,
+        net_taker_flow: buy_volume - sell_volume,
+        buy_ratio: (total_volume > 0.0).then(|| buy_volume / total_volume),
+        avg_trade_size: if trades.is_empty() {
+            0.0
+        } else {
+            total_volume / trades.len() as f64
+        },
+        largest_trade: largest.map(|(trade, _)| LargestTrade {
+            id: trade.id,
+            price: trade.price.clone(),
+            qty: trade.qty.clone(),
+            side: TakerSide::of(trade),
+            time: trade.time,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(id: i64, price: &str, qty: &str, is_buyer_maker: bool) -> Trade {
+        let quote_qty = price.parse::<f64>().unwrap() * qty.parse::<f64>().unwrap();
+        Trade {
+            id,
+            price: price.to_string(),
+            qty: qty.to_string(),
+            quote_qty: quote_qty.to_string(),
+            time: 1_700_000_000_000 + id * 1000,
+            is_buyer_maker,
+            is_best_match: true,
+        }
+    }
+
+    #[test]
+    fn test_summary_splits_taker_buys_and_sells() {
+        // Three taker buys (3.0 BTC) and two taker sells (1.5 BTC)
+        let trades = vec![
+            trade(1, "100", "1.0", false),
+            trade(2, "101", "0.5", true),
+            trade(3, "102", "1.5", false),
+            trade(4, "101", "1.0", true),
+            trade(5, "100", "0.5", false),
+        ];
+
+        let summary = summarize_trades("BTCUSDT", &trades);
+
+        assert_eq!(summary.trade_count, 5);
+        assert_eq!(summary.first_trade_time, Some(1_700_000_001_000));
+        assert_eq!(summary.last_trade_time, Some(1_700_000_005_000));
+        assert_eq!(summary.buy_volume, 3.0);
+        assert_eq!(summary.sell_volume, 1.5);
+        assert_eq!(summary.net_taker_flow, 1.5);
+        assert_eq!(summary.buy_quote_volume, 100.0 + 153.0 + 50.0);
+        assert_eq!(summary.sell_quote_volume, 50.5 + 101.0);
+        assert!((summary.buy_ratio.unwrap() - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(summary.avg_trade_size, 0.9);
+
+        let largest = summary.largest_trade.unwrap();
+        assert_eq!(largest.id, 3);
+        assert_eq!(largest.side, TakerSide::Buy);
+    }
+
+    #[test]
+    fn test_summary_of_no_trades() {
+        let summary = summarize_trades("BTCUSDT", &[]);
+
+        assert_eq!(summary.trade_count, 0);
+        assert_eq!(summary.first_trade_time, None);
+        assert_eq!(summary.buy_ratio, None);
+        assert_eq!(summary.avg_trade_size, 0.0);
+        assert!(summary.largest_trade.is_none());
+    }
+
+    #[test]
+    fn test_recent_trade_side() {
+        // Buyer resting on the book: the taker sold
+        let sold = serde_json::to_value(RecentTrade::from(trade(1, "100", "1.0", true))).unwrap();
+        assert_eq!(sold["isBuyerMaker"], true);
+        assert_eq!(sold["side"], "sell");
+        assert_eq!(sold["quoteQty"], "100");
+
+        let bought =
+            serde_json::to_value(RecentTrade::from(trade(2, "100", "1.0", false))).unwrap();
+        assert_eq!(bought["isBuyerMaker"], false);
+        assert_eq!(bought["side"], "buy");
+    }
+
+    #[test]
+    fn test_taker_side_serializes_lowercase() {
+        assert_eq!(serde_json::to_value(TakerSide::Sell).unwrap(), "sell");
+    }
+}
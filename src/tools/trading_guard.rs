@@ -0,0 +1,167 @@
+//! Mainnet trading guard for order tools
+//!
+//! `configure_credentials` accepts mainnet keys, so an agent could place real
+//! orders by accident. `place_order` and `cancel_order` refuse to run against
+//! mainnet unless the operator sets `MAINNET_TRADING_ENABLED`; they return a
+//! structured `MAINNET_TRADING_DISABLED` error instead. Testnet is always allowed.
+//!
+//! Operators can also restrict which pairs may be traded with
+//! `TRADING_SYMBOL_WHITELIST`; orders for other symbols return
+//! `SYMBOL_NOT_ALLOWED` before reaching the exchange. Market data tools are
+//! not affected.
+
+use serde_json::json;
+use std::collections::BTreeSet;
+
+/// Error code returned when a mainnet order is blocked
+pub const MAINNET_TRADING_DISABLED: &str = "MAINNET_TRADING_DISABLED";
+
+/// Error code returned when an order targets a symbol outside the whitelist
+pub const SYMBOL_NOT_ALLOWED: &str = "SYMBOL_NOT_ALLOWED";
+
+/// Symbols order tools may trade (`TRADING_SYMBOL_WHITELIST`)
+///
+/// Empty means every symbol is allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolWhitelist {
+    symbols: BTreeSet<String>,
+}
+
+impl SymbolWhitelist {
+    /// Parses a comma-separated symbol list; entries are trimmed and uppercased
+    pub fn parse(value: Option<&str>) -> Self {
+        let symbols = value
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_ascii_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+        Self { symbols }
+    }
+
+    /// Reads `TRADING_SYMBOL_WHITELIST`
+    pub fn from_env() -> Self {
+        Self::parse(std::env::var("TRADING_SYMBOL_WHITELIST").ok().as_deref())
+    }
+
+    /// Whether trading is restricted to a list of symbols
+    pub fn is_restricted(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+
+    /// Allowed symbols, sorted
+    pub fn symbols(&self) -> Vec<&str> {
+        self.symbols.iter().map(String::as_str).collect()
+    }
+
+    /// Whether `symbol` (normalized, uppercase) may be traded
+    pub fn allows(&self, symbol: &str) -> bool {
+        self.symbols.is_empty() || self.symbols.contains(symbol)
+    }
+
+    /// Checks whether `tool` may send an order for `symbol`
+    ///
+    /// # Returns
+    /// `None` when allowed, otherwise the `SYMBOL_NOT_ALLOWED` error payload
+    pub fn check(&self, tool: &str, symbol: &str) -> Option<serde_json::Value> {
+        if self.allows(symbol) {
+            return None;
+        }
+
+        let allowed = self.symbols();
+        Some(json!({
+            "error_code": SYMBOL_NOT_ALLOWED,
+            "message": format!(
+                "{} was not executed: trading {} is not allowed on this server. Allowed symbols: {}.",
+                tool,
+                symbol,
+                allowed.join(", ")
+            ),
+            "symbol": symbol,
+            "allowed_symbols": allowed,
+        }))
+    }
+}
+
+/// Returns true if `base_url` points at Binance production (`*.binance.com`)
+///
+/// Testnet (`testnet.binance.vision`) and local mock servers are not mainnet.
+pub fn is_mainnet_url(base_url: &str) -> bool {
+    reqwest::Url::parse(base_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+        .is_some_and(|host| host == "binance.com" || host.ends_with(".binance.com"))
+}
+
+/// Checks whether `tool` may send an order request to `base_url`
+///
+/// # Returns
+/// `None` when allowed, otherwise the `MAINNET_TRADING_DISABLED` error payload
+pub fn check_mainnet_trading(
+    tool: &str,
+    base_url: &str,
+    mainnet_trading_enabled: bool,
+) -> Option<serde_json::Value> {
+    if mainnet_trading_enabled || !is_mainnet_url(base_url) {
+        return None;
+    }
+
+    Some(json!({
+        "error_code": MAINNET_TRADING_DISABLED,
+        "message": format!(
+            "{} was not executed: these credentials trade on Binance mainnet, and mainnet trading is disabled on this server. \
+             Use testnet credentials, or ask the operator to set MAINNET_TRADING_ENABLED=true and restart the server.",
+            tool
+        )
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mainnet_url() {
+        assert!(is_mainnet_url("https://api.binance.com"));
+        assert!(is_mainnet_url("https://api3.binance.com/"));
+        assert!(!is_mainnet_url("https://testnet.binance.vision"));
+        assert!(!is_mainnet_url("http://127.0.0.1:8080"));
+        assert!(!is_mainnet_url("https://notbinance.com"));
+        assert!(!is_mainnet_url("not a url"));
+    }
+
+    #[test]
+    fn test_mainnet_blocked_unless_enabled() {
+        let blocked = check_mainnet_trading("place_order", "https://api.binance.com", false)
+            .expect("mainnet should be blocked by default");
+        assert_eq!(blocked["error_code"], MAINNET_TRADING_DISABLED);
+        assert!(blocked["message"]
+            .as_str()
+            .unwrap()
+            .contains("MAINNET_TRADING_ENABLED"));
+
+        assert!(check_mainnet_trading("place_order", "https://api.binance.com", true).is_none());
+        assert!(
+            check_mainnet_trading("cancel_order", "https://testnet.binance.vision", false)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_symbol_whitelist() {
+        let whitelist = SymbolWhitelist::parse(Some(" btcusdt, ETHUSDT ,,"));
+        assert!(whitelist.is_restricted());
+        assert!(whitelist.check("place_order", "BTCUSDT").is_none());
+        assert!(whitelist.check("cancel_order", "ETHUSDT").is_none());
+
+        let blocked = whitelist
+            .check("place_order", "DOGEUSDT")
+            .expect("symbol outside the whitelist should be blocked");
+        assert_eq!(blocked["error_code"], SYMBOL_NOT_ALLOWED);
+        assert_eq!(blocked["allowed_symbols"], json!(["BTCUSDT", "ETHUSDT"]));
+
+        // Unset or empty allows everything
+        assert!(SymbolWhitelist::parse(None).allows("DOGEUSDT"));
+        assert!(!SymbolWhitelist::parse(Some(" , ")).is_restricted());
+    }
+}
@@ -0,0 +1,118 @@
+//! Trailing-stop order helper
+//!
+//! Binance expresses trailing stops as a `trailingDelta` in basis points on a
+//! `STOP_LOSS` or `TAKE_PROFIT` order. Traders think in percent ("trail 2%
+//! behind the high"), so this converts the percent and picks the order type.
+//!
+//! An optional activation price becomes the order's `stopPrice`: trailing only
+//! starts once the market reaches it. Binance requires `TAKE_PROFIT` when that
+//! price is on the profit side of the market (above it for SELL, below it for
+//! BUY) and `STOP_LOSS` otherwise. Without an activation price, trailing starts
+//! immediately on a `STOP_LOSS`.
+
+use crate::error::McpError;
+
+/// Smallest `trailingDelta` Binance accepts, in basis points (0.1%)
+pub const MIN_TRAILING_DELTA_BPS: u32 = 10;
+
+/// Largest `trailingDelta` Binance accepts, in basis points (20%)
+pub const MAX_TRAILING_DELTA_BPS: u32 = 2000;
+
+/// Converts a trailing percent into a `trailingDelta` (bps = percent * 100)
+///
+/// Fractions of a basis point are rounded to the nearest one.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` outside
+/// [`MIN_TRAILING_DELTA_BPS`]..=[`MAX_TRAILING_DELTA_BPS`] (0.1% to 20%)
+pub fn trailing_delta_bps(trailing_percent: f64) -> Result<u32, McpError> {
+    let bps = (trailing_percent * 100.0).round();
+    if !bps.is_finite()
+        || bps < f64::from(MIN_TRAILING_DELTA_BPS)
+        || bps > f64::from(MAX_TRAILING_DELTA_BPS)
+    {
+        return Err(McpError::InvalidRequest(format!(
+            "trailing_percent {} is out of range. Use {}% to {}%",
+            trailing_percent,
+            f64::from(MIN_TRAILING_DELTA_BPS) / 100.0,
+            f64::from(MAX_TRAILING_DELTA_BPS) / 100.0
+        )));
+    }
+    Ok(bps as u32)
+}
+
+/// Picks the order type for a trailing stop
+///
+/// `STOP_LOSS` without an activation price; with one, `TAKE_PROFIT` if it is
+/// above `market_price` for SELL or below it for BUY.
+///
+/// # Errors
+/// Returns `McpError::InvalidRequest` if `side` is not BUY or SELL
+pub fn trailing_stop_type(
+    side: &str,
+    activation_price: Option<f64>,
+    market_price: f64,
+) -> Result<&'static str, McpError> {
+    let profit_side = match (side, activation_price) {
+        ("SELL", Some(activation)) => activation > market_price,
+        ("BUY", Some(activation)) => activation < market_price,
+        ("SELL" | "BUY", None) => false,
+        _ => {
+            return Err(McpError::InvalidRequest(format!(
+                "Invalid side '{}'. Use BUY or SELL",
+                side
+            )))
+        }
+    };
+    Ok(if profit_side {
+        "TAKE_PROFIT"
+    } else {
+        "STOP_LOSS"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_delta_bps() {
+        assert_eq!(trailing_delta_bps(2.0).unwrap(), 200);
+        assert_eq!(trailing_delta_bps(0.1).unwrap(), 10);
+        assert_eq!(trailing_delta_bps(20.0).unwrap(), 2000);
+        assert_eq!(trailing_delta_bps(1.234).unwrap(), 123);
+
+        for bad in [0.0, 0.05, 20.5, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(
+                trailing_delta_bps(bad).is_err(),
+                "{} should be rejected",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_trailing_stop_type() {
+        assert_eq!(
+            trailing_stop_type("SELL", None, 100.0).unwrap(),
+            "STOP_LOSS"
+        );
+        assert_eq!(
+            trailing_stop_type("SELL", Some(110.0), 100.0).unwrap(),
+            "TAKE_PROFIT"
+        );
+        assert_eq!(
+            trailing_stop_type("SELL", Some(90.0), 100.0).unwrap(),
+            "STOP_LOSS"
+        );
+        assert_eq!(
+            trailing_stop_type("BUY", Some(90.0), 100.0).unwrap(),
+            "TAKE_PROFIT"
+        );
+        assert_eq!(
+            trailing_stop_type("BUY", Some(110.0), 100.0).unwrap(),
+            "STOP_LOSS"
+        );
+        assert!(trailing_stop_type("HOLD", None, 100.0).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Combined market overview for `get_watchlist_overview`
+//!
+//! The "dashboard" view agents would otherwise build from many `get_ticker`
+//! calls: for every symbol of a watchlist the 24hr change and the best
+//! bid/ask are fetched and folded into one compact row.
+//! Symbols are fetched with [`fetch_many`] (`MULTI_FETCH_CONCURRENCY` at a
+//! time), and a symbol that fails (unknown pair, malformed name) becomes an
+//! entry in `failed` instead of failing the whole overview.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::future::Future;
+use std::str::FromStr;
+
+use crate::binance::fetch_many::fetch_many;
+use crate::binance::normalize_symbol;
+use crate::binance::types::{BookTicker, Ticker24hr};
+use crate::error::{map_binance_err, tool_error_code, McpError};
+
+/// Maximum symbols accepted per `get_watchlist_overview` call
+pub const MAX_WATCHLIST_SYMBOLS: usize = 20;
+
+/// Decimal places of `spread_bps`
+const SPREAD_BPS_DP: u32 = 2;
+
+/// One symbol of the overview
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistRow {
+    /// Trading pair
+    pub symbol: String,
+    /// Last traded price
+    pub price: String,
+    /// 24-hour price change in percent
+    pub change_pct: String,
+    /// Best bid/ask spread in basis points of the mid price (null when one side is empty)
+    pub spread_bps: Option<String>,
+}
+
+/// A symbol the overview could not include
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedSymbol {
+    /// Symbol as requested
+    pub symbol: String,
+    /// Machine-readable code (e.g., INVALID_SYMBOL, INVALID_PARAMS, RATE_LIMITED)
+    pub error_code: String,
+    /// Error message
+    pub error: String,
+}
+
+/// Response of `get_watchlist_overview`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistOverview {
+    /// Rows sorted by 24-hour change, biggest gainer first
+    pub rows: Vec<WatchlistRow>,
+    /// Symbols that were skipped, in request order
+    pub failed: Vec<FailedSymbol>,
+}
+
+/// Normalizes the requested symbols, dropping blanks and repeats while keeping order
+///
+/// Symbols that can't be normalized are returned as failures without a request.
+pub fn prepare_watchlist(symbols: &[String]) -> (Vec<String>, Vec<FailedSymbol>) {
+    let mut seen = HashSet::new();
+    let mut valid = Vec::new();
+    let mut failed = Vec::new();
+
+    for requested in symbols.iter().map(|s| s.trim()).filter(|s| !s.is_empty()) {
+        match normalize_symbol(requested) {
+            Ok(symbol) => {
+                if seen.insert(symbol.clone()) {
+                    valid.push(symbol);
+                }
+            }
+            Err(err) => {
+                let error = err.to_string();
+                failed.push(FailedSymbol {
+                    symbol: requested.to_string(),
+                    error_code: tool_error_code(&err.into()),
+                    error,
+                });
+            }
+        }
+    }
+    (valid, failed)
+}
+
+/// Spread between the best bid and ask in basis points of their mid price
+///
+/// Returns `None` when either side is missing (zero) or unparseable.
+pub fn spread_bps(book: &BookTicker) -> Option<Decimal> {
+    let bid = Decimal::from_str(&book.bid_price).ok()?;
+    let ask = Decimal::from_str(&book.ask_price).ok()?;
+    bid_ask_spread_bps(bid, ask)
+}
+
+/// `(ask - bid) / mid` in basis points, rounded to 2 decimal places
+///
+/// Returns `None` unless both prices are positive.
+pub fn bid_ask_spread_bps(bid: Decimal, ask: Decimal) -> Option<Decimal> {
+    if bid <= Decimal::ZERO || ask <= Decimal::ZERO {
+        return None;
+    }
+    let mid = (bid + ask) / Decimal::TWO;
+    Some(
+        ((ask - bid) / mid * Decimal::from(10_000))
+            .round_dp_with_strategy(SPREAD_BPS_DP, RoundingStrategy::MidpointAwayFromZero)
+            .normalize(),
+    )
+}
+
+/// Builds the overview row of one symbol
+pub fn watchlist_row(ticker: &Ticker24hr, book: &BookTicker) -> WatchlistRow {
+    WatchlistRow {
+        symbol: ticker.symbol.clone(),
+        price: ticker.last_price.clone(),
+        change_pct: ticker.price_change_percent.clone(),
+        spread_bps: spread_bps(book).map(|bps| bps.to_string()),
+    }
+}
+
+/// Fetches every symbol with `fetch`, at most `concurrency` at a time
+///
+/// `failed` starts with the failures from [`prepare_watchlist`]; fetch errors
+/// are appended in request order.
+pub async fn overview_each<F, Fut>(
+    symbols: Vec<String>,
+    mut failed: Vec<FailedSymbol>,
+    concurrency: usize,
+    fetch: F,
+) -> WatchlistOverview
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<(Ticker24hr, BookTicker), McpError>>,
+{
+    let results = fetch_many(symbols, concurrency, fetch).await;
+
+    let mut rows = Vec::new();
+    for (symbol, result) in results {
+        match result {
+            Ok((ticker, book)) => rows.push(watchlist_row(&ticker, &book)),
+            Err(err) => {
+                let error = err.to_string();
+                failed.push(FailedSymbol {
+                    symbol,
+                    error_code: tool_error_code(&map_binance_err(err)),
+                    error,
+                });
+            }
+        }
+    }
+
+    // Unparseable changes (never sent by Binance) sort last
+    rows.sort_by_key(|row| Reverse(Decimal::from_str(&row.change_pct).unwrap_or(Decimal::MIN)));
+
+    WatchlistOverview { rows, failed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binance::fetch_many::DEFAULT_MULTI_FETCH_CONCURRENCY;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn ticker(symbol: &str, last_price: &str, change_pct: &str) -> Ticker24hr {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "priceChange": "0",
+            "priceChangePercent": change_pct,
+            "weightedAvgPrice": last_price,
+            "prevClosePrice": last_price,
+            "lastPrice": last_price,
+            "lastQty": "1",
+            "bidPrice": last_price,
+            "askPrice": last_price,
+            "openPrice": last_price,
+            "highPrice": last_price,
+            "lowPrice": last_price,
+            "volume": "1",
+            "quoteVolume": "1",
+            "openTime": 0,
+            "closeTime": 0,
+            "firstId": 0,
+            "lastId": 0,
+            "count": 0
+        }))
+        .unwrap()
+    }
+
+    fn book(symbol: &str, bid: &str, ask: &str) -> BookTicker {
+        BookTicker {
+            symbol: symbol.to_string(),
+            bid_price: bid.to_string(),
+            bid_qty: "1".to_string(),
+            ask_price: ask.to_string(),
+            ask_qty: "1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_prepare_watchlist() {
+        let requested = ["btc/usdt", "BTCUSDT", " ", "not a symbol!", "ethusdt"]
+            .map(String::from)
+            .to_vec();
+        let (valid, failed) = prepare_watchlist(&requested);
+
+        assert_eq!(valid, vec!["BTCUSDT", "ETHUSDT"]);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].symbol, "not a symbol!");
+        assert_eq!(failed[0].error_code, "INVALID_SYMBOL");
+    }
+
+    #[test]
+    fn test_spread_bps() {
+        // (100.1 - 99.9) / 100 * 10_000 = 20
+        assert_eq!(
+            spread_bps(&book("X", "99.9", "100.1")),
+            Some(Decimal::from(20))
+        );
+        // 0.01 / 65000.005 * 10_000 = 0.00153.. rounds to 0
+        assert_eq!(
+            spread_bps(&book("X", "65000.00", "65000.01")),
+            Some(Decimal::ZERO)
+        );
+        assert_eq!(
+            spread_bps(&book("X", "1.0000", "1.0003")).map(|d| d.to_string()),
+            Some("3".to_string())
+        );
+        assert_eq!(spread_bps(&book("X", "0.00000000", "1.5")), None);
+        assert_eq!(spread_bps(&book("X", "abc", "1.5")), None);
+    }
+
+    #[tokio::test]
+    async fn test_overview_sorts_rows_and_reports_failures() {
+        let (symbols, failed) =
+            prepare_watchlist(&["ETHUSDT", "FAKEUSDT", "BTCUSDT", "bad!"].map(String::from));
+
+        let overview = overview_each(
+            symbols,
+            failed,
+            DEFAULT_MULTI_FETCH_CONCURRENCY,
+            |symbol| async move {
+                match symbol.as_str() {
+                    "BTCUSDT" => Ok((
+                        ticker("BTCUSDT", "65000.00", "2.50"),
+                        book("BTCUSDT", "64999.99", "65000.00"),
+                    )),
+                    "ETHUSDT" => Ok((
+                        ticker("ETHUSDT", "3000.00", "-1.20"),
+                        book("ETHUSDT", "2999.00", "3001.00"),
+                    )),
+                    _ => Err(McpError::InvalidRequest(
+                        "Invalid symbol. (-1121)".to_string(),
+                    )),
+                }
+            },
+        )
+        .await;
+
+        let symbols: Vec<&str> = overview.rows.iter().map(|r| r.symbol.as_str()).collect();
+        assert_eq!(symbols, vec!["BTCUSDT", "ETHUSDT"]);
+        assert_eq!(overview.rows[1].spread_bps.as_deref(), Some("6.67"));
+
+        let failed: Vec<&str> = overview.failed.iter().map(|f| f.symbol.as_str()).collect();
+        assert_eq!(failed, vec!["bad!", "FAKEUSDT"]);
+        assert_eq!(overview.failed[1].error_code, "INVALID_PARAMS");
+        assert!(overview.failed[1].error.contains("-1121"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let symbols: Vec<String> = (0..12).map(|i| format!("SYM{i}USDT")).collect();
+
+        let overview = overview_each(symbols, Vec::new(), 3, |symbol| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok((ticker(&symbol, "1", "0"), book(&symbol, "1", "1")))
+            }
+        })
+        .await;
+
+        assert_eq!(overview.rows.len(), 12);
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+}
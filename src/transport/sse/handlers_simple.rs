@@ -6,7 +6,10 @@
 use axum::{
     extract::State,
     http::{HeaderMap, StatusCode},
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     Json,
 };
 use serde_json::{json, Value};
@@ -14,10 +17,16 @@ use std::sync::Arc;
 
 use super::session::SessionManager;
 use crate::binance::BinanceClient;
+use crate::error::tool_error_code;
+use crate::server::instrumentation::instrument_tool_call;
+use crate::server::pagination::{paginate, DEFAULT_PAGE_SIZE};
 use crate::server::tool_router::*; // Import all parameter types
 use crate::server::BinanceServer;
+use crate::server::{response_limit, response_meta};
 use crate::tools::chatgpt::{fetch_symbol_details, search_symbols};
 use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, GetPromptResult, ListPromptsResult};
+use rmcp::ErrorData;
 
 /// Shared state for SSE handlers
 #[derive(Clone)]
@@ -28,6 +37,10 @@ pub struct SseState {
 }
 
 impl SseState {
+    /// Creates handler state
+    ///
+    /// Pass the server's own `session_manager` (it is cheaply cloneable) so that
+    /// session credentials and notifications are shared with the tool handlers.
     pub fn new(session_manager: SessionManager, mcp_server: BinanceServer) -> Self {
         Self {
             session_manager,
@@ -41,9 +54,11 @@ impl SseState {
 ///
 /// Streamable HTTP transport (March 2025 spec):
 /// - First request (initialize) creates session, returns Mcp-Session-Id header
+/// - Initialize with a valid Mcp-Session-Id reuses that session (and its credentials)
 /// - Subsequent requests must include Mcp-Session-Id header
 /// - Returns JSON-RPC response as application/json (default)
 /// - Can return text/event-stream for long-running operations (future)
+/// - Accepts JSON-RPC batches (arrays); responses are returned as an array in request order
 pub async fn message_post(
     State(state): State<SseState>,
     headers: HeaderMap,
@@ -59,8 +74,21 @@ pub async fn message_post(
         .and_then(|h| h.to_str().ok())
         .map(|s| s.to_string());
 
-    let connection_id = if is_initialize {
-        // Initialize: Create new session (even if Mcp-Session-Id present)
+    // Re-initializing with a live session (e.g., after a client reconnect) reuses it,
+    // keeping any configured credentials, instead of leaking a new session
+    let reusable_session = match session_id.as_ref() {
+        Some(id) if is_initialize && state.session_manager.is_valid_connection(id).await => {
+            Some(id.clone())
+        }
+        _ => None,
+    };
+
+    let connection_id = if let Some(id) = reusable_session {
+        state.session_manager.update_activity(&id).await;
+        tracing::info!(session_id = %id, "Re-initialized existing MCP session");
+        id
+    } else if is_initialize {
+        // Initialize without a valid Mcp-Session-Id: create new session
         let addr = "127.0.0.1:0".parse().unwrap();
         match state.session_manager.register_connection(addr, None).await {
             Some(id) => {
@@ -122,9 +150,153 @@ pub async fn message_post(
         }
     };
 
-    // For MVP: Process JSON-RPC request synchronously and return as SSE event
-    // This is a simplified implementation - proper async SSE streaming in Phase 6
+    // A JSON array is a batch: each element is routed independently and the
+    // responses come back in request order (session was validated above)
+    let json_rpc_response = match &payload {
+        Value::Array(requests) if requests.is_empty() => {
+            json_rpc_error(Value::Null, -32600, "Invalid Request: empty batch")
+        }
+        Value::Array(requests) => {
+            let responses = futures_util::future::join_all(
+                requests
+                    .iter()
+                    .map(|request| handle_batch_element(&state, &connection_id, request)),
+            )
+            .await;
+            Value::Array(responses)
+        }
+        request => handle_rpc_request(&state, &connection_id, request).await,
+    };
+
+    // Streamable HTTP transport (March 2025 spec):
+    // Check Accept header to determine response format
+    let accept = headers
+        .get(axum::http::header::ACCEPT)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("application/json");
+
+    // Build response based on Accept header
+    let mut response = if accept.contains("text/event-stream") {
+        // Client wants SSE stream - return as SSE event
+        let sse_event = format!(
+            "data: {}\n\n",
+            serde_json::to_string(&json_rpc_response).unwrap()
+        );
+        (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "text/event-stream")],
+            sse_event,
+        )
+            .into_response()
+    } else {
+        // Client wants JSON (default) - return plain JSON-RPC response
+        (StatusCode::OK, Json(json_rpc_response)).into_response()
+    };
+
+    // For initialize requests, add Mcp-Session-Id header (Streamable HTTP spec)
+    if is_initialize {
+        response
+            .headers_mut()
+            .insert("Mcp-Session-Id", connection_id.parse().unwrap());
+        tracing::info!(session_id = %connection_id, "Returned Mcp-Session-Id in initialize response");
+    }
+
+    response
+}
+
+/// Builds a JSON-RPC error response
+fn json_rpc_error(id: Value, code: i64, message: &str) -> Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": code,
+            "message": message
+        }
+    })
+}
+
+/// Builds the MCP error envelope for a failed tool call
+///
+/// `{"content": [{"type": "text", "text": ...}], "isError": true}` where the text
+/// is `{"error_code": ..., "message": ...}`, plus `details` when available.
+fn tool_error(error_code: &str, message: impl Into<String>) -> Value {
+    tool_error_with_details(error_code, message.into(), None)
+}
+
+fn tool_error_with_details(error_code: &str, message: String, details: Option<Value>) -> Value {
+    let mut body = json!({
+        "error_code": error_code,
+        "message": message,
+    });
+    if let Some(details) = details {
+        body["details"] = details;
+    }
+    json!({
+        "content": [{"type": "text", "text": body.to_string()}],
+        "isError": true
+    })
+}
+
+/// Envelope for arguments that don't deserialize into the tool's parameters
+fn invalid_params(err: &serde_json::Error) -> Value {
+    tool_error("INVALID_PARAMS", format!("Invalid parameters: {}", err))
+}
+
+/// Binds a credentialed tool call to the calling session
+///
+/// Any `session_id` the client put in the arguments is overwritten, so a call
+/// can't borrow another session's credentials or be audited under its name.
+fn with_session_id(arguments: &Value, connection_id: &str) -> Value {
+    let mut arguments = arguments.clone();
+    if let Some(obj) = arguments.as_object_mut() {
+        obj.insert("session_id".to_string(), Value::from(connection_id));
+    }
+    arguments
+}
+
+/// Converts a tool handler result into the MCP result envelope
+///
+/// Handler errors become a [`tool_error`] carrying their structured error code.
+/// Results whose body is a structured refusal (an `error_code` such as
+/// `CREDENTIALS_NOT_CONFIGURED`) are flagged `isError: true` as well.
+fn tool_result(result: Result<CallToolResult, ErrorData>) -> Value {
+    match result {
+        Ok(result) => {
+            let refused = result
+                .content
+                .first()
+                .and_then(|c| c.as_text())
+                .and_then(|t| serde_json::from_str::<Value>(&t.text).ok())
+                .is_some_and(|body| body.get("error_code").is_some_and(Value::is_string));
+
+            let mut value = serde_json::to_value(&result).unwrap();
+            if refused {
+                value["isError"] = Value::Bool(true);
+            }
+            value
+        }
+        Err(e) => tool_error_with_details(&tool_error_code(&e), e.message.to_string(), e.data),
+    }
+}
+
+/// Routes one element of a JSON-RPC batch
+///
+/// Malformed elements and `initialize` (which must not be batched) get an
+/// error response of their own without affecting the rest of the batch.
+async fn handle_batch_element(state: &SseState, connection_id: &str, request: &Value) -> Value {
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    match request.get("method").and_then(|m| m.as_str()) {
+        None => json_rpc_error(id, -32600, "Invalid Request: missing method"),
+        Some("initialize") => {
+            json_rpc_error(id, -32600, "Invalid Request: initialize cannot be batched")
+        }
+        Some(_) => handle_rpc_request(state, connection_id, request).await,
+    }
+}
 
+/// Routes a single JSON-RPC request and builds its response
+async fn handle_rpc_request(state: &SseState, connection_id: &str, payload: &Value) -> Value {
     // Extract method and params from JSON-RPC request
     let method = payload.get("method").and_then(|m| m.as_str()).unwrap_or("");
     let params = payload.get("params").cloned().unwrap_or(Value::Null);
@@ -140,15 +312,21 @@ pub async fn message_post(
     let result = match method {
         "initialize" => {
             // MCP initialize handshake - return server capabilities
+            let protocol_version = state
+                .mcp_server
+                .protocol
+                .negotiate(params.get("protocolVersion").and_then(|v| v.as_str()));
             serde_json::json!({
-                "protocolVersion": "2024-11-05",
+                "protocolVersion": protocol_version,
                 "capabilities": {
-                    "tools": {}
+                    "tools": {},
+                    "prompts": {}
                 },
                 "serverInfo": {
-                    "name": "Binance MCP Server",
+                    "name": state.mcp_server.identity.title,
                     "version": env!("CARGO_PKG_VERSION")
-                }
+                },
+                "instructions": state.mcp_server.identity.instructions
             })
         }
         "tools/list" => {
@@ -196,6 +374,7 @@ pub async fn message_post(
                     "required": ["id"]
                 }
             }));
+            retain_enabled_tools(state, &mut all_tools);
 
             serde_json::json!({
                 "tools": all_tools
@@ -215,351 +394,648 @@ pub async fn message_post(
                 "Calling MCP tool"
             );
 
-            // Route to appropriate tool handler
-            // MCP requires results in content array format
-            match tool_name {
-                "search" => {
-                    // ChatGPT search tool - search trading symbols
-                    let query = arguments
-                        .get("query")
-                        .and_then(|q| q.as_str())
-                        .unwrap_or("");
-
-                    match search_symbols(&state.binance_client, query).await {
-                        Ok(results) => {
-                            // MCP format: wrap in content array with type "text"
-                            let results_json = serde_json::json!({"results": results});
-                            serde_json::json!({
-                                "content": [{
-                                    "type": "text",
-                                    "text": serde_json::to_string(&results_json).unwrap()
-                                }]
-                            })
-                        }
-                        Err(e) => {
-                            serde_json::json!({
-                                "content": [{
-                                    "type": "text",
-                                    "text": format!("{{\"error\": \"Search failed: {}\"}}", e)
-                                }],
-                                "isError": true
-                            })
-                        }
-                    }
-                }
-                "fetch" => {
-                    // ChatGPT fetch tool - get detailed symbol info
-                    let symbol_id = arguments.get("id").and_then(|s| s.as_str()).unwrap_or("");
-
-                    match fetch_symbol_details(&state.binance_client, symbol_id).await {
-                        Ok(details) => {
-                            // MCP format: wrap in content array with type "text"
-                            serde_json::json!({
-                                "content": [{
-                                    "type": "text",
-                                    "text": serde_json::to_string(&details).unwrap()
-                                }]
-                            })
-                        }
-                        Err(e) => {
-                            serde_json::json!({
-                                "content": [{
-                                    "type": "text",
-                                    "text": format!("{{\"error\": \"Fetch failed: {}\"}}", e)
-                                }],
-                                "isError": true
-                            })
-                        }
-                    }
-                }
-                // SDK tools - call methods directly with deserialized parameters
-                "get_server_time" => match state.mcp_server.get_server_time().await {
-                    Ok(result) => serde_json::to_value(&result).unwrap(),
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                "get_ticker" => match serde_json::from_value::<SymbolParam>(arguments.clone()) {
-                    Ok(params) => match state.mcp_server.get_ticker(Parameters(params)).await {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    },
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                "get_klines" => match serde_json::from_value::<KlinesParam>(arguments.clone()) {
-                    Ok(params) => match state.mcp_server.get_klines(Parameters(params)).await {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    },
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                "get_order_book" => {
-                    match serde_json::from_value::<OrderBookParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.get_order_book(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
-                }
-                "get_recent_trades" => {
-                    match serde_json::from_value::<RecentTradesParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.get_recent_trades(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
-                }
-                "get_average_price" => {
-                    match serde_json::from_value::<SymbolParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.get_average_price(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+            let call = instrument_tool_call(
+                tool_name,
+                |result: &Value| result.get("isError").and_then(|v| v.as_bool()) == Some(true),
+                dispatch_tool(state, connection_id, tool_name, arguments),
+            );
+            let max_bytes = state.mcp_server.max_tool_response_bytes;
+            let call = async {
+                let mut result = call.await;
+                response_limit::guard_result_value(tool_name, &mut result, max_bytes);
+                result
+            };
+            if state.mcp_server.response_include_meta {
+                let (mut result, meta) = response_meta::measure(tool_name, call).await;
+                response_meta::wrap_result_value(&mut result, &meta);
+                result
+            } else {
+                call.await
+            }
+        }
+        "prompts/list" => {
+            // Same ordering and cursor pagination as the stdio transport
+            let (prompts, next_cursor) = paginate(
+                state.mcp_server.prompt_router.list_all(),
+                |prompt| prompt.name.as_str(),
+                params.get("cursor").and_then(|c| c.as_str()),
+                DEFAULT_PAGE_SIZE,
+            );
+
+            serde_json::to_value(ListPromptsResult {
+                prompts,
+                next_cursor,
+            })
+            .unwrap()
+        }
+        "prompts/get" => {
+            let prompt_name = params.get("name").and_then(|n| n.as_str()).unwrap_or("");
+            let arguments = params
+                .get("arguments")
+                .cloned()
+                .unwrap_or(Value::Object(Default::default()));
+
+            tracing::info!(
+                connection_id = %connection_id,
+                prompt = %prompt_name,
+                "Rendering MCP prompt"
+            );
+
+            match dispatch_prompt(state, prompt_name, arguments).await {
+                Ok(result) => serde_json::to_value(result).unwrap(),
+                Err(e) => return json_rpc_error(request_id, e.code.0 as i64, &e.message),
+            }
+        }
+        _ => {
+            return json_rpc_error(request_id, -32601, &format!("Method not found: {}", method));
+        }
+    };
+
+    // Build JSON-RPC response
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": request_id,
+        "result": result
+    })
+}
+
+/// Server-initiated notification stream (Streamable HTTP `GET /mcp`)
+///
+/// Clients open `GET /mcp` with `Accept: text/event-stream` and their
+/// `Mcp-Session-Id` to receive JSON-RPC notifications for the session, such as
+/// order updates after `configure_credentials` (see `order_updates`).
+/// The stream ends when the session is removed.
+pub async fn notification_stream(
+    State(state): State<SseState>,
+    headers: HeaderMap,
+) -> axum::response::Response {
+    let Some(session_id) = headers.get("Mcp-Session-Id").and_then(|h| h.to_str().ok()) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": {
+                    "code": -32002,
+                    "message": "Missing Mcp-Session-Id header"
                 }
-                "get_account_info" => {
-                    let params = AccountInfoParam {
-                        session_id: connection_id.clone(),
-                    };
-                    match state.mcp_server.get_account_info(Parameters(params)).await {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+            })),
+        )
+            .into_response();
+    };
+
+    let Some(rx) = state
+        .session_manager
+        .subscribe_notifications(session_id)
+        .await
+    else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": {
+                    "code": -32001,
+                    "message": "Session not found or expired"
                 }
-                "get_account_trades" => {
-                    match serde_json::from_value::<AccountTradesParam>(arguments.clone()) {
-                        Ok(params) => match state
-                            .mcp_server
-                            .get_account_trades(Parameters(params))
-                            .await
-                        {
-                            Ok(result) => serde_json::to_value(&result).unwrap(),
-                            Err(e) => serde_json::json!({
-                                "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                "isError": true
-                            }),
-                        },
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+            })),
+        )
+            .into_response();
+    };
+    tracing::info!(session_id = %session_id, "Notification stream opened");
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(notification) => {
+                    let event = Event::default().data(notification.to_string());
+                    return Some((Ok::<_, std::convert::Infallible>(event), rx));
                 }
-                "place_order" => {
-                    match serde_json::from_value::<PlaceOrderParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.place_order(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "Notification stream lagging: {} notifications skipped",
+                        skipped
+                    );
                 }
-                "get_order" => match serde_json::from_value::<OrderParam>(arguments.clone()) {
-                    Ok(params) => match state.mcp_server.get_order(Parameters(params)).await {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    },
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                "cancel_order" => match serde_json::from_value::<OrderParam>(arguments.clone()) {
-                    Ok(params) => match state.mcp_server.cancel_order(Parameters(params)).await {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    },
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                "get_open_orders" => {
-                    match serde_json::from_value::<OpenOrdersParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.get_open_orders(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Drops tool entries hidden by `TOOLS_ENABLED` / `TOOLS_DISABLED`
+///
+/// The SDK router entries are already filtered; this covers the prepended
+/// `search`/`fetch` tools.
+fn retain_enabled_tools(state: &SseState, tools: &mut Vec<Value>) {
+    let filter = &state.mcp_server.tool_filter;
+    tools.retain(|tool| {
+        tool["name"]
+            .as_str()
+            .is_some_and(|name| filter.allows(name))
+    });
+}
+
+/// Routes a `tools/call` request to the matching tool handler
+///
+/// Returns the MCP tool result (content array format). Every tool-level
+/// failure, including bad arguments, unknown tools and sessions over their
+/// call rate limit, is reported in-band with the [`tool_error`] envelope;
+/// only protocol problems (bad session, unknown method) use JSON-RPC `error`
+/// objects.
+async fn dispatch_tool(
+    state: &SseState,
+    connection_id: &str,
+    tool_name: &str,
+    arguments: Value,
+) -> Value {
+    if !state.mcp_server.tool_filter.allows(tool_name) {
+        return tool_error(
+            "TOOL_DISABLED",
+            format!("Tool '{}' is disabled on this server", tool_name),
+        );
+    }
+
+    if let Err(retry_after) = state.session_manager.check_rate_limit(connection_id) {
+        let retry_after_secs = retry_after.as_secs_f64().ceil() as u64;
+        tracing::warn!(
+            connection_id = %connection_id,
+            tool = %tool_name,
+            retry_after_secs,
+            "Session tool call rate limit exceeded"
+        );
+        return tool_error_with_details(
+            "SESSION_RATE_LIMITED",
+            format!(
+                "Session exceeded {} tool calls per minute. Retry after {} seconds.",
+                state.session_manager.calls_per_minute(),
+                retry_after_secs
+            ),
+            Some(json!({ "retry_after_secs": retry_after_secs })),
+        );
+    }
+
+    // Route to appropriate tool handler
+    // MCP requires results in content array format
+    match tool_name {
+        "search" => {
+            // ChatGPT search tool - search trading symbols
+            let query = arguments
+                .get("query")
+                .and_then(|q| q.as_str())
+                .unwrap_or("");
+
+            match search_symbols(&state.binance_client, query).await {
+                Ok(results) => {
+                    // MCP format: wrap in content array with type "text"
+                    let results_json = serde_json::json!({"results": results});
+                    serde_json::json!({
+                        "content": [{
+                            "type": "text",
+                            "text": serde_json::to_string(&results_json).unwrap()
+                        }]
+                    })
                 }
-                "get_all_orders" => {
-                    match serde_json::from_value::<AllOrdersParam>(arguments.clone()) {
-                        Ok(params) => {
-                            match state.mcp_server.get_all_orders(Parameters(params)).await {
-                                Ok(result) => serde_json::to_value(&result).unwrap(),
-                                Err(e) => serde_json::json!({
-                                    "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                                    "isError": true
-                                }),
-                            }
-                        }
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"Invalid parameters: {}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+                Err(e) => tool_error("SEARCH_FAILED", format!("Search failed: {}", e)),
+            }
+        }
+        "fetch" => {
+            // ChatGPT fetch tool - get detailed symbol info
+            let symbol_id = arguments.get("id").and_then(|s| s.as_str()).unwrap_or("");
+
+            match fetch_symbol_details(&state.binance_client, symbol_id).await {
+                Ok(details) => {
+                    // MCP format: wrap in content array with type "text"
+                    serde_json::json!({
+                        "content": [{
+                            "type": "text",
+                            "text": serde_json::to_string(&details).unwrap()
+                        }]
+                    })
                 }
-                // Orderbook tools - these may return "feature not enabled" error if orderbook feature is disabled
-                "get_orderbook_metrics" => {
-                    // Try to deserialize params, but use empty JSON if deserialization fails
-                    match state
+                Err(e) => tool_error("FETCH_FAILED", format!("Fetch failed: {}", e)),
+            }
+        }
+        // SDK tools - call methods directly with deserialized parameters
+        "get_server_time" => tool_result(state.mcp_server.get_server_time().await),
+        "ping_binance" => match serde_json::from_value::<PingParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.ping_binance(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_capabilities" => {
+            let params = CapabilitiesParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(state.mcp_server.get_capabilities(Parameters(params)).await)
+        }
+        "get_ticker" => match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_ticker(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_momentum" => match serde_json::from_value::<MomentumParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_momentum(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        #[cfg(feature = "futures")]
+        "get_futures_ticker" => match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+            Ok(params) => tool_result(
+                state
+                    .mcp_server
+                    .get_futures_ticker(Parameters(params))
+                    .await,
+            ),
+            Err(e) => invalid_params(&e),
+        },
+        #[cfg(feature = "futures")]
+        "get_futures_funding_rate" => {
+            match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_futures_funding_rate(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        #[cfg(feature = "futures")]
+        "get_futures_open_interest" => {
+            match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_futures_open_interest(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_all_tickers" => match serde_json::from_value::<TickersParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_all_tickers(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_watchlist_overview" => {
+            match serde_json::from_value::<WatchlistParam>(arguments.clone()) {
+                Ok(params) => tool_result(
+                    state
                         .mcp_server
-                        .get_orderbook_metrics(Parameters(
-                            serde_json::from_value(arguments.clone()).unwrap_or_default(),
-                        ))
-                        .await
-                    {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+                        .get_watchlist_overview(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_klines" => match serde_json::from_value::<KlinesParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_klines(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_resampled_klines" => {
+            match serde_json::from_value::<ResampledKlinesParam>(arguments.clone()) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_resampled_klines(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_order_book" => match serde_json::from_value::<OrderBookParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_order_book(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_recent_trades" => {
+            match serde_json::from_value::<RecentTradesParam>(arguments.clone()) {
+                Ok(params) => {
+                    tool_result(state.mcp_server.get_recent_trades(Parameters(params)).await)
                 }
-                "get_orderbook_depth" => {
-                    match state
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_trade_flow_summary" => {
+            match serde_json::from_value::<TradeFlowParam>(arguments.clone()) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_trade_flow_summary(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_average_price" => match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_average_price(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_account_info" => {
+            let params = AccountInfoParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(state.mcp_server.get_account_info(Parameters(params)).await)
+        }
+        "get_account_status" => {
+            let params = AccountInfoParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(
+                state
+                    .mcp_server
+                    .get_account_status(Parameters(params))
+                    .await,
+            )
+        }
+        "configure_credentials" => {
+            // Credentials are always bound to the calling session
+            match serde_json::from_value::<ConfigureCredentialsParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .configure_credentials(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_credentials_status" => {
+            let params = CredentialsStatusParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(
+                state
+                    .mcp_server
+                    .get_credentials_status(Parameters(params))
+                    .await,
+            )
+        }
+        "get_user_stream_health" => {
+            let params = UserStreamHealthParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(
+                state
+                    .mcp_server
+                    .get_user_stream_health(Parameters(params))
+                    .await,
+            )
+        }
+        "revoke_credentials" => {
+            let params = RevokeCredentialsParam {
+                session_id: connection_id.to_string(),
+            };
+            tool_result(
+                state
+                    .mcp_server
+                    .revoke_credentials(Parameters(params))
+                    .await,
+            )
+        }
+        "get_account_trades" => {
+            match serde_json::from_value::<AccountTradesParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_account_trades(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_deposit_history" => {
+            match serde_json::from_value::<TransferHistoryParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .get_deposit_history(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_withdraw_history" => {
+            match serde_json::from_value::<TransferHistoryParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
                         .mcp_server
-                        .get_orderbook_depth(Parameters(
-                            serde_json::from_value(arguments.clone()).unwrap_or_default(),
-                        ))
-                        .await
-                    {
-                        Ok(result) => serde_json::to_value(&result).unwrap(),
-                        Err(e) => serde_json::json!({
-                            "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                            "isError": true
-                        }),
-                    }
+                        .get_withdraw_history(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "explain_symbol_rules" => match serde_json::from_value::<SymbolParam>(arguments.clone()) {
+            Ok(params) => tool_result(
+                state
+                    .mcp_server
+                    .explain_symbol_rules(Parameters(params))
+                    .await,
+            ),
+            Err(e) => invalid_params(&e),
+        },
+        "explain_error" => match serde_json::from_value::<ExplainErrorParam>(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.explain_error(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "quote_to_quantity" => {
+            match serde_json::from_value::<QuoteToQuantityParam>(arguments.clone()) {
+                Ok(params) => {
+                    tool_result(state.mcp_server.quote_to_quantity(Parameters(params)).await)
                 }
-                "get_orderbook_health" => match state.mcp_server.get_orderbook_health().await {
-                    Ok(result) => serde_json::to_value(&result).unwrap(),
-                    Err(e) => serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"{}\"}}", e)}],
-                        "isError": true
-                    }),
-                },
-                _ => {
-                    serde_json::json!({
-                        "content": [{"type": "text", "text": format!("{{\"error\": \"Unknown tool: {}\"}}", tool_name)}],
-                        "isError": true
-                    })
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "place_order" => match serde_json::from_value::<PlaceOrderParam>(with_session_id(
+            &arguments,
+            connection_id,
+        )) {
+            Ok(params) => tool_result(state.mcp_server.place_order(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "place_trailing_stop" => {
+            // Orders are always placed with the calling session's credentials
+            match serde_json::from_value::<TrailingStopParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .place_trailing_stop(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_order" => {
+            match serde_json::from_value::<OrderParam>(with_session_id(&arguments, connection_id)) {
+                Ok(params) => tool_result(state.mcp_server.get_order(Parameters(params)).await),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "wait_for_order" => {
+            // Orders are always queried with the calling session's credentials
+            match serde_json::from_value::<WaitForOrderParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => {
+                    tool_result(state.mcp_server.wait_for_order(Parameters(params)).await)
                 }
+                Err(e) => invalid_params(&e),
             }
         }
-        _ => {
-            serde_json::json!({
-                "error": format!("Unknown method: {}", method)
-            })
+        "cancel_order" => {
+            match serde_json::from_value::<OrderParam>(with_session_id(&arguments, connection_id)) {
+                Ok(params) => tool_result(state.mcp_server.cancel_order(Parameters(params)).await),
+                Err(e) => invalid_params(&e),
+            }
         }
-    };
-
-    // Build JSON-RPC response
-    let json_rpc_response = serde_json::json!({
-        "jsonrpc": "2.0",
-        "id": request_id,
-        "result": result
-    });
-
-    // Streamable HTTP transport (March 2025 spec):
-    // Check Accept header to determine response format
-    let accept = headers
-        .get(axum::http::header::ACCEPT)
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("application/json");
+        "cancel_orders" => {
+            // Orders are always cancelled with the calling session's credentials
+            match serde_json::from_value::<CancelOrdersParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(state.mcp_server.cancel_orders(Parameters(params)).await),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        "get_open_orders" => match serde_json::from_value::<OpenOrdersParam>(with_session_id(
+            &arguments,
+            connection_id,
+        )) {
+            Ok(params) => tool_result(state.mcp_server.get_open_orders(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_all_orders" => match serde_json::from_value::<AllOrdersParam>(with_session_id(
+            &arguments,
+            connection_id,
+        )) {
+            Ok(params) => tool_result(state.mcp_server.get_all_orders(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "export_order_history" => {
+            // Exports always read the calling session's orders
+            match serde_json::from_value::<ExportOrderHistoryParam>(with_session_id(
+                &arguments,
+                connection_id,
+            )) {
+                Ok(params) => tool_result(
+                    state
+                        .mcp_server
+                        .export_order_history(Parameters(params))
+                        .await,
+                ),
+                Err(e) => invalid_params(&e),
+            }
+        }
+        // Orderbook tools - these may return "feature not enabled" error if orderbook feature is disabled
+        "get_orderbook_metrics" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(
+                state
+                    .mcp_server
+                    .get_orderbook_metrics(Parameters(params))
+                    .await,
+            ),
+            Err(e) => invalid_params(&e),
+        },
+        "get_orderbook_depth" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(
+                state
+                    .mcp_server
+                    .get_orderbook_depth(Parameters(params))
+                    .await,
+            ),
+            Err(e) => invalid_params(&e),
+        },
+        "get_aggregated_depth" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(
+                state
+                    .mcp_server
+                    .get_aggregated_depth(Parameters(params))
+                    .await,
+            ),
+            Err(e) => invalid_params(&e),
+        },
+        "get_key_levels" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_key_levels(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_book_gaps" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_book_gaps(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_depth_stats" => match serde_json::from_value(arguments.clone()) {
+            Ok(params) => tool_result(state.mcp_server.get_depth_stats(Parameters(params)).await),
+            Err(e) => invalid_params(&e),
+        },
+        "get_orderbook_health" => tool_result(state.mcp_server.get_orderbook_health().await),
+        _ => tool_error("UNKNOWN_TOOL", format!("Unknown tool: {}", tool_name)),
+    }
+}
 
-    // Build response based on Accept header
-    let mut response = if accept.contains("text/event-stream") {
-        // Client wants SSE stream - return as SSE event
-        let sse_event = format!(
-            "data: {}\n\n",
-            serde_json::to_string(&json_rpc_response).unwrap()
-        );
-        (
-            StatusCode::OK,
-            [(axum::http::header::CONTENT_TYPE, "text/event-stream")],
-            sse_event,
-        )
-            .into_response()
-    } else {
-        // Client wants JSON (default) - return plain JSON-RPC response
-        (StatusCode::OK, Json(json_rpc_response)).into_response()
-    };
+/// Renders a prompt by name
+///
+/// The prompt router needs an rmcp request context that only exists inside
+/// the stdio service, so prompts are called directly here. Unlike tools,
+/// prompt failures are JSON-RPC errors, as `prompts/get` has no in-band error
+/// envelope.
+async fn dispatch_prompt(
+    state: &SseState,
+    prompt_name: &str,
+    arguments: Value,
+) -> Result<GetPromptResult, ErrorData> {
+    if !state.mcp_server.prompt_router.has_route(prompt_name) {
+        return Err(ErrorData::invalid_params(
+            format!("Unknown prompt: {}", prompt_name),
+            None,
+        ));
+    }
 
-    // For initialize requests, add Mcp-Session-Id header (Streamable HTTP spec)
-    if is_initialize {
-        response
-            .headers_mut()
-            .insert("Mcp-Session-Id", connection_id.parse().unwrap());
-        tracing::info!(session_id = %connection_id, "Returned Mcp-Session-Id in initialize response");
+    let server = &state.mcp_server;
+    match prompt_name {
+        "trading_analysis" => server.trading_analysis(prompt_args(arguments)?).await,
+        "portfolio_risk" => server.portfolio_risk(prompt_args(arguments)?).await,
+        "pre_trade_check" => server.pre_trade_check(prompt_args(arguments)?).await,
+        "advanced_market_analysis" => {
+            server
+                .advanced_market_analysis(prompt_args(arguments)?)
+                .await
+        }
+        "orderflow_snapshot" => server.orderflow_snapshot(prompt_args(arguments)?).await,
+        "market_health_check" => server.market_health_check(prompt_args(arguments)?).await,
+        _ => Err(ErrorData::internal_error(
+            format!(
+                "Prompt {} is registered but not routed over HTTP",
+                prompt_name
+            ),
+            None,
+        )),
     }
+}
 
-    response
+/// Parses prompt arguments, reporting bad ones as invalid params
+fn prompt_args<T: serde::de::DeserializeOwned>(
+    arguments: Value,
+) -> Result<Parameters<T>, ErrorData> {
+    serde_json::from_value(arguments)
+        .map(Parameters)
+        .map_err(|e| ErrorData::invalid_params(format!("Invalid arguments: {}", e), None))
 }
 
 /// Root endpoint for MCP server discovery
@@ -580,7 +1056,7 @@ pub async fn server_info() -> impl IntoResponse {
         },
         "capabilities": {
             "tools": true,
-            "prompts": false,
+            "prompts": true,
             "resources": false
         }
     });
@@ -639,6 +1115,7 @@ pub async fn tools_list(State(state): State<SseState>) -> impl IntoResponse {
             "required": ["id"]
         }
     }));
+    retain_enabled_tools(&state, &mut all_tools);
 
     let tools = json!({
         "jsonrpc": "2.0",
@@ -650,3 +1127,206 @@ pub async fn tools_list(State(state): State<SseState>) -> impl IntoResponse {
 
     (StatusCode::OK, Json(tools)).into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn state_at(base_url: &str) -> SseState {
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: base_url.to_string(),
+                ..BinanceClient::new()
+            },
+            ..BinanceServer::new()
+        };
+        SseState::new(server.session_manager.clone(), server)
+    }
+
+    #[tokio::test]
+    async fn test_credentialed_tools_ignore_foreign_session_id() {
+        use crate::transport::sse::session::Credentials;
+        use crate::types::Environment;
+
+        let state = state_at("http://127.0.0.1:1");
+        let addr: std::net::SocketAddr = "127.0.0.1:9000".parse().unwrap();
+        let victim = state
+            .session_manager
+            .register_connection(addr, None)
+            .await
+            .unwrap();
+        let attacker = state
+            .session_manager
+            .register_connection(addr, None)
+            .await
+            .unwrap();
+        assert!(
+            state
+                .session_manager
+                .store_credentials(Credentials::new(
+                    "k".repeat(64),
+                    "s".repeat(64),
+                    Environment::Testnet,
+                    victim.clone(),
+                ))
+                .await
+        );
+
+        // Naming the victim's session doesn't lend the attacker its credentials
+        let calls = [
+            (
+                "place_order",
+                json!({"symbol": "BTCUSDT", "side": "BUY", "type": "LIMIT",
+                       "quantity": "0.001", "price": "50000", "session_id": victim}),
+            ),
+            (
+                "cancel_order",
+                json!({"symbol": "BTCUSDT", "order_id": 42, "session_id": victim}),
+            ),
+            (
+                "get_open_orders",
+                json!({"symbol": "BTCUSDT", "session_id": victim}),
+            ),
+        ];
+        for (tool, arguments) in calls {
+            let result = dispatch_tool(&state, &attacker, tool, arguments).await;
+            assert_eq!(result["isError"], true, "{tool}");
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(
+                text.contains("CREDENTIALS_NOT_CONFIGURED"),
+                "{tool}: {text}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prompts_get_renders_trading_analysis() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .and(query_param("symbol", "BTCUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "BTCUSDT", "priceChange": "1200.00", "priceChangePercent": "1.81",
+                "weightedAvgPrice": "66900.00", "prevClosePrice": "66450.00",
+                "lastPrice": "67650.10", "lastQty": "0.01", "bidPrice": "67650.00",
+                "askPrice": "67650.10", "openPrice": "66450.10", "highPrice": "68000.00",
+                "lowPrice": "66100.00", "volume": "12345.6", "quoteVolume": "825000000.0",
+                "openTime": 0, "closeTime": 0, "firstId": 0, "lastId": 0, "count": 0
+            })))
+            .mount(&mock_server)
+            .await;
+        let state = state_at(&mock_server.uri());
+
+        let response = handle_rpc_request(
+            &state,
+            "conn",
+            &json!({
+                "jsonrpc": "2.0", "id": 7, "method": "prompts/get",
+                "params": {"name": "trading_analysis", "arguments": {"symbol": "BTCUSDT", "strategy": "balanced"}}
+            }),
+        )
+        .await;
+
+        assert_eq!(response["id"], 7);
+        let text = response["result"]["messages"][0]["content"]["text"]
+            .as_str()
+            .unwrap();
+        assert!(text.contains("# Market Analysis: BTCUSDT"));
+        assert!(text.contains("67650.10"));
+        assert!(text.contains("Balanced"));
+    }
+
+    #[tokio::test]
+    async fn test_trading_analysis_defaults_to_default_symbol() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/ticker/24hr"))
+            .and(query_param("symbol", "ETHUSDT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "symbol": "ETHUSDT", "priceChange": "12.00", "priceChangePercent": "0.37",
+                "weightedAvgPrice": "3250.00", "prevClosePrice": "3240.00",
+                "lastPrice": "3252.40", "lastQty": "0.5", "bidPrice": "3252.30",
+                "askPrice": "3252.40", "openPrice": "3240.40", "highPrice": "3280.00",
+                "lowPrice": "3210.00", "volume": "98765.4", "quoteVolume": "321000000.0",
+                "openTime": 0, "closeTime": 0, "firstId": 0, "lastId": 0, "count": 0
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let server = BinanceServer {
+            binance_client: BinanceClient {
+                base_url: mock_server.uri(),
+                ..BinanceClient::new()
+            },
+            default_symbol: Some("ETHUSDT".to_string()),
+            ..BinanceServer::new()
+        };
+        let state = SseState::new(server.session_manager.clone(), server);
+
+        let response = handle_rpc_request(
+            &state,
+            "conn",
+            &json!({"jsonrpc": "2.0", "id": 3, "method": "prompts/get", "params": {"name": "trading_analysis"}}),
+        )
+        .await;
+
+        let text = response["result"]["messages"][0]["content"]["text"]
+            .as_str()
+            .unwrap();
+        assert!(text.contains("# Market Analysis: ETHUSDT"));
+    }
+
+    #[tokio::test]
+    async fn test_prompts_get_errors_are_json_rpc_errors() {
+        let state = state_at("http://127.0.0.1:1");
+
+        let unknown = handle_rpc_request(
+            &state,
+            "conn",
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "prompts/get", "params": {"name": "nope"}}),
+        )
+        .await;
+        assert_eq!(unknown["error"]["code"], -32602);
+        assert!(unknown.get("result").is_none());
+
+        let missing_symbol = handle_rpc_request(
+            &state,
+            "conn",
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "prompts/get", "params": {"name": "trading_analysis"}}),
+        )
+        .await;
+        assert_eq!(missing_symbol["error"]["code"], -32602);
+        assert!(missing_symbol["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("symbol"));
+    }
+
+    #[tokio::test]
+    async fn test_initialize_negotiates_protocol_version() {
+        let state = state_at("http://127.0.0.1:1");
+        let initialize = |version: Value| {
+            json!({
+                "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": {"protocolVersion": version, "capabilities": {},
+                           "clientInfo": {"name": "test", "version": "1.0"}}
+            })
+        };
+
+        let supported = handle_rpc_request(&state, "conn", &initialize(json!("2025-03-26"))).await;
+        assert_eq!(supported["result"]["protocolVersion"], "2025-03-26");
+
+        let unknown = handle_rpc_request(&state, "conn", &initialize(json!("2099-01-01"))).await;
+        assert_eq!(unknown["result"]["protocolVersion"], "2024-11-05");
+
+        let missing = handle_rpc_request(
+            &state,
+            "conn",
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "initialize", "params": {}}),
+        )
+        .await;
+        assert_eq!(missing["result"]["protocolVersion"], "2024-11-05");
+    }
+}
@@ -25,7 +25,10 @@
 //! - `session`: Connection lifecycle management
 //! - `handlers`: HTTP endpoint handlers (T020-T022)
 //! - `stream`: SSE event stream writer (T022)
+//! - `order_updates`: Order fill notifications from the user data stream (requires `websocket`)
 
+#[cfg(feature = "websocket")]
+pub mod order_updates;
 pub mod server;
 pub mod session;
 pub mod types;
@@ -33,7 +36,7 @@ pub mod types;
 pub mod handlers_simple; // MVP implementation
 
 // Re-export main types for convenience
-pub use handlers_simple::{message_post, server_info, tools_list, SseState};
+pub use handlers_simple::{message_post, notification_stream, server_info, tools_list, SseState};
 pub use server::SseConfig;
 pub use session::SessionManager;
 pub use types::{ConnectionId, SessionMetadata};
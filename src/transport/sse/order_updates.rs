@@ -0,0 +1,194 @@
+//! Order update notifications for SSE sessions
+//!
+//! When a session configures credentials, a Binance user data stream is opened
+//! with those credentials and every `executionReport` is pushed to the session
+//! as an MCP `notifications/message` (logger `binance/orderUpdate`). Clients
+//! receive them on `GET /mcp` with `Accept: text/event-stream`, so an agent can
+//! follow an order from NEW to FILLED without polling `get_order`.
+//!
+//! The stream is stopped and its listen key closed when credentials are
+//! revoked or replaced, or when the session ends.
+
+use super::session::{Credentials, SessionManager};
+use crate::binance::websocket::{
+    BinanceWebSocketClient, ExecutionReport, UserDataEvent, UserStreamStatus,
+};
+use crate::binance::BinanceClient;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+/// Listen key renewal interval (keys expire after 60 minutes)
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Logger name used for order update notifications
+pub const ORDER_UPDATE_LOGGER: &str = "binance/orderUpdate";
+
+/// Builds the JSON-RPC notification for an execution report
+pub fn order_update_notification(report: &ExecutionReport) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/message",
+        "params": {
+            "level": "info",
+            "logger": ORDER_UPDATE_LOGGER,
+            "data": {
+                "symbol": report.symbol,
+                "orderId": report.order_id,
+                "clientOrderId": report.client_order_id,
+                "side": report.side,
+                "type": report.order_type,
+                "executionType": report.execution_type,
+                "status": report.order_status,
+                "price": report.price,
+                "quantity": report.quantity,
+                "lastExecutedQuantity": report.last_executed_quantity,
+                "lastExecutedPrice": report.last_executed_price,
+                "cumulativeFilledQuantity": report.cumulative_filled_quantity,
+                "eventTime": report.event_time,
+            }
+        }
+    })
+}
+
+/// Starts forwarding order updates to the session that owns `credentials`
+///
+/// Any stream previously attached to the session is cancelled first. The
+/// stream's liveness is reported by `get_user_stream_health`.
+pub async fn spawn_order_update_stream(
+    session_manager: SessionManager,
+    binance_client: BinanceClient,
+    credentials: Credentials,
+) {
+    let token = CancellationToken::new();
+    let status = UserStreamStatus::default();
+    session_manager
+        .attach_task(&credentials.session_id, token.clone())
+        .await;
+    session_manager
+        .attach_user_stream(&credentials.session_id, status.clone())
+        .await;
+
+    tokio::spawn(run_order_update_stream(
+        session_manager,
+        binance_client,
+        credentials,
+        token,
+        status,
+    ));
+}
+
+/// Runs the user data stream for one session until `token` is cancelled
+async fn run_order_update_stream(
+    session_manager: SessionManager,
+    binance_client: BinanceClient,
+    credentials: Credentials,
+    token: CancellationToken,
+    status: UserStreamStatus,
+) {
+    let session_id = credentials.session_id.clone();
+
+    let listen_key = tokio::select! {
+        _ = token.cancelled() => return,
+        result = binance_client.create_session_listen_key(&credentials) => match result {
+            Ok(key) => key,
+            Err(e) => {
+                tracing::warn!(
+                    session_id = %session_id,
+                    "Order updates unavailable: failed to create listen key: {}",
+                    e
+                );
+                return;
+            }
+        },
+    };
+    tracing::info!(
+        session_id = %session_id,
+        environment = %credentials.environment,
+        "Order update stream started"
+    );
+
+    let (tx, mut rx) = broadcast::channel::<UserDataEvent>(100);
+    let ws_client = BinanceWebSocketClient {
+        base_url: credentials.environment.ws_base_url().to_string(),
+    };
+    let stream_key = listen_key.clone();
+    let stream_status = status.clone();
+    let stream_task = tokio::spawn(async move {
+        if let Err(e) = ws_client
+            .user_data_stream_task_with_status(&stream_key, tx, &stream_status)
+            .await
+        {
+            tracing::error!("User data stream task failed: {}", e);
+        }
+    });
+
+    let mut keepalive = tokio::time::interval_at(
+        tokio::time::Instant::now() + KEEPALIVE_INTERVAL,
+        KEEPALIVE_INTERVAL,
+    );
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => break,
+            _ = keepalive.tick() => {
+                if let Err(e) = binance_client
+                    .keepalive_session_listen_key(&listen_key, &credentials)
+                    .await
+                {
+                    tracing::error!(session_id = %session_id, "Failed to renew listen key: {}", e);
+                    break;
+                }
+            }
+            event = rx.recv() => match event {
+                Ok(UserDataEvent::ExecutionReport(report)) => {
+                    session_manager
+                        .notify(&session_id, order_update_notification(&report))
+                        .await;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(session_id = %session_id, "Order updates lagging: {} events skipped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        }
+    }
+
+    stream_task.abort();
+    status.set_connected(false);
+    if let Err(e) = binance_client
+        .close_session_listen_key(&listen_key, &credentials)
+        .await
+    {
+        tracing::warn!(session_id = %session_id, "Failed to close listen key: {}", e);
+    }
+    tracing::info!(session_id = %session_id, "Order update stream stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_update_notification() {
+        let report: ExecutionReport = serde_json::from_value(json!({
+            "E": 1700000000000i64, "s": "BTCUSDT", "c": "my-order", "S": "BUY",
+            "o": "LIMIT", "f": "GTC", "q": "0.01", "p": "50000.00",
+            "x": "TRADE", "X": "FILLED", "r": "NONE", "i": 42,
+            "l": "0.01", "z": "0.01", "L": "50000.00", "n": "0", "N": null,
+            "T": 1700000000000i64, "t": 7, "w": false, "m": false,
+            "O": 1699999999000i64, "Z": "500.00", "Y": "500.00", "Q": "0"
+        }))
+        .unwrap();
+
+        let notification = order_update_notification(&report);
+        assert_eq!(notification["method"], "notifications/message");
+        assert_eq!(notification["params"]["logger"], ORDER_UPDATE_LOGGER);
+        let data = &notification["params"]["data"];
+        assert_eq!(data["orderId"], 42);
+        assert_eq!(data["status"], "FILLED");
+        assert_eq!(data["cumulativeFilledQuantity"], "0.01");
+    }
+}
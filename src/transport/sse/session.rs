@@ -3,17 +3,23 @@
 //! Manages lifecycle of active SSE connections including:
 //! - Connection registration and cleanup
 //! - Connection limit enforcement (max 50)
-//! - Timeout detection and stale session removal
+//! - Timeout detection and stale session removal (`SSE_SESSION_TIMEOUT_SECS`)
+//! - Per-session tool call rate limiting (`SSE_SESSION_CALLS_PER_MINUTE`)
 //! - Per-session credential storage (Feature 011)
+//! - Per-session server-initiated notifications and background tasks
 
 use super::types::{ConnectionId, SessionMetadata};
+#[cfg(feature = "websocket")]
+use crate::binance::websocket::{UserStreamHealth, UserStreamStatus};
 pub use crate::types::Environment; // Re-export for credential tools
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, RwLock};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 /// Maximum concurrent SSE connections allowed
@@ -21,8 +27,58 @@ use uuid::Uuid;
 /// Per spec SC-004: "Server handles at least 50 concurrent SSE connections"
 pub const MAX_CONNECTIONS: usize = 50;
 
-/// Session timeout in seconds (30s of inactivity)
-pub const SESSION_TIMEOUT_SECS: u64 = 30;
+/// Default session timeout in seconds of inactivity
+///
+/// Override with `SSE_SESSION_TIMEOUT_SECS`.
+pub const DEFAULT_SESSION_TIMEOUT_SECS: u64 = 300;
+
+/// Parses `SSE_SESSION_TIMEOUT_SECS`, falling back to the default on missing, zero or invalid input
+pub fn parse_session_timeout(value: Option<&str>) -> u64 {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_SESSION_TIMEOUT_SECS)
+}
+
+/// Default tool calls allowed per session in any 60-second window
+///
+/// Override with `SSE_SESSION_CALLS_PER_MINUTE` (`0` disables the limit).
+pub const DEFAULT_SESSION_CALLS_PER_MINUTE: u32 = 120;
+
+/// Sliding window for the per-session call limit
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Parses `SSE_SESSION_CALLS_PER_MINUTE`, falling back to the default on missing or invalid input
+///
+/// `0` is kept: it disables the limit.
+pub fn parse_session_rate_limit(value: Option<&str>) -> u32 {
+    value
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(DEFAULT_SESSION_CALLS_PER_MINUTE)
+}
+
+/// Records a call at `now` in a session's sliding window if it is under `limit`
+///
+/// Calls older than [`RATE_LIMIT_WINDOW`] are dropped first. When the window
+/// is full, returns how long until its oldest call expires.
+fn admit_call(window: &mut VecDeque<Instant>, limit: u32, now: Instant) -> Result<(), Duration> {
+    while window
+        .front()
+        .is_some_and(|&at| now.duration_since(at) >= RATE_LIMIT_WINDOW)
+    {
+        window.pop_front();
+    }
+
+    if window.len() >= limit as usize {
+        let oldest = window.front().copied().unwrap_or(now);
+        return Err(RATE_LIMIT_WINDOW.saturating_sub(now.duration_since(oldest)));
+    }
+    window.push_back(now);
+    Ok(())
+}
+
+/// Buffered notifications per session before slow listeners start lagging
+const NOTIFICATION_BUFFER: usize = 64;
 
 /// Session-scoped API credentials for Binance authentication
 ///
@@ -109,17 +165,91 @@ pub struct SessionManager {
     /// - Value: Credentials (api_key, api_secret, environment)
     /// - Cleared atomically when session expires (FR-003, FR-004)
     credentials: Arc<RwLock<HashMap<ConnectionId, Credentials>>>,
+
+    /// Per-session notification channels (JSON-RPC notifications pushed to `GET /mcp`)
+    notifications: Arc<RwLock<HashMap<ConnectionId, broadcast::Sender<serde_json::Value>>>>,
+
+    /// Per-session background tasks (e.g., order update streams)
+    /// - Cancelled when credentials are revoked or the session ends
+    tasks: Arc<RwLock<HashMap<ConnectionId, CancellationToken>>>,
+
+    /// Per-session user data stream liveness (order update streams)
+    #[cfg(feature = "websocket")]
+    user_streams: Arc<RwLock<HashMap<ConnectionId, UserStreamStatus>>>,
+
+    /// Recent tool call times per session (sliding window for `calls_per_minute`)
+    call_windows: Arc<std::sync::Mutex<HashMap<ConnectionId, VecDeque<Instant>>>>,
+
+    /// Seconds of inactivity after which a session is stale
+    timeout_secs: u64,
+
+    /// Tool calls allowed per session per minute (`0` = unlimited)
+    calls_per_minute: u32,
 }
 
 impl SessionManager {
     /// Creates a new empty session manager
+    ///
+    /// The inactivity timeout comes from `SSE_SESSION_TIMEOUT_SECS`
+    /// (default: [`DEFAULT_SESSION_TIMEOUT_SECS`]) and the per-session call
+    /// limit from `SSE_SESSION_CALLS_PER_MINUTE`
+    /// (default: [`DEFAULT_SESSION_CALLS_PER_MINUTE`]).
     pub fn new() -> Self {
+        Self::with_timeout(parse_session_timeout(
+            std::env::var("SSE_SESSION_TIMEOUT_SECS").ok().as_deref(),
+        ))
+        .with_rate_limit(parse_session_rate_limit(
+            std::env::var("SSE_SESSION_CALLS_PER_MINUTE")
+                .ok()
+                .as_deref(),
+        ))
+    }
+
+    /// Creates a new empty session manager with an explicit inactivity timeout
+    pub fn with_timeout(timeout_secs: u64) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             credentials: Arc::new(RwLock::new(HashMap::new())),
+            notifications: Arc::new(RwLock::new(HashMap::new())),
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+            #[cfg(feature = "websocket")]
+            user_streams: Arc::new(RwLock::new(HashMap::new())),
+            call_windows: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            timeout_secs,
+            calls_per_minute: DEFAULT_SESSION_CALLS_PER_MINUTE,
         }
     }
 
+    /// Sets the tool calls allowed per session per minute (`0` disables the limit)
+    pub fn with_rate_limit(mut self, calls_per_minute: u32) -> Self {
+        self.calls_per_minute = calls_per_minute;
+        self
+    }
+
+    /// Seconds of inactivity after which a session is stale
+    pub fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    /// Tool calls allowed per session per minute (`0` = unlimited)
+    pub fn calls_per_minute(&self) -> u32 {
+        self.calls_per_minute
+    }
+
+    /// Counts a tool call against the session's per-minute limit
+    ///
+    /// Sessions are limited independently over a sliding 60-second window.
+    /// Returns `Err(retry_after)` without counting the call when the session
+    /// is at its limit.
+    pub fn check_rate_limit(&self, session_id: &str) -> Result<(), Duration> {
+        if self.calls_per_minute == 0 {
+            return Ok(());
+        }
+        let mut windows = self.call_windows.lock().expect("call window lock poisoned");
+        let window = windows.entry(session_id.to_string()).or_default();
+        admit_call(window, self.calls_per_minute, Instant::now())
+    }
+
     /// Registers a new SSE connection session
     ///
     /// Returns `Some(connection_id)` if registration succeeds,
@@ -170,6 +300,7 @@ impl SessionManager {
             // Atomically remove credentials when session is removed (FR-003, FR-004)
             let mut creds = self.credentials.write().await;
             let had_credentials = creds.remove(connection_id).is_some();
+            self.release_session_resources(connection_id).await;
 
             tracing::info!(
                 connection_id = %connection_id,
@@ -218,11 +349,11 @@ impl SessionManager {
 
         sessions
             .get(connection_id)
-            .map(|session| !session.is_stale(SESSION_TIMEOUT_SECS))
+            .map(|session| !session.is_stale(self.timeout_secs))
             .unwrap_or(false)
     }
 
-    /// Removes all stale connections (inactive >30s)
+    /// Removes all stale connections (inactive longer than the session timeout)
     ///
     /// Atomically removes both session metadata AND credentials (Feature 011 - T010).
     ///
@@ -235,7 +366,7 @@ impl SessionManager {
         let stale_ids: Vec<String> = sessions
             .iter()
             .filter_map(|(connection_id, session)| {
-                if session.is_stale(SESSION_TIMEOUT_SECS) {
+                if session.is_stale(self.timeout_secs) {
                     Some(connection_id.clone())
                 } else {
                     None
@@ -249,7 +380,7 @@ impl SessionManager {
             tracing::info!(
                 connection_id = %connection_id,
                 "Removing stale session (inactive >{}s)",
-                SESSION_TIMEOUT_SECS
+                self.timeout_secs
             );
         }
 
@@ -260,6 +391,7 @@ impl SessionManager {
             if creds.remove(connection_id).is_some() {
                 credentials_cleared += 1;
             }
+            self.release_session_resources(connection_id).await;
         }
 
         let cleaned = stale_ids.len();
@@ -382,6 +514,13 @@ impl SessionManager {
         let mut creds = self.credentials.write().await;
         let removed = creds.remove(session_id).is_some();
 
+        // Background tasks run with the revoked credentials, so stop them too
+        if let Some(token) = self.tasks.write().await.remove(session_id) {
+            token.cancel();
+        }
+        #[cfg(feature = "websocket")]
+        self.user_streams.write().await.remove(session_id);
+
         if removed {
             tracing::info!(
                 session_id = %session_id,
@@ -399,6 +538,89 @@ impl SessionManager {
         // Write lock released - credentials permanently removed from memory
         // Session continues to exist and can be used for public API calls
     }
+
+    /// Subscribes to server-initiated notifications for a session
+    ///
+    /// Returns `None` if the session doesn't exist. Multiple subscribers
+    /// (e.g., a reconnecting client) each receive every notification.
+    pub async fn subscribe_notifications(
+        &self,
+        session_id: &str,
+    ) -> Option<broadcast::Receiver<serde_json::Value>> {
+        if !self.sessions.read().await.contains_key(session_id) {
+            return None;
+        }
+
+        let mut notifications = self.notifications.write().await;
+        let sender = notifications
+            .entry(session_id.to_string())
+            .or_insert_with(|| broadcast::channel(NOTIFICATION_BUFFER).0);
+        Some(sender.subscribe())
+    }
+
+    /// Sends a JSON-RPC notification to a session's subscribers
+    ///
+    /// Returns `true` if at least one subscriber received it. Notifications
+    /// sent while nobody is listening are dropped.
+    pub async fn notify(&self, session_id: &str, notification: serde_json::Value) -> bool {
+        self.notifications
+            .read()
+            .await
+            .get(session_id)
+            .map(|sender| sender.send(notification).is_ok())
+            .unwrap_or(false)
+    }
+
+    /// Attaches a background task to a session
+    ///
+    /// The token is cancelled when credentials are revoked, the session ends,
+    /// or another task is attached (the previous task is cancelled first).
+    pub async fn attach_task(&self, session_id: &str, token: CancellationToken) {
+        if let Some(previous) = self
+            .tasks
+            .write()
+            .await
+            .insert(session_id.to_string(), token)
+        {
+            previous.cancel();
+        }
+    }
+
+    /// Records the status of the user data stream serving a session
+    ///
+    /// Replaces the status of any previous stream. Dropped with the stream's
+    /// task when credentials are revoked or the session ends.
+    #[cfg(feature = "websocket")]
+    pub async fn attach_user_stream(&self, session_id: &str, status: UserStreamStatus) {
+        self.user_streams
+            .write()
+            .await
+            .insert(session_id.to_string(), status);
+    }
+
+    /// Liveness of the session's user data stream (`None` if none is running)
+    #[cfg(feature = "websocket")]
+    pub async fn user_stream_health(&self, session_id: &str) -> Option<UserStreamHealth> {
+        self.user_streams
+            .read()
+            .await
+            .get(session_id)
+            .map(UserStreamStatus::health)
+    }
+
+    /// Cancels background tasks and closes notification channels for a session
+    async fn release_session_resources(&self, session_id: &str) {
+        if let Some(token) = self.tasks.write().await.remove(session_id) {
+            token.cancel();
+        }
+        #[cfg(feature = "websocket")]
+        self.user_streams.write().await.remove(session_id);
+        self.notifications.write().await.remove(session_id);
+        self.call_windows
+            .lock()
+            .expect("call window lock poisoned")
+            .remove(session_id);
+    }
 }
 
 impl Default for SessionManager {
@@ -439,6 +661,57 @@ mod tests {
         assert!(manager.register_connection(addr, None).await.is_none());
     }
 
+    #[test]
+    fn test_session_rate_limit_is_per_session() {
+        let manager = SessionManager::with_timeout(300).with_rate_limit(3);
+
+        for _ in 0..3 {
+            assert!(manager.check_rate_limit("busy").is_ok());
+        }
+        let retry_after = manager.check_rate_limit("busy").unwrap_err();
+        assert!(retry_after <= RATE_LIMIT_WINDOW && retry_after > Duration::ZERO);
+
+        // Another session has its own window
+        assert!(manager.check_rate_limit("quiet").is_ok());
+
+        // 0 disables the limit
+        let unlimited = SessionManager::with_timeout(300).with_rate_limit(0);
+        for _ in 0..1000 {
+            assert!(unlimited.check_rate_limit("busy").is_ok());
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_window_slides() {
+        let start = Instant::now();
+        let mut window = VecDeque::new();
+
+        assert!(admit_call(&mut window, 2, start).is_ok());
+        assert!(admit_call(&mut window, 2, start + Duration::from_secs(20)).is_ok());
+        assert_eq!(
+            admit_call(&mut window, 2, start + Duration::from_secs(30)),
+            Err(Duration::from_secs(30))
+        );
+
+        // The first call leaves the window after 60s, freeing one slot
+        assert!(admit_call(&mut window, 2, start + Duration::from_secs(60)).is_ok());
+        assert!(admit_call(&mut window, 2, start + Duration::from_secs(61)).is_err());
+    }
+
+    #[test]
+    fn test_parse_session_rate_limit() {
+        assert_eq!(parse_session_rate_limit(Some("30")), 30);
+        assert_eq!(parse_session_rate_limit(Some("0")), 0);
+        assert_eq!(
+            parse_session_rate_limit(Some("lots")),
+            DEFAULT_SESSION_CALLS_PER_MINUTE
+        );
+        assert_eq!(
+            parse_session_rate_limit(None),
+            DEFAULT_SESSION_CALLS_PER_MINUTE
+        );
+    }
+
     #[tokio::test]
     async fn test_remove_connection() {
         let manager = SessionManager::new();
@@ -474,6 +747,91 @@ mod tests {
         assert!(!manager.is_valid_connection("invalid-id").await);
     }
 
+    #[tokio::test]
+    async fn test_custom_timeout_drives_staleness() {
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let strict = SessionManager::with_timeout(0);
+        let lenient = SessionManager::with_timeout(DEFAULT_SESSION_TIMEOUT_SECS);
+        assert_eq!(strict.timeout_secs(), 0);
+
+        let strict_id = strict.register_connection(addr, None).await.unwrap();
+        let lenient_id = lenient.register_connection(addr, None).await.unwrap();
+        sleep(Duration::from_millis(1100)).await;
+
+        assert!(!strict.is_valid_connection(&strict_id).await);
+        assert!(lenient.is_valid_connection(&lenient_id).await);
+        assert_eq!(strict.cleanup_stale_sessions().await, 1);
+        assert_eq!(lenient.cleanup_stale_sessions().await, 0);
+    }
+
+    #[test]
+    fn test_parse_session_timeout() {
+        assert_eq!(parse_session_timeout(None), DEFAULT_SESSION_TIMEOUT_SECS);
+        assert_eq!(parse_session_timeout(Some(" 900 ")), 900);
+        assert_eq!(
+            parse_session_timeout(Some("0")),
+            DEFAULT_SESSION_TIMEOUT_SECS
+        );
+        assert_eq!(
+            parse_session_timeout(Some("soon")),
+            DEFAULT_SESSION_TIMEOUT_SECS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notifications_reach_subscribers() {
+        let manager = SessionManager::new();
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let conn_id = manager.register_connection(addr, None).await.unwrap();
+
+        assert!(manager.subscribe_notifications("unknown").await.is_none());
+        let mut rx = manager.subscribe_notifications(&conn_id).await.unwrap();
+
+        let notification = serde_json::json!({"jsonrpc": "2.0", "method": "notifications/message"});
+        assert!(manager.notify(&conn_id, notification.clone()).await);
+        assert_eq!(rx.recv().await.unwrap(), notification);
+
+        // Channel closes when the session ends
+        manager.remove_connection(&conn_id).await;
+        assert!(rx.recv().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tasks_cancelled_on_revoke_and_replace() {
+        let manager = SessionManager::new();
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let conn_id = manager.register_connection(addr, None).await.unwrap();
+
+        let first = CancellationToken::new();
+        let second = CancellationToken::new();
+        manager.attach_task(&conn_id, first.clone()).await;
+        manager.attach_task(&conn_id, second.clone()).await;
+        assert!(first.is_cancelled(), "replaced task is cancelled");
+        assert!(!second.is_cancelled());
+
+        manager.revoke_credentials(&conn_id).await;
+        assert!(second.is_cancelled(), "revoking credentials stops the task");
+    }
+
+    #[cfg(feature = "websocket")]
+    #[tokio::test]
+    async fn test_user_stream_health_follows_session() {
+        let manager = SessionManager::new();
+        let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let conn_id = manager.register_connection(addr, None).await.unwrap();
+        assert!(manager.user_stream_health(&conn_id).await.is_none());
+
+        let status = UserStreamStatus::default();
+        manager.attach_user_stream(&conn_id, status.clone()).await;
+        status.set_connected(true);
+        let health = manager.user_stream_health(&conn_id).await.unwrap();
+        assert!(health.connected);
+        assert_eq!(health.last_event_age_ms, None);
+
+        manager.revoke_credentials(&conn_id).await;
+        assert!(manager.user_stream_health(&conn_id).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_cleanup_stale_sessions() {
         let manager = SessionManager::new();
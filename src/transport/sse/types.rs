@@ -60,7 +60,7 @@ impl SessionMetadata {
         self.last_activity = SystemTime::now();
     }
 
-    /// Checks if session has timed out (no activity for >30 seconds)
+    /// Checks if session has timed out (no activity for more than `timeout_secs`)
     pub fn is_stale(&self, timeout_secs: u64) -> bool {
         self.last_activity
             .elapsed()
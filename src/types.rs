@@ -35,6 +35,23 @@ impl Environment {
             Self::Mainnet => "https://api.binance.com",
         }
     }
+
+    /// Returns the WebSocket stream base URL for the environment
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mcp_binance_server::types::Environment;
+    ///
+    /// assert_eq!(Environment::Testnet.ws_base_url(), "wss://stream.testnet.binance.vision/ws");
+    /// assert_eq!(Environment::Mainnet.ws_base_url(), "wss://stream.binance.com:9443/ws");
+    /// ```
+    pub fn ws_base_url(&self) -> &'static str {
+        match self {
+            Self::Testnet => "wss://stream.testnet.binance.vision/ws",
+            Self::Mainnet => "wss://stream.binance.com:9443/ws",
+        }
+    }
 }
 
 impl FromStr for Environment {
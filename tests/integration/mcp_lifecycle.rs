@@ -5,8 +5,10 @@
 
 use mcp_binance_server::server::BinanceServer;
 use rmcp::handler::server::ServerHandler;
+use serial_test::serial;
 
 #[test]
+#[serial]
 fn test_server_initialization() {
     // Create server instance
     let server = BinanceServer::new();
@@ -40,6 +42,7 @@ fn test_server_initialization() {
 }
 
 #[test]
+#[serial]
 fn test_initialization_without_credentials() {
     // Unset credentials (they may be in environment)
     unsafe {
@@ -68,3 +71,38 @@ fn test_server_clone_and_concurrent_access() {
 
     assert_eq!(info1.server_info.name, info2.server_info.name);
 }
+
+#[test]
+#[serial]
+fn test_initialization_reflects_identity_overrides() {
+    unsafe {
+        std::env::set_var("MCP_SERVER_NAME", "Acme Testnet Desk");
+        std::env::set_var(
+            "MCP_SERVER_INSTRUCTIONS",
+            "Testnet only, do not place mainnet orders.",
+        );
+    }
+    let info = BinanceServer::new().get_info();
+    unsafe {
+        std::env::remove_var("MCP_SERVER_NAME");
+        std::env::remove_var("MCP_SERVER_INSTRUCTIONS");
+    }
+
+    assert_eq!(info.server_info.name, "Acme Testnet Desk");
+    assert_eq!(info.server_info.title.as_deref(), Some("Acme Testnet Desk"));
+    assert_eq!(
+        info.instructions.as_deref(),
+        Some("Testnet only, do not place mainnet orders.")
+    );
+
+    // Unset overrides fall back to the defaults
+    let info = BinanceServer::new().get_info();
+    assert_eq!(info.server_info.name, "mcp-binance-server");
+    assert_eq!(
+        info.server_info.title.as_deref(),
+        Some("Binance MCP Server")
+    );
+    assert!(info
+        .instructions
+        .is_some_and(|instructions| instructions.starts_with("Binance MCP Server")));
+}
@@ -24,9 +24,9 @@ fn test_spread_accuracy() {
 
     let metrics = calculate_metrics(&ob).expect("Should calculate metrics");
 
-    // Expected spread: ((67651 - 67650) / 67650) * 10000 = 0.1478 bps
+    // Expected spread: ((67651 - 67650) / 67650.5) * 10000 = 0.1478 bps
     let expected_spread = 0.1478;
-    let actual_spread = metrics.spread_bps;
+    let actual_spread: f64 = metrics.spread_bps.parse().unwrap();
 
     assert!(
         (actual_spread - expected_spread).abs() < 0.01,
@@ -52,7 +52,7 @@ fn test_microprice_accuracy() {
 
     // Expected microprice: (67650 * 15 + 67651 * 10) / 25 = 67650.4
     let expected_microprice = 67650.4;
-    let actual_microprice = metrics.microprice;
+    let actual_microprice: f64 = metrics.microprice.parse().unwrap();
 
     assert!(
         (actual_microprice - expected_microprice).abs() < 0.01,
@@ -1,6 +1,7 @@
 //! Integration tests for orderbook depth analysis feature
 //!
-//! Tests WebSocket connectivity, rate limiting, and metrics calculations.
+//! Tests WebSocket connectivity, rate limiting, metrics calculations, and
+//! analytics replay over stored snapshots.
 
 #[cfg(feature = "orderbook")]
 pub mod websocket_reconnection;
@@ -10,3 +11,6 @@ pub mod rate_limit;
 
 #[cfg(feature = "orderbook")]
 pub mod metrics;
+
+#[cfg(feature = "orderbook_analytics")]
+pub mod analytics_replay;
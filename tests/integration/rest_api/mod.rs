@@ -10,6 +10,7 @@ pub mod market_data;
 pub mod orders;
 
 use crate::common::{binance_client, fixtures::TestCredentials};
+use mcp_binance_server::binance::signing;
 use std::time::Duration;
 
 /// Helper: Create authenticated test client with credentials
@@ -24,23 +25,9 @@ pub fn setup_public_client() -> reqwest::Client {
     binance_client::create_public_client()
 }
 
-/// Helper: Sign request parameters for authenticated endpoints
-/// Uses HMAC-SHA256 signature as required by Binance API
-pub fn sign_request_params(query_string: &str, secret: &str) -> String {
-    use hmac::{Hmac, Mac};
-    use sha2::Sha256;
-
-    type HmacSha256 = Hmac<Sha256>;
-
-    let mut mac =
-        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
-    mac.update(query_string.as_bytes());
-
-    let result = mac.finalize();
-    hex::encode(result.into_bytes())
-}
-
 /// Helper: Build authenticated request URL with timestamp and signature
+///
+/// Signs with `binance::signing`, the implementation `BinanceClient` uses.
 pub fn build_authenticated_url(
     base_url: &str,
     endpoint: &str,
@@ -50,19 +37,17 @@ pub fn build_authenticated_url(
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
-        .as_millis();
-
-    let mut query_params: Vec<String> =
-        params.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        .as_millis()
+        .to_string();
 
-    query_params.push(format!("timestamp={}", timestamp));
-
-    let query_string = query_params.join("&");
-    let signature = sign_request_params(&query_string, secret);
+    let mut query_params = params.to_vec();
+    query_params.push(("timestamp", &timestamp));
 
     format!(
-        "{}/api/v3/{}?{}&signature={}",
-        base_url, endpoint, query_string, signature
+        "{}/api/v3/{}?{}",
+        base_url,
+        endpoint,
+        signing::signed_query(&query_params, secret)
     )
 }
 
@@ -94,17 +79,6 @@ pub async fn wait_for_rate_limit() {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_sign_request_params() {
-        let query = "symbol=BTCUSDT&timestamp=1234567890";
-        let secret = "test_secret";
-        let signature = sign_request_params(query, secret);
-
-        // Should produce consistent hex-encoded HMAC-SHA256
-        assert_eq!(signature.len(), 64); // 32 bytes = 64 hex chars
-        assert!(signature.chars().all(|c| c.is_ascii_hexdigit()));
-    }
-
     #[test]
     fn test_build_authenticated_url() {
         let url = build_authenticated_url(
@@ -11,6 +11,11 @@
 //! - T017: POST /mcp with valid Mcp-Session-Id executes tools/list
 //! - T018: Call `get_ticker` via Streamable HTTP returns valid ticker data within 2s
 //! - T019: 3 concurrent sessions all succeed and receive unique Mcp-Session-Id values
+//! - Batch of `tools/list` + `get_ticker` returns two responses correlated by id
+//! - Re-initializing with a valid Mcp-Session-Id reuses the session
+//! - Tool failures use the `isError` content envelope with a structured `error_code`
+//! - Tools disabled via `TOOLS_DISABLED` are hidden from `tools/list` and refuse calls
+//! - `prompts/list` returns the registered prompts with their arguments
 //!
 //! ## Running Tests
 //!
@@ -120,6 +125,369 @@ async fn test_initialize_returns_session_id() {
     );
 }
 
+/// Test initialize with an existing Mcp-Session-Id reuses the session
+///
+/// ## Acceptance Criteria
+///
+/// - Re-initializing with a valid Mcp-Session-Id returns the same ID
+/// - The session count does not grow
+/// - Initialize with an unknown ID still mints a new session
+#[tokio::test]
+async fn test_reinitialize_with_existing_session_reuses_it() {
+    use mcp_binance_server::server::BinanceServer;
+    use mcp_binance_server::transport::sse::{message_post, SessionManager, SseState};
+
+    let session_manager = SessionManager::new();
+    let state = SseState::new(session_manager.clone(), BinanceServer::new());
+    let app = axum::Router::new()
+        .route("/mcp", axum::routing::post(message_post))
+        .with_state(state);
+
+    let initialize = |session_id: Option<&str>| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("Content-Type", "application/json");
+        if let Some(id) = session_id {
+            request = request.header("Mcp-Session-Id", id);
+        }
+        request
+            .body(Body::from(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "initialize",
+                    "params": {
+                        "protocolVersion": "2024-11-05",
+                        "capabilities": {},
+                        "clientInfo": {"name": "test-client", "version": "1.0"}
+                    }
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+    let session_header = |response: &axum::response::Response| {
+        response.headers()["Mcp-Session-Id"]
+            .to_str()
+            .unwrap()
+            .to_string()
+    };
+
+    let first = app.clone().oneshot(initialize(None)).await.unwrap();
+    let first_id = session_header(&first);
+    assert_eq!(session_manager.connection_count().await, 1);
+
+    let again = app
+        .clone()
+        .oneshot(initialize(Some(&first_id)))
+        .await
+        .unwrap();
+    assert_eq!(again.status(), StatusCode::OK);
+    assert_eq!(session_header(&again), first_id, "Same session ID returned");
+    assert_eq!(
+        session_manager.connection_count().await,
+        1,
+        "Re-initialize must not register another session"
+    );
+
+    let unknown = app
+        .oneshot(initialize(Some("00000000-0000-4000-8000-000000000000")))
+        .await
+        .unwrap();
+    assert_ne!(session_header(&unknown), first_id);
+    assert_eq!(session_manager.connection_count().await, 2);
+}
+
+/// Test tool-level failures share one error envelope
+///
+/// ## Acceptance Criteria
+///
+/// - Bad arguments and missing credentials return `result.isError: true`
+/// - The content text carries `error_code` and `message`
+/// - Unknown methods return a top-level JSON-RPC error (-32601)
+#[tokio::test]
+async fn test_tool_errors_use_content_envelope() {
+    let app = create_test_sse_router().await;
+
+    let post = |session_id: Option<&str>, body: Value| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("Content-Type", "application/json");
+        if let Some(id) = session_id {
+            request = request.header("Mcp-Session-Id", id);
+        }
+        request.body(Body::from(body.to_string())).unwrap()
+    };
+    async fn body_json(response: axum::response::Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+    let error_body = |response: &Value| -> Value {
+        assert_eq!(response["result"]["isError"], true, "{}", response);
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    };
+
+    let init = app
+        .clone()
+        .oneshot(post(
+            None,
+            json!({
+                "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": {"protocolVersion": "2024-11-05", "capabilities": {},
+                           "clientInfo": {"name": "test-client", "version": "1.0"}}
+            }),
+        ))
+        .await
+        .unwrap();
+    let session_id = init.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    // Bad params: get_ticker without a symbol
+    let response = body_json(
+        app.clone()
+            .oneshot(post(
+                Some(&session_id),
+                json!({
+                    "jsonrpc": "2.0", "id": 2, "method": "tools/call",
+                    "params": {"name": "get_ticker", "arguments": {}}
+                }),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+    assert!(response.get("error").is_none());
+    let error = error_body(&response);
+    assert_eq!(error["error_code"], "INVALID_PARAMS");
+    assert!(error["message"].as_str().unwrap().contains("symbol"));
+
+    // Credentials not configured for this session
+    let response = body_json(
+        app.clone()
+            .oneshot(post(
+                Some(&session_id),
+                json!({
+                    "jsonrpc": "2.0", "id": 3, "method": "tools/call",
+                    "params": {"name": "get_account_info", "arguments": {}}
+                }),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+    let error = error_body(&response);
+    assert_eq!(error["error_code"], "CREDENTIALS_NOT_CONFIGURED");
+    assert!(error["message"].is_string());
+
+    // Unknown method is a protocol error
+    let response = body_json(
+        app.oneshot(post(
+            Some(&session_id),
+            json!({"jsonrpc": "2.0", "id": 4, "method": "tools/unknown"}),
+        ))
+        .await
+        .unwrap(),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32601);
+    assert!(response.get("result").is_none());
+}
+
+/// Test that a disabled tool is hidden and cannot be called
+///
+/// ## Acceptance Criteria
+///
+/// - `tools/list` omits `place_order` when it is disabled
+/// - Calling `place_order` anyway returns `isError` with `TOOL_DISABLED`
+#[tokio::test]
+async fn test_disabled_tool_is_hidden_and_blocked() {
+    use mcp_binance_server::config::ToolFilter;
+    use mcp_binance_server::server::BinanceServer;
+    use mcp_binance_server::transport::sse::{message_post, SessionManager, SseState};
+
+    let mut mcp_server = BinanceServer::new();
+    mcp_server.apply_tool_filter(ToolFilter::parse(None, Some("place_order")));
+    let app = axum::Router::new()
+        .route("/mcp", axum::routing::post(message_post))
+        .with_state(SseState::new(SessionManager::new(), mcp_server));
+
+    let post = |session_id: Option<&str>, body: Value| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("Content-Type", "application/json");
+        if let Some(id) = session_id {
+            request = request.header("Mcp-Session-Id", id);
+        }
+        request.body(Body::from(body.to_string())).unwrap()
+    };
+    async fn body_json(response: axum::response::Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    let init = app
+        .clone()
+        .oneshot(post(
+            None,
+            json!({
+                "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": {"protocolVersion": "2024-11-05", "capabilities": {},
+                           "clientInfo": {"name": "test-client", "version": "1.0"}}
+            }),
+        ))
+        .await
+        .unwrap();
+    let session_id = init.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let listing = body_json(
+        app.clone()
+            .oneshot(post(
+                Some(&session_id),
+                json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+    let names: Vec<&str> = listing["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    assert!(names.contains(&"get_ticker"), "{:?}", names);
+    assert!(!names.contains(&"place_order"), "{:?}", names);
+
+    let response = body_json(
+        app.oneshot(post(
+            Some(&session_id),
+            json!({
+                "jsonrpc": "2.0", "id": 3, "method": "tools/call",
+                "params": {"name": "place_order", "arguments": {
+                    "symbol": "BTCUSDT", "side": "BUY", "order_type": "MARKET",
+                    "quantity": "0.001"
+                }}
+            }),
+        ))
+        .await
+        .unwrap(),
+    )
+    .await;
+    assert_eq!(response["result"]["isError"], true, "{}", response);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let error: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(error["error_code"], "TOOL_DISABLED");
+}
+
+/// Test that prompts are discoverable over Streamable HTTP
+///
+/// ## Acceptance Criteria
+///
+/// - `initialize` advertises the prompts capability
+/// - `prompts/list` returns every registered prompt with its arguments
+/// - `prompts/get` for an unknown prompt is a JSON-RPC invalid params error
+#[tokio::test]
+async fn test_prompts_list_and_get() {
+    let app = create_test_sse_router().await;
+
+    let post = |session_id: Option<&str>, body: Value| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/mcp")
+            .header("Content-Type", "application/json");
+        if let Some(id) = session_id {
+            request = request.header("Mcp-Session-Id", id);
+        }
+        request.body(Body::from(body.to_string())).unwrap()
+    };
+    async fn body_json(response: axum::response::Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    let init = app
+        .clone()
+        .oneshot(post(
+            None,
+            json!({
+                "jsonrpc": "2.0", "id": 1, "method": "initialize",
+                "params": {"protocolVersion": "2024-11-05", "capabilities": {},
+                           "clientInfo": {"name": "test-client", "version": "1.0"}}
+            }),
+        ))
+        .await
+        .unwrap();
+    let session_id = init.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let init = body_json(init).await;
+    assert!(init["result"]["capabilities"]["prompts"].is_object());
+
+    let response = body_json(
+        app.clone()
+            .oneshot(post(
+                Some(&session_id),
+                json!({"jsonrpc": "2.0", "id": 2, "method": "prompts/list", "params": {}}),
+            ))
+            .await
+            .unwrap(),
+    )
+    .await;
+    let prompts = response["result"]["prompts"].as_array().unwrap();
+    let names: Vec<&str> = prompts
+        .iter()
+        .map(|p| p["name"].as_str().unwrap())
+        .collect();
+    for expected in ["trading_analysis", "portfolio_risk", "pre_trade_check"] {
+        assert!(
+            names.contains(&expected),
+            "missing {}: {:?}",
+            expected,
+            names
+        );
+    }
+    let trading_analysis = prompts
+        .iter()
+        .find(|p| p["name"] == "trading_analysis")
+        .unwrap();
+    let symbol_arg = trading_analysis["arguments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|a| a["name"] == "symbol")
+        .expect("trading_analysis should take a symbol argument");
+    // Optional: falls back to DEFAULT_SYMBOL
+    assert_ne!(symbol_arg["required"], true);
+
+    let response = body_json(
+        app.oneshot(post(
+            Some(&session_id),
+            json!({"jsonrpc": "2.0", "id": 3, "method": "prompts/get",
+                   "params": {"name": "no_such_prompt"}}),
+        ))
+        .await
+        .unwrap(),
+    )
+    .await;
+    assert_eq!(response["error"]["code"], -32602);
+}
+
 /// T017: Test POST /mcp with valid Mcp-Session-Id executes tools/list
 ///
 /// ## Acceptance Criteria (Streamable HTTP spec)
@@ -351,6 +719,100 @@ async fn test_get_ticker_via_streamable_http_returns_valid_data() {
     );
 }
 
+/// Test JSON-RPC batch requests return one response per request, in order
+///
+/// ## Acceptance Criteria
+///
+/// - A JSON array body is processed as a batch under one Mcp-Session-Id
+/// - The response is an array with one JSON-RPC response per request, in order
+/// - Response ids match request ids
+/// - A malformed element gets its own error without failing the batch
+#[tokio::test]
+async fn test_batch_request_returns_correlated_responses() {
+    let app = create_test_sse_router().await;
+
+    let initialize_request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": {"name": "test", "version": "1.0"}
+        }
+    });
+
+    let init_response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/mcp")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::to_string(&initialize_request).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let session_id = init_response
+        .headers()
+        .get("Mcp-Session-Id")
+        .expect("Initialize should return session ID")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let batch = json!([
+        {"jsonrpc": "2.0", "id": "list", "method": "tools/list", "params": {}},
+        {
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": "tools/call",
+            "params": {"name": "get_ticker", "arguments": {"symbol": "BTCUSDT"}}
+        },
+        {"jsonrpc": "2.0", "id": 8}
+    ]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/mcp")
+                .header("Content-Type", "application/json")
+                .header("Mcp-Session-Id", &session_id)
+                .body(Body::from(serde_json::to_string(&batch).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let responses: Value = serde_json::from_slice(&body_bytes).unwrap();
+    let responses = responses.as_array().expect("Batch should return an array");
+    assert_eq!(responses.len(), 3, "One response per batched request");
+
+    // tools/list
+    assert_eq!(responses[0]["id"], "list");
+    assert!(responses[0]["result"]["tools"]
+        .as_array()
+        .is_some_and(|tools| tools.iter().any(|t| t["name"] == "get_ticker")));
+
+    // get_ticker: success or an isolated tool error, but always correlated
+    assert_eq!(responses[1]["id"], 7);
+    assert!(responses[1]["result"]["content"].is_array());
+
+    // Malformed element fails on its own
+    assert_eq!(responses[2]["id"], 8);
+    assert_eq!(responses[2]["error"]["code"], -32600);
+}
+
 /// T019: Test 3 concurrent sessions all succeed and receive unique Mcp-Session-Id values
 ///
 /// ## Acceptance Criteria (Streamable HTTP spec)
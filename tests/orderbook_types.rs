@@ -2,7 +2,7 @@
 //!
 //! Tests serialization, compact integer encoding, and core OrderBook operations.
 
-use mcp_binance_server::orderbook::types::{OrderBook, OrderBookDepth};
+use mcp_binance_server::orderbook::types::{OrderBook, OrderBookDepth, SnapshotFreshness};
 use rust_decimal::Decimal;
 use std::str::FromStr;
 
@@ -143,6 +143,7 @@ fn test_orderbook_depth_compact_encoding() {
         qty_scale: 100_000,
         bids: vec![[6765000, 123400], [6764950, 45600]],
         asks: vec![[6765100, 98700], [6765150, 40000]],
+        freshness: SnapshotFreshness::default(),
     };
 
     // Verify scaling
@@ -170,6 +171,7 @@ fn test_orderbook_depth_decoding_price() {
         qty_scale: 100_000,
         bids: vec![[6765000, 123400]],
         asks: vec![],
+        freshness: SnapshotFreshness::default(),
     };
 
     // Decode price: 6765000 / 100 = 67650.00
@@ -188,6 +190,7 @@ fn test_orderbook_depth_decoding_quantity() {
         qty_scale: 100_000,
         bids: vec![[6765000, 123400]],
         asks: vec![],
+        freshness: SnapshotFreshness::default(),
     };
 
     // Decode quantity: 123400 / 100000 = 1.23400
@@ -206,6 +209,7 @@ fn test_orderbook_depth_empty() {
         qty_scale: 100_000,
         bids: vec![],
         asks: vec![],
+        freshness: SnapshotFreshness::default(),
     };
 
     assert!(depth.bids.is_empty());
@@ -222,6 +226,7 @@ fn test_orderbook_depth_json_size_reduction() {
         qty_scale: 100_000,
         bids: vec![[6765000, 123400]],
         asks: vec![[6765100, 98700]],
+        freshness: SnapshotFreshness::default(),
     };
 
     // Simulate uncompressed format (strings)